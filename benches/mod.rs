@@ -158,6 +158,33 @@ mod suite {
         });
     }
 
+    pub(super) fn simple_sched_getcpu(c: &mut Criterion) {
+        // On Linux, this uses the vDSO where available.
+        #[cfg(linux_kernel)]
+        {
+            use rustix::process::sched_getcpu;
+
+            c.bench_function("simple sched_getcpu", |b| {
+                b.iter(|| {
+                    let _ = sched_getcpu();
+                })
+            });
+        }
+        #[cfg(not(linux_kernel))]
+        let _ = c;
+    }
+
+    pub(super) fn simple_sched_getcpu_libc(c: &mut Criterion) {
+        #[cfg(linux_kernel)]
+        c.bench_function("simple sched_getcpu libc", |b| {
+            b.iter(|| unsafe {
+                let _ = libc::sched_getcpu();
+            })
+        });
+        #[cfg(not(linux_kernel))]
+        let _ = c;
+    }
+
     #[cfg(not(target_os = "wasi"))]
     pub(super) fn simple_getpid(c: &mut Criterion) {
         use rustix::process::getpid;
@@ -200,6 +227,8 @@ criterion_group!(
     suite::simple_fstat_libc,
     suite::simple_clock_gettime,
     suite::simple_clock_gettime_libc,
+    suite::simple_sched_getcpu,
+    suite::simple_sched_getcpu_libc,
     suite::simple_getpid,
     suite::simple_getpid_libc
 );
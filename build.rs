@@ -82,6 +82,12 @@ fn main() {
         use_feature_or_nothing("wasi_ext");
     }
 
+    // Abstract Unix-domain socket addresses in std's `SocketAddr` need Rust
+    // 1.70.
+    if (os == "linux" || os == "android") && has_socket_addr_ext(&os) {
+        use_feature("unix_socket_abstract");
+    }
+
     // If the libc backend is requested, or if we're not on a platform for
     // which we have linux_raw support, use the libc backend.
     //
@@ -94,7 +100,8 @@ fn main() {
         || !inline_asm_name_present
         || is_unsupported_abi
         || miri
-        || ((arch == "powerpc64" || arch.starts_with("mips")) && !rustix_use_experimental_asm);
+        || ((arch == "powerpc64" || arch == "s390x" || arch.starts_with("mips"))
+            && !rustix_use_experimental_asm);
     if libc {
         // Use the libc backend.
         use_feature("libc");
@@ -174,6 +181,11 @@ fn use_static_assertions() -> bool {
     can_compile("const unsafe fn foo(p: *const u8) -> isize { p.offset_from(p) }")
 }
 
+fn has_socket_addr_ext(os: &str) -> bool {
+    // `SocketAddrExt` was stabilized in Rust 1.70.
+    can_compile(format!("use std::os::{}::net::SocketAddrExt;", os))
+}
+
 fn use_thumb_mode() -> bool {
     // In thumb mode, r7 is reserved.
     !can_compile("pub unsafe fn f() { core::arch::asm!(\"udf #16\", in(\"r7\") 0); }")
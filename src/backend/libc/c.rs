@@ -63,6 +63,30 @@ pub(crate) const ETH_P_XDSA: c_int = linux_raw_sys::if_ether::ETH_P_XDSA as _;
 pub(crate) const ETH_P_MAP: c_int = linux_raw_sys::if_ether::ETH_P_MAP as _;
 #[cfg(all(linux_kernel, feature = "net"))]
 pub(crate) const ETH_P_MCTP: c_int = linux_raw_sys::if_ether::ETH_P_MCTP as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_PEERSEC: c_int = linux_raw_sys::net::SO_PEERSEC as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_PASSSEC: c_int = linux_raw_sys::net::SO_PASSSEC as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SCM_SECURITY: c_int = linux_raw_sys::net::SCM_SECURITY as _;
+#[cfg(all(target_os = "linux", feature = "net"))]
+pub(crate) const SO_BUSY_POLL: c_int = linux_raw_sys::net::SO_BUSY_POLL as _;
+#[cfg(all(target_os = "linux", feature = "net"))]
+pub(crate) const SO_PREFER_BUSY_POLL: c_int = linux_raw_sys::net::SO_PREFER_BUSY_POLL as _;
+#[cfg(all(target_os = "linux", feature = "net"))]
+pub(crate) const SO_INCOMING_NAPI_ID: c_int = linux_raw_sys::net::SO_INCOMING_NAPI_ID as _;
+#[cfg(all(target_os = "linux", feature = "net"))]
+pub(crate) const SO_RCVBUFFORCE: c_int = linux_raw_sys::net::SO_RCVBUFFORCE as _;
+#[cfg(all(target_os = "linux", feature = "net"))]
+pub(crate) const SO_SNDBUFFORCE: c_int = linux_raw_sys::net::SO_SNDBUFFORCE as _;
+#[cfg(all(target_os = "linux", feature = "net"))]
+pub(crate) const SO_ATTACH_REUSEPORT_CBPF: c_int =
+    linux_raw_sys::net::SO_ATTACH_REUSEPORT_CBPF as _;
+#[cfg(all(target_os = "linux", feature = "net"))]
+pub(crate) const SO_ATTACH_REUSEPORT_EBPF: c_int =
+    linux_raw_sys::net::SO_ATTACH_REUSEPORT_EBPF as _;
+#[cfg(all(target_os = "linux", feature = "net"))]
+pub(crate) const SO_DETACH_REUSEPORT_BPF: c_int = linux_raw_sys::net::SO_DETACH_REUSEPORT_BPF as _;
 
 #[cfg(all(
     linux_kernel,
@@ -151,6 +175,29 @@ pub(super) use libc::{
     rlimit64 as rlimit, setrlimit64 as setrlimit, statfs64 as statfs, statvfs64 as statvfs,
     RLIM64_INFINITY as RLIM_INFINITY,
 };
+// On 32-bit glibc and Android, `struct flock` has a 32-bit `off_t`, and
+// the libc crate's `F_GETLK` etc. are the non-LFS commands, so use `flock64`
+// and the 64-bit commands. musl's `struct flock` is always 64-bit.
+#[cfg(all(
+    any(target_os = "android", all(target_os = "linux", target_env = "gnu")),
+    target_pointer_width = "32",
+))]
+pub(super) use libc::flock64 as flock;
+#[cfg(all(
+    any(target_os = "android", all(target_os = "linux", target_env = "gnu")),
+    target_pointer_width = "32",
+))]
+pub(super) const F_GETLK: c_int = linux_raw_sys::general::F_GETLK64 as _;
+#[cfg(all(
+    any(target_os = "android", all(target_os = "linux", target_env = "gnu")),
+    target_pointer_width = "32",
+))]
+pub(super) const F_SETLK: c_int = linux_raw_sys::general::F_SETLK64 as _;
+#[cfg(all(
+    any(target_os = "android", all(target_os = "linux", target_env = "gnu")),
+    target_pointer_width = "32",
+))]
+pub(super) const F_SETLKW: c_int = linux_raw_sys::general::F_SETLKW64 as _;
 #[cfg(apple)]
 pub(super) use libc::{
     host_info64_t as host_info_t, host_statistics64 as host_statistics,
@@ -136,7 +136,7 @@ pub(super) fn ret_discarded_fd(raw: LibcFd) -> io::Result<()> {
     }
 }
 
-#[cfg(all(feature = "alloc", not(any(windows, target_os = "wasi"))))]
+#[cfg(all(feature = "fs", not(any(windows, target_os = "wasi"))))]
 #[inline]
 pub(super) fn ret_discarded_char_ptr(raw: *mut c::c_char) -> io::Result<()> {
     if raw.is_null() {
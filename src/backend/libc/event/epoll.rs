@@ -84,6 +84,9 @@ use core::hash::{Hash, Hasher};
 use core::ptr::null_mut;
 use core::slice;
 
+#[cfg(feature = "alloc")]
+pub use crate::event::epoll_poller::{Poller, PollerEvents, Token};
+
 bitflags! {
     /// `EPOLL_*` for use with [`new`].
     #[repr(transparent)]
@@ -8,6 +8,12 @@ use crate::event::PollFd;
 #[cfg(any(linux_kernel, bsd, solarish, target_os = "espidf"))]
 use crate::fd::OwnedFd;
 use crate::io;
+#[cfg(all(linux_kernel, not(fix_y2038)))]
+use crate::timespec::to_libc_timespec;
+#[cfg(all(linux_kernel, fix_y2038, target_env = "gnu"))]
+use crate::timespec::LibcTimespec;
+#[cfg(linux_kernel)]
+use crate::timespec::Timespec;
 #[cfg(any(bsd, solarish))]
 use {crate::backend::conv::borrowed_fd, crate::fd::BorrowedFd, core::mem::MaybeUninit};
 #[cfg(solarish)]
@@ -100,6 +106,68 @@ pub(crate) fn poll(fds: &mut [PollFd<'_>], timeout: c::c_int) -> io::Result<usiz
         .map(|nready| nready as usize)
 }
 
+#[cfg(all(linux_kernel, fix_y2038, target_env = "gnu"))]
+weak!(fn __ppoll64(*mut c::pollfd, c::nfds_t, *const LibcTimespec, *const c::sigset_t) -> c::c_int);
+
+#[cfg(linux_kernel)]
+pub(crate) fn ppoll(fds: &mut [PollFd<'_>], timeout: Option<&Timespec>) -> io::Result<usize> {
+    let nfds = fds
+        .len()
+        .try_into()
+        .map_err(|_convert_err| io::Errno::INVAL)?;
+
+    // Old 32-bit version: libc has `ppoll` but it is not y2038 safe by
+    // default. But there may be a `__ppoll64` we can use.
+    #[cfg(fix_y2038)]
+    {
+        #[cfg(target_env = "gnu")]
+        if let Some(libc_ppoll) = __ppoll64.get() {
+            let timeout: Option<LibcTimespec> = timeout.map(|timeout| timeout.clone().into());
+            return ret_c_int(unsafe {
+                libc_ppoll(
+                    fds.as_mut_ptr().cast(),
+                    nfds,
+                    timeout.as_ref().map_or(core::ptr::null(), |t| t),
+                    core::ptr::null(),
+                )
+            })
+            .map(|nready| nready as usize);
+        }
+
+        let old_timeout = match timeout {
+            Some(timeout) => Some(c::timespec {
+                tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::OVERFLOW)?,
+                tv_nsec: timeout.tv_nsec.try_into().map_err(|_| io::Errno::INVAL)?,
+            }),
+            None => None,
+        };
+        ret_c_int(unsafe {
+            c::ppoll(
+                fds.as_mut_ptr().cast(),
+                nfds,
+                old_timeout.as_ref().map_or(core::ptr::null(), |t| t),
+                core::ptr::null(),
+            )
+        })
+        .map(|nready| nready as usize)
+    }
+
+    // Main version: libc is y2038 safe and has `ppoll`.
+    #[cfg(not(fix_y2038))]
+    {
+        let timeout = timeout.map(to_libc_timespec).transpose()?;
+        ret_c_int(unsafe {
+            c::ppoll(
+                fds.as_mut_ptr().cast(),
+                nfds,
+                crate::utils::option_as_ptr(timeout.as_ref()),
+                core::ptr::null(),
+            )
+        })
+        .map(|nready| nready as usize)
+    }
+}
+
 #[cfg(solarish)]
 pub(crate) fn port_create() -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(c::port_create()) }
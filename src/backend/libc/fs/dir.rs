@@ -50,6 +50,9 @@ pub struct Dir {
 impl Dir {
     /// Take ownership of `fd` and construct a `Dir` that reads entries from
     /// the given directory file descriptor.
+    ///
+    /// This fails with [`io::Errno::NOTDIR`] if `fd` isn't a directory, as
+    /// `fdopendir` checks up front.
     #[inline]
     pub fn new<Fd: Into<OwnedFd>>(fd: Fd) -> io::Result<Self> {
         Self::_new(fd.into())
@@ -182,6 +185,23 @@ impl Dir {
         }
     }
 
+    /// `dirfd(self)`—Borrow the file descriptor this `Dir` reads from.
+    ///
+    /// The file descriptor's file offset is used by the iteration, so it
+    /// shouldn't be used for seeking or reading directory entries.
+    #[inline]
+    #[doc(alias = "dirfd")]
+    pub fn fd(&self) -> io::Result<BorrowedFd<'_>> {
+        unsafe {
+            let raw = c::dirfd(self.libc_dir.as_ptr());
+            if raw < 0 {
+                Err(io::Errno::last_os_error())
+            } else {
+                Ok(BorrowedFd::borrow_raw(raw))
+            }
+        }
+    }
+
     /// `fstat(self)`
     #[cfg(not(target_os = "vita"))]
     #[inline]
@@ -287,6 +307,13 @@ impl DirEntry {
     }
 
     /// Returns the type of this directory entry.
+    ///
+    /// This is [`FileType::Unknown`] on filesystems which don't record file
+    /// types in their directory entries. Use [`statat`] with
+    /// [`AtFlags::SYMLINK_NOFOLLOW`] to determine the type in that case.
+    ///
+    /// [`statat`]: crate::fs::statat
+    /// [`AtFlags::SYMLINK_NOFOLLOW`]: crate::fs::AtFlags::SYMLINK_NOFOLLOW
     #[cfg(not(any(
         solarish,
         target_os = "aix",
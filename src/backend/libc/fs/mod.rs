@@ -1,7 +1,7 @@
 #[cfg(all(feature = "alloc", not(any(target_os = "espidf", target_os = "redox"))))]
 pub(crate) mod dir;
 #[cfg(linux_kernel)]
-pub mod inotify;
+pub(crate) mod inotify;
 #[cfg(not(any(
     target_os = "espidf",
     target_os = "haiku",
@@ -56,6 +56,8 @@ use crate::fs::SealFlags;
 use crate::fs::StatFs;
 #[cfg(not(any(target_os = "espidf", target_os = "vita")))]
 use crate::fs::Timestamps;
+#[cfg(linux_kernel)]
+use crate::fs::{inotify, DnotifyFlags, LeaseType};
 #[cfg(not(any(
     apple,
     target_os = "espidf",
@@ -64,10 +66,21 @@ use crate::fs::Timestamps;
     target_os = "wasi"
 )))]
 use crate::fs::{Dev, FileType};
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+use crate::fs::{Flock, FlockType};
 use crate::fs::{Mode, OFlags, SeekFrom, Stat};
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 use crate::fs::{StatVfs, StatVfsMountFlags};
 use crate::io;
+#[cfg(not(any(fix_y2038, target_os = "espidf", target_os = "vita")))]
+use crate::timespec::to_libc_timespec;
 #[cfg(all(target_env = "gnu", fix_y2038))]
 use crate::timespec::LibcTimespec;
 #[cfg(not(target_os = "wasi"))]
@@ -75,6 +88,8 @@ use crate::ugid::{Gid, Uid};
 #[cfg(all(apple, feature = "alloc"))]
 use alloc::vec;
 use core::mem::MaybeUninit;
+#[cfg(freebsdlike)]
+use core::ptr::null_mut;
 #[cfg(apple)]
 use {
     crate::backend::conv::nonnegative_ret,
@@ -273,9 +288,8 @@ pub(crate) fn statvfs(filename: &CStr) -> io::Result<StatVfs> {
     }
 }
 
-#[cfg(feature = "alloc")]
 #[inline]
-pub(crate) fn readlink(path: &CStr, buf: &mut [u8]) -> io::Result<usize> {
+pub(crate) fn readlink(path: &CStr, buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
     unsafe {
         ret_usize(
             c::readlink(c_str(path), buf.as_mut_ptr().cast::<c::c_char>(), buf.len()) as isize,
@@ -838,12 +852,12 @@ pub(crate) fn utimensat(
     // is not y2038 safe and there's nothing practical we can do.
     #[cfg(not(any(apple, fix_y2038)))]
     unsafe {
-        use crate::utils::as_ptr;
+        let libc_times = to_libc_timestamps(times)?;
 
         ret(c::utimensat(
             borrowed_fd(dirfd),
             c_str(path),
-            as_ptr(times).cast(),
+            libc_times.as_ptr(),
             bitflags_bits!(flags),
         ))
     }
@@ -875,10 +889,12 @@ pub(crate) fn utimensat(
 
         // If we have `utimensat`, use it.
         if let Some(have_utimensat) = utimensat.get() {
+            let libc_times = to_libc_timestamps(times)?;
+
             return ret(have_utimensat(
                 borrowed_fd(dirfd),
                 c_str(path),
-                as_ptr(times).cast(),
+                libc_times.as_ptr(),
                 bitflags_bits!(flags),
             ));
         }
@@ -1055,11 +1071,39 @@ pub(crate) fn chmodat(
             mode: c::mode_t
         ) via SYS_fchmodat -> c::c_int
     }
-    if flags == AtFlags::SYMLINK_NOFOLLOW {
-        return Err(io::Errno::OPNOTSUPP);
+    if !flags
+        .difference(AtFlags::SYMLINK_NOFOLLOW | AtFlags::EMPTY_PATH)
+        .is_empty()
+    {
+        return Err(io::Errno::INVAL);
     }
+
+    // If we have any flags, use the newer `fchmodat2` introduced in Linux
+    // 6.6 which supports them. Unless we're on Android where using newer
+    // system calls can cause seccomp to abort the process.
+    #[cfg(not(target_os = "android"))]
     if !flags.is_empty() {
-        return Err(io::Errno::INVAL);
+        match unsafe {
+            ret(c::syscall(
+                SYS_FCHMODAT2,
+                borrowed_fd(dirfd),
+                c_str(path),
+                mode.bits() as c::mode_t,
+                bitflags_bits!(flags),
+            ) as c::c_int)
+        } {
+            Ok(()) => return Ok(()),
+            Err(io::Errno::NOSYS) => {}
+            Err(other) => return Err(other),
+        }
+    }
+
+    // An empty path with `AT_EMPTY_PATH` refers to `dirfd` itself.
+    if flags.contains(AtFlags::EMPTY_PATH) && path.to_bytes().is_empty() {
+        return fchmod(dirfd, mode);
+    }
+    if flags.contains(AtFlags::SYMLINK_NOFOLLOW) {
+        return Err(io::Errno::OPNOTSUPP);
     }
     unsafe {
         ret(fchmodat(
@@ -1070,6 +1114,39 @@ pub(crate) fn chmodat(
     }
 }
 
+/// The `fchmodat2` syscall number, which the libc crate doesn't define on
+/// all targets.
+#[cfg(all(linux_kernel, not(target_os = "android")))]
+const SYS_FCHMODAT2: c::c_long = {
+    #[cfg(any(target_arch = "mips", target_arch = "mips32r6"))]
+    {
+        4452
+    }
+    #[cfg(all(
+        any(target_arch = "mips64", target_arch = "mips64r6"),
+        target_pointer_width = "64"
+    ))]
+    {
+        5452
+    }
+    #[cfg(all(
+        any(target_arch = "mips64", target_arch = "mips64r6"),
+        target_pointer_width = "32"
+    ))]
+    {
+        6452
+    }
+    #[cfg(not(any(
+        target_arch = "mips",
+        target_arch = "mips32r6",
+        target_arch = "mips64",
+        target_arch = "mips64r6"
+    )))]
+    {
+        452
+    }
+};
+
 #[cfg(apple)]
 pub(crate) fn fclonefileat(
     srcfd: BorrowedFd<'_>,
@@ -1096,6 +1173,35 @@ pub(crate) fn fclonefileat(
     }
 }
 
+#[cfg(apple)]
+pub(crate) fn clonefileat(
+    src_dirfd: BorrowedFd<'_>,
+    src: &CStr,
+    dst_dirfd: BorrowedFd<'_>,
+    dst: &CStr,
+    flags: CloneFlags,
+) -> io::Result<()> {
+    syscall! {
+        fn clonefileat(
+            src_dirfd: BorrowedFd<'_>,
+            src: *const c::c_char,
+            dst_dirfd: BorrowedFd<'_>,
+            dst: *const c::c_char,
+            flags: c::c_int
+        ) via SYS_clonefileat -> c::c_int
+    }
+
+    unsafe {
+        ret(clonefileat(
+            src_dirfd,
+            c_str(src),
+            dst_dirfd,
+            c_str(dst),
+            bitflags_bits!(flags),
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "espidf", target_os = "redox", target_os = "wasi")))]
 pub(crate) fn chownat(
     dirfd: BorrowedFd<'_>,
@@ -1140,7 +1246,7 @@ pub(crate) fn mknodat(
     }
 }
 
-#[cfg(linux_kernel)]
+#[cfg(any(linux_kernel, target_os = "freebsd"))]
 pub(crate) fn copy_file_range(
     fd_in: BorrowedFd<'_>,
     off_in: Option<&mut u64>,
@@ -1148,6 +1254,7 @@ pub(crate) fn copy_file_range(
     off_out: Option<&mut u64>,
     len: usize,
 ) -> io::Result<usize> {
+    #[cfg(linux_kernel)]
     syscall! {
         fn copy_file_range(
             fd_in: c::c_int,
@@ -1159,8 +1266,26 @@ pub(crate) fn copy_file_range(
         ) via SYS_copy_file_range -> c::ssize_t
     }
 
-    let mut off_in_val: c::loff_t = 0;
-    let mut off_out_val: c::loff_t = 0;
+    // FreeBSD 13 added `copy_file_range`.
+    #[cfg(target_os = "freebsd")]
+    weak! {
+        fn copy_file_range(
+            c::c_int,
+            *mut c::off_t,
+            c::c_int,
+            *mut c::off_t,
+            usize,
+            c::c_uint
+        ) -> c::ssize_t
+    }
+
+    #[cfg(linux_kernel)]
+    let func = copy_file_range;
+    #[cfg(target_os = "freebsd")]
+    let func = copy_file_range.get().ok_or(io::Errno::NOSYS)?;
+
+    let mut off_in_val: i64 = 0;
+    let mut off_out_val: i64 = 0;
     // Silently cast; we'll get `EINVAL` if the value is negative.
     let off_in_ptr = if let Some(off_in) = &off_in {
         off_in_val = **off_in as i64;
@@ -1174,8 +1299,9 @@ pub(crate) fn copy_file_range(
     } else {
         null_mut()
     };
+
     let copied = unsafe {
-        ret_usize(copy_file_range(
+        ret_usize(func(
             borrowed_fd(fd_in),
             off_in_ptr,
             borrowed_fd(fd_out),
@@ -1253,6 +1379,37 @@ pub(crate) fn fcntl_add_seals(fd: BorrowedFd<'_>, seals: SealFlags) -> io::Resul
     unsafe { ret(c::fcntl(borrowed_fd(fd), c::F_ADD_SEALS, seals.bits())) }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn fcntl_setlease(fd: BorrowedFd<'_>, lease: Option<LeaseType>) -> io::Result<()> {
+    let arg = match lease {
+        Some(lease) => lease as c::c_int,
+        None => c::F_UNLCK as c::c_int,
+    };
+    unsafe { ret(c::fcntl(borrowed_fd(fd), c::F_SETLEASE, arg)) }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn fcntl_getlease(fd: BorrowedFd<'_>) -> io::Result<Option<LeaseType>> {
+    let lease = unsafe { ret_c_int(c::fcntl(borrowed_fd(fd), c::F_GETLEASE))? };
+    match lease {
+        _ if lease == c::F_RDLCK as c::c_int => Ok(Some(LeaseType::Read)),
+        _ if lease == c::F_WRLCK as c::c_int => Ok(Some(LeaseType::Write)),
+        _ if lease == c::F_UNLCK as c::c_int => Ok(None),
+        _ => Err(io::Errno::INVAL),
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn fcntl_notify(fd: BorrowedFd<'_>, flags: DnotifyFlags) -> io::Result<()> {
+    unsafe {
+        ret(c::fcntl(
+            borrowed_fd(fd),
+            c::F_NOTIFY,
+            flags.bits() as c::c_int,
+        ))
+    }
+}
+
 #[cfg(not(any(
     target_os = "emscripten",
     target_os = "espidf",
@@ -1289,6 +1446,133 @@ pub(crate) fn fcntl_lock(fd: BorrowedFd<'_>, operation: FlockOperation) -> io::R
     }
 }
 
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+#[inline]
+pub(crate) fn fcntl_setlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    fcntl_set_record_lock(fd, c::F_SETLK, lock)
+}
+
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+#[inline]
+pub(crate) fn fcntl_setlkw(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    fcntl_set_record_lock(fd, c::F_SETLKW, lock)
+}
+
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+#[inline]
+pub(crate) fn fcntl_getlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<Option<Flock>> {
+    fcntl_get_record_lock(fd, c::F_GETLK, lock)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn fcntl_ofd_setlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    fcntl_set_record_lock(fd, c::F_OFD_SETLK, lock)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn fcntl_ofd_setlkw(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    fcntl_set_record_lock(fd, c::F_OFD_SETLKW, lock)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn fcntl_ofd_getlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<Option<Flock>> {
+    fcntl_get_record_lock(fd, c::F_OFD_GETLK, lock)
+}
+
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+fn fcntl_set_record_lock(fd: BorrowedFd<'_>, cmd: c::c_int, lock: &Flock) -> io::Result<()> {
+    let lock = flock_to_raw(lock)?;
+    unsafe { ret(c::fcntl(borrowed_fd(fd), cmd, &lock)) }
+}
+
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+fn fcntl_get_record_lock(
+    fd: BorrowedFd<'_>,
+    cmd: c::c_int,
+    lock: &Flock,
+) -> io::Result<Option<Flock>> {
+    let mut lock = flock_to_raw(lock)?;
+    unsafe { ret(c::fcntl(borrowed_fd(fd), cmd, &mut lock))? };
+
+    let typ = if lock.l_type == c::F_RDLCK as _ {
+        FlockType::ReadLock
+    } else if lock.l_type == c::F_WRLCK as _ {
+        FlockType::WriteLock
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(Flock {
+        typ,
+        start: lock.l_start as u64,
+        len: lock.l_len as u64,
+        pid: crate::pid::Pid::from_raw(lock.l_pid),
+    }))
+}
+
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+fn flock_to_raw(lock: &Flock) -> io::Result<c::flock> {
+    let l_type = match lock.typ {
+        FlockType::ReadLock => c::F_RDLCK,
+        FlockType::WriteLock => c::F_WRLCK,
+        FlockType::Unlocked => c::F_UNLCK,
+    };
+    unsafe {
+        let mut raw: c::flock = core::mem::zeroed();
+        raw.l_type = l_type as _;
+        raw.l_whence = c::SEEK_SET as _;
+        raw.l_start = lock.start.try_into().map_err(|_| io::Errno::INVAL)?;
+        raw.l_len = lock.len.try_into().map_err(|_| io::Errno::INVAL)?;
+
+        // `l_pid` must be zero for open file description locks.
+        Ok(raw)
+    }
+}
+
 pub(crate) fn seek(fd: BorrowedFd<'_>, pos: SeekFrom) -> io::Result<u64> {
     let (whence, offset) = match pos {
         SeekFrom::Start(pos) => {
@@ -1537,9 +1821,9 @@ pub(crate) fn futimens(fd: BorrowedFd<'_>, times: &Timestamps) -> io::Result<()>
     // is not y2038 safe and there's nothing practical we can do.
     #[cfg(not(any(apple, fix_y2038)))]
     unsafe {
-        use crate::utils::as_ptr;
+        let libc_times = to_libc_timestamps(times)?;
 
-        ret(c::futimens(borrowed_fd(fd), as_ptr(times).cast()))
+        ret(c::futimens(borrowed_fd(fd), libc_times.as_ptr()))
     }
 
     // Apple version: `futimens` was introduced in macOS 10.13.
@@ -1563,7 +1847,9 @@ pub(crate) fn futimens(fd: BorrowedFd<'_>, times: &Timestamps) -> io::Result<()>
 
         // If we have `futimens`, use it.
         if let Some(have_futimens) = futimens.get() {
-            return ret(have_futimens(borrowed_fd(fd), as_ptr(times).cast()));
+            let libc_times = to_libc_timestamps(times)?;
+
+            return ret(have_futimens(borrowed_fd(fd), libc_times.as_ptr()));
         }
 
         // Otherwise use `fsetattrlist`.
@@ -1579,6 +1865,16 @@ pub(crate) fn futimens(fd: BorrowedFd<'_>, times: &Timestamps) -> io::Result<()>
     }
 }
 
+/// Convert `times` into the platform `timespec` type, field by field, since
+/// `c::timespec` may have a 32-bit `tv_sec`.
+#[cfg(not(any(fix_y2038, target_os = "espidf", target_os = "vita")))]
+fn to_libc_timestamps(times: &Timestamps) -> io::Result<[c::timespec; 2]> {
+    Ok([
+        to_libc_timespec(&times.last_access)?,
+        to_libc_timespec(&times.last_modification)?,
+    ])
+}
+
 #[cfg(all(fix_y2038, not(apple)))]
 fn futimens_old(fd: BorrowedFd<'_>, times: &Timestamps) -> io::Result<()> {
     let old_times = [
@@ -1664,16 +1960,27 @@ pub(crate) fn fallocate(
     len: u64,
 ) -> io::Result<()> {
     let offset: i64 = offset.try_into().map_err(|_e| io::Errno::INVAL)?;
-    let len = len as i64;
+    let len: i64 = len.try_into().map_err(|_e| io::Errno::INVAL)?;
 
     assert!(mode.is_empty());
 
     let new_len = offset.checked_add(len).ok_or(io::Errno::FBIG)?;
+
+    // Like `posix_fallocate`, never shrink the file. `F_PREALLOCATE` can
+    // only add space at the end of the file, so there's nothing more to do
+    // if the range is within it.
+    let size = fstat(fd)?.st_size;
+    if new_len <= size {
+        return Ok(());
+    }
+
+    // `F_PEOFPOSMODE` allocates relative to the end of the space already
+    // allocated to the file, which is at least `size`.
     let mut store = c::fstore_t {
         fst_flags: c::F_ALLOCATECONTIG,
         fst_posmode: c::F_PEOFPOSMODE,
         fst_offset: 0,
-        fst_length: new_len,
+        fst_length: new_len - size,
         fst_bytesalloc: 0,
     };
     unsafe {
@@ -1764,6 +2071,31 @@ pub(crate) fn openat2(
         ))
     }
 }
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn is_openat2_available() -> bool {
+    use linux_raw_sys::general::open_how;
+
+    syscall! {
+        fn openat2(
+            base_dirfd: c::c_int,
+            pathname: *const c::c_char,
+            how: *mut open_how,
+            size: usize
+        ) via SYS_OPENAT2 -> c::c_int
+    }
+
+    unsafe {
+        // Call `openat2` with a zero size so that if it fails for any reason
+        // other than `EINVAL`, we know it's not supported.
+        matches!(
+            ret_owned_fd(openat2(borrowed_fd(CWD), null(), null_mut(), 0)),
+            Err(io::Errno::INVAL)
+        )
+    }
+}
+
 #[cfg(all(linux_kernel, target_pointer_width = "32"))]
 const SYS_OPENAT2: i32 = 437;
 #[cfg(all(linux_kernel, target_pointer_width = "64"))]
@@ -1786,6 +2118,73 @@ pub(crate) fn sendfile(
     }
 }
 
+#[cfg(any(apple, freebsdlike))]
+pub(crate) fn sendfile(
+    out_fd: BorrowedFd<'_>,
+    in_fd: BorrowedFd<'_>,
+    offset: Option<&mut u64>,
+    count: usize,
+) -> io::Result<usize> {
+    // A count of zero means to send the whole file on these platforms.
+    if count == 0 {
+        return Ok(0);
+    }
+
+    // These platforms' `sendfile` always takes an offset, and doesn't use or
+    // update the file offset, so emulate that when `offset` is `None`.
+    let start = match &offset {
+        Some(offset) => **offset,
+        None => tell(in_fd)?,
+    };
+    let start_off: c::off_t = start.try_into().map_err(|_| io::Errno::INVAL)?;
+
+    #[cfg(freebsdlike)]
+    let (result, sent) = unsafe {
+        let mut sbytes: c::off_t = 0;
+        let result = ret(c::sendfile(
+            borrowed_fd(in_fd),
+            borrowed_fd(out_fd),
+            start_off,
+            count,
+            null_mut(),
+            &mut sbytes,
+            0,
+        ));
+        (result, sbytes)
+    };
+
+    #[cfg(apple)]
+    let (result, sent) = unsafe {
+        let mut len: c::off_t = count.try_into().unwrap_or(c::off_t::MAX);
+        let result = ret(c::sendfile(
+            borrowed_fd(in_fd),
+            borrowed_fd(out_fd),
+            start_off,
+            &mut len,
+            null_mut(),
+            0,
+        ));
+        (result, len)
+    };
+
+    // If the call was interrupted after sending some data, report the
+    // partial transfer, as Linux does.
+    match result {
+        Ok(()) => (),
+        Err(io::Errno::AGAIN) | Err(io::Errno::INTR) if sent > 0 => (),
+        Err(err) => return Err(err),
+    }
+
+    let end = start + sent as u64;
+    match offset {
+        Some(offset) => *offset = end,
+        None => {
+            seek(in_fd, SeekFrom::Start(end))?;
+        }
+    }
+    Ok(sent as usize)
+}
+
 /// Convert from a Linux `statx` value to rustix's `Stat`.
 #[cfg(all(linux_kernel, target_pointer_width = "32"))]
 #[allow(deprecated)] // for `st_[amc]time` u64->i64 transition
@@ -2116,6 +2515,11 @@ pub(crate) fn fcntl_fullfsync(fd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(c::fcntl(borrowed_fd(fd), c::F_FULLFSYNC)) }
 }
 
+#[cfg(apple)]
+pub(crate) fn fcntl_barrierfsync(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(c::fcntl(borrowed_fd(fd), c::F_BARRIERFSYNC)) }
+}
+
 #[cfg(apple)]
 pub(crate) fn fcntl_nocache(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
     unsafe { ret(c::fcntl(borrowed_fd(fd), c::F_NOCACHE, value as c::c_int)) }
@@ -2239,8 +2643,41 @@ struct Attrlist {
     forkattr: Attrgroup,
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn inotify_init1(flags: inotify::CreateFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(c::inotify_init1(bitflags_bits!(flags))) }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn inotify_add_watch(
+    infd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: inotify::WatchFlags,
+) -> io::Result<i32> {
+    unsafe {
+        ret_c_int(c::inotify_add_watch(
+            borrowed_fd(infd),
+            c_str(path),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn inotify_rm_watch(infd: BorrowedFd<'_>, wfd: i32) -> io::Result<()> {
+    // Android's `inotify_rm_watch` takes `u32` despite that
+    // `inotify_add_watch` expects a `i32`.
+    #[cfg(target_os = "android")]
+    let wfd = wfd as u32;
+    unsafe { ret(c::inotify_rm_watch(borrowed_fd(infd), wfd)) }
+}
+
 #[cfg(any(apple, linux_kernel))]
-pub(crate) fn getxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+pub(crate) fn getxattr(
+    path: &CStr,
+    name: &CStr,
+    value: &mut [MaybeUninit<u8>],
+) -> io::Result<usize> {
     let value_ptr = value.as_mut_ptr();
 
     #[cfg(not(apple))]
@@ -2276,7 +2713,11 @@ pub(crate) fn getxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result
 }
 
 #[cfg(any(apple, linux_kernel))]
-pub(crate) fn lgetxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+pub(crate) fn lgetxattr(
+    path: &CStr,
+    name: &CStr,
+    value: &mut [MaybeUninit<u8>],
+) -> io::Result<usize> {
     let value_ptr = value.as_mut_ptr();
 
     #[cfg(not(apple))]
@@ -2313,7 +2754,11 @@ pub(crate) fn lgetxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Resul
 }
 
 #[cfg(any(apple, linux_kernel))]
-pub(crate) fn fgetxattr(fd: BorrowedFd<'_>, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+pub(crate) fn fgetxattr(
+    fd: BorrowedFd<'_>,
+    name: &CStr,
+    value: &mut [MaybeUninit<u8>],
+) -> io::Result<usize> {
     let value_ptr = value.as_mut_ptr();
 
     #[cfg(not(apple))]
@@ -2442,17 +2887,21 @@ pub(crate) fn fsetxattr(
 }
 
 #[cfg(any(apple, linux_kernel))]
-pub(crate) fn listxattr(path: &CStr, list: &mut [c::c_char]) -> io::Result<usize> {
+pub(crate) fn listxattr(path: &CStr, list: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
     #[cfg(not(apple))]
     unsafe {
-        ret_usize(c::listxattr(path.as_ptr(), list.as_mut_ptr(), list.len()))
+        ret_usize(c::listxattr(
+            path.as_ptr(),
+            list.as_mut_ptr().cast(),
+            list.len(),
+        ))
     }
 
     #[cfg(apple)]
     unsafe {
         ret_usize(c::listxattr(
             path.as_ptr(),
-            list.as_mut_ptr(),
+            list.as_mut_ptr().cast(),
             list.len(),
             0,
         ))
@@ -2460,17 +2909,21 @@ pub(crate) fn listxattr(path: &CStr, list: &mut [c::c_char]) -> io::Result<usize
 }
 
 #[cfg(any(apple, linux_kernel))]
-pub(crate) fn llistxattr(path: &CStr, list: &mut [c::c_char]) -> io::Result<usize> {
+pub(crate) fn llistxattr(path: &CStr, list: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
     #[cfg(not(apple))]
     unsafe {
-        ret_usize(c::llistxattr(path.as_ptr(), list.as_mut_ptr(), list.len()))
+        ret_usize(c::llistxattr(
+            path.as_ptr(),
+            list.as_mut_ptr().cast(),
+            list.len(),
+        ))
     }
 
     #[cfg(apple)]
     unsafe {
         ret_usize(c::listxattr(
             path.as_ptr(),
-            list.as_mut_ptr(),
+            list.as_mut_ptr().cast(),
             list.len(),
             c::XATTR_NOFOLLOW,
         ))
@@ -2478,17 +2931,17 @@ pub(crate) fn llistxattr(path: &CStr, list: &mut [c::c_char]) -> io::Result<usiz
 }
 
 #[cfg(any(apple, linux_kernel))]
-pub(crate) fn flistxattr(fd: BorrowedFd<'_>, list: &mut [c::c_char]) -> io::Result<usize> {
+pub(crate) fn flistxattr(fd: BorrowedFd<'_>, list: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
     let fd = borrowed_fd(fd);
 
     #[cfg(not(apple))]
     unsafe {
-        ret_usize(c::flistxattr(fd, list.as_mut_ptr(), list.len()))
+        ret_usize(c::flistxattr(fd, list.as_mut_ptr().cast(), list.len()))
     }
 
     #[cfg(apple)]
     unsafe {
-        ret_usize(c::flistxattr(fd, list.as_mut_ptr(), list.len(), 0))
+        ret_usize(c::flistxattr(fd, list.as_mut_ptr().cast(), list.len(), 0))
     }
 }
 
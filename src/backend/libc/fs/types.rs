@@ -283,6 +283,15 @@ bitflags! {
         const TRUNC = bitcast!(c::O_TRUNC);
 
         /// `O_PATH`
+        ///
+        /// Opens a file descriptor which only identifies a file, without
+        /// opening the file itself. It can be used as the `dirfd` argument
+        /// of `*at` functions, with `AT_EMPTY_PATH` to operate on the file
+        /// itself, and with `fstat`, `fstatfs`, `fchdir`, and `close`. It
+        /// can't be used to read or write the file; use [`reopen`] to get a
+        /// file descriptor which can.
+        ///
+        /// [`reopen`]: crate::fs::reopen
         #[cfg(any(
             linux_kernel,
             target_os = "emscripten",
@@ -313,8 +322,8 @@ bitflags! {
         /// `O_DIRECT`
         #[cfg(any(
             linux_kernel,
+            freebsdlike,
             target_os = "emscripten",
-            target_os = "freebsd",
             target_os = "fuchsia",
             target_os = "netbsd",
         ))]
@@ -343,9 +352,11 @@ bitflags! {
 
 #[cfg(apple)]
 bitflags! {
-    /// `CLONE_*` constants for use with [`fclonefileat`].
+    /// `CLONE_*` constants for use with [`fclonefileat`] and
+    /// [`clonefileat`].
     ///
     /// [`fclonefileat`]: crate::fs::fclonefileat
+    /// [`clonefileat`]: crate::fs::clonefileat
     #[repr(transparent)]
     #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct CloneFlags: u32 {
@@ -413,22 +424,30 @@ bitflags! {
     #[repr(transparent)]
     #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct ResolveFlags: u64 {
-        /// `RESOLVE_NO_XDEV`
+        /// `RESOLVE_NO_XDEV`—Fail with `EXDEV` if resolution would cross a
+        /// mount point, including bind mounts.
         const NO_XDEV = 0x01;
 
-        /// `RESOLVE_NO_MAGICLINKS`
+        /// `RESOLVE_NO_MAGICLINKS`—Fail with `ELOOP` if resolution would
+        /// follow a "magic link", such as the `/proc/<pid>/fd/*` entries.
         const NO_MAGICLINKS = 0x02;
 
-        /// `RESOLVE_NO_SYMLINKS`
+        /// `RESOLVE_NO_SYMLINKS`—Fail with `ELOOP` if resolution would follow
+        /// any symlink, including magic links. This implies `NO_MAGICLINKS`.
         const NO_SYMLINKS = 0x04;
 
-        /// `RESOLVE_BENEATH`
+        /// `RESOLVE_BENEATH`—Fail with `EXDEV` if resolution would leave the
+        /// directory `dirfd`, such as through `..` components, absolute
+        /// paths, or symlinks which lead outside of it.
         const BENEATH = 0x08;
 
-        /// `RESOLVE_IN_ROOT`
+        /// `RESOLVE_IN_ROOT`—Resolve as if `dirfd` were the root directory,
+        /// as with `chroot`, so that `..` components and absolute symlinks
+        /// stay within it.
         const IN_ROOT = 0x10;
 
-        /// `RESOLVE_CACHED` (since Linux 5.12)
+        /// `RESOLVE_CACHED` (since Linux 5.12)—Fail with `EAGAIN` unless
+        /// resolution can be completed entirely from the dentry cache.
         const CACHED = 0x20;
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
@@ -572,22 +591,26 @@ impl FileType {
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[repr(u32)]
 pub enum Advice {
-    /// `POSIX_FADV_NORMAL`
+    /// `POSIX_FADV_NORMAL`—No particular access pattern.
     Normal = c::POSIX_FADV_NORMAL as c::c_uint,
 
-    /// `POSIX_FADV_SEQUENTIAL`
+    /// `POSIX_FADV_SEQUENTIAL`—The data will be accessed sequentially,
+    /// so read ahead aggressively.
     Sequential = c::POSIX_FADV_SEQUENTIAL as c::c_uint,
 
-    /// `POSIX_FADV_RANDOM`
+    /// `POSIX_FADV_RANDOM`—The data will be accessed randomly, so don't
+    /// read ahead.
     Random = c::POSIX_FADV_RANDOM as c::c_uint,
 
-    /// `POSIX_FADV_NOREUSE`
+    /// `POSIX_FADV_NOREUSE`—The data will only be accessed once.
     NoReuse = c::POSIX_FADV_NOREUSE as c::c_uint,
 
-    /// `POSIX_FADV_WILLNEED`
+    /// `POSIX_FADV_WILLNEED`—The data will be accessed soon, so start
+    /// reading it into the page cache.
     WillNeed = c::POSIX_FADV_WILLNEED as c::c_uint,
 
-    /// `POSIX_FADV_DONTNEED`
+    /// `POSIX_FADV_DONTNEED`—The data won't be accessed soon, so it can
+    /// be dropped from the page cache.
     DontNeed = c::POSIX_FADV_DONTNEED as c::c_uint,
 }
 
@@ -672,6 +695,49 @@ bitflags! {
     }
 }
 
+/// `F_RDLCK` and `F_WRLCK` constants for use with [`fcntl_setlease`] and
+/// [`fcntl_getlease`].
+///
+/// [`fcntl_setlease`]: crate::fs::fcntl_setlease
+/// [`fcntl_getlease`]: crate::fs::fcntl_getlease
+#[cfg(linux_kernel)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum LeaseType {
+    /// `F_RDLCK`
+    Read = c::F_RDLCK as u32,
+    /// `F_WRLCK`
+    Write = c::F_WRLCK as u32,
+}
+
+#[cfg(linux_kernel)]
+bitflags! {
+    /// `DN_*` constants for use with [`fcntl_notify`].
+    ///
+    /// [`fcntl_notify`]: crate::fs::fcntl_notify
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct DnotifyFlags: u32 {
+        /// `DN_ACCESS`
+        const ACCESS = linux_raw_sys::general::DN_ACCESS;
+        /// `DN_MODIFY`
+        const MODIFY = linux_raw_sys::general::DN_MODIFY;
+        /// `DN_CREATE`
+        const CREATE = linux_raw_sys::general::DN_CREATE;
+        /// `DN_DELETE`
+        const DELETE = linux_raw_sys::general::DN_DELETE;
+        /// `DN_RENAME`
+        const RENAME = linux_raw_sys::general::DN_RENAME;
+        /// `DN_ATTRIB`
+        const ATTRIB = linux_raw_sys::general::DN_ATTRIB;
+        /// `DN_MULTISHOT`
+        const MULTISHOT = linux_raw_sys::general::DN_MULTISHOT;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 bitflags! {
     /// `STATX_*` constants for use with [`statx`].
@@ -809,7 +875,8 @@ bitflags! {
     #[repr(transparent)]
     #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct FallocateFlags: u32 {
-        /// `FALLOC_FL_KEEP_SIZE`
+        /// `FALLOC_FL_KEEP_SIZE`—Don't change the file size, even when
+        /// allocating past the end of the file.
         #[cfg(not(any(
             bsd,
             target_os = "aix",
@@ -818,7 +885,12 @@ bitflags! {
             target_os = "wasi",
         )))]
         const KEEP_SIZE = bitcast!(c::FALLOC_FL_KEEP_SIZE);
-        /// `FALLOC_FL_PUNCH_HOLE`
+        /// `FALLOC_FL_PUNCH_HOLE`—Deallocate the range, so that it reads as
+        /// zeros.
+        ///
+        /// This must be combined with [`KEEP_SIZE`].
+        ///
+        /// [`KEEP_SIZE`]: Self::KEEP_SIZE
         #[cfg(not(any(
             bsd,
             target_os = "aix",
@@ -840,7 +912,11 @@ bitflags! {
             target_os = "wasi",
         )))]
         const NO_HIDE_STALE = bitcast!(c::FALLOC_FL_NO_HIDE_STALE);
-        /// `FALLOC_FL_COLLAPSE_RANGE`
+        /// `FALLOC_FL_COLLAPSE_RANGE`—Remove the range from the file, shifting
+        /// the data after it down and shrinking the file.
+        ///
+        /// `offset` and `len` must be multiples of the filesystem block size,
+        /// and the range must not reach the end of the file.
         #[cfg(not(any(
             bsd,
             target_os = "aix",
@@ -850,7 +926,12 @@ bitflags! {
             target_os = "wasi",
         )))]
         const COLLAPSE_RANGE = bitcast!(c::FALLOC_FL_COLLAPSE_RANGE);
-        /// `FALLOC_FL_ZERO_RANGE`
+        /// `FALLOC_FL_ZERO_RANGE`—Zero the range, allocating blocks for it.
+        ///
+        /// Unless combined with [`KEEP_SIZE`], this extends the file if the
+        /// range goes past the end of it.
+        ///
+        /// [`KEEP_SIZE`]: Self::KEEP_SIZE
         #[cfg(not(any(
             bsd,
             target_os = "aix",
@@ -860,7 +941,10 @@ bitflags! {
             target_os = "wasi",
         )))]
         const ZERO_RANGE = bitcast!(c::FALLOC_FL_ZERO_RANGE);
-        /// `FALLOC_FL_INSERT_RANGE`
+        /// `FALLOC_FL_INSERT_RANGE`—Insert a hole at `offset`, shifting the
+        /// data after it up and growing the file.
+        ///
+        /// `offset` and `len` must be multiples of the filesystem block size.
         #[cfg(not(any(
             bsd,
             target_os = "aix",
@@ -870,7 +954,8 @@ bitflags! {
             target_os = "wasi",
         )))]
         const INSERT_RANGE = bitcast!(c::FALLOC_FL_INSERT_RANGE);
-        /// `FALLOC_FL_UNSHARE_RANGE`
+        /// `FALLOC_FL_UNSHARE_RANGE`—Unshare any blocks in the range which are
+        /// shared with other files, such as by reflinks.
         #[cfg(not(any(
             bsd,
             target_os = "aix",
@@ -889,6 +974,12 @@ bitflags! {
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 bitflags! {
     /// `ST_*` constants for use with [`StatVfs`].
+    ///
+    /// POSIX only specifies [`RDONLY`] and [`NOSUID`]; the others are only
+    /// reported on Linux.
+    ///
+    /// [`RDONLY`]: Self::RDONLY
+    /// [`NOSUID`]: Self::NOSUID
     #[repr(transparent)]
     #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct StatVfsMountFlags: u64 {
@@ -1067,7 +1158,7 @@ pub type Statx = c::statx;
 /// `struct statx_timestamp` for use with [`Statx`].
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 // Use the glibc `struct statx_timestamp`.
-pub type StatxTimestamp = c::statx;
+pub type StatxTimestamp = c::statx_timestamp;
 
 /// `struct statx` for use with [`statx`].
 ///
@@ -1079,6 +1170,7 @@ pub type StatxTimestamp = c::statx;
     all(target_os = "linux", not(target_env = "gnu")),
 ))]
 #[repr(C)]
+#[derive(Debug, Copy, Clone)]
 #[allow(missing_docs)]
 pub struct Statx {
     pub stx_mask: u32,
@@ -1102,7 +1194,8 @@ pub struct Statx {
     pub stx_dev_major: u32,
     pub stx_dev_minor: u32,
     pub stx_mnt_id: u64,
-    __statx_pad2: u64,
+    pub stx_dio_mem_align: u32,
+    pub stx_dio_offset_align: u32,
     __statx_pad3: [u64; 12],
 }
 
@@ -1114,6 +1207,7 @@ pub struct Statx {
     all(target_os = "linux", not(target_env = "gnu")),
 ))]
 #[repr(C)]
+#[derive(Debug, Copy, Clone)]
 #[allow(missing_docs)]
 pub struct StatxTimestamp {
     pub tv_sec: i64,
@@ -246,6 +246,9 @@ pub(crate) fn is_read_write(fd: BorrowedFd<'_>) -> io::Result<(bool, bool)> {
                 match errno().0 {
                     c::EAGAIN | c::EWOULDBLOCK => (),
                     c::ENOTSOCK => not_socket = true,
+                    // A stream socket which isn't connected, such as a
+                    // listening socket, can't be read from.
+                    c::ENOTCONN => read = false,
                     err => return Err(io::Errno(err)),
                 }
             }
@@ -254,12 +257,46 @@ pub(crate) fn is_read_write(fd: BorrowedFd<'_>) -> io::Result<(bool, bool)> {
     }
     if write && !not_socket {
         // Do a `send` with `DONTWAIT` for 0 bytes. An `EPIPE` indicates
-        // the write side is shut down.
-        if unsafe { c::send(borrowed_fd(fd), [].as_ptr(), 0, c::MSG_DONTWAIT) } == -1 {
+        // the write side is shut down. Pass `NOSIGNAL` so that this doesn't
+        // raise `SIGPIPE` when it is.
+        #[cfg(not(any(apple, windows, target_os = "vita")))]
+        let flags = c::MSG_DONTWAIT | c::MSG_NOSIGNAL;
+        #[cfg(any(apple, windows, target_os = "vita"))]
+        let flags = c::MSG_DONTWAIT;
+
+        // Apple platforms don't have `NOSIGNAL`, so set `SO_NOSIGPIPE` on the
+        // socket for the duration of the `send` instead.
+        #[cfg(apple)]
+        let restore_nosigpipe = {
+            use crate::backend::net::sockopt::{get_socket_nosigpipe, set_socket_nosigpipe};
+
+            match get_socket_nosigpipe(fd) {
+                Ok(true) => false,
+                Ok(false) => {
+                    set_socket_nosigpipe(fd, true)?;
+                    true
+                }
+                Err(io::Errno::NOTSOCK) => return Ok((read, write)),
+                Err(err) => return Err(err),
+            }
+        };
+
+        let nwritten = unsafe { c::send(borrowed_fd(fd), [].as_ptr(), 0, flags) };
+        let err = errno();
+
+        #[cfg(apple)]
+        if restore_nosigpipe {
+            crate::backend::net::sockopt::set_socket_nosigpipe(fd, false)?;
+        }
+
+        if nwritten == -1 {
             #[allow(unreachable_patterns)] // `EAGAIN` may equal `EWOULDBLOCK`
-            match errno().0 {
+            match err.0 {
                 c::EAGAIN | c::EWOULDBLOCK | c::ENOTSOCK => (),
-                c::EPIPE => write = false,
+                // Depending on the platform and the socket type, an
+                // unconnected stream socket fails with `ENOTCONN` rather
+                // than `EPIPE`.
+                c::EPIPE | c::ENOTCONN => write = false,
                 err => return Err(io::Errno(err)),
             }
         }
@@ -282,12 +319,19 @@ pub(crate) fn fcntl_setfd(fd: BorrowedFd<'_>, flags: FdFlags) -> io::Result<()>
     unsafe { ret(c::fcntl(borrowed_fd(fd), c::F_SETFD, flags.bits())) }
 }
 
+#[cfg(not(windows))]
+#[cfg(all(feature = "event", feature = "net", feature = "std"))]
+pub(crate) fn fcntl_getfl_nonblock(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    let flags = unsafe { ret_c_int(c::fcntl(borrowed_fd(fd), c::F_GETFL))? };
+    Ok(flags & c::O_NONBLOCK != 0)
+}
+
 #[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
 pub(crate) fn fcntl_dupfd_cloexec(fd: BorrowedFd<'_>, min: RawFd) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(c::fcntl(borrowed_fd(fd), c::F_DUPFD_CLOEXEC, min)) }
 }
 
-#[cfg(target_os = "espidf")]
+#[cfg(not(target_os = "wasi"))]
 pub(crate) fn fcntl_dupfd(fd: BorrowedFd<'_>, min: RawFd) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(c::fcntl(borrowed_fd(fd), c::F_DUPFD, min)) }
 }
@@ -416,6 +416,9 @@ pub enum Advice {
     /// `MADV_DONTNEED_LOCKED` (since Linux 5.18)
     #[cfg(linux_kernel)]
     LinuxDontneedLocked = bitcast!(c::MADV_DONTNEED_LOCKED),
+    /// `MADV_COLLAPSE` (since Linux 6.1)
+    #[cfg(linux_kernel)]
+    LinuxCollapse = linux_raw_sys::general::MADV_COLLAPSE,
 }
 
 #[cfg(target_os = "emscripten")]
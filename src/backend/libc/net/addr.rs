@@ -32,49 +32,91 @@ impl SocketAddrUnix {
 
     #[inline]
     fn _new(path: &CStr) -> io::Result<Self> {
+        Self::new_path_bytes(path.to_bytes())
+    }
+
+    /// Construct a new Unix-domain address from the bytes of a filesystem
+    /// path, not including a trailing NUL.
+    ///
+    /// This is a `const` variant of [`Self::new`]. It fails with
+    /// [`io::Errno::INVAL`] if `path` contains a NUL byte.
+    #[inline]
+    pub const fn new_path_bytes(path: &[u8]) -> io::Result<Self> {
         let mut unix = Self::init();
-        let bytes = path.to_bytes_with_nul();
-        if bytes.len() > unix.sun_path.len() {
+        if path.len() + 1 > unix.sun_path.len() {
             return Err(io::Errno::NAMETOOLONG);
         }
-        for (i, b) in bytes.iter().enumerate() {
-            unix.sun_path[i] = *b as c::c_char;
+        let mut i = 0;
+        while i < path.len() {
+            if path[i] == b'\0' {
+                return Err(io::Errno::INVAL);
+            }
+            unix.sun_path[i] = path[i] as c::c_char;
+            i += 1;
         }
+        let len = offsetof_sun_path() + path.len() + 1;
 
         #[cfg(any(bsd, target_os = "haiku"))]
         {
-            unix.sun_len = (offsetof_sun_path() + bytes.len()).try_into().unwrap();
+            unix.sun_len = len as _;
         }
 
         Ok(Self {
             unix,
             #[cfg(not(any(bsd, target_os = "haiku")))]
-            len: (offsetof_sun_path() + bytes.len()).try_into().unwrap(),
+            len: len as _,
         })
     }
 
     /// Construct a new abstract Unix-domain address from a byte slice.
     #[cfg(linux_kernel)]
     #[inline]
-    pub fn new_abstract_name(name: &[u8]) -> io::Result<Self> {
+    pub const fn new_abstract_name(name: &[u8]) -> io::Result<Self> {
         let mut unix = Self::init();
         if 1 + name.len() > unix.sun_path.len() {
             return Err(io::Errno::NAMETOOLONG);
         }
         unix.sun_path[0] = 0;
-        for (i, b) in name.iter().enumerate() {
-            unix.sun_path[1 + i] = *b as c::c_char;
+        let mut i = 0;
+        while i < name.len() {
+            unix.sun_path[1 + i] = name[i] as c::c_char;
+            i += 1;
         }
         let len = offsetof_sun_path() + 1 + name.len();
-        let len = len.try_into().unwrap();
         Ok(Self {
             unix,
             #[cfg(not(any(bsd, target_os = "haiku")))]
-            len,
+            len: len as _,
         })
     }
 
-    fn init() -> c::sockaddr_un {
+    /// Construct a new unnamed Unix-domain address.
+    ///
+    /// This is the address of a socket which isn't bound. On Linux, binding a
+    /// socket to this address makes the kernel "autobind" it to a unique
+    /// abstract address, which [`getsockname`] returns. The kernel also
+    /// autobinds unbound sockets with `SO_PASSCRED` enabled when they connect
+    /// or send.
+    ///
+    /// [`getsockname`]: crate::net::getsockname
+    #[inline]
+    pub const fn new_unnamed() -> Self {
+        #[cfg_attr(not(any(bsd, target_os = "haiku")), allow(unused_mut))]
+        let mut unix = Self::init();
+
+        #[cfg(any(bsd, target_os = "haiku"))]
+        {
+            unix.sun_len = offsetof_sun_path() as _;
+        }
+
+        Self {
+            unix,
+            #[cfg(not(any(bsd, target_os = "haiku")))]
+            len: offsetof_sun_path() as _,
+        }
+    }
+
+    const fn init() -> c::sockaddr_un {
         c::sockaddr_un {
             #[cfg(any(bsd, target_os = "aix", target_os = "haiku", target_os = "nto"))]
             sun_len: 0,
@@ -96,7 +138,7 @@ impl SocketAddrUnix {
     #[inline]
     pub fn path(&self) -> Option<&CStr> {
         let len = self.len();
-        if len != 0 && self.unix.sun_path[0] != 0 {
+        if len > offsetof_sun_path() && self.unix.sun_path[0] != 0 {
             let end = len as usize - offsetof_sun_path();
             let bytes = &self.unix.sun_path[..end];
             // SAFETY: `from_raw_parts` to convert from `&[c_char]` to `&[u8]`.
@@ -118,7 +160,7 @@ impl SocketAddrUnix {
     #[inline]
     pub fn abstract_name(&self) -> Option<&[u8]> {
         let len = self.len();
-        if len != 0 && self.unix.sun_path[0] == 0 {
+        if len > offsetof_sun_path() && self.unix.sun_path[0] == 0 {
             let end = len as usize - offsetof_sun_path();
             let bytes = &self.unix.sun_path[1..end];
             // SAFETY: `from_raw_parts` to convert from `&[c_char]` to `&[u8]`.
@@ -128,6 +170,12 @@ impl SocketAddrUnix {
         }
     }
 
+    /// Is this address unnamed?
+    #[inline]
+    pub fn is_unnamed(&self) -> bool {
+        self.len() == offsetof_sun_path()
+    }
+
     #[inline]
     pub(crate) fn addr_len(&self) -> c::socklen_t {
         #[cfg(not(any(bsd, target_os = "haiku")))]
@@ -206,40 +254,11 @@ impl fmt::Debug for SocketAddrUnix {
 pub type SocketAddrStorage = c::sockaddr_storage;
 
 /// Return the offset of the `sun_path` field of `sockaddr_un`.
-#[cfg(not(windows))]
+#[cfg(unix)]
 #[inline]
-pub(crate) fn offsetof_sun_path() -> usize {
-    let z = c::sockaddr_un {
-        #[cfg(any(bsd, target_os = "aix", target_os = "haiku", target_os = "nto"))]
-        sun_len: 0_u8,
-        #[cfg(target_os = "vita")]
-        ss_len: 0,
-        #[cfg(any(
-            bsd,
-            target_os = "aix",
-            target_os = "espidf",
-            target_os = "haiku",
-            target_os = "nto",
-            target_os = "vita"
-        ))]
-        sun_family: 0_u8,
-        #[cfg(not(any(
-            bsd,
-            target_os = "aix",
-            target_os = "espidf",
-            target_os = "haiku",
-            target_os = "nto",
-            target_os = "vita"
-        )))]
-        sun_family: 0_u16,
-        #[cfg(any(bsd, target_os = "nto"))]
-        sun_path: [0; 104],
-        #[cfg(not(any(bsd, target_os = "aix", target_os = "haiku", target_os = "nto")))]
-        sun_path: [0; 108],
-        #[cfg(target_os = "haiku")]
-        sun_path: [0; 126],
-        #[cfg(target_os = "aix")]
-        sun_path: [0; 1023],
-    };
-    (crate::utils::as_ptr(&z.sun_path) as usize) - (crate::utils::as_ptr(&z) as usize)
+pub(crate) const fn offsetof_sun_path() -> usize {
+    // `sun_path` is the last field, and it's an array of bytes, so there's no
+    // trailing padding after it.
+    let z = SocketAddrUnix::init();
+    core::mem::size_of::<c::sockaddr_un>() - z.sun_path.len()
 }
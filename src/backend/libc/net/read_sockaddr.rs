@@ -8,9 +8,13 @@ use crate::backend::c;
 #[cfg(not(windows))]
 use crate::ffi::CStr;
 use crate::io;
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::{SockaddrXdpFlags, SocketAddrXdp};
-use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
+use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrRaw, SocketAddrV4, SocketAddrV6};
 use core::mem::size_of;
 
 // This must match the header of `sockaddr`.
@@ -51,7 +55,7 @@ struct sockaddr_header {
 ///
 /// `storage` must point to a valid socket address returned from the OS.
 #[inline]
-unsafe fn read_ss_family(storage: *const c::sockaddr_storage) -> u16 {
+pub(crate) unsafe fn read_ss_family(storage: *const c::sockaddr_storage) -> u16 {
     // Assert that we know the layout of `sockaddr`.
     let _ = c::sockaddr {
         #[cfg(any(
@@ -148,7 +152,7 @@ pub(crate) unsafe fn read_sockaddr(
                 return Err(io::Errno::INVAL);
             }
             if len == offsetof_sun_path {
-                SocketAddrUnix::new(&[][..]).map(SocketAddrAny::Unix)
+                Ok(SocketAddrAny::Unix(SocketAddrUnix::new_unnamed()))
             } else {
                 let decode = &*storage.cast::<c::sockaddr_un>();
 
@@ -191,6 +195,12 @@ pub(crate) unsafe fn read_sockaddr(
                     &decode.sun_path[..provided_len]
                 };
 
+                // Some platforms report an unbound socket's address as an
+                // empty path rather than a zero-length one.
+                if path_bytes.is_empty() {
+                    return Ok(SocketAddrAny::Unix(SocketAddrUnix::new_unnamed()));
+                }
+
                 SocketAddrUnix::new(core::mem::transmute::<&[c::c_char], &[u8]>(path_bytes))
                     .map(SocketAddrAny::Unix)
             }
@@ -208,7 +218,33 @@ pub(crate) unsafe fn read_sockaddr(
                 u32::from_be(decode.sxdp_shared_umem_fd),
             )))
         }
-        _ => Err(io::Errno::INVAL),
+        #[cfg(linux_kernel)]
+        c::AF_NETLINK => {
+            if len < size_of::<c::sockaddr_nl>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_nl>();
+            Ok(SocketAddrAny::Netlink(SocketAddrNetlink::new(
+                decode.nl_pid,
+                decode.nl_groups,
+            )))
+        }
+        #[cfg(linux_kernel)]
+        c::AF_VSOCK => {
+            if len < size_of::<c::sockaddr_vm>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_vm>();
+            Ok(SocketAddrAny::Vsock(SocketAddrVsock::new(
+                decode.svm_cid,
+                decode.svm_port,
+            )))
+        }
+        other => Ok(SocketAddrAny::Raw(SocketAddrRaw::read(
+            other as _,
+            storage.cast(),
+            len,
+        ))),
     }
 }
 
@@ -280,7 +316,7 @@ unsafe fn inner_read_sockaddr_os(
         c::AF_UNIX => {
             assert!(len >= offsetof_sun_path);
             if len == offsetof_sun_path {
-                SocketAddrAny::Unix(SocketAddrUnix::new(&[][..]).unwrap())
+                SocketAddrAny::Unix(SocketAddrUnix::new_unnamed())
             } else {
                 let decode = &*storage.cast::<c::sockaddr_un>();
 
@@ -310,6 +346,12 @@ unsafe fn inner_read_sockaddr_os(
                 #[cfg(any(solarish, target_os = "freebsd"))]
                 let path_bytes = &path_bytes[..path_bytes.iter().position(|b| *b == 0).unwrap()];
 
+                // Some platforms report an unbound socket's address as an
+                // empty path rather than a zero-length one.
+                if path_bytes.is_empty() {
+                    return SocketAddrAny::Unix(SocketAddrUnix::new_unnamed());
+                }
+
                 SocketAddrAny::Unix(
                     SocketAddrUnix::new(core::mem::transmute::<&[c::c_char], &[u8]>(path_bytes))
                         .unwrap(),
@@ -327,6 +369,18 @@ unsafe fn inner_read_sockaddr_os(
                 u32::from_be(decode.sxdp_shared_umem_fd),
             ))
         }
-        other => unimplemented!("{:?}", other),
+        #[cfg(linux_kernel)]
+        c::AF_NETLINK => {
+            assert!(len >= size_of::<c::sockaddr_nl>());
+            let decode = &*storage.cast::<c::sockaddr_nl>();
+            SocketAddrAny::Netlink(SocketAddrNetlink::new(decode.nl_pid, decode.nl_groups))
+        }
+        #[cfg(linux_kernel)]
+        c::AF_VSOCK => {
+            assert!(len >= size_of::<c::sockaddr_vm>());
+            let decode = &*storage.cast::<c::sockaddr_vm>();
+            SocketAddrAny::Vsock(SocketAddrVsock::new(decode.svm_cid, decode.svm_port))
+        }
+        other => SocketAddrAny::Raw(SocketAddrRaw::read(other as _, storage.cast(), len)),
     }
 }
@@ -35,6 +35,10 @@ bitflags! {
         #[cfg(not(windows))]
         const EOR = bitcast!(c::MSG_EOR);
         /// `MSG_MORE`
+        ///
+        /// Indicates that more data follows, so the kernel holds back a
+        /// partial segment as if the socket were corked with `TCP_CORK`,
+        /// until a send without this flag.
         #[cfg(not(any(
             bsd,
             solarish,
@@ -77,6 +81,9 @@ bitflags! {
         )))]
         /// `MSG_CMSG_CLOEXEC`
         const CMSG_CLOEXEC = bitcast!(c::MSG_CMSG_CLOEXEC);
+        /// `MSG_CTRUNC`
+        #[cfg(not(any(windows, target_os = "espidf", target_os = "vita")))]
+        const CTRUNC = bitcast!(c::MSG_CTRUNC);
         /// `MSG_DONTWAIT`
         #[cfg(not(windows))]
         const DONTWAIT = bitcast!(c::MSG_DONTWAIT);
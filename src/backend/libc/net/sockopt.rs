@@ -13,6 +13,8 @@ use crate::fd::BorrowedFd;
 ))]
 use crate::ffi::CStr;
 use crate::io;
+#[cfg(linux_kernel)]
+use crate::net::sockopt::PathMtuDiscovery;
 use crate::net::sockopt::Timeout;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::{XdpMmapOffsets, XdpOptionsFlags, XdpRingOffset, XdpStatistics, XdpUmemReg};
@@ -47,11 +49,21 @@ use crate::net::Protocol;
     target_env = "newlib"
 ))]
 use crate::net::RawProtocol;
+#[cfg(target_os = "linux")]
+use crate::net::SockFilter;
+#[cfg(linux_kernel)]
+use crate::net::SocketAddrV6;
+#[cfg(any(
+    linux_kernel,
+    apple,
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+use crate::net::UCred;
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketType};
 #[cfg(any(linux_kernel, target_os = "fuchsia"))]
 use crate::net::{SocketAddrAny, SocketAddrStorage, SocketAddrV4};
-#[cfg(linux_kernel)]
-use crate::net::{SocketAddrV6, UCred};
 use crate::utils::as_mut_ptr;
 #[cfg(feature = "alloc")]
 #[cfg(any(
@@ -69,6 +81,11 @@ use alloc::borrow::ToOwned;
     target_os = "illumos"
 ))]
 use alloc::string::String;
+// Apple's `SO_LINGER` is in clock ticks; `SO_LINGER_SEC` is in seconds.
+#[cfg(not(apple))]
+use c::SO_LINGER;
+#[cfg(apple)]
+use c::SO_LINGER_SEC as SO_LINGER;
 #[cfg(apple)]
 use c::TCP_KEEPALIVE as TCP_KEEPIDLE;
 #[cfg(not(any(apple, target_os = "openbsd", target_os = "haiku", target_os = "nto")))]
@@ -123,6 +140,29 @@ fn getsockopt_raw<T>(
     }
 }
 
+/// Like `getsockopt_raw`, but for variable-length options, returning the
+/// number of bytes written into `buf`.
+#[cfg(linux_kernel)]
+#[inline]
+fn getsockopt_buf(
+    fd: BorrowedFd<'_>,
+    level: i32,
+    optname: i32,
+    buf: &mut [MaybeUninit<u8>],
+) -> io::Result<usize> {
+    let mut optlen: c::socklen_t = buf.len().try_into().unwrap_or(c::socklen_t::MAX);
+    unsafe {
+        ret(c::getsockopt(
+            borrowed_fd(fd),
+            level,
+            optname,
+            buf.as_mut_ptr().cast(),
+            &mut optlen,
+        ))?;
+    }
+    Ok(optlen as usize)
+}
+
 #[inline]
 fn setsockopt<T: Copy>(fd: BorrowedFd<'_>, level: i32, optname: i32, value: T) -> io::Result<()> {
     let optlen = core::mem::size_of::<T>().try_into().unwrap();
@@ -189,12 +229,12 @@ pub(crate) fn set_socket_linger(fd: BorrowedFd<'_>, linger: Option<Duration>) ->
         l_onoff: linger.is_some().into(),
         l_linger,
     };
-    setsockopt(fd, c::SOL_SOCKET, c::SO_LINGER, linger)
+    setsockopt(fd, c::SOL_SOCKET, SO_LINGER, linger)
 }
 
 #[inline]
 pub(crate) fn get_socket_linger(fd: BorrowedFd<'_>) -> io::Result<Option<Duration>> {
-    let linger: c::linger = getsockopt(fd, c::SOL_SOCKET, c::SO_LINGER)?;
+    let linger: c::linger = getsockopt(fd, c::SOL_SOCKET, SO_LINGER)?;
     Ok((linger.l_onoff != 0).then(|| Duration::from_secs(linger.l_linger as u64)))
 }
 
@@ -210,6 +250,18 @@ pub(crate) fn get_socket_passcred(fd: BorrowedFd<'_>) -> io::Result<bool> {
     getsockopt(fd, c::SOL_SOCKET, c::SO_PASSCRED).map(to_bool)
 }
 
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_socket_passsec(fd: BorrowedFd<'_>, passsec: bool) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_PASSSEC, from_bool(passsec))
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_socket_passsec(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_PASSSEC).map(to_bool)
+}
+
 #[inline]
 pub(crate) fn set_socket_timeout(
     fd: BorrowedFd<'_>,
@@ -360,6 +412,20 @@ pub(crate) fn get_socket_send_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usiz
     getsockopt(fd, c::SOL_SOCKET, c::SO_SNDBUF).map(|size: u32| size as usize)
 }
 
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_socket_recv_buffer_size_force(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+    let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+    setsockopt(fd, c::SOL_SOCKET, c::SO_RCVBUFFORCE, size)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_socket_send_buffer_size_force(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+    let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+    setsockopt(fd, c::SOL_SOCKET, c::SO_SNDBUFFORCE, size)
+}
+
 #[inline]
 #[cfg(not(any(
     apple,
@@ -452,6 +518,69 @@ pub(crate) fn set_socket_incoming_cpu(fd: BorrowedFd<'_>, value: u32) -> io::Res
     setsockopt(fd, c::SOL_SOCKET, c::SO_INCOMING_CPU, value)
 }
 
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn set_socket_busy_poll(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_BUSY_POLL, value)
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn get_socket_busy_poll(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_BUSY_POLL)
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn set_socket_prefer_busy_poll(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_PREFER_BUSY_POLL, from_bool(value))
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn get_socket_prefer_busy_poll(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_PREFER_BUSY_POLL).map(to_bool)
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn get_socket_incoming_napi_id(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_INCOMING_NAPI_ID)
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn set_socket_attach_reuseport_cbpf(
+    fd: BorrowedFd<'_>,
+    program: &[SockFilter],
+) -> io::Result<()> {
+    let fprog = c::sock_fprog {
+        len: program.len().try_into().map_err(|_| io::Errno::INVAL)?,
+        filter: program.as_ptr() as *mut c::sock_filter,
+    };
+    setsockopt(fd, c::SOL_SOCKET, c::SO_ATTACH_REUSEPORT_CBPF, fprog)
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn set_socket_attach_reuseport_ebpf(
+    fd: BorrowedFd<'_>,
+    program: BorrowedFd<'_>,
+) -> io::Result<()> {
+    setsockopt(
+        fd,
+        c::SOL_SOCKET,
+        c::SO_ATTACH_REUSEPORT_EBPF,
+        borrowed_fd(program),
+    )
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn set_socket_detach_reuseport_bpf(fd: BorrowedFd<'_>) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_DETACH_REUSEPORT_BPF, 0 as c::c_int)
+}
+
 #[inline]
 pub(crate) fn set_ip_ttl(fd: BorrowedFd<'_>, ttl: u32) -> io::Result<()> {
     setsockopt(fd, c::IPPROTO_IP, c::IP_TTL, ttl)
@@ -711,6 +840,54 @@ pub(crate) fn get_ip_recvtos(fd: BorrowedFd<'_>) -> io::Result<bool> {
     getsockopt(fd, c::IPPROTO_IP, c::IP_RECVTOS).map(to_bool)
 }
 
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_ip_recverr(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IP, c::IP_RECVERR, from_bool(value))
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_ip_recverr(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_RECVERR).map(to_bool)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_ipv6_recverr(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVERR, from_bool(value))
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_ipv6_recverr(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVERR).map(to_bool)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_ip_pktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IP, c::IP_PKTINFO, from_bool(value))
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_ip_pktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_PKTINFO).map(to_bool)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_ipv6_recvpktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVPKTINFO, from_bool(value))
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_ipv6_recvpktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVPKTINFO).map(to_bool)
+}
+
 #[cfg(any(
     bsd,
     linux_like,
@@ -759,6 +936,30 @@ pub(crate) fn get_ipv6_freebind(fd: BorrowedFd<'_>) -> io::Result<bool> {
     getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_FREEBIND).map(to_bool)
 }
 
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_ip_transparent(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IP, c::IP_TRANSPARENT, from_bool(value))
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_ip_transparent(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_TRANSPARENT).map(to_bool)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_ipv6_transparent(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_TRANSPARENT, from_bool(value))
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_ipv6_transparent(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_TRANSPARENT).map(to_bool)
+}
+
 #[cfg(any(linux_kernel, target_os = "fuchsia"))]
 #[inline]
 pub(crate) fn get_ip_original_dst(fd: BorrowedFd<'_>) -> io::Result<SocketAddrV4> {
@@ -817,6 +1018,48 @@ pub(crate) fn get_ipv6_tclass(fd: BorrowedFd<'_>) -> io::Result<u32> {
     getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_TCLASS)
 }
 
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_ip_mtu_discover(fd: BorrowedFd<'_>, value: PathMtuDiscovery) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IP, c::IP_MTU_DISCOVER, value as c::c_int)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_ip_mtu_discover(fd: BorrowedFd<'_>) -> io::Result<PathMtuDiscovery> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_MTU_DISCOVER).and_then(to_path_mtu_discovery)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_ip_mtu(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_MTU)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_ipv6_mtu_discover(fd: BorrowedFd<'_>, value: PathMtuDiscovery) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_MTU_DISCOVER, value as c::c_int)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_ipv6_mtu_discover(fd: BorrowedFd<'_>) -> io::Result<PathMtuDiscovery> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_MTU_DISCOVER).and_then(to_path_mtu_discovery)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_ipv6_mtu(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_MTU, value)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_ipv6_mtu(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_MTU)
+}
+
 #[inline]
 pub(crate) fn set_tcp_nodelay(fd: BorrowedFd<'_>, nodelay: bool) -> io::Result<()> {
     setsockopt(fd, c::IPPROTO_TCP, c::TCP_NODELAY, from_bool(nodelay))
@@ -827,6 +1070,18 @@ pub(crate) fn get_tcp_nodelay(fd: BorrowedFd<'_>) -> io::Result<bool> {
     getsockopt(fd, c::IPPROTO_TCP, c::TCP_NODELAY).map(to_bool)
 }
 
+#[inline]
+#[cfg(not(windows))]
+pub(crate) fn set_tcp_maxseg(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_TCP, c::TCP_MAXSEG, value)
+}
+
+#[inline]
+#[cfg(not(windows))]
+pub(crate) fn get_tcp_maxseg(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::IPPROTO_TCP, c::TCP_MAXSEG)
+}
+
 #[inline]
 #[cfg(not(any(target_os = "openbsd", target_os = "haiku", target_os = "nto")))]
 pub(crate) fn set_tcp_keepcnt(fd: BorrowedFd<'_>, count: u32) -> io::Result<()> {
@@ -961,12 +1216,87 @@ pub(crate) fn get_tcp_cork(fd: BorrowedFd<'_>) -> io::Result<bool> {
     getsockopt(fd, c::IPPROTO_TCP, c::TCP_CORK).map(to_bool)
 }
 
+#[cfg(any(apple, freebsdlike, target_os = "openbsd"))]
+#[inline]
+pub(crate) fn set_tcp_nopush(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_TCP, c::TCP_NOPUSH, from_bool(value))
+}
+
+#[cfg(any(apple, freebsdlike, target_os = "openbsd"))]
+#[inline]
+pub(crate) fn get_tcp_nopush(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_TCP, c::TCP_NOPUSH).map(to_bool)
+}
+
 #[cfg(linux_kernel)]
 #[inline]
 pub(crate) fn get_socket_peercred(fd: BorrowedFd<'_>) -> io::Result<UCred> {
     getsockopt(fd, c::SOL_SOCKET, c::SO_PEERCRED)
 }
 
+#[cfg(any(apple, target_os = "freebsd"))]
+#[inline]
+pub(crate) fn get_socket_peercred(fd: BorrowedFd<'_>) -> io::Result<UCred> {
+    let xucred: c::xucred = getsockopt(fd, c::SOL_LOCAL, c::LOCAL_PEERCRED)?;
+    if xucred.cr_version != c::XUCRED_VERSION || xucred.cr_ngroups < 1 {
+        return Err(io::Errno::INVAL);
+    }
+
+    // macOS reports the pid with a separate option.
+    #[cfg(apple)]
+    let pid: c::pid_t = getsockopt(fd, c::SOL_LOCAL, c::LOCAL_PEERPID)?;
+
+    // FreeBSD reports the pid in the `xucred`, since FreeBSD 13.
+    #[cfg(target_os = "freebsd")]
+    let pid = unsafe { xucred.cr_pid__c_anonymous_union.cr_pid };
+
+    // The first group in an `xucred` is the effective group ID.
+    peercred(pid, xucred.cr_uid, xucred.cr_groups[0])
+}
+
+#[cfg(target_os = "netbsd")]
+#[inline]
+pub(crate) fn get_socket_peercred(fd: BorrowedFd<'_>) -> io::Result<UCred> {
+    // NetBSD's `libc` doesn't have `SOL_LOCAL`, which is 0.
+    let id: c::unpcbid = getsockopt(fd, 0, c::LOCAL_PEEREID)?;
+    peercred(id.unp_pid, id.unp_euid, id.unp_egid)
+}
+
+#[cfg(target_os = "openbsd")]
+#[inline]
+pub(crate) fn get_socket_peercred(fd: BorrowedFd<'_>) -> io::Result<UCred> {
+    let cred: c::sockpeercred = getsockopt(fd, c::SOL_SOCKET, c::SO_PEERCRED)?;
+    peercred(cred.pid, cred.uid, cred.gid)
+}
+
+/// Construct a `UCred` from the raw IDs reported by the OS, failing with
+/// `NOTSUP` if the OS doesn't report a pid.
+#[cfg(any(
+    apple,
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn peercred(pid: c::pid_t, uid: c::uid_t, gid: c::gid_t) -> io::Result<UCred> {
+    Ok(UCred {
+        pid: crate::pid::Pid::from_raw(pid).ok_or(io::Errno::NOTSUP)?,
+        uid: unsafe { crate::ugid::Uid::from_raw(uid) },
+        gid: unsafe { crate::ugid::Gid::from_raw(gid) },
+    })
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_socket_peersec(
+    fd: BorrowedFd<'_>,
+    buf: &mut [MaybeUninit<u8>],
+) -> io::Result<usize> {
+    // Reserve space for a NUL terminator, since not all LSMs include one.
+    let max = buf.len().checked_sub(1).ok_or(io::Errno::RANGE)?;
+    let len = getsockopt_buf(fd, c::SOL_SOCKET, c::SO_PEERSEC, &mut buf[..max])?;
+    Ok(terminate_label(buf, len))
+}
+
 #[cfg(target_os = "linux")]
 #[inline]
 pub(crate) fn set_xdp_umem_reg(fd: BorrowedFd<'_>, value: XdpUmemReg) -> io::Result<()> {
@@ -1224,6 +1554,34 @@ fn to_bool(value: SocketBool) -> bool {
     value.0 != 0
 }
 
+/// Convert a raw `IP_PMTUDISC_*` value into a `PathMtuDiscovery`.
+#[cfg(linux_kernel)]
+#[inline]
+fn to_path_mtu_discovery(value: c::c_int) -> io::Result<PathMtuDiscovery> {
+    Ok(match value {
+        c::IP_PMTUDISC_DONT => PathMtuDiscovery::Dont,
+        c::IP_PMTUDISC_WANT => PathMtuDiscovery::Want,
+        c::IP_PMTUDISC_DO => PathMtuDiscovery::Do,
+        c::IP_PMTUDISC_PROBE => PathMtuDiscovery::Probe,
+        c::IP_PMTUDISC_INTERFACE => PathMtuDiscovery::Interface,
+        c::IP_PMTUDISC_OMIT => PathMtuDiscovery::Omit,
+        _ => return Err(io::Errno::RANGE),
+    })
+}
+
+/// NUL-terminate an LSM security label which the kernel wrote into the first
+/// `len` bytes of `buf`, truncating it at any NUL the label already contains,
+/// and return its length including the terminator.
+#[cfg(linux_kernel)]
+#[inline]
+fn terminate_label(buf: &mut [MaybeUninit<u8>], len: usize) -> usize {
+    // SAFETY: The kernel initialized the first `len` bytes.
+    let label = unsafe { core::slice::from_raw_parts(buf.as_ptr().cast::<u8>(), len) };
+    let len = label.iter().position(|b| *b == b'\0').unwrap_or(len);
+    buf[len] = MaybeUninit::new(b'\0');
+    len + 1
+}
+
 /// Convert to seconds, rounding up if necessary.
 #[inline]
 fn duration_to_secs<T: TryFrom<u64>>(duration: Duration) -> io::Result<T> {
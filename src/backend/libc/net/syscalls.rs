@@ -4,15 +4,23 @@
 use super::addr::SocketAddrUnix;
 #[cfg(target_os = "linux")]
 use super::msghdr::with_xdp_msghdr;
+#[cfg(linux_kernel)]
+use super::msghdr::{with_netlink_msghdr, with_vsock_msghdr};
 #[cfg(target_os = "linux")]
 use super::write_sockaddr::encode_sockaddr_xdp;
+#[cfg(linux_kernel)]
+use super::write_sockaddr::{encode_sockaddr_netlink, encode_sockaddr_vsock};
 use crate::backend::c;
 use crate::backend::conv::{borrowed_fd, ret, ret_owned_fd, ret_send_recv, send_recv_len};
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io;
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
-use crate::net::{SocketAddrAny, SocketAddrV4, SocketAddrV6};
+use crate::net::{SocketAddrAny, SocketAddrRaw, SocketAddrV4, SocketAddrV6};
 use crate::utils::as_ptr;
 use core::mem::{size_of, MaybeUninit};
 #[cfg(not(any(
@@ -23,7 +31,9 @@ use core::mem::{size_of, MaybeUninit};
     target_os = "wasi"
 )))]
 use {
-    super::msghdr::{with_noaddr_msghdr, with_recv_msghdr, with_v4_msghdr, with_v6_msghdr},
+    super::msghdr::{
+        with_noaddr_msghdr, with_raw_msghdr, with_recv_msghdr, with_v4_msghdr, with_v6_msghdr,
+    },
     crate::io::{IoSlice, IoSliceMut},
     crate::net::{RecvAncillaryBuffer, RecvMsgReturn, SendAncillaryBuffer},
 };
@@ -71,6 +81,18 @@ pub(crate) unsafe fn recvfrom(
     flags: RecvFlags,
 ) -> io::Result<(usize, Option<SocketAddrAny>)> {
     let mut storage = MaybeUninit::<c::sockaddr_storage>::uninit();
+    let (nread, len) = recvfrom_into(fd, buf, buf_len, flags, &mut storage)?;
+    Ok((nread, maybe_read_sockaddr_os(storage.as_ptr(), len)))
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) unsafe fn recvfrom_into(
+    fd: BorrowedFd<'_>,
+    buf: *mut u8,
+    buf_len: usize,
+    flags: RecvFlags,
+    storage: &mut MaybeUninit<c::sockaddr_storage>,
+) -> io::Result<(usize, usize)> {
     let mut len = size_of::<c::sockaddr_storage>() as c::socklen_t;
 
     // `recvfrom` does not write to the storage if the socket is
@@ -78,20 +100,15 @@ pub(crate) unsafe fn recvfrom(
     // `AF_UNSPEC` so that we can detect this case.
     initialize_family_to_unspec(storage.as_mut_ptr());
 
-    ret_send_recv(c::recvfrom(
+    let nread = ret_send_recv(c::recvfrom(
         borrowed_fd(fd),
         buf.cast(),
         send_recv_len(buf_len),
         bitflags_bits!(flags),
         storage.as_mut_ptr().cast(),
         &mut len,
-    ))
-    .map(|nread| {
-        (
-            nread,
-            maybe_read_sockaddr_os(storage.as_ptr(), len.try_into().unwrap()),
-        )
-    })
+    ))?;
+    Ok((nread, len.try_into().unwrap()))
 }
 
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
@@ -170,6 +187,64 @@ pub(crate) fn sendto_xdp(
     }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn sendto_netlink(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrNetlink,
+) -> io::Result<usize> {
+    unsafe {
+        ret_send_recv(c::sendto(
+            borrowed_fd(fd),
+            buf.as_ptr().cast(),
+            send_recv_len(buf.len()),
+            bitflags_bits!(flags),
+            as_ptr(&encode_sockaddr_netlink(addr)).cast::<c::sockaddr>(),
+            size_of::<c::sockaddr_nl>() as _,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn sendto_vsock(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrVsock,
+) -> io::Result<usize> {
+    unsafe {
+        ret_send_recv(c::sendto(
+            borrowed_fd(fd),
+            buf.as_ptr().cast(),
+            send_recv_len(buf.len()),
+            bitflags_bits!(flags),
+            as_ptr(&encode_sockaddr_vsock(addr)).cast::<c::sockaddr>(),
+            size_of::<c::sockaddr_vm>() as _,
+        ))
+    }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn sendto_raw(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrRaw,
+) -> io::Result<usize> {
+    let encoded = addr.as_bytes();
+    unsafe {
+        ret_send_recv(c::sendto(
+            borrowed_fd(fd),
+            buf.as_ptr().cast(),
+            send_recv_len(buf.len()),
+            bitflags_bits!(flags),
+            encoded.as_ptr().cast::<c::sockaddr>(),
+            encoded.len() as _,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn socket(
     domain: AddressFamily,
@@ -253,6 +328,62 @@ pub(crate) fn bind_xdp(sockfd: BorrowedFd<'_>, addr: &SocketAddrXdp) -> io::Resu
     }
 }
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn bind_raw(sockfd: BorrowedFd<'_>, addr: &SocketAddrRaw) -> io::Result<()> {
+    let encoded = addr.as_bytes();
+    unsafe {
+        ret(c::bind(
+            borrowed_fd(sockfd),
+            encoded.as_ptr().cast(),
+            encoded.len() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn bind_netlink(sockfd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    unsafe {
+        ret(c::bind(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_netlink(addr)).cast(),
+            size_of::<c::sockaddr_nl>() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn bind_vsock(sockfd: BorrowedFd<'_>, addr: &SocketAddrVsock) -> io::Result<()> {
+    unsafe {
+        ret(c::bind(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_vsock(addr)).cast(),
+            size_of::<c::sockaddr_vm>() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn connect_netlink(sockfd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    unsafe {
+        ret(c::connect(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_netlink(addr)).cast(),
+            size_of::<c::sockaddr_nl>() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn connect_vsock(sockfd: BorrowedFd<'_>, addr: &SocketAddrVsock) -> io::Result<()> {
+    unsafe {
+        ret(c::connect(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_vsock(addr)).cast(),
+            size_of::<c::sockaddr_vm>() as c::socklen_t,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn connect_v4(sockfd: BorrowedFd<'_>, addr: &SocketAddrV4) -> io::Result<()> {
     unsafe {
@@ -286,6 +417,18 @@ pub(crate) fn connect_unix(sockfd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io:
     }
 }
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn connect_raw(sockfd: BorrowedFd<'_>, addr: &SocketAddrRaw) -> io::Result<()> {
+    let encoded = addr.as_bytes();
+    unsafe {
+        ret(c::connect(
+            borrowed_fd(sockfd),
+            encoded.as_ptr().cast(),
+            encoded.len() as c::socklen_t,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn connect_unspec(sockfd: BorrowedFd<'_>) -> io::Result<()> {
     debug_assert_eq!(c::AF_UNSPEC, 0);
@@ -455,6 +598,63 @@ pub(crate) fn sendmsg_xdp(
     })
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn sendmsg_netlink(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrNetlink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_netlink_msghdr(addr, iov, control, |msghdr| unsafe {
+        ret_send_recv(c::sendmsg(
+            borrowed_fd(sockfd),
+            &msghdr,
+            bitflags_bits!(msg_flags),
+        ))
+    })
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn sendmsg_vsock(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrVsock,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_vsock_msghdr(addr, iov, control, |msghdr| unsafe {
+        ret_send_recv(c::sendmsg(
+            borrowed_fd(sockfd),
+            &msghdr,
+            bitflags_bits!(msg_flags),
+        ))
+    })
+}
+
+#[cfg(not(any(
+    windows,
+    target_os = "espidf",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+pub(crate) fn sendmsg_raw(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrRaw,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_raw_msghdr(addr, iov, control, |msghdr| unsafe {
+        ret_send_recv(c::sendmsg(
+            borrowed_fd(sockfd),
+            &msghdr,
+            bitflags_bits!(msg_flags),
+        ))
+    })
+}
+
 #[cfg(not(any(
     apple,
     windows,
@@ -480,18 +680,26 @@ pub(crate) fn accept_with(sockfd: BorrowedFd<'_>, flags: SocketFlags) -> io::Res
 
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn acceptfrom(sockfd: BorrowedFd<'_>) -> io::Result<(OwnedFd, Option<SocketAddrAny>)> {
+    let mut storage = MaybeUninit::<c::sockaddr_storage>::uninit();
+    let (owned_fd, len) = acceptfrom_into(sockfd, &mut storage)?;
+    Ok((owned_fd, unsafe {
+        maybe_read_sockaddr_os(storage.as_ptr(), len)
+    }))
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+fn acceptfrom_into(
+    sockfd: BorrowedFd<'_>,
+    storage: &mut MaybeUninit<c::sockaddr_storage>,
+) -> io::Result<(OwnedFd, usize)> {
     unsafe {
-        let mut storage = MaybeUninit::<c::sockaddr_storage>::uninit();
         let mut len = size_of::<c::sockaddr_storage>() as c::socklen_t;
         let owned_fd = ret_owned_fd(c::accept(
             borrowed_fd(sockfd),
             storage.as_mut_ptr().cast(),
             &mut len,
         ))?;
-        Ok((
-            owned_fd,
-            maybe_read_sockaddr_os(storage.as_ptr(), len.try_into().unwrap()),
-        ))
+        Ok((owned_fd, len.try_into().unwrap()))
     }
 }
 
@@ -510,8 +718,30 @@ pub(crate) fn acceptfrom_with(
     sockfd: BorrowedFd<'_>,
     flags: SocketFlags,
 ) -> io::Result<(OwnedFd, Option<SocketAddrAny>)> {
+    let mut storage = MaybeUninit::<c::sockaddr_storage>::uninit();
+    let (owned_fd, len) = acceptfrom_with_into(sockfd, flags, &mut storage)?;
+    Ok((owned_fd, unsafe {
+        maybe_read_sockaddr_os(storage.as_ptr(), len)
+    }))
+}
+
+#[cfg(not(any(
+    apple,
+    windows,
+    target_os = "aix",
+    target_os = "espidf",
+    target_os = "haiku",
+    target_os = "nto",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi",
+)))]
+pub(crate) fn acceptfrom_with_into(
+    sockfd: BorrowedFd<'_>,
+    flags: SocketFlags,
+    storage: &mut MaybeUninit<c::sockaddr_storage>,
+) -> io::Result<(OwnedFd, usize)> {
     unsafe {
-        let mut storage = MaybeUninit::<c::sockaddr_storage>::uninit();
         let mut len = size_of::<c::sockaddr_storage>() as c::socklen_t;
         let owned_fd = ret_owned_fd(c::accept4(
             borrowed_fd(sockfd),
@@ -519,10 +749,7 @@ pub(crate) fn acceptfrom_with(
             &mut len,
             flags.bits() as c::c_int,
         ))?;
-        Ok((
-            owned_fd,
-            maybe_read_sockaddr_os(storage.as_ptr(), len.try_into().unwrap()),
-        ))
+        Ok((owned_fd, len.try_into().unwrap()))
     }
 }
 
@@ -559,6 +786,25 @@ pub(crate) fn acceptfrom_with(
     acceptfrom(sockfd)
 }
 
+/// Darwin lacks `accept4`, but does have `accept`. We define
+/// `SocketFlags` to have no flags, so we can discard it here.
+#[cfg(any(
+    apple,
+    windows,
+    target_os = "aix",
+    target_os = "espidf",
+    target_os = "haiku",
+    target_os = "nto",
+    target_os = "vita",
+))]
+pub(crate) fn acceptfrom_with_into(
+    sockfd: BorrowedFd<'_>,
+    _flags: SocketFlags,
+    storage: &mut MaybeUninit<c::sockaddr_storage>,
+) -> io::Result<(OwnedFd, usize)> {
+    acceptfrom_into(sockfd, storage)
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn shutdown(sockfd: BorrowedFd<'_>, how: Shutdown) -> io::Result<()> {
     unsafe { ret(c::shutdown(borrowed_fd(sockfd), how as c::c_int)) }
@@ -580,18 +826,24 @@ pub(crate) fn getsockname(sockfd: BorrowedFd<'_>) -> io::Result<SocketAddrAny> {
 
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn getpeername(sockfd: BorrowedFd<'_>) -> io::Result<Option<SocketAddrAny>> {
+    let mut storage = MaybeUninit::<c::sockaddr_storage>::uninit();
+    let len = getpeername_into(sockfd, &mut storage)?;
+    Ok(unsafe { maybe_read_sockaddr_os(storage.as_ptr(), len) })
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn getpeername_into(
+    sockfd: BorrowedFd<'_>,
+    storage: &mut MaybeUninit<c::sockaddr_storage>,
+) -> io::Result<usize> {
     unsafe {
-        let mut storage = MaybeUninit::<c::sockaddr_storage>::uninit();
         let mut len = size_of::<c::sockaddr_storage>() as c::socklen_t;
         ret(c::getpeername(
             borrowed_fd(sockfd),
             storage.as_mut_ptr().cast(),
             &mut len,
         ))?;
-        Ok(maybe_read_sockaddr_os(
-            storage.as_ptr(),
-            len.try_into().unwrap(),
-        ))
+        Ok(len.try_into().unwrap())
     }
 }
 
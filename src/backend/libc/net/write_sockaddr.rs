@@ -6,6 +6,10 @@ use super::addr::SocketAddrStorage;
 use super::addr::SocketAddrUnix;
 use super::ext::{in6_addr_new, in_addr_new, sockaddr_in6_new};
 use crate::backend::c;
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
 use crate::net::{SocketAddrAny, SocketAddrV4, SocketAddrV6};
@@ -22,6 +26,11 @@ pub(crate) unsafe fn write_sockaddr(
         SocketAddrAny::Unix(unix) => write_sockaddr_unix(unix, storage),
         #[cfg(target_os = "linux")]
         SocketAddrAny::Xdp(xdp) => write_sockaddr_xdp(xdp, storage),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Netlink(netlink) => write_sockaddr_netlink(netlink, storage),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Vsock(vsock) => write_sockaddr_vsock(vsock, storage),
+        SocketAddrAny::Raw(raw) => raw.write(storage),
     }
 }
 
@@ -123,3 +132,42 @@ unsafe fn write_sockaddr_xdp(xdp: &SocketAddrXdp, storage: *mut SocketAddrStorag
     core::ptr::write(storage.cast(), encoded);
     size_of::<c::sockaddr_xdp>()
 }
+
+#[cfg(linux_kernel)]
+pub(crate) fn encode_sockaddr_netlink(netlink: &SocketAddrNetlink) -> c::sockaddr_nl {
+    // SAFETY: `sockaddr_nl` is a plain C struct, for which all-zeros is a
+    // valid value, and `nl_pad` is private in the libc crate.
+    let mut encoded: c::sockaddr_nl = unsafe { core::mem::zeroed() };
+    encoded.nl_family = c::AF_NETLINK as _;
+    encoded.nl_pid = netlink.pid();
+    encoded.nl_groups = netlink.groups();
+    encoded
+}
+
+#[cfg(linux_kernel)]
+unsafe fn write_sockaddr_netlink(
+    netlink: &SocketAddrNetlink,
+    storage: *mut SocketAddrStorage,
+) -> usize {
+    let encoded = encode_sockaddr_netlink(netlink);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_nl>()
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn encode_sockaddr_vsock(vsock: &SocketAddrVsock) -> c::sockaddr_vm {
+    // SAFETY: `sockaddr_vm` is a plain C struct, for which all-zeros is a
+    // valid value.
+    let mut encoded: c::sockaddr_vm = unsafe { core::mem::zeroed() };
+    encoded.svm_family = c::AF_VSOCK as _;
+    encoded.svm_port = vsock.port();
+    encoded.svm_cid = vsock.cid();
+    encoded
+}
+
+#[cfg(linux_kernel)]
+unsafe fn write_sockaddr_vsock(vsock: &SocketAddrVsock, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_vsock(vsock);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_vm>()
+}
@@ -1,5 +1,7 @@
 #[cfg(any(freebsdlike, linux_kernel, target_os = "fuchsia"))]
 pub(crate) mod cpu_set;
+#[cfg(linux_kernel)]
+pub(crate) mod sig_set;
 #[cfg(not(windows))]
 pub(crate) mod syscalls;
 pub(crate) mod types;
@@ -0,0 +1,33 @@
+//! Wrappers for the libc `sigsetops` API.
+
+use super::types::RawSigSet;
+use crate::backend::c;
+use core::mem::MaybeUninit;
+
+#[inline]
+pub(crate) fn sigemptyset() -> RawSigSet {
+    let mut set = MaybeUninit::<RawSigSet>::uninit();
+    unsafe {
+        c::sigemptyset(set.as_mut_ptr());
+        set.assume_init()
+    }
+}
+
+#[inline]
+pub(crate) fn sigaddset(set: &mut RawSigSet, sig: c::c_int) {
+    unsafe {
+        c::sigaddset(set, sig);
+    }
+}
+
+#[inline]
+pub(crate) fn sigdelset(set: &mut RawSigSet, sig: c::c_int) {
+    unsafe {
+        c::sigdelset(set, sig);
+    }
+}
+
+#[inline]
+pub(crate) fn sigismember(set: &RawSigSet, sig: c::c_int) -> bool {
+    unsafe { c::sigismember(set, sig) == 1 }
+}
@@ -5,9 +5,9 @@ use super::types::RawCpuSet;
 use crate::backend::c;
 #[cfg(not(any(target_os = "wasi", target_os = "fuchsia")))]
 use crate::backend::conv::borrowed_fd;
-#[cfg(feature = "fs")]
+#[cfg(any(feature = "fs", target_os = "openbsd"))]
 use crate::backend::conv::c_str;
-#[cfg(all(feature = "alloc", feature = "fs", not(target_os = "wasi")))]
+#[cfg(all(feature = "fs", not(target_os = "wasi")))]
 use crate::backend::conv::ret_discarded_char_ptr;
 #[cfg(not(any(
     target_os = "espidf",
@@ -26,14 +26,21 @@ use crate::backend::conv::ret_usize;
 use crate::backend::conv::{ret, ret_c_int};
 #[cfg(not(any(target_os = "wasi", target_os = "fuchsia")))]
 use crate::fd::BorrowedFd;
+#[cfg(linux_kernel)]
+use crate::fd::OwnedFd;
 #[cfg(target_os = "linux")]
-use crate::fd::{AsRawFd, OwnedFd, RawFd};
-#[cfg(feature = "fs")]
+use crate::fd::{AsRawFd, RawFd};
+#[cfg(any(feature = "fs", target_os = "openbsd"))]
 use crate::ffi::CStr;
 #[cfg(feature = "fs")]
 use crate::fs::Mode;
 use crate::io;
-#[cfg(all(feature = "alloc", not(target_os = "wasi")))]
+#[cfg(any(
+    all(feature = "alloc", not(target_os = "wasi")),
+    linux_kernel,
+    freebsdlike,
+    target_os = "openbsd"
+))]
 use crate::process::Gid;
 #[cfg(not(target_os = "wasi"))]
 use crate::process::Pid;
@@ -48,6 +55,8 @@ use crate::process::Signal;
 use crate::process::Uid;
 #[cfg(linux_kernel)]
 use crate::process::{Cpuid, MembarrierCommand, MembarrierQuery};
+#[cfg(target_os = "linux")]
+use crate::process::{PidfdFlags, PidfdGetfdFlags};
 #[cfg(not(any(target_os = "espidf", target_os = "vita", target_os = "wasi")))]
 use crate::process::{RawPid, WaitOptions, WaitStatus};
 #[cfg(not(any(
@@ -67,9 +76,11 @@ use crate::process::{Resource, Rlimit};
 )))]
 use crate::process::{WaitId, WaitidOptions, WaitidStatus};
 use core::mem::MaybeUninit;
-#[cfg(target_os = "linux")]
+#[cfg(linux_kernel)]
 use {
-    super::super::conv::ret_owned_fd, crate::process::PidfdFlags, crate::process::PidfdGetfdFlags,
+    super::super::conv::{ret_discarded_fd, ret_owned_fd},
+    super::types::{RawSigSet, SigmaskHow, SignalfdFlags},
+    crate::utils::option_as_ptr,
 };
 
 #[cfg(any(linux_kernel, target_os = "dragonfly"))]
@@ -97,7 +108,7 @@ pub(crate) fn chroot(path: &CStr) -> io::Result<()> {
     unsafe { ret(c::chroot(c_str(path))) }
 }
 
-#[cfg(all(feature = "alloc", feature = "fs"))]
+#[cfg(feature = "fs")]
 #[cfg(not(target_os = "wasi"))]
 pub(crate) fn getcwd(buf: &mut [MaybeUninit<u8>]) -> io::Result<()> {
     unsafe { ret_discarded_char_ptr(c::getcwd(buf.as_mut_ptr().cast(), buf.len())) }
@@ -667,6 +678,67 @@ pub(crate) unsafe fn procctl(
     ret(c::procctl(idtype, id, option, data))
 }
 
+#[cfg(target_os = "freebsd")]
+#[inline]
+pub(crate) fn cap_enter() -> io::Result<()> {
+    unsafe { ret(c::cap_enter()) }
+}
+
+#[cfg(target_os = "freebsd")]
+#[inline]
+pub(crate) fn cap_getmode() -> io::Result<bool> {
+    let mut mode = 0;
+    unsafe {
+        ret(c::cap_getmode(&mut mode))?;
+    }
+    Ok(mode != 0)
+}
+
+#[cfg(target_os = "freebsd")]
+#[inline]
+pub(crate) fn cap_rights_limit(fd: BorrowedFd<'_>, rights: &[u64; 2]) -> io::Result<()> {
+    unsafe {
+        ret(c::cap_rights_limit(
+            borrowed_fd(fd),
+            rights.as_ptr().cast::<c::cap_rights_t>(),
+        ))
+    }
+}
+
+#[cfg(target_os = "freebsd")]
+#[inline]
+pub(crate) fn cap_rights_get(fd: BorrowedFd<'_>) -> io::Result<[u64; 2]> {
+    let mut rights = [0_u64; 2];
+    unsafe {
+        ret(c::__cap_rights_get(
+            c::CAP_RIGHTS_VERSION,
+            borrowed_fd(fd),
+            rights.as_mut_ptr().cast::<c::cap_rights_t>(),
+        ))?;
+    }
+    Ok(rights)
+}
+
+#[cfg(target_os = "openbsd")]
+pub(crate) fn pledge(promises: Option<&CStr>, execpromises: Option<&CStr>) -> io::Result<()> {
+    unsafe {
+        ret(c::pledge(
+            promises.map_or(core::ptr::null(), c_str),
+            execpromises.map_or(core::ptr::null(), c_str),
+        ))
+    }
+}
+
+#[cfg(target_os = "openbsd")]
+pub(crate) fn unveil(path: Option<&CStr>, permissions: Option<&CStr>) -> io::Result<()> {
+    unsafe {
+        ret(c::unveil(
+            path.map_or(core::ptr::null(), c_str),
+            permissions.map_or(core::ptr::null(), c_str),
+        ))
+    }
+}
+
 #[cfg(target_os = "linux")]
 pub(crate) fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
     syscall! {
@@ -731,3 +803,58 @@ pub(crate) fn getgroups(buf: &mut [Gid]) -> io::Result<usize> {
 
     unsafe { ret_usize(c::getgroups(len, buf.as_mut_ptr().cast()) as isize) }
 }
+
+#[cfg(any(linux_kernel, freebsdlike, target_os = "openbsd"))]
+pub(crate) fn getresuid() -> (Uid, Uid, Uid) {
+    let mut ruid = MaybeUninit::<c::uid_t>::uninit();
+    let mut euid = MaybeUninit::<c::uid_t>::uninit();
+    let mut suid = MaybeUninit::<c::uid_t>::uninit();
+    unsafe {
+        let r = c::getresuid(ruid.as_mut_ptr(), euid.as_mut_ptr(), suid.as_mut_ptr());
+        debug_assert_eq!(r, 0);
+        (
+            Uid::from_raw(ruid.assume_init()),
+            Uid::from_raw(euid.assume_init()),
+            Uid::from_raw(suid.assume_init()),
+        )
+    }
+}
+
+#[cfg(any(linux_kernel, freebsdlike, target_os = "openbsd"))]
+pub(crate) fn getresgid() -> (Gid, Gid, Gid) {
+    let mut rgid = MaybeUninit::<c::gid_t>::uninit();
+    let mut egid = MaybeUninit::<c::gid_t>::uninit();
+    let mut sgid = MaybeUninit::<c::gid_t>::uninit();
+    unsafe {
+        let r = c::getresgid(rgid.as_mut_ptr(), egid.as_mut_ptr(), sgid.as_mut_ptr());
+        debug_assert_eq!(r, 0);
+        (
+            Gid::from_raw(rgid.assume_init()),
+            Gid::from_raw(egid.assume_init()),
+            Gid::from_raw(sgid.assume_init()),
+        )
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn sigprocmask(how: SigmaskHow, set: Option<&RawSigSet>) -> io::Result<RawSigSet> {
+    let mut old = MaybeUninit::<RawSigSet>::uninit();
+    unsafe {
+        // `pthread_sigmask` returns the error code rather than setting
+        // `errno`.
+        match c::pthread_sigmask(how as c::c_int, option_as_ptr(set), old.as_mut_ptr()) {
+            0 => Ok(old.assume_init()),
+            err => Err(io::Errno::from_raw_os_error(err)),
+        }
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn signalfd(mask: &RawSigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(c::signalfd(-1, mask, bitflags_bits!(flags) as c::c_int)) }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn signalfd_set_mask(fd: BorrowedFd<'_>, mask: &RawSigSet) -> io::Result<()> {
+    unsafe { ret_discarded_fd(c::signalfd(borrowed_fd(fd), mask, 0)) }
+}
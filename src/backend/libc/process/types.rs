@@ -170,3 +170,39 @@ pub(crate) fn raw_cpu_set_new() -> RawCpuSet {
 
 #[cfg(any(freebsdlike, linux_kernel, target_os = "fuchsia"))]
 pub(crate) const CPU_SETSIZE: usize = c::CPU_SETSIZE as usize;
+
+#[cfg(linux_kernel)]
+pub(crate) type RawSigSet = c::sigset_t;
+
+/// `SIG_*` constants for use with [`sigprocmask`].
+///
+/// [`sigprocmask`]: crate::process::sigprocmask
+#[cfg(linux_kernel)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SigmaskHow {
+    /// `SIG_BLOCK`
+    Block = c::SIG_BLOCK as u32,
+    /// `SIG_UNBLOCK`
+    Unblock = c::SIG_UNBLOCK as u32,
+    /// `SIG_SETMASK`
+    SetMask = c::SIG_SETMASK as u32,
+}
+
+#[cfg(linux_kernel)]
+bitflags::bitflags! {
+    /// `SFD_*` flags for use with [`signalfd`].
+    ///
+    /// [`signalfd`]: crate::process::signalfd
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SignalfdFlags: c::c_uint {
+        /// `SFD_CLOEXEC`
+        const CLOEXEC = bitcast!(c::SFD_CLOEXEC);
+        /// `SFD_NONBLOCK`
+        const NONBLOCK = bitcast!(c::SFD_NONBLOCK);
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
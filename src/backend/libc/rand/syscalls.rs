@@ -1,7 +1,11 @@
 //! libc syscalls supporting `rustix::rand`.
 
-#[cfg(linux_kernel)]
-use {crate::backend::c, crate::backend::conv::ret_usize, crate::io, crate::rand::GetRandomFlags};
+#[cfg(any(apple, target_os = "openbsd"))]
+use crate::backend::conv::ret;
+#[cfg(any(linux_kernel, freebsdlike, target_os = "netbsd"))]
+use crate::backend::conv::ret_usize;
+#[cfg(any(linux_kernel, apple, freebsdlike, netbsdlike))]
+use {crate::backend::c, crate::io, crate::rand::GetRandomFlags};
 
 #[cfg(linux_kernel)]
 pub(crate) unsafe fn getrandom(
@@ -16,3 +20,28 @@ pub(crate) unsafe fn getrandom(
 
     ret_usize(getrandom(buf.cast(), cap, flags.bits()))
 }
+
+#[cfg(any(freebsdlike, target_os = "netbsd"))]
+pub(crate) unsafe fn getrandom(
+    buf: *mut u8,
+    cap: usize,
+    flags: GetRandomFlags,
+) -> io::Result<usize> {
+    ret_usize(c::getrandom(buf.cast(), cap, flags.bits()))
+}
+
+#[cfg(any(apple, target_os = "openbsd"))]
+pub(crate) unsafe fn getrandom(
+    buf: *mut u8,
+    cap: usize,
+    flags: GetRandomFlags,
+) -> io::Result<usize> {
+    // `getentropy` has no flags.
+    let _ = flags;
+
+    // `getentropy` fails with `EIO` if asked for more than 256 bytes, so
+    // return a short read instead, like `getrandom` does.
+    let len = core::cmp::min(cap, 256);
+    ret(c::getentropy(buf.cast(), len))?;
+    Ok(len)
+}
@@ -1,21 +1,28 @@
-#[cfg(linux_kernel)]
+#[cfg(any(linux_kernel, freebsdlike, target_os = "netbsd"))]
 use crate::backend::c;
-#[cfg(linux_kernel)]
+#[cfg(any(linux_kernel, apple, freebsdlike, netbsdlike))]
 use bitflags::bitflags;
 
-#[cfg(linux_kernel)]
+#[cfg(any(linux_kernel, apple, freebsdlike, netbsdlike))]
 bitflags! {
     /// `GRND_*` flags for use with [`getrandom`].
     ///
+    /// On Apple platforms and OpenBSD, there are no flags, as [`getrandom`]
+    /// uses `getentropy`, which never blocks once the system's random number
+    /// generator has been seeded.
+    ///
     /// [`getrandom`]: crate::rand::getrandom
     #[repr(transparent)]
     #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct GetRandomFlags: u32 {
         /// `GRND_RANDOM`
+        #[cfg(any(linux_kernel, freebsdlike, target_os = "netbsd"))]
         const RANDOM = c::GRND_RANDOM;
         /// `GRND_NONBLOCK`
+        #[cfg(any(linux_kernel, freebsdlike, target_os = "netbsd"))]
         const NONBLOCK = c::GRND_NONBLOCK;
         /// `GRND_INSECURE`
+        #[cfg(any(linux_kernel, freebsdlike, target_os = "netbsd"))]
         const INSECURE = c::GRND_INSECURE;
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
@@ -63,6 +63,16 @@ pub(crate) fn sethostname(name: &[u8]) -> io::Result<()> {
     }
 }
 
+#[cfg(any(linux_kernel, bsd))]
+pub(crate) fn setdomainname(name: &[u8]) -> io::Result<()> {
+    unsafe {
+        ret(c::setdomainname(
+            name.as_ptr().cast(),
+            name.len().try_into().map_err(|_| io::Errno::INVAL)?,
+        ))
+    }
+}
+
 #[cfg(target_os = "linux")]
 pub(crate) fn reboot(cmd: RebootCommand) -> io::Result<()> {
     unsafe { ret(c::reboot(cmd as i32)) }
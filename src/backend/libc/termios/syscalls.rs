@@ -9,7 +9,7 @@ use crate::backend::c;
 use crate::backend::conv::ret_pid_t;
 use crate::backend::conv::{borrowed_fd, ret};
 use crate::fd::BorrowedFd;
-#[cfg(all(feature = "alloc", feature = "procfs"))]
+#[cfg(feature = "procfs")]
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 use crate::ffi::CStr;
 #[cfg(any(
@@ -390,7 +390,7 @@ pub(crate) fn isatty(fd: BorrowedFd<'_>) -> bool {
     unsafe { c::isatty(borrowed_fd(fd)) != 0 }
 }
 
-#[cfg(all(feature = "alloc", feature = "procfs"))]
+#[cfg(feature = "procfs")]
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 pub(crate) fn ttyname(dirfd: BorrowedFd<'_>, buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
     unsafe {
@@ -7,6 +7,9 @@ use crate::io;
 use crate::thread::{NanosleepRelativeResult, Timespec};
 #[cfg(all(target_env = "gnu", fix_y2038))]
 use crate::timespec::LibcTimespec;
+#[cfg(not(target_os = "redox"))]
+#[cfg(not(fix_y2038))]
+use crate::timespec::{from_libc_timespec, to_libc_timespec};
 use core::mem::MaybeUninit;
 #[cfg(linux_kernel)]
 use {
@@ -80,12 +83,16 @@ pub(crate) fn clock_nanosleep_relative(id: ClockId, request: &Timespec) -> Nanos
     #[cfg(not(fix_y2038))]
     unsafe {
         let flags = 0;
-        let mut remain = MaybeUninit::<Timespec>::uninit();
+        let request = match to_libc_timespec(request) {
+            Ok(request) => request,
+            Err(err) => return NanosleepRelativeResult::Err(err),
+        };
+        let mut remain = MaybeUninit::<c::timespec>::uninit();
 
-        match c::clock_nanosleep(id as c::clockid_t, flags, request, remain.as_mut_ptr()) {
+        match c::clock_nanosleep(id as c::clockid_t, flags, &request, remain.as_mut_ptr()) {
             0 => NanosleepRelativeResult::Ok,
             err if err == io::Errno::INTR.0 => {
-                NanosleepRelativeResult::Interrupted(remain.assume_init())
+                NanosleepRelativeResult::Interrupted(from_libc_timespec(&remain.assume_init()))
             }
             err => NanosleepRelativeResult::Err(io::Errno(err)),
         }
@@ -181,8 +188,9 @@ pub(crate) fn clock_nanosleep_absolute(id: ClockId, request: &Timespec) -> io::R
     #[cfg(not(fix_y2038))]
     {
         let flags = c::TIMER_ABSTIME;
+        let request = to_libc_timespec(request)?;
 
-        match unsafe { c::clock_nanosleep(id as c::clockid_t, flags as _, request, null_mut()) } {
+        match unsafe { c::clock_nanosleep(id as c::clockid_t, flags as _, &request, null_mut()) } {
             0 => Ok(()),
             err => Err(io::Errno(err)),
         }
@@ -245,11 +253,17 @@ pub(crate) fn nanosleep(request: &Timespec) -> NanosleepRelativeResult {
     // Main version: libc is y2038 safe and has `nanosleep`.
     #[cfg(not(fix_y2038))]
     unsafe {
-        let mut remain = MaybeUninit::<Timespec>::uninit();
+        let request = match to_libc_timespec(request) {
+            Ok(request) => request,
+            Err(err) => return NanosleepRelativeResult::Err(err),
+        };
+        let mut remain = MaybeUninit::<c::timespec>::uninit();
 
-        match ret(c::nanosleep(request, remain.as_mut_ptr())) {
+        match ret(c::nanosleep(&request, remain.as_mut_ptr())) {
             Ok(()) => NanosleepRelativeResult::Ok,
-            Err(io::Errno::INTR) => NanosleepRelativeResult::Interrupted(remain.assume_init()),
+            Err(io::Errno::INTR) => {
+                NanosleepRelativeResult::Interrupted(from_libc_timespec(&remain.assume_init()))
+            }
             Err(err) => NanosleepRelativeResult::Err(err),
         }
     }
@@ -540,3 +554,45 @@ pub(crate) fn setgroups_thread(groups: &[crate::ugid::Gid]) -> io::Result<()> {
     }
     ret(unsafe { setgroups(groups.len(), groups.as_ptr().cast()) })
 }
+
+#[cfg(linux_kernel)]
+/// Returns the stack's address range and whether it's currently in use, or
+/// `None` if it's disabled.
+pub(crate) fn sigaltstack_query() -> io::Result<Option<(usize, usize, bool)>> {
+    let mut old = MaybeUninit::<c::stack_t>::uninit();
+    unsafe {
+        ret(c::sigaltstack(core::ptr::null(), old.as_mut_ptr()))?;
+        let old = old.assume_init();
+        if old.ss_flags & c::SS_DISABLE != 0 {
+            return Ok(None);
+        }
+        let start = old.ss_sp as usize;
+        Ok(Some((
+            start,
+            start + old.ss_size,
+            old.ss_flags & c::SS_ONSTACK != 0,
+        )))
+    }
+}
+
+#[cfg(feature = "procfs")]
+#[cfg(linux_kernel)]
+pub(crate) fn stack_bounds() -> io::Result<(usize, usize)> {
+    let mut attr = MaybeUninit::<c::pthread_attr_t>::uninit();
+    let mut addr = core::ptr::null_mut();
+    let mut size = 0;
+    unsafe {
+        // The `pthread_*` functions return the error code rather than
+        // setting `errno`.
+        match c::pthread_getattr_np(c::pthread_self(), attr.as_mut_ptr()) {
+            0 => {}
+            err => return Err(io::Errno::from_raw_os_error(err)),
+        }
+        let r = c::pthread_attr_getstack(attr.as_ptr(), &mut addr, &mut size);
+        c::pthread_attr_destroy(attr.as_mut_ptr());
+        match r {
+            0 => Ok((addr as usize, addr as usize + size)),
+            err => Err(io::Errno::from_raw_os_error(err)),
+        }
+    }
+}
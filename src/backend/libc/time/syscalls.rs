@@ -12,6 +12,8 @@ use crate::io;
 #[cfg(all(target_env = "gnu", fix_y2038))]
 use crate::timespec::LibcTimespec;
 use crate::timespec::Timespec;
+#[cfg(not(fix_y2038))]
+use crate::timespec::{from_libc_timespec, to_libc_timespec};
 use core::mem::MaybeUninit;
 #[cfg(any(linux_kernel, target_os = "fuchsia"))]
 #[cfg(feature = "time")]
@@ -59,9 +61,9 @@ pub(crate) fn clock_getres(id: ClockId) -> Timespec {
     // Main version: libc is y2038 safe and has `clock_getres`.
     #[cfg(not(fix_y2038))]
     unsafe {
-        let mut timespec = MaybeUninit::<Timespec>::uninit();
+        let mut timespec = MaybeUninit::<c::timespec>::uninit();
         let _ = c::clock_getres(id as c::clockid_t, timespec.as_mut_ptr());
-        timespec.assume_init()
+        from_libc_timespec(&timespec.assume_init())
     }
 }
 
@@ -115,9 +117,9 @@ pub(crate) fn clock_gettime(id: ClockId) -> Timespec {
     // configured.
     #[cfg(not(fix_y2038))]
     unsafe {
-        let mut timespec = MaybeUninit::<Timespec>::uninit();
+        let mut timespec = MaybeUninit::<c::timespec>::uninit();
         ret(c::clock_gettime(id as c::clockid_t, timespec.as_mut_ptr())).unwrap();
-        timespec.assume_init()
+        from_libc_timespec(&timespec.assume_init())
     }
 }
 
@@ -201,11 +203,11 @@ pub(crate) fn clock_gettime_dynamic(id: DynamicClockId<'_>) -> io::Result<Timesp
     // Main version: libc is y2038 safe and has `clock_gettime`.
     #[cfg(not(fix_y2038))]
     unsafe {
-        let mut timespec = MaybeUninit::<Timespec>::uninit();
+        let mut timespec = MaybeUninit::<c::timespec>::uninit();
 
         ret(c::clock_gettime(id as c::clockid_t, timespec.as_mut_ptr()))?;
 
-        Ok(timespec.assume_init())
+        Ok(from_libc_timespec(&timespec.assume_init()))
     }
 }
 
@@ -255,8 +257,9 @@ pub(crate) fn clock_settime(id: ClockId, timespec: Timespec) -> io::Result<()> {
 
     // Main version: libc is y2038 safe and has `clock_settime`.
     #[cfg(not(fix_y2038))]
-    unsafe {
-        ret(c::clock_settime(id as c::clockid_t, &timespec))
+    {
+        let timespec = to_libc_timespec(&timespec)?;
+        unsafe { ret(c::clock_settime(id as c::clockid_t, &timespec)) }
     }
 }
 
@@ -302,7 +305,7 @@ pub(crate) fn timerfd_settime(
                 ret(libc_timerfd_settime(
                     borrowed_fd(fd),
                     bitflags_bits!(flags),
-                    &new_value.clone().into(),
+                    &(*new_value).into(),
                     result.as_mut_ptr(),
                 ))?;
                 return Ok(result.assume_init().into());
@@ -314,14 +317,18 @@ pub(crate) fn timerfd_settime(
 
     #[cfg(not(fix_y2038))]
     unsafe {
+        let new_value = LibcItimerspec {
+            it_interval: to_libc_timespec(&new_value.it_interval)?,
+            it_value: to_libc_timespec(&new_value.it_value)?,
+        };
         let mut result = MaybeUninit::<LibcItimerspec>::uninit();
         ret(c::timerfd_settime(
             borrowed_fd(fd),
             bitflags_bits!(flags),
-            new_value,
+            &new_value,
             result.as_mut_ptr(),
         ))?;
-        Ok(result.assume_init())
+        Ok(from_libc_itimerspec(&result.assume_init()))
     }
 }
 
@@ -416,7 +423,7 @@ pub(crate) fn timerfd_gettime(fd: BorrowedFd<'_>) -> io::Result<Itimerspec> {
     unsafe {
         let mut result = MaybeUninit::<LibcItimerspec>::uninit();
         ret(c::timerfd_gettime(borrowed_fd(fd), result.as_mut_ptr()))?;
-        Ok(result.assume_init())
+        Ok(from_libc_itimerspec(&result.assume_init()))
     }
 }
 
@@ -450,3 +457,14 @@ fn timerfd_gettime_old(fd: BorrowedFd<'_>) -> io::Result<Itimerspec> {
         },
     })
 }
+
+/// Convert the platform `itimerspec` type into an `Itimerspec`.
+#[cfg(any(linux_kernel, target_os = "fuchsia"))]
+#[cfg(feature = "time")]
+#[cfg(not(fix_y2038))]
+fn from_libc_itimerspec(t: &LibcItimerspec) -> Itimerspec {
+    Itimerspec {
+        it_interval: from_libc_timespec(&t.it_interval),
+        it_value: from_libc_timespec(&t.it_value),
+    }
+}
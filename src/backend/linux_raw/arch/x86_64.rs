@@ -291,3 +291,48 @@ pub(in crate::backend) unsafe fn syscall6_readonly(
     );
     FromAsm::from_asm(r0)
 }
+
+/// `clone(flags, stack, 0, 0, 0)`, calling `func(arg)` on `stack` in the
+/// child.
+///
+/// `stack` must be 16-byte aligned, and `func` must not return.
+#[cfg(all(
+    feature = "alloc",
+    feature = "fs",
+    feature = "pipe",
+    feature = "process"
+))]
+#[inline]
+pub(in crate::backend) unsafe fn clone_with_stack(
+    nr: SyscallNumber<'_>,
+    flags: ArgReg<'_, A0>,
+    stack: ArgReg<'_, A1>,
+    func: unsafe extern "C" fn(*mut core::ffi::c_void) -> !,
+    arg: *mut core::ffi::c_void,
+) -> RetReg<R0> {
+    let r0;
+    asm!(
+        "syscall",
+        "test eax, eax",
+        "jnz 2f",
+        // In the child, on the new stack. Clear the frame pointer so that
+        // backtraces end here, and call `func`.
+        "xor ebp, ebp",
+        "mov rdi, r13",
+        "call r12",
+        "ud2",
+        "2:",
+        inlateout("rax") nr.to_asm() => r0,
+        in("rdi") flags.to_asm(),
+        in("rsi") stack.to_asm(),
+        in("rdx") 0_usize,
+        in("r10") 0_usize,
+        in("r8") 0_usize,
+        in("r12") func,
+        in("r13") arg,
+        lateout("rcx") _,
+        lateout("r11") _,
+        options(nostack)
+    );
+    FromAsm::from_asm(r0)
+}
@@ -15,7 +15,17 @@
 
 use super::c;
 use super::fd::{AsRawFd, BorrowedFd, FromRawFd, RawFd};
-#[cfg(any(feature = "event", feature = "runtime", feature = "system"))]
+#[cfg(any(
+    feature = "event",
+    feature = "runtime",
+    feature = "system",
+    all(
+        feature = "alloc",
+        feature = "fs",
+        feature = "pipe",
+        feature = "process"
+    )
+))]
 use super::io::errno::try_decode_error;
 #[cfg(target_pointer_width = "64")]
 use super::io::errno::try_decode_u64;
@@ -180,6 +190,7 @@ pub(super) unsafe fn raw_fd<'a, Num: ArgNumber>(fd: RawFd) -> ArgReg<'a, Num> {
 
 /// Deliberately pass `-1` to a file-descriptor argument, for system calls
 /// like `mmap` where this indicates the argument is omitted.
+#[cfg(not(target_arch = "s390x"))]
 #[inline]
 pub(super) fn no_fd<'a, Num: ArgNumber>() -> ArgReg<'a, Num> {
     pass_usize(!0_usize)
@@ -235,7 +246,7 @@ pub(super) fn opt_mut<T: Sized, Num: ArgNumber>(t: Option<&mut T>) -> ArgReg<'_,
 
 /// Convert an optional immutable reference into a `usize` for passing to a
 /// syscall.
-#[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
+#[cfg(any(feature = "event", feature = "process", feature = "runtime"))]
 #[inline]
 pub(super) fn opt_ref<T: Sized, Num: ArgNumber>(t: Option<&T>) -> ArgReg<'_, Num> {
     // This optimizes into the equivalent of `transmute(t)`, and has the
@@ -848,14 +859,6 @@ impl<'a, Num: ArgNumber> From<crate::ugid::Gid> for ArgReg<'a, Num> {
     }
 }
 
-#[cfg(feature = "runtime")]
-impl<'a, Num: ArgNumber> From<crate::runtime::How> for ArgReg<'a, Num> {
-    #[inline]
-    fn from(flags: crate::runtime::How) -> Self {
-        c_uint(flags as u32)
-    }
-}
-
 /// Convert a `usize` returned from a syscall that effectively returns `()` on
 /// success.
 ///
@@ -874,7 +877,17 @@ pub(super) unsafe fn ret(raw: RetReg<R0>) -> io::Result<()> {
 ///
 /// The caller must ensure that this is the return value of a syscall which
 /// doesn't return on success.
-#[cfg(any(feature = "event", feature = "runtime", feature = "system"))]
+#[cfg(any(
+    feature = "event",
+    feature = "runtime",
+    feature = "system",
+    all(
+        feature = "alloc",
+        feature = "fs",
+        feature = "pipe",
+        feature = "process"
+    )
+))]
 #[inline]
 pub(super) unsafe fn ret_error(raw: RetReg<R0>) -> io::Errno {
     try_decode_error(raw)
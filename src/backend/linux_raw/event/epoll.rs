@@ -82,6 +82,9 @@ use core::ffi::c_void;
 use core::hash::{Hash, Hasher};
 use core::slice;
 
+#[cfg(feature = "alloc")]
+pub use crate::event::epoll_poller::{Poller, PollerEvents, Token};
+
 bitflags! {
     /// `EPOLL_*` for use with [`new`].
     #[repr(transparent)]
@@ -9,17 +9,18 @@ use crate::backend::c;
 #[cfg(feature = "alloc")]
 use crate::backend::conv::pass_usize;
 use crate::backend::conv::{
-    by_ref, c_int, c_uint, raw_fd, ret, ret_error, ret_owned_fd, ret_usize, slice_mut, zero,
+    by_ref, c_int, c_uint, opt_ref, raw_fd, ret, ret_error, ret_owned_fd, ret_usize, size_of,
+    slice_mut, zero,
 };
 use crate::event::{epoll, EventfdFlags, PollFd};
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io;
-use linux_raw_sys::general::{EPOLL_CTL_ADD, EPOLL_CTL_DEL, EPOLL_CTL_MOD};
+use crate::timespec::Timespec;
+#[cfg(target_pointer_width = "32")]
+use linux_raw_sys::general::__kernel_old_timespec;
 #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
-use {
-    crate::backend::conv::{opt_ref, size_of},
-    linux_raw_sys::general::{__kernel_timespec, kernel_sigset_t},
-};
+use linux_raw_sys::general::__kernel_timespec;
+use linux_raw_sys::general::{kernel_sigset_t, EPOLL_CTL_ADD, EPOLL_CTL_DEL, EPOLL_CTL_MOD};
 
 #[inline]
 pub(crate) fn poll(fds: &mut [PollFd<'_>], timeout: c::c_int) -> io::Result<usize> {
@@ -50,6 +51,59 @@ pub(crate) fn poll(fds: &mut [PollFd<'_>], timeout: c::c_int) -> io::Result<usiz
     }
 }
 
+#[inline]
+pub(crate) fn ppoll(fds: &mut [PollFd<'_>], timeout: Option<&Timespec>) -> io::Result<usize> {
+    let (fds_addr_mut, fds_len) = slice_mut(fds);
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        match ret_usize(syscall!(
+            __NR_ppoll_time64,
+            fds_addr_mut,
+            fds_len,
+            opt_ref(timeout),
+            zero(),
+            size_of::<kernel_sigset_t, _>()
+        )) {
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            Err(io::Errno::NOSYS) => ppoll_old(fds, timeout),
+            otherwise => otherwise,
+        }
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret_usize(syscall!(
+            __NR_ppoll,
+            fds_addr_mut,
+            fds_len,
+            opt_ref(timeout),
+            zero(),
+            size_of::<kernel_sigset_t, _>()
+        ))
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+unsafe fn ppoll_old(fds: &mut [PollFd<'_>], timeout: Option<&Timespec>) -> io::Result<usize> {
+    let old_timeout = match timeout {
+        Some(timeout) => Some(__kernel_old_timespec {
+            tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
+            tv_nsec: timeout.tv_nsec.try_into().map_err(|_| io::Errno::INVAL)?,
+        }),
+        None => None,
+    };
+    let (fds_addr_mut, fds_len) = slice_mut(fds);
+    ret_usize(syscall!(
+        __NR_ppoll,
+        fds_addr_mut,
+        fds_len,
+        opt_ref(old_timeout.as_ref()),
+        zero(),
+        size_of::<kernel_sigset_t, _>()
+    ))
+}
+
 #[inline]
 pub(crate) fn epoll_create(flags: epoll::CreateFlags) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(syscall_readonly!(__NR_epoll_create1, flags)) }
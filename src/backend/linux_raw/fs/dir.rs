@@ -34,6 +34,9 @@ pub struct Dir {
 impl Dir {
     /// Take ownership of `fd` and construct a `Dir` that reads entries from
     /// the given directory file descriptor.
+    ///
+    /// If `fd` isn't a directory, this doesn't check up front; `getdents64`
+    /// fails with [`io::Errno::NOTDIR`] on the first [`Dir::read`].
     #[inline]
     pub fn new<Fd: Into<OwnedFd>>(fd: Fd) -> io::Result<Self> {
         Self::_new(fd.into())
@@ -214,6 +217,16 @@ impl Dir {
         }
     }
 
+    /// `dirfd(self)`—Borrow the file descriptor this `Dir` reads from.
+    ///
+    /// The file descriptor's file offset is used by the iteration, so it
+    /// shouldn't be used for seeking or reading directory entries.
+    #[inline]
+    #[doc(alias = "dirfd")]
+    pub fn fd(&self) -> io::Result<BorrowedFd<'_>> {
+        Ok(self.fd.as_fd())
+    }
+
     /// `fstat(self)`
     #[inline]
     pub fn stat(&self) -> io::Result<Stat> {
@@ -272,6 +285,13 @@ impl DirEntry {
     }
 
     /// Returns the type of this directory entry.
+    ///
+    /// This is [`FileType::Unknown`] on filesystems which don't record file
+    /// types in their directory entries. Use [`statat`] with
+    /// [`AtFlags::SYMLINK_NOFOLLOW`] to determine the type in that case.
+    ///
+    /// [`statat`]: crate::fs::statat
+    /// [`AtFlags::SYMLINK_NOFOLLOW`]: crate::fs::AtFlags::SYMLINK_NOFOLLOW
     #[inline]
     pub fn file_type(&self) -> FileType {
         FileType::from_dirent_d_type(self.d_type)
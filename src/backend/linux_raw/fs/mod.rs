@@ -1,6 +1,6 @@
 #[cfg(feature = "alloc")]
 pub(crate) mod dir;
-pub mod inotify;
+pub(crate) mod inotify;
 pub(crate) mod makedev;
 pub(crate) mod syscalls;
 pub(crate) mod types;
@@ -8,17 +8,9 @@
 
 use crate::backend::c;
 use crate::backend::conv::fs::oflags_for_open_how;
-#[cfg(any(
-    not(feature = "linux_4_11"),
-    target_arch = "aarch64",
-    target_arch = "riscv64",
-    target_arch = "mips",
-    target_arch = "mips32r6",
-))]
-use crate::backend::conv::zero;
 use crate::backend::conv::{
-    by_ref, c_int, c_uint, dev_t, opt_mut, pass_usize, raw_fd, ret, ret_c_int, ret_c_uint,
-    ret_infallible, ret_owned_fd, ret_usize, size_of, slice, slice_mut,
+    by_mut, by_ref, c_int, c_uint, dev_t, opt_mut, pass_usize, raw_fd, ret, ret_c_int, ret_c_uint,
+    ret_infallible, ret_owned_fd, ret_usize, size_of, slice, slice_mut, zero,
 };
 #[cfg(target_pointer_width = "64")]
 use crate::backend::conv::{loff_t, loff_t_from_u64, ret_u64};
@@ -35,12 +27,15 @@ use crate::ffi::CStr;
 #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
 use crate::fs::CWD;
 use crate::fs::{
-    inotify, Access, Advice, AtFlags, FallocateFlags, FileType, FlockOperation, Gid, MemfdFlags,
-    Mode, OFlags, RenameFlags, ResolveFlags, SealFlags, SeekFrom, Stat, StatFs, StatVfs,
-    StatVfsMountFlags, StatxFlags, Timestamps, Uid, XattrFlags,
+    inotify, Access, Advice, AtFlags, DnotifyFlags, FallocateFlags, FileType, Flock,
+    FlockOperation, FlockType, Gid, LeaseType, MemfdFlags, Mode, OFlags, RenameFlags, ResolveFlags,
+    SealFlags, SeekFrom, Stat, StatFs, StatVfs, StatVfsMountFlags, StatxFlags, Timestamps, Uid,
+    XattrFlags,
 };
 use crate::io;
 use core::mem::MaybeUninit;
+#[cfg(target_pointer_width = "64")]
+use linux_raw_sys::general::flock as linux_flock;
 #[cfg(any(target_arch = "mips64", target_arch = "mips64r6"))]
 use linux_raw_sys::general::stat as linux_stat64;
 use linux_raw_sys::general::{
@@ -51,6 +46,7 @@ use linux_raw_sys::general::{
 #[cfg(target_pointer_width = "32")]
 use {
     crate::backend::conv::{hi, lo, slice_just_addr},
+    linux_raw_sys::general::flock64 as linux_flock,
     linux_raw_sys::general::stat64 as linux_stat64,
     linux_raw_sys::general::timespec as __kernel_old_timespec,
 };
@@ -112,6 +108,25 @@ pub(crate) fn openat2(
     }
 }
 
+#[inline]
+pub(crate) fn is_openat2_available() -> bool {
+    unsafe {
+        // Call `openat2` with a zero size so that if it fails for any reason
+        // other than `EINVAL`, we know it's not supported. This can use
+        // "readonly" because we don't pass it a buffer to mutate.
+        matches!(
+            ret_owned_fd(syscall_readonly!(
+                __NR_openat2,
+                raw_fd(AT_FDCWD),
+                zero(),
+                zero(),
+                zero()
+            )),
+            Err(io::Errno::INVAL)
+        )
+    }
+}
+
 #[inline]
 pub(crate) fn chmod(path: &CStr, mode: Mode) -> io::Result<()> {
     unsafe {
@@ -131,15 +146,69 @@ pub(crate) fn chmodat(
     mode: Mode,
     flags: AtFlags,
 ) -> io::Result<()> {
-    if flags == AtFlags::SYMLINK_NOFOLLOW {
-        return Err(io::Errno::OPNOTSUPP);
+    if !flags
+        .difference(AtFlags::SYMLINK_NOFOLLOW | AtFlags::EMPTY_PATH)
+        .is_empty()
+    {
+        return Err(io::Errno::INVAL);
     }
+
+    // Linux's `fchmodat` syscall doesn't have a flags argument, so if we have
+    // any flags, use the newer `fchmodat2` introduced in Linux 6.6 which
+    // does. Unless we're on Android where using newer system calls can cause
+    // seccomp to abort the process.
+    #[cfg(not(target_os = "android"))]
     if !flags.is_empty() {
-        return Err(io::Errno::INVAL);
+        // linux-raw-sys 0.4 doesn't have `__NR_fchmodat2`, so we can't use
+        // `syscall_readonly!`.
+        unsafe {
+            match ret(crate::backend::arch::choose::syscall4_readonly(
+                crate::backend::reg::nr(__NR_fchmodat2),
+                dirfd.into(),
+                path.into(),
+                mode.into(),
+                flags.into(),
+            )) {
+                Ok(()) => return Ok(()),
+                Err(io::Errno::NOSYS) => {}
+                Err(other) => return Err(other),
+            }
+        }
+    }
+
+    // An empty path with `AT_EMPTY_PATH` refers to `dirfd` itself.
+    if flags.contains(AtFlags::EMPTY_PATH) && path.to_bytes().is_empty() {
+        return fchmod(dirfd, mode);
+    }
+    if flags.contains(AtFlags::SYMLINK_NOFOLLOW) {
+        return Err(io::Errno::OPNOTSUPP);
     }
     unsafe { ret(syscall_readonly!(__NR_fchmodat, dirfd, path, mode)) }
 }
 
+/// The `fchmodat2` syscall number.
+#[cfg(not(target_os = "android"))]
+#[allow(non_upper_case_globals)]
+const __NR_fchmodat2: u32 = {
+    #[cfg(any(target_arch = "mips", target_arch = "mips32r6"))]
+    {
+        4452
+    }
+    #[cfg(any(target_arch = "mips64", target_arch = "mips64r6"))]
+    {
+        5452
+    }
+    #[cfg(not(any(
+        target_arch = "mips",
+        target_arch = "mips32r6",
+        target_arch = "mips64",
+        target_arch = "mips64r6"
+    )))]
+    {
+        452
+    }
+};
+
 #[inline]
 pub(crate) fn fchmod(fd: BorrowedFd<'_>, mode: Mode) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_fchmod, fd, mode)) }
@@ -919,6 +988,9 @@ pub(crate) fn statvfs(path: &CStr) -> io::Result<StatVfs> {
     Ok(statfs_to_statvfs(statfs))
 }
 
+/// `ST_VALID`, which linux-raw-sys doesn't have.
+const ST_VALID: u64 = 0x20;
+
 fn statfs_to_statvfs(statfs: StatFs) -> StatVfs {
     let __kernel_fsid_t { val } = statfs.f_fsid;
     let [f_fsid_val0, f_fsid_val1]: [i32; 2] = val;
@@ -937,14 +1009,15 @@ fn statfs_to_statvfs(statfs: StatFs) -> StatVfs {
         f_ffree: statfs.f_ffree as u64,
         f_favail: statfs.f_ffree as u64,
         f_fsid: u64::from(f_fsid_val0 as u32) | u64::from(f_fsid_val1 as u32) << 32,
-        f_flag: StatVfsMountFlags::from_bits_retain(statfs.f_flags as u64),
+        // Linux sets `ST_VALID` to indicate that `f_flags` is supported, which
+        // isn't a mount flag, so clear it, as glibc does.
+        f_flag: StatVfsMountFlags::from_bits_retain(statfs.f_flags as u64 & !ST_VALID),
         f_namemax: statfs.f_namelen as u64,
     }
 }
 
-#[cfg(feature = "alloc")]
 #[inline]
-pub(crate) fn readlink(path: &CStr, buf: &mut [u8]) -> io::Result<usize> {
+pub(crate) fn readlink(path: &CStr, buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
     unsafe {
         ret_usize(syscall!(
@@ -1039,6 +1112,76 @@ pub(crate) fn fcntl_add_seals(fd: BorrowedFd<'_>, seals: SealFlags) -> io::Resul
     }
 }
 
+#[inline]
+pub(crate) fn fcntl_setlease(fd: BorrowedFd<'_>, lease: Option<LeaseType>) -> io::Result<()> {
+    use linux_raw_sys::general::{F_SETLEASE, F_UNLCK};
+
+    let arg = match lease {
+        Some(lease) => lease as u32,
+        None => F_UNLCK,
+    };
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl64,
+            fd,
+            c_uint(F_SETLEASE),
+            c_uint(arg)
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl,
+            fd,
+            c_uint(F_SETLEASE),
+            c_uint(arg)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fcntl_getlease(fd: BorrowedFd<'_>) -> io::Result<Option<LeaseType>> {
+    use linux_raw_sys::general::{F_GETLEASE, F_RDLCK, F_UNLCK, F_WRLCK};
+
+    #[cfg(target_pointer_width = "32")]
+    let lease = unsafe { ret_c_uint(syscall_readonly!(__NR_fcntl64, fd, c_uint(F_GETLEASE)))? };
+    #[cfg(target_pointer_width = "64")]
+    let lease = unsafe { ret_c_uint(syscall_readonly!(__NR_fcntl, fd, c_uint(F_GETLEASE)))? };
+
+    match lease {
+        F_RDLCK => Ok(Some(LeaseType::Read)),
+        F_WRLCK => Ok(Some(LeaseType::Write)),
+        F_UNLCK => Ok(None),
+        _ => Err(io::Errno::INVAL),
+    }
+}
+
+#[inline]
+pub(crate) fn fcntl_notify(fd: BorrowedFd<'_>, flags: DnotifyFlags) -> io::Result<()> {
+    use linux_raw_sys::general::F_NOTIFY;
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl64,
+            fd,
+            c_uint(F_NOTIFY),
+            c_uint(flags.bits())
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl,
+            fd,
+            c_uint(F_NOTIFY),
+            c_uint(flags.bits())
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn fcntl_lock(fd: BorrowedFd<'_>, operation: FlockOperation) -> io::Result<()> {
     #[cfg(target_pointer_width = "64")]
@@ -1090,6 +1233,122 @@ pub(crate) fn fcntl_lock(fd: BorrowedFd<'_>, operation: FlockOperation) -> io::R
     }
 }
 
+#[inline]
+pub(crate) fn fcntl_setlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    #[cfg(target_pointer_width = "64")]
+    use linux_raw_sys::general::F_SETLK;
+    #[cfg(target_pointer_width = "32")]
+    use linux_raw_sys::general::F_SETLK64 as F_SETLK;
+
+    fcntl_set_record_lock(fd, F_SETLK, lock)
+}
+
+#[inline]
+pub(crate) fn fcntl_setlkw(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    #[cfg(target_pointer_width = "64")]
+    use linux_raw_sys::general::F_SETLKW;
+    #[cfg(target_pointer_width = "32")]
+    use linux_raw_sys::general::F_SETLKW64 as F_SETLKW;
+
+    fcntl_set_record_lock(fd, F_SETLKW, lock)
+}
+
+#[inline]
+pub(crate) fn fcntl_getlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<Option<Flock>> {
+    #[cfg(target_pointer_width = "64")]
+    use linux_raw_sys::general::F_GETLK;
+    #[cfg(target_pointer_width = "32")]
+    use linux_raw_sys::general::F_GETLK64 as F_GETLK;
+
+    fcntl_get_record_lock(fd, F_GETLK, lock)
+}
+
+#[inline]
+pub(crate) fn fcntl_ofd_setlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    fcntl_set_record_lock(fd, linux_raw_sys::general::F_OFD_SETLK, lock)
+}
+
+#[inline]
+pub(crate) fn fcntl_ofd_setlkw(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    fcntl_set_record_lock(fd, linux_raw_sys::general::F_OFD_SETLKW, lock)
+}
+
+#[inline]
+pub(crate) fn fcntl_ofd_getlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<Option<Flock>> {
+    fcntl_get_record_lock(fd, linux_raw_sys::general::F_OFD_GETLK, lock)
+}
+
+fn fcntl_set_record_lock(fd: BorrowedFd<'_>, cmd: c::c_uint, lock: &Flock) -> io::Result<()> {
+    let lock = flock_to_raw(lock)?;
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl64,
+            fd,
+            c_uint(cmd),
+            by_ref(&lock)
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl,
+            fd,
+            c_uint(cmd),
+            by_ref(&lock)
+        ))
+    }
+}
+
+fn fcntl_get_record_lock(
+    fd: BorrowedFd<'_>,
+    cmd: c::c_uint,
+    lock: &Flock,
+) -> io::Result<Option<Flock>> {
+    let mut lock = flock_to_raw(lock)?;
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall!(__NR_fcntl64, fd, c_uint(cmd), by_mut(&mut lock)))?;
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall!(__NR_fcntl, fd, c_uint(cmd), by_mut(&mut lock)))?;
+    }
+
+    let typ = match lock.l_type as c::c_uint {
+        linux_raw_sys::general::F_RDLCK => FlockType::ReadLock,
+        linux_raw_sys::general::F_WRLCK => FlockType::WriteLock,
+        _ => return Ok(None),
+    };
+    Ok(Some(Flock {
+        typ,
+        start: lock.l_start as u64,
+        len: lock.l_len as u64,
+        pid: crate::pid::Pid::from_raw(lock.l_pid),
+    }))
+}
+
+fn flock_to_raw(lock: &Flock) -> io::Result<linux_flock> {
+    use linux_raw_sys::general::{F_RDLCK, F_UNLCK, F_WRLCK};
+
+    let l_type = match lock.typ {
+        FlockType::ReadLock => F_RDLCK,
+        FlockType::WriteLock => F_WRLCK,
+        FlockType::Unlocked => F_UNLCK,
+    };
+    Ok(linux_flock {
+        l_type: l_type as _,
+        l_whence: SEEK_SET as _,
+        l_start: lock.start.try_into().map_err(|_| io::Errno::INVAL)?,
+        l_len: lock.len.try_into().map_err(|_| io::Errno::INVAL)?,
+
+        // Must be zero for open file description locks.
+        l_pid: 0,
+    })
+}
+
 #[inline]
 pub(crate) fn rename(old_path: &CStr, new_path: &CStr) -> io::Result<()> {
     #[cfg(target_arch = "riscv64")]
@@ -1395,7 +1654,7 @@ pub(crate) fn accessat(
     flags: AtFlags,
 ) -> io::Result<()> {
     if !flags
-        .difference(AtFlags::EACCESS | AtFlags::SYMLINK_NOFOLLOW)
+        .difference(AtFlags::EACCESS | AtFlags::SYMLINK_NOFOLLOW | AtFlags::EMPTY_PATH)
         .is_empty()
     {
         return Err(io::Errno::INVAL);
@@ -1517,7 +1776,11 @@ pub(crate) fn inotify_rm_watch(infd: BorrowedFd<'_>, wfd: i32) -> io::Result<()>
 }
 
 #[inline]
-pub(crate) fn getxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+pub(crate) fn getxattr(
+    path: &CStr,
+    name: &CStr,
+    value: &mut [MaybeUninit<u8>],
+) -> io::Result<usize> {
     let (value_addr_mut, value_len) = slice_mut(value);
     unsafe {
         ret_usize(syscall!(
@@ -1531,7 +1794,11 @@ pub(crate) fn getxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result
 }
 
 #[inline]
-pub(crate) fn lgetxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+pub(crate) fn lgetxattr(
+    path: &CStr,
+    name: &CStr,
+    value: &mut [MaybeUninit<u8>],
+) -> io::Result<usize> {
     let (value_addr_mut, value_len) = slice_mut(value);
     unsafe {
         ret_usize(syscall!(
@@ -1545,7 +1812,11 @@ pub(crate) fn lgetxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Resul
 }
 
 #[inline]
-pub(crate) fn fgetxattr(fd: BorrowedFd<'_>, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+pub(crate) fn fgetxattr(
+    fd: BorrowedFd<'_>,
+    name: &CStr,
+    value: &mut [MaybeUninit<u8>],
+) -> io::Result<usize> {
     let (value_addr_mut, value_len) = slice_mut(value);
     unsafe {
         ret_usize(syscall!(
@@ -1619,19 +1890,19 @@ pub(crate) fn fsetxattr(
 }
 
 #[inline]
-pub(crate) fn listxattr(path: &CStr, list: &mut [c::c_char]) -> io::Result<usize> {
+pub(crate) fn listxattr(path: &CStr, list: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
     let (list_addr_mut, list_len) = slice_mut(list);
     unsafe { ret_usize(syscall!(__NR_listxattr, path, list_addr_mut, list_len)) }
 }
 
 #[inline]
-pub(crate) fn llistxattr(path: &CStr, list: &mut [c::c_char]) -> io::Result<usize> {
+pub(crate) fn llistxattr(path: &CStr, list: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
     let (list_addr_mut, list_len) = slice_mut(list);
     unsafe { ret_usize(syscall!(__NR_llistxattr, path, list_addr_mut, list_len)) }
 }
 
 #[inline]
-pub(crate) fn flistxattr(fd: BorrowedFd<'_>, list: &mut [c::c_char]) -> io::Result<usize> {
+pub(crate) fn flistxattr(fd: BorrowedFd<'_>, list: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
     let (list_addr_mut, list_len) = slice_mut(list);
     unsafe { ret_usize(syscall!(__NR_flistxattr, fd, list_addr_mut, list_len)) }
 }
@@ -236,6 +236,15 @@ bitflags! {
         const TRUNC = linux_raw_sys::general::O_TRUNC;
 
         /// `O_PATH`
+        ///
+        /// Opens a file descriptor which only identifies a file, without
+        /// opening the file itself. It can be used as the `dirfd` argument
+        /// of `*at` functions, with `AT_EMPTY_PATH` to operate on the file
+        /// itself, and with `fstat`, `fstatfs`, `fchdir`, and `close`. It
+        /// can't be used to read or write the file; use [`reopen`] to get a
+        /// file descriptor which can.
+        ///
+        /// [`reopen`]: crate::fs::reopen
         const PATH = linux_raw_sys::general::O_PATH;
 
         /// `O_CLOEXEC`
@@ -269,22 +278,30 @@ bitflags! {
     #[repr(transparent)]
     #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct ResolveFlags: u64 {
-        /// `RESOLVE_NO_XDEV`
+        /// `RESOLVE_NO_XDEV`—Fail with `EXDEV` if resolution would cross a
+        /// mount point, including bind mounts.
         const NO_XDEV = linux_raw_sys::general::RESOLVE_NO_XDEV as u64;
 
-        /// `RESOLVE_NO_MAGICLINKS`
+        /// `RESOLVE_NO_MAGICLINKS`—Fail with `ELOOP` if resolution would
+        /// follow a "magic link", such as the `/proc/<pid>/fd/*` entries.
         const NO_MAGICLINKS = linux_raw_sys::general::RESOLVE_NO_MAGICLINKS as u64;
 
-        /// `RESOLVE_NO_SYMLINKS`
+        /// `RESOLVE_NO_SYMLINKS`—Fail with `ELOOP` if resolution would follow
+        /// any symlink, including magic links. This implies `NO_MAGICLINKS`.
         const NO_SYMLINKS = linux_raw_sys::general::RESOLVE_NO_SYMLINKS as u64;
 
-        /// `RESOLVE_BENEATH`
+        /// `RESOLVE_BENEATH`—Fail with `EXDEV` if resolution would leave the
+        /// directory `dirfd`, such as through `..` components, absolute
+        /// paths, or symlinks which lead outside of it.
         const BENEATH = linux_raw_sys::general::RESOLVE_BENEATH as u64;
 
-        /// `RESOLVE_IN_ROOT`
+        /// `RESOLVE_IN_ROOT`—Resolve as if `dirfd` were the root directory,
+        /// as with `chroot`, so that `..` components and absolute symlinks
+        /// stay within it.
         const IN_ROOT = linux_raw_sys::general::RESOLVE_IN_ROOT as u64;
 
-        /// `RESOLVE_CACHED` (since Linux 5.12)
+        /// `RESOLVE_CACHED` (since Linux 5.12)—Fail with `EAGAIN` unless
+        /// resolution can be completed entirely from the dentry cache.
         const CACHED = linux_raw_sys::general::RESOLVE_CACHED as u64;
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
@@ -400,22 +417,26 @@ impl FileType {
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[repr(u32)]
 pub enum Advice {
-    /// `POSIX_FADV_NORMAL`
+    /// `POSIX_FADV_NORMAL`—No particular access pattern.
     Normal = linux_raw_sys::general::POSIX_FADV_NORMAL,
 
-    /// `POSIX_FADV_SEQUENTIAL`
+    /// `POSIX_FADV_SEQUENTIAL`—The data will be accessed sequentially,
+    /// so read ahead aggressively.
     Sequential = linux_raw_sys::general::POSIX_FADV_SEQUENTIAL,
 
-    /// `POSIX_FADV_RANDOM`
+    /// `POSIX_FADV_RANDOM`—The data will be accessed randomly, so don't
+    /// read ahead.
     Random = linux_raw_sys::general::POSIX_FADV_RANDOM,
 
-    /// `POSIX_FADV_NOREUSE`
+    /// `POSIX_FADV_NOREUSE`—The data will only be accessed once.
     NoReuse = linux_raw_sys::general::POSIX_FADV_NOREUSE,
 
-    /// `POSIX_FADV_WILLNEED`
+    /// `POSIX_FADV_WILLNEED`—The data will be accessed soon, so start
+    /// reading it into the page cache.
     WillNeed = linux_raw_sys::general::POSIX_FADV_WILLNEED,
 
-    /// `POSIX_FADV_DONTNEED`
+    /// `POSIX_FADV_DONTNEED`—The data won't be accessed soon, so it can
+    /// be dropped from the page cache.
     DontNeed = linux_raw_sys::general::POSIX_FADV_DONTNEED,
 }
 
@@ -495,6 +516,47 @@ bitflags! {
     }
 }
 
+/// `F_RDLCK` and `F_WRLCK` constants for use with [`fcntl_setlease`] and
+/// [`fcntl_getlease`].
+///
+/// [`fcntl_setlease`]: crate::fs::fcntl_setlease
+/// [`fcntl_getlease`]: crate::fs::fcntl_getlease
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum LeaseType {
+    /// `F_RDLCK`
+    Read = linux_raw_sys::general::F_RDLCK,
+    /// `F_WRLCK`
+    Write = linux_raw_sys::general::F_WRLCK,
+}
+
+bitflags! {
+    /// `DN_*` constants for use with [`fcntl_notify`].
+    ///
+    /// [`fcntl_notify`]: crate::fs::fcntl_notify
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct DnotifyFlags: u32 {
+        /// `DN_ACCESS`
+        const ACCESS = linux_raw_sys::general::DN_ACCESS;
+        /// `DN_MODIFY`
+        const MODIFY = linux_raw_sys::general::DN_MODIFY;
+        /// `DN_CREATE`
+        const CREATE = linux_raw_sys::general::DN_CREATE;
+        /// `DN_DELETE`
+        const DELETE = linux_raw_sys::general::DN_DELETE;
+        /// `DN_RENAME`
+        const RENAME = linux_raw_sys::general::DN_RENAME;
+        /// `DN_ATTRIB`
+        const ATTRIB = linux_raw_sys::general::DN_ATTRIB;
+        /// `DN_MULTISHOT`
+        const MULTISHOT = linux_raw_sys::general::DN_MULTISHOT;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
 bitflags! {
     /// `STATX_*` constants for use with [`statx`].
     ///
@@ -562,19 +624,38 @@ bitflags! {
     #[repr(transparent)]
     #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct FallocateFlags: u32 {
-        /// `FALLOC_FL_KEEP_SIZE`
+        /// `FALLOC_FL_KEEP_SIZE`—Don't change the file size, even when
+        /// allocating past the end of the file.
         const KEEP_SIZE = linux_raw_sys::general::FALLOC_FL_KEEP_SIZE;
-        /// `FALLOC_FL_PUNCH_HOLE`
+        /// `FALLOC_FL_PUNCH_HOLE`—Deallocate the range, so that it reads as
+        /// zeros.
+        ///
+        /// This must be combined with [`KEEP_SIZE`].
+        ///
+        /// [`KEEP_SIZE`]: Self::KEEP_SIZE
         const PUNCH_HOLE = linux_raw_sys::general::FALLOC_FL_PUNCH_HOLE;
         /// `FALLOC_FL_NO_HIDE_STALE`
         const NO_HIDE_STALE = linux_raw_sys::general::FALLOC_FL_NO_HIDE_STALE;
-        /// `FALLOC_FL_COLLAPSE_RANGE`
+        /// `FALLOC_FL_COLLAPSE_RANGE`—Remove the range from the file, shifting
+        /// the data after it down and shrinking the file.
+        ///
+        /// `offset` and `len` must be multiples of the filesystem block size,
+        /// and the range must not reach the end of the file.
         const COLLAPSE_RANGE = linux_raw_sys::general::FALLOC_FL_COLLAPSE_RANGE;
-        /// `FALLOC_FL_ZERO_RANGE`
+        /// `FALLOC_FL_ZERO_RANGE`—Zero the range, allocating blocks for it.
+        ///
+        /// Unless combined with [`KEEP_SIZE`], this extends the file if the
+        /// range goes past the end of it.
+        ///
+        /// [`KEEP_SIZE`]: Self::KEEP_SIZE
         const ZERO_RANGE = linux_raw_sys::general::FALLOC_FL_ZERO_RANGE;
-        /// `FALLOC_FL_INSERT_RANGE`
+        /// `FALLOC_FL_INSERT_RANGE`—Insert a hole at `offset`, shifting the
+        /// data after it up and growing the file.
+        ///
+        /// `offset` and `len` must be multiples of the filesystem block size.
         const INSERT_RANGE = linux_raw_sys::general::FALLOC_FL_INSERT_RANGE;
-        /// `FALLOC_FL_UNSHARE_RANGE`
+        /// `FALLOC_FL_UNSHARE_RANGE`—Unshare any blocks in the range which are
+        /// shared with other files, such as by reflinks.
         const UNSHARE_RANGE = linux_raw_sys::general::FALLOC_FL_UNSHARE_RANGE;
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
@@ -584,6 +665,12 @@ bitflags! {
 
 bitflags! {
     /// `ST_*` constants for use with [`StatVfs`].
+    ///
+    /// POSIX only specifies [`RDONLY`] and [`NOSUID`]; the others are only
+    /// reported on Linux.
+    ///
+    /// [`RDONLY`]: Self::RDONLY
+    /// [`NOSUID`]: Self::NOSUID
     #[repr(transparent)]
     #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct StatVfsMountFlags: u64 {
@@ -609,7 +696,9 @@ bitflags! {
         const RDONLY = linux_raw_sys::general::MS_RDONLY as u64;
 
         /// `ST_RELATIME`
-        const RELATIME = linux_raw_sys::general::MS_RELATIME as u64;
+        // Unlike the others, this differs from the corresponding `MS_*`
+        // constant, and linux-raw-sys doesn't have the `ST_*` constants.
+        const RELATIME = 0x1000;
 
         /// `ST_SYNCHRONOUS`
         const SYNCHRONOUS = linux_raw_sys::general::MS_SYNCHRONOUS as u64;
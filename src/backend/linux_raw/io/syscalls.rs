@@ -26,7 +26,7 @@ use crate::ioctl::{IoctlOutput, RawOpcode};
 #[cfg(all(feature = "fs", feature = "net"))]
 use crate::net::{RecvFlags, SendFlags};
 use core::cmp;
-use linux_raw_sys::general::{F_DUPFD_CLOEXEC, F_GETFD, F_SETFD};
+use linux_raw_sys::general::{F_DUPFD, F_DUPFD_CLOEXEC, F_GETFD, F_SETFD};
 
 #[inline]
 pub(crate) unsafe fn read(fd: BorrowedFd<'_>, buf: *mut u8, len: usize) -> io::Result<usize> {
@@ -282,6 +282,9 @@ pub(crate) fn is_read_write(fd: BorrowedFd<'_>) -> io::Result<(bool, bool)> {
                 match err {
                     io::Errno::AGAIN | io::Errno::WOULDBLOCK => (),
                     io::Errno::NOTSOCK => not_socket = true,
+                    // A stream socket which isn't connected, such as a
+                    // listening socket, can't be read from.
+                    io::Errno::NOTCONN => read = false,
                     _ => return Err(err),
                 }
             }
@@ -290,11 +293,16 @@ pub(crate) fn is_read_write(fd: BorrowedFd<'_>) -> io::Result<(bool, bool)> {
     }
     if write && !not_socket {
         // Do a `send` with `DONTWAIT` for 0 bytes. An `EPIPE` indicates
-        // the write side is shut down.
+        // the write side is shut down. Pass `NOSIGNAL` so that this doesn't
+        // raise `SIGPIPE` when it is.
         #[allow(unreachable_patterns)] // `EAGAIN` equals `EWOULDBLOCK`
-        match crate::backend::net::syscalls::send(fd, &[], SendFlags::DONTWAIT) {
+        match crate::backend::net::syscalls::send(
+            fd,
+            &[],
+            SendFlags::DONTWAIT | SendFlags::NOSIGNAL,
+        ) {
             Err(io::Errno::AGAIN | io::Errno::WOULDBLOCK | io::Errno::NOTSOCK) => (),
-            Err(io::Errno::PIPE) => write = false,
+            Err(io::Errno::PIPE | io::Errno::NOTCONN) => write = false,
             Err(err) => return Err(err),
             Ok(_) => (),
         }
@@ -344,6 +352,18 @@ pub(crate) fn fcntl_getfd(fd: BorrowedFd<'_>) -> io::Result<FdFlags> {
     }
 }
 
+#[cfg(all(feature = "event", feature = "net", feature = "std"))]
+#[inline]
+pub(crate) fn fcntl_getfl_nonblock(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    use linux_raw_sys::general::{F_GETFL, O_NONBLOCK};
+
+    #[cfg(target_pointer_width = "32")]
+    let flags = unsafe { ret_c_uint(syscall_readonly!(__NR_fcntl64, fd, c_uint(F_GETFL)))? };
+    #[cfg(target_pointer_width = "64")]
+    let flags = unsafe { ret_c_uint(syscall_readonly!(__NR_fcntl, fd, c_uint(F_GETFL)))? };
+    Ok(flags & O_NONBLOCK != 0)
+}
+
 #[inline]
 pub(crate) fn fcntl_setfd(fd: BorrowedFd<'_>, flags: FdFlags) -> io::Result<()> {
     #[cfg(target_pointer_width = "32")]
@@ -377,3 +397,25 @@ pub(crate) fn fcntl_dupfd_cloexec(fd: BorrowedFd<'_>, min: RawFd) -> io::Result<
         ))
     }
 }
+
+#[inline]
+pub(crate) fn fcntl_dupfd(fd: BorrowedFd<'_>, min: RawFd) -> io::Result<OwnedFd> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_fcntl64,
+            fd,
+            c_uint(F_DUPFD),
+            raw_fd(min)
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_fcntl,
+            fd,
+            c_uint(F_DUPFD),
+            raw_fd(min)
+        ))
+    }
+}
@@ -11,9 +11,15 @@ use super::types::{
     UserfaultfdFlags,
 };
 use crate::backend::c;
-#[cfg(target_pointer_width = "64")]
+#[cfg(target_arch = "s390x")]
+use crate::backend::conv::by_ref;
+#[cfg(all(target_pointer_width = "64", not(target_arch = "s390x")))]
 use crate::backend::conv::loff_t_from_u64;
-use crate::backend::conv::{c_uint, no_fd, pass_usize, ret, ret_owned_fd, ret_void_star};
+#[cfg(not(target_arch = "s390x"))]
+use crate::backend::conv::no_fd;
+use crate::backend::conv::{c_uint, pass_usize, ret, ret_owned_fd, ret_void_star};
+#[cfg(target_arch = "s390x")]
+use crate::fd::AsRawFd;
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io;
 use linux_raw_sys::general::MAP_ANONYMOUS;
@@ -63,7 +69,7 @@ pub(crate) unsafe fn mmap(
                 .map_err(|_| io::Errno::INVAL)?
         ))
     }
-    #[cfg(target_pointer_width = "64")]
+    #[cfg(all(target_pointer_width = "64", not(target_arch = "s390x")))]
     {
         ret_void_star(syscall!(
             __NR_mmap,
@@ -75,6 +81,17 @@ pub(crate) unsafe fn mmap(
             loff_t_from_u64(offset)
         ))
     }
+    #[cfg(target_arch = "s390x")]
+    {
+        mmap_s390x(&[
+            addr as usize,
+            length,
+            prot.bits() as usize,
+            flags.bits() as usize,
+            fd.as_raw_fd() as isize as usize,
+            offset as usize,
+        ])
+    }
 }
 
 /// # Safety
@@ -100,7 +117,7 @@ pub(crate) unsafe fn mmap_anonymous(
             pass_usize(0)
         ))
     }
-    #[cfg(target_pointer_width = "64")]
+    #[cfg(all(target_pointer_width = "64", not(target_arch = "s390x")))]
     {
         ret_void_star(syscall!(
             __NR_mmap,
@@ -112,6 +129,26 @@ pub(crate) unsafe fn mmap_anonymous(
             loff_t_from_u64(0)
         ))
     }
+    #[cfg(target_arch = "s390x")]
+    {
+        mmap_s390x(&[
+            addr as usize,
+            length,
+            prot.bits() as usize,
+            (flags.bits() | MAP_ANONYMOUS) as usize,
+            -1_isize as usize,
+            0,
+        ])
+    }
+}
+
+/// On s390x, `mmap` takes a pointer to its six arguments, in the order
+/// `addr`, `length`, `prot`, `flags`, `fd`, and `offset`, rather than taking
+/// them in registers.
+#[cfg(target_arch = "s390x")]
+#[inline]
+unsafe fn mmap_s390x(args: &[usize; 6]) -> io::Result<*mut c::c_void> {
+    ret_void_star(syscall!(__NR_mmap, by_ref(args)))
 }
 
 #[inline]
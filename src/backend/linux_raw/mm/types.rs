@@ -234,6 +234,8 @@ pub enum Advice {
     LinuxPopulateWrite = linux_raw_sys::general::MADV_POPULATE_WRITE,
     /// `MADV_DONTNEED_LOCKED` (since Linux 5.18)
     LinuxDontneedLocked = linux_raw_sys::general::MADV_DONTNEED_LOCKED,
+    /// `MADV_COLLAPSE` (since Linux 6.1)
+    LinuxCollapse = linux_raw_sys::general::MADV_COLLAPSE,
 }
 
 #[allow(non_upper_case_globals)]
@@ -95,6 +95,8 @@ pub(crate) mod c;
 pub(crate) mod pid;
 #[cfg(any(feature = "process", feature = "thread"))]
 pub(crate) mod prctl;
+#[cfg(any(feature = "process", feature = "runtime"))]
+pub(crate) mod signal;
 #[cfg(any(
     feature = "fs",
     feature = "process",
@@ -30,35 +30,69 @@ impl SocketAddrUnix {
 
     #[inline]
     fn _new(path: &CStr) -> io::Result<Self> {
+        Self::new_path_bytes(path.to_bytes())
+    }
+
+    /// Construct a new Unix-domain address from the bytes of a filesystem
+    /// path, not including a trailing NUL.
+    ///
+    /// This is a `const` variant of [`Self::new`]. It fails with
+    /// [`io::Errno::INVAL`] if `path` contains a NUL byte.
+    #[inline]
+    pub const fn new_path_bytes(path: &[u8]) -> io::Result<Self> {
         let mut unix = Self::init();
-        let bytes = path.to_bytes_with_nul();
-        if bytes.len() > unix.sun_path.len() {
+        if path.len() + 1 > unix.sun_path.len() {
             return Err(io::Errno::NAMETOOLONG);
         }
-        for (i, b) in bytes.iter().enumerate() {
-            unix.sun_path[i] = *b as _;
+        let mut i = 0;
+        while i < path.len() {
+            if path[i] == b'\0' {
+                return Err(io::Errno::INVAL);
+            }
+            unix.sun_path[i] = path[i] as _;
+            i += 1;
         }
-        let len = offsetof_sun_path() + bytes.len();
-        let len = len.try_into().unwrap();
-        Ok(Self { unix, len })
+        let len = offsetof_sun_path() + path.len() + 1;
+        Ok(Self {
+            unix,
+            len: len as _,
+        })
     }
 
     /// Construct a new abstract Unix-domain address from a byte slice.
     #[inline]
-    pub fn new_abstract_name(name: &[u8]) -> io::Result<Self> {
+    pub const fn new_abstract_name(name: &[u8]) -> io::Result<Self> {
         let mut unix = Self::init();
-        let id = &mut unix.sun_path[1..];
-
-        // SAFETY: Convert `&mut [c_char]` to `&mut [u8]`.
-        let id = unsafe { slice::from_raw_parts_mut(id.as_mut_ptr().cast::<u8>(), id.len()) };
+        if 1 + name.len() > unix.sun_path.len() {
+            return Err(io::Errno::NAMETOOLONG);
+        }
+        let mut i = 0;
+        while i < name.len() {
+            unix.sun_path[1 + i] = name[i] as _;
+            i += 1;
+        }
+        let len = offsetof_sun_path() + 1 + name.len();
+        Ok(Self {
+            unix,
+            len: len as _,
+        })
+    }
 
-        if let Some(id) = id.get_mut(..name.len()) {
-            id.copy_from_slice(name);
-            let len = offsetof_sun_path() + 1 + name.len();
-            let len = len.try_into().unwrap();
-            Ok(Self { unix, len })
-        } else {
-            Err(io::Errno::NAMETOOLONG)
+    /// Construct a new unnamed Unix-domain address.
+    ///
+    /// This is the address of a socket which isn't bound. On Linux, binding a
+    /// socket to this address makes the kernel "autobind" it to a unique
+    /// abstract address, which [`getsockname`] returns. The kernel also
+    /// autobinds unbound sockets with [`set_socket_passcred`] enabled when
+    /// they connect or send.
+    ///
+    /// [`getsockname`]: crate::net::getsockname
+    /// [`set_socket_passcred`]: crate::net::sockopt::set_socket_passcred
+    #[inline]
+    pub const fn new_unnamed() -> Self {
+        Self {
+            unix: Self::init(),
+            len: offsetof_sun_path() as _,
         }
     }
 
@@ -73,7 +107,7 @@ impl SocketAddrUnix {
     #[inline]
     pub fn path(&self) -> Option<&CStr> {
         let len = self.len();
-        if len != 0 && self.unix.sun_path[0] as u8 != b'\0' {
+        if len > offsetof_sun_path() && self.unix.sun_path[0] as u8 != b'\0' {
             let end = len as usize - offsetof_sun_path();
             let bytes = &self.unix.sun_path[..end];
 
@@ -92,7 +126,7 @@ impl SocketAddrUnix {
     #[inline]
     pub fn abstract_name(&self) -> Option<&[u8]> {
         let len = self.len();
-        if len != 0 && self.unix.sun_path[0] as u8 == b'\0' {
+        if len > offsetof_sun_path() && self.unix.sun_path[0] as u8 == b'\0' {
             let end = len as usize - offsetof_sun_path();
             let bytes = &self.unix.sun_path[1..end];
 
@@ -105,6 +139,12 @@ impl SocketAddrUnix {
         }
     }
 
+    /// Is this address unnamed?
+    #[inline]
+    pub fn is_unnamed(&self) -> bool {
+        self.len() == offsetof_sun_path()
+    }
+
     #[inline]
     pub(crate) fn addr_len(&self) -> c::socklen_t {
         self.len
@@ -168,10 +208,9 @@ pub type SocketAddrStorage = c::sockaddr;
 
 /// Return the offset of the `sun_path` field of `sockaddr_un`.
 #[inline]
-pub(crate) fn offsetof_sun_path() -> usize {
-    let z = c::sockaddr_un {
-        sun_family: 0_u16,
-        sun_path: [0; 108],
-    };
-    (crate::utils::as_ptr(&z.sun_path) as usize) - (crate::utils::as_ptr(&z) as usize)
+pub(crate) const fn offsetof_sun_path() -> usize {
+    // `sun_path` is the last field, and it's an array of bytes, so there's no
+    // trailing padding after it.
+    let z = SocketAddrUnix::init();
+    core::mem::size_of::<c::sockaddr_un>() - z.sun_path.len()
 }
@@ -4,9 +4,13 @@
 
 use crate::backend::c;
 use crate::io;
+use crate::net::netlink::SocketAddrNetlink;
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::{SockaddrXdpFlags, SocketAddrXdp};
-use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrRaw, SocketAddrUnix, SocketAddrV4, SocketAddrV6,
+};
 use core::mem::size_of;
 use core::slice;
 
@@ -22,7 +26,7 @@ struct sockaddr_header {
 ///
 /// `storage` must point to a valid socket address returned from the OS.
 #[inline]
-unsafe fn read_ss_family(storage: *const c::sockaddr) -> u16 {
+pub(crate) unsafe fn read_ss_family(storage: *const c::sockaddr) -> u16 {
     // Assert that we know the layout of `sockaddr`.
     let _ = c::sockaddr {
         __storage: c::sockaddr_storage {
@@ -88,7 +92,7 @@ pub(crate) unsafe fn read_sockaddr(
                 return Err(io::Errno::INVAL);
             }
             if len == offsetof_sun_path {
-                Ok(SocketAddrAny::Unix(SocketAddrUnix::new(&[][..])?))
+                Ok(SocketAddrAny::Unix(SocketAddrUnix::new_unnamed()))
             } else {
                 let decode = &*storage.cast::<c::sockaddr_un>();
 
@@ -127,7 +131,31 @@ pub(crate) unsafe fn read_sockaddr(
                 u32::from_be(decode.sxdp_shared_umem_fd),
             )))
         }
-        _ => Err(io::Errno::NOTSUP),
+        c::AF_NETLINK => {
+            if len < size_of::<c::sockaddr_nl>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_nl>();
+            Ok(SocketAddrAny::Netlink(SocketAddrNetlink::new(
+                decode.nl_pid,
+                decode.nl_groups,
+            )))
+        }
+        c::AF_VSOCK => {
+            if len < size_of::<c::sockaddr_vm>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_vm>();
+            Ok(SocketAddrAny::Vsock(SocketAddrVsock::new(
+                decode.svm_cid,
+                decode.svm_port,
+            )))
+        }
+        other => Ok(SocketAddrAny::Raw(SocketAddrRaw::read(
+            other as _,
+            storage.cast(),
+            len,
+        ))),
     }
 }
 
@@ -178,7 +206,7 @@ pub(crate) unsafe fn read_sockaddr_os(storage: *const c::sockaddr, len: usize) -
         c::AF_UNIX => {
             assert!(len >= offsetof_sun_path);
             if len == offsetof_sun_path {
-                SocketAddrAny::Unix(SocketAddrUnix::new(&[][..]).unwrap())
+                SocketAddrAny::Unix(SocketAddrUnix::new_unnamed())
             } else {
                 let decode = &*storage.cast::<c::sockaddr_un>();
 
@@ -216,6 +244,16 @@ pub(crate) unsafe fn read_sockaddr_os(storage: *const c::sockaddr, len: usize) -
                 u32::from_be(decode.sxdp_shared_umem_fd),
             ))
         }
-        other => unimplemented!("{:?}", other),
+        c::AF_NETLINK => {
+            assert!(len >= size_of::<c::sockaddr_nl>());
+            let decode = &*storage.cast::<c::sockaddr_nl>();
+            SocketAddrAny::Netlink(SocketAddrNetlink::new(decode.nl_pid, decode.nl_groups))
+        }
+        c::AF_VSOCK => {
+            assert!(len >= size_of::<c::sockaddr_vm>());
+            let decode = &*storage.cast::<c::sockaddr_vm>();
+            SocketAddrAny::Vsock(SocketAddrVsock::new(decode.svm_cid, decode.svm_port))
+        }
+        other => SocketAddrAny::Raw(SocketAddrRaw::read(other as _, storage.cast(), len)),
     }
 }
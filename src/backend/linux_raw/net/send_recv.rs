@@ -22,6 +22,10 @@ bitflags! {
         /// `MSG_EOR`
         const EOR = c::MSG_EOR;
         /// `MSG_MORE`
+        ///
+        /// Indicates that more data follows, so the kernel holds back a
+        /// partial segment as if the socket were corked with `TCP_CORK`,
+        /// until a send without this flag.
         const MORE = c::MSG_MORE;
         /// `MSG_NOSIGNAL`
         const NOSIGNAL = c::MSG_NOSIGNAL;
@@ -44,6 +48,8 @@ bitflags! {
     pub struct RecvFlags: u32 {
         /// `MSG_CMSG_CLOEXEC`
         const CMSG_CLOEXEC = c::MSG_CMSG_CLOEXEC;
+        /// `MSG_CTRUNC`
+        const CTRUNC = c::MSG_CTRUNC;
         /// `MSG_DONTWAIT`
         const DONTWAIT = c::MSG_DONTWAIT;
         /// `MSG_ERRQUEUE`
@@ -7,13 +7,17 @@
 
 use crate::backend::c;
 use crate::backend::conv::{by_mut, c_uint, ret, socklen_t};
+#[cfg(target_os = "linux")]
+use crate::fd::AsRawFd;
 use crate::fd::BorrowedFd;
 #[cfg(feature = "alloc")]
 use crate::ffi::CStr;
 use crate::io;
-use crate::net::sockopt::Timeout;
+use crate::net::sockopt::{PathMtuDiscovery, Timeout};
 #[cfg(target_os = "linux")]
 use crate::net::xdp::{XdpMmapOffsets, XdpOptionsFlags, XdpRingOffset, XdpStatistics, XdpUmemReg};
+#[cfg(target_os = "linux")]
+use crate::net::SockFilter;
 use crate::net::{
     AddressFamily, Ipv4Addr, Ipv6Addr, Protocol, RawProtocol, SocketAddrAny, SocketAddrStorage,
     SocketAddrV4, SocketAddrV6, SocketType, UCred,
@@ -89,6 +93,46 @@ fn getsockopt_raw<T>(
     }
 }
 
+/// Like `getsockopt_raw`, but for variable-length options, returning the
+/// number of bytes written into `buf`.
+#[inline]
+fn getsockopt_buf(
+    fd: BorrowedFd<'_>,
+    level: u32,
+    optname: u32,
+    buf: &mut [MaybeUninit<u8>],
+) -> io::Result<usize> {
+    let mut optlen: c::socklen_t = buf.len().try_into().unwrap_or(c::socklen_t::MAX);
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall!(
+            __NR_getsockopt,
+            fd,
+            c_uint(level),
+            c_uint(optname),
+            buf,
+            by_mut(&mut optlen)
+        ))?;
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall!(
+            __NR_socketcall,
+            x86_sys(SYS_GETSOCKOPT),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                c_uint(level),
+                c_uint(optname),
+                buf.into(),
+                by_mut(&mut optlen),
+            ])
+        ))?;
+    }
+
+    Ok(optlen as usize)
+}
+
 #[inline]
 fn setsockopt<T: Copy>(fd: BorrowedFd<'_>, level: u32, optname: u32, value: T) -> io::Result<()> {
     let optlen = core::mem::size_of::<T>().try_into().unwrap();
@@ -190,6 +234,16 @@ pub(crate) fn get_socket_passcred(fd: BorrowedFd<'_>) -> io::Result<bool> {
     getsockopt(fd, c::SOL_SOCKET, c::SO_PASSCRED).map(to_bool)
 }
 
+#[inline]
+pub(crate) fn set_socket_passsec(fd: BorrowedFd<'_>, passsec: bool) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_PASSSEC, from_bool(passsec))
+}
+
+#[inline]
+pub(crate) fn get_socket_passsec(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_PASSSEC).map(to_bool)
+}
+
 #[inline]
 pub(crate) fn set_socket_timeout(
     fd: BorrowedFd<'_>,
@@ -365,6 +419,18 @@ pub(crate) fn get_socket_send_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usiz
     getsockopt(fd, c::SOL_SOCKET, c::SO_SNDBUF).map(|size: u32| size as usize)
 }
 
+#[inline]
+pub(crate) fn set_socket_recv_buffer_size_force(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+    let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+    setsockopt(fd, c::SOL_SOCKET, c::SO_RCVBUFFORCE, size)
+}
+
+#[inline]
+pub(crate) fn set_socket_send_buffer_size_force(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+    let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+    setsockopt(fd, c::SOL_SOCKET, c::SO_SNDBUFFORCE, size)
+}
+
 #[inline]
 pub(crate) fn get_socket_domain(fd: BorrowedFd<'_>) -> io::Result<AddressFamily> {
     let domain: c::c_int = getsockopt(fd, c::SOL_SOCKET, c::SO_DOMAIN)?;
@@ -419,6 +485,69 @@ pub(crate) fn set_socket_incoming_cpu(fd: BorrowedFd<'_>, value: u32) -> io::Res
     setsockopt(fd, c::SOL_SOCKET, c::SO_INCOMING_CPU, value)
 }
 
+#[inline]
+pub(crate) fn set_socket_busy_poll(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_BUSY_POLL, value)
+}
+
+#[inline]
+pub(crate) fn get_socket_busy_poll(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_BUSY_POLL)
+}
+
+#[inline]
+pub(crate) fn set_socket_prefer_busy_poll(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_PREFER_BUSY_POLL, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn get_socket_prefer_busy_poll(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_PREFER_BUSY_POLL).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn get_socket_incoming_napi_id(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_INCOMING_NAPI_ID)
+}
+
+#[inline]
+pub(crate) fn set_socket_attach_reuseport_cbpf(
+    fd: BorrowedFd<'_>,
+    program: &[SockFilter],
+) -> io::Result<()> {
+    /// `struct sock_fprog`, which linux-raw-sys doesn't have.
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    struct SockFprog {
+        len: u16,
+        filter: *const SockFilter,
+    }
+
+    let fprog = SockFprog {
+        len: program.len().try_into().map_err(|_| io::Errno::INVAL)?,
+        filter: program.as_ptr(),
+    };
+    setsockopt(fd, c::SOL_SOCKET, c::SO_ATTACH_REUSEPORT_CBPF, fprog)
+}
+
+#[inline]
+pub(crate) fn set_socket_attach_reuseport_ebpf(
+    fd: BorrowedFd<'_>,
+    program: BorrowedFd<'_>,
+) -> io::Result<()> {
+    setsockopt(
+        fd,
+        c::SOL_SOCKET,
+        c::SO_ATTACH_REUSEPORT_EBPF,
+        program.as_raw_fd(),
+    )
+}
+
+#[inline]
+pub(crate) fn set_socket_detach_reuseport_bpf(fd: BorrowedFd<'_>) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_DETACH_REUSEPORT_BPF, 0 as c::c_int)
+}
+
 #[inline]
 pub(crate) fn set_ip_ttl(fd: BorrowedFd<'_>, ttl: u32) -> io::Result<()> {
     setsockopt(fd, c::IPPROTO_IP, c::IP_TTL, ttl)
@@ -608,6 +737,46 @@ pub(crate) fn get_ip_recvtos(fd: BorrowedFd<'_>) -> io::Result<bool> {
     getsockopt(fd, c::IPPROTO_IP, c::IP_RECVTOS).map(to_bool)
 }
 
+#[inline]
+pub(crate) fn set_ip_recverr(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IP, c::IP_RECVERR, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn get_ip_recverr(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_RECVERR).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_ipv6_recverr(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVERR, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn get_ipv6_recverr(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVERR).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_ip_pktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IP, c::IP_PKTINFO, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn get_ip_pktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_PKTINFO).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_ipv6_recvpktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVPKTINFO, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn get_ipv6_recvpktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVPKTINFO).map(to_bool)
+}
+
 #[inline]
 pub(crate) fn set_ipv6_recvtclass(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
     setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVTCLASS, from_bool(value))
@@ -638,6 +807,26 @@ pub(crate) fn get_ipv6_freebind(fd: BorrowedFd<'_>) -> io::Result<bool> {
     getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_FREEBIND).map(to_bool)
 }
 
+#[inline]
+pub(crate) fn set_ip_transparent(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IP, c::IP_TRANSPARENT, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn get_ip_transparent(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_TRANSPARENT).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_ipv6_transparent(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_TRANSPARENT, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn get_ipv6_transparent(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_TRANSPARENT).map(to_bool)
+}
+
 #[inline]
 pub(crate) fn get_ip_original_dst(fd: BorrowedFd<'_>) -> io::Result<SocketAddrV4> {
     let level = c::IPPROTO_IP;
@@ -680,6 +869,46 @@ pub(crate) fn get_ipv6_tclass(fd: BorrowedFd<'_>) -> io::Result<u32> {
     getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_TCLASS)
 }
 
+#[inline]
+pub(crate) fn set_ip_mtu_discover(fd: BorrowedFd<'_>, value: PathMtuDiscovery) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IP, c::IP_MTU_DISCOVER, value as c::c_uint)
+}
+
+#[inline]
+pub(crate) fn get_ip_mtu_discover(fd: BorrowedFd<'_>) -> io::Result<PathMtuDiscovery> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_MTU_DISCOVER).and_then(to_path_mtu_discovery)
+}
+
+#[inline]
+pub(crate) fn get_ip_mtu(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_MTU)
+}
+
+#[inline]
+pub(crate) fn set_ipv6_mtu_discover(fd: BorrowedFd<'_>, value: PathMtuDiscovery) -> io::Result<()> {
+    setsockopt(
+        fd,
+        c::IPPROTO_IPV6,
+        c::IPV6_MTU_DISCOVER,
+        value as c::c_uint,
+    )
+}
+
+#[inline]
+pub(crate) fn get_ipv6_mtu_discover(fd: BorrowedFd<'_>) -> io::Result<PathMtuDiscovery> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_MTU_DISCOVER).and_then(to_path_mtu_discovery)
+}
+
+#[inline]
+pub(crate) fn set_ipv6_mtu(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_MTU, value)
+}
+
+#[inline]
+pub(crate) fn get_ipv6_mtu(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_MTU)
+}
+
 #[inline]
 pub(crate) fn set_tcp_nodelay(fd: BorrowedFd<'_>, nodelay: bool) -> io::Result<()> {
     setsockopt(fd, c::IPPROTO_TCP, c::TCP_NODELAY, from_bool(nodelay))
@@ -690,6 +919,16 @@ pub(crate) fn get_tcp_nodelay(fd: BorrowedFd<'_>) -> io::Result<bool> {
     getsockopt(fd, c::IPPROTO_TCP, c::TCP_NODELAY).map(to_bool)
 }
 
+#[inline]
+pub(crate) fn set_tcp_maxseg(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_TCP, c::TCP_MAXSEG, value)
+}
+
+#[inline]
+pub(crate) fn get_tcp_maxseg(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::IPPROTO_TCP, c::TCP_MAXSEG)
+}
+
 #[inline]
 pub(crate) fn set_tcp_keepcnt(fd: BorrowedFd<'_>, count: u32) -> io::Result<()> {
     setsockopt(fd, c::IPPROTO_TCP, c::TCP_KEEPCNT, count)
@@ -803,6 +1042,17 @@ pub(crate) fn get_socket_peercred(fd: BorrowedFd<'_>) -> io::Result<UCred> {
     getsockopt(fd, c::SOL_SOCKET, linux_raw_sys::net::SO_PEERCRED)
 }
 
+#[inline]
+pub(crate) fn get_socket_peersec(
+    fd: BorrowedFd<'_>,
+    buf: &mut [MaybeUninit<u8>],
+) -> io::Result<usize> {
+    // Reserve space for a NUL terminator, since not all LSMs include one.
+    let max = buf.len().checked_sub(1).ok_or(io::Errno::RANGE)?;
+    let len = getsockopt_buf(fd, c::SOL_SOCKET, c::SO_PEERSEC, &mut buf[..max])?;
+    Ok(terminate_label(buf, len))
+}
+
 #[cfg(target_os = "linux")]
 #[inline]
 pub(crate) fn set_xdp_umem_reg(fd: BorrowedFd<'_>, value: XdpUmemReg) -> io::Result<()> {
@@ -1038,6 +1288,32 @@ fn to_bool(value: c::c_uint) -> bool {
     value != 0
 }
 
+/// Convert a raw `IP_PMTUDISC_*` value into a `PathMtuDiscovery`.
+#[inline]
+fn to_path_mtu_discovery(value: c::c_uint) -> io::Result<PathMtuDiscovery> {
+    Ok(match value {
+        c::IP_PMTUDISC_DONT => PathMtuDiscovery::Dont,
+        c::IP_PMTUDISC_WANT => PathMtuDiscovery::Want,
+        c::IP_PMTUDISC_DO => PathMtuDiscovery::Do,
+        c::IP_PMTUDISC_PROBE => PathMtuDiscovery::Probe,
+        c::IP_PMTUDISC_INTERFACE => PathMtuDiscovery::Interface,
+        c::IP_PMTUDISC_OMIT => PathMtuDiscovery::Omit,
+        _ => return Err(io::Errno::RANGE),
+    })
+}
+
+/// NUL-terminate an LSM security label which the kernel wrote into the first
+/// `len` bytes of `buf`, truncating it at any NUL the label already contains,
+/// and return its length including the terminator.
+#[inline]
+fn terminate_label(buf: &mut [MaybeUninit<u8>], len: usize) -> usize {
+    // SAFETY: The kernel initialized the first `len` bytes.
+    let label = unsafe { core::slice::from_raw_parts(buf.as_ptr().cast::<u8>(), len) };
+    let len = label.iter().position(|b| *b == b'\0').unwrap_or(len);
+    buf[len] = MaybeUninit::new(b'\0');
+    len + 1
+}
+
 /// Convert to seconds, rounding up if necessary.
 #[inline]
 fn duration_to_secs<T: TryFrom<u64>>(duration: Duration) -> io::Result<T> {
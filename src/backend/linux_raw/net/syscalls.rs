@@ -8,13 +8,16 @@
 #[cfg(target_os = "linux")]
 use super::msghdr::with_xdp_msghdr;
 use super::msghdr::{
-    with_noaddr_msghdr, with_recv_msghdr, with_unix_msghdr, with_v4_msghdr, with_v6_msghdr,
+    with_netlink_msghdr, with_noaddr_msghdr, with_raw_msghdr, with_recv_msghdr, with_unix_msghdr,
+    with_v4_msghdr, with_v6_msghdr, with_vsock_msghdr,
 };
 use super::read_sockaddr::{initialize_family_to_unspec, maybe_read_sockaddr_os, read_sockaddr_os};
 use super::send_recv::{RecvFlags, SendFlags};
 #[cfg(target_os = "linux")]
 use super::write_sockaddr::encode_sockaddr_xdp;
-use super::write_sockaddr::{encode_sockaddr_v4, encode_sockaddr_v6};
+use super::write_sockaddr::{
+    encode_sockaddr_netlink, encode_sockaddr_v4, encode_sockaddr_v6, encode_sockaddr_vsock,
+};
 use crate::backend::c;
 use crate::backend::conv::{
     by_mut, by_ref, c_int, c_uint, pass_usize, ret, ret_owned_fd, ret_usize, size_of, slice,
@@ -22,11 +25,14 @@ use crate::backend::conv::{
 };
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io::{self, IoSlice, IoSliceMut};
+use crate::net::netlink::SocketAddrNetlink;
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
 use crate::net::{
     AddressFamily, Protocol, RecvAncillaryBuffer, RecvMsgReturn, SendAncillaryBuffer, Shutdown,
-    SocketAddrAny, SocketAddrUnix, SocketAddrV4, SocketAddrV6, SocketFlags, SocketType,
+    SocketAddrAny, SocketAddrRaw, SocketAddrUnix, SocketAddrV4, SocketAddrV6, SocketFlags,
+    SocketType,
 };
 use c::{sockaddr, sockaddr_in, sockaddr_in6, socklen_t};
 use core::mem::MaybeUninit;
@@ -135,7 +141,7 @@ pub(crate) fn socketpair(
 
 #[inline]
 pub(crate) fn accept(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
-    #[cfg(not(target_arch = "x86"))]
+    #[cfg(not(any(target_arch = "s390x", target_arch = "x86")))]
     unsafe {
         let fd = ret_owned_fd(syscall_readonly!(__NR_accept, fd, zero(), zero()))?;
         Ok(fd)
@@ -149,6 +155,11 @@ pub(crate) fn accept(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
         ))?;
         Ok(fd)
     }
+    // s390x has no `accept` syscall, only `accept4`.
+    #[cfg(target_arch = "s390x")]
+    {
+        accept_with(fd, SocketFlags::empty())
+    }
 }
 
 #[inline]
@@ -171,38 +182,42 @@ pub(crate) fn accept_with(fd: BorrowedFd<'_>, flags: SocketFlags) -> io::Result<
 
 #[inline]
 pub(crate) fn acceptfrom(fd: BorrowedFd<'_>) -> io::Result<(OwnedFd, Option<SocketAddrAny>)> {
-    #[cfg(not(target_arch = "x86"))]
+    let mut storage = MaybeUninit::<sockaddr>::uninit();
+    let (fd, addrlen) = acceptfrom_into(fd, &mut storage)?;
+    Ok((fd, unsafe {
+        maybe_read_sockaddr_os(storage.as_ptr(), addrlen)
+    }))
+}
+
+#[inline]
+fn acceptfrom_into(
+    fd: BorrowedFd<'_>,
+    storage: &mut MaybeUninit<sockaddr>,
+) -> io::Result<(OwnedFd, usize)> {
+    #[cfg(not(any(target_arch = "s390x", target_arch = "x86")))]
     unsafe {
         let mut addrlen = core::mem::size_of::<sockaddr>() as socklen_t;
-        let mut storage = MaybeUninit::<sockaddr>::uninit();
-        let fd = ret_owned_fd(syscall!(
-            __NR_accept,
-            fd,
-            &mut storage,
-            by_mut(&mut addrlen)
-        ))?;
-        Ok((
-            fd,
-            maybe_read_sockaddr_os(&storage.assume_init(), addrlen.try_into().unwrap()),
-        ))
+        let fd = ret_owned_fd(syscall!(__NR_accept, fd, storage, by_mut(&mut addrlen)))?;
+        Ok((fd, addrlen.try_into().unwrap()))
     }
     #[cfg(target_arch = "x86")]
     unsafe {
         let mut addrlen = core::mem::size_of::<sockaddr>() as socklen_t;
-        let mut storage = MaybeUninit::<sockaddr>::uninit();
         let fd = ret_owned_fd(syscall!(
             __NR_socketcall,
             x86_sys(SYS_ACCEPT),
             slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
                 fd.into(),
-                (&mut storage).into(),
+                storage.into(),
                 by_mut(&mut addrlen),
             ])
         ))?;
-        Ok((
-            fd,
-            maybe_read_sockaddr_os(&storage.assume_init(), addrlen.try_into().unwrap()),
-        ))
+        Ok((fd, addrlen.try_into().unwrap()))
+    }
+    // s390x has no `accept` syscall, only `accept4`.
+    #[cfg(target_arch = "s390x")]
+    {
+        acceptfrom_with_into(fd, SocketFlags::empty(), storage)
     }
 }
 
@@ -211,40 +226,45 @@ pub(crate) fn acceptfrom_with(
     fd: BorrowedFd<'_>,
     flags: SocketFlags,
 ) -> io::Result<(OwnedFd, Option<SocketAddrAny>)> {
+    let mut storage = MaybeUninit::<sockaddr>::uninit();
+    let (fd, addrlen) = acceptfrom_with_into(fd, flags, &mut storage)?;
+    Ok((fd, unsafe {
+        maybe_read_sockaddr_os(storage.as_ptr(), addrlen)
+    }))
+}
+
+#[inline]
+pub(crate) fn acceptfrom_with_into(
+    fd: BorrowedFd<'_>,
+    flags: SocketFlags,
+    storage: &mut MaybeUninit<sockaddr>,
+) -> io::Result<(OwnedFd, usize)> {
     #[cfg(not(target_arch = "x86"))]
     unsafe {
         let mut addrlen = core::mem::size_of::<sockaddr>() as socklen_t;
-        let mut storage = MaybeUninit::<sockaddr>::uninit();
         let fd = ret_owned_fd(syscall!(
             __NR_accept4,
             fd,
-            &mut storage,
+            storage,
             by_mut(&mut addrlen),
             flags
         ))?;
-        Ok((
-            fd,
-            maybe_read_sockaddr_os(&storage.assume_init(), addrlen.try_into().unwrap()),
-        ))
+        Ok((fd, addrlen.try_into().unwrap()))
     }
     #[cfg(target_arch = "x86")]
     unsafe {
         let mut addrlen = core::mem::size_of::<sockaddr>() as socklen_t;
-        let mut storage = MaybeUninit::<sockaddr>::uninit();
         let fd = ret_owned_fd(syscall!(
             __NR_socketcall,
             x86_sys(SYS_ACCEPT4),
             slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
                 fd.into(),
-                (&mut storage).into(),
+                storage.into(),
                 by_mut(&mut addrlen),
                 flags.into(),
             ])
         ))?;
-        Ok((
-            fd,
-            maybe_read_sockaddr_os(&storage.assume_init(), addrlen.try_into().unwrap()),
-        ))
+        Ok((fd, addrlen.try_into().unwrap()))
     }
 }
 
@@ -439,6 +459,96 @@ pub(crate) fn sendmsg_xdp(
     })
 }
 
+#[inline]
+pub(crate) fn sendmsg_netlink(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrNetlink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_netlink_msghdr(addr, iov, control, |msghdr| {
+        #[cfg(not(target_arch = "x86"))]
+        let result =
+            unsafe { ret_usize(syscall!(__NR_sendmsg, sockfd, by_ref(&msghdr), msg_flags)) };
+
+        #[cfg(target_arch = "x86")]
+        let result = unsafe {
+            ret_usize(syscall!(
+                __NR_socketcall,
+                x86_sys(SYS_SENDMSG),
+                slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                    sockfd.into(),
+                    by_ref(&msghdr),
+                    msg_flags.into()
+                ])
+            ))
+        };
+
+        result
+    })
+}
+
+#[inline]
+pub(crate) fn sendmsg_vsock(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrVsock,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_vsock_msghdr(addr, iov, control, |msghdr| {
+        #[cfg(not(target_arch = "x86"))]
+        let result =
+            unsafe { ret_usize(syscall!(__NR_sendmsg, sockfd, by_ref(&msghdr), msg_flags)) };
+
+        #[cfg(target_arch = "x86")]
+        let result = unsafe {
+            ret_usize(syscall!(
+                __NR_socketcall,
+                x86_sys(SYS_SENDMSG),
+                slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                    sockfd.into(),
+                    by_ref(&msghdr),
+                    msg_flags.into()
+                ])
+            ))
+        };
+
+        result
+    })
+}
+
+#[inline]
+pub(crate) fn sendmsg_raw(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrRaw,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_raw_msghdr(addr, iov, control, |msghdr| {
+        #[cfg(not(target_arch = "x86"))]
+        let result =
+            unsafe { ret_usize(syscall!(__NR_sendmsg, sockfd, by_ref(&msghdr), msg_flags)) };
+
+        #[cfg(target_arch = "x86")]
+        let result = unsafe {
+            ret_usize(syscall!(
+                __NR_socketcall,
+                x86_sys(SYS_SENDMSG),
+                slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                    sockfd.into(),
+                    by_ref(&msghdr),
+                    msg_flags.into()
+                ])
+            ))
+        };
+
+        result
+    })
+}
+
 #[inline]
 pub(crate) fn shutdown(fd: BorrowedFd<'_>, how: Shutdown) -> io::Result<()> {
     #[cfg(not(target_arch = "x86"))]
@@ -468,6 +578,7 @@ pub(crate) fn send(fd: BorrowedFd<'_>, buf: &[u8], flags: SendFlags) -> io::Resu
         target_arch = "mips64",
         target_arch = "mips64r6",
         target_arch = "riscv64",
+        target_arch = "s390x",
         target_arch = "x86",
         target_arch = "x86_64",
     )))]
@@ -479,6 +590,7 @@ pub(crate) fn send(fd: BorrowedFd<'_>, buf: &[u8], flags: SendFlags) -> io::Resu
         target_arch = "mips64",
         target_arch = "mips64r6",
         target_arch = "riscv64",
+        target_arch = "s390x",
         target_arch = "x86_64",
     ))]
     unsafe {
@@ -660,6 +772,121 @@ pub(crate) fn sendto_xdp(
     }
 }
 
+#[inline]
+pub(crate) fn sendto_netlink(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrNetlink,
+) -> io::Result<usize> {
+    let (buf_addr, buf_len) = slice(buf);
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_sendto,
+            fd,
+            buf_addr,
+            buf_len,
+            flags,
+            by_ref(&encode_sockaddr_netlink(addr)),
+            size_of::<c::sockaddr_nl, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDTO),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                buf_addr,
+                buf_len,
+                flags.into(),
+                by_ref(&encode_sockaddr_netlink(addr)),
+                size_of::<c::sockaddr_nl, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sendto_vsock(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrVsock,
+) -> io::Result<usize> {
+    let (buf_addr, buf_len) = slice(buf);
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_sendto,
+            fd,
+            buf_addr,
+            buf_len,
+            flags,
+            by_ref(&encode_sockaddr_vsock(addr)),
+            size_of::<c::sockaddr_vm, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDTO),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                buf_addr,
+                buf_len,
+                flags.into(),
+                by_ref(&encode_sockaddr_vsock(addr)),
+                size_of::<c::sockaddr_vm, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sendto_raw(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrRaw,
+) -> io::Result<usize> {
+    let (buf_addr, buf_len) = slice(buf);
+    let (addr_addr, addr_len) = slice(addr.as_bytes());
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_sendto,
+            fd,
+            buf_addr,
+            buf_len,
+            flags,
+            addr_addr,
+            addr_len
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDTO),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                buf_addr,
+                buf_len,
+                flags.into(),
+                addr_addr,
+                addr_len,
+            ])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) unsafe fn recv(
     fd: BorrowedFd<'_>,
@@ -672,6 +899,7 @@ pub(crate) unsafe fn recv(
         target_arch = "mips64",
         target_arch = "mips64r6",
         target_arch = "riscv64",
+        target_arch = "s390x",
         target_arch = "x86",
         target_arch = "x86_64",
     )))]
@@ -683,6 +911,7 @@ pub(crate) unsafe fn recv(
         target_arch = "mips64",
         target_arch = "mips64r6",
         target_arch = "riscv64",
+        target_arch = "s390x",
         target_arch = "x86_64",
     ))]
     {
@@ -718,8 +947,20 @@ pub(crate) unsafe fn recvfrom(
     len: usize,
     flags: RecvFlags,
 ) -> io::Result<(usize, Option<SocketAddrAny>)> {
-    let mut addrlen = core::mem::size_of::<sockaddr>() as socklen_t;
     let mut storage = MaybeUninit::<sockaddr>::uninit();
+    let (nread, addrlen) = recvfrom_into(fd, buf, len, flags, &mut storage)?;
+    Ok((nread, maybe_read_sockaddr_os(storage.as_ptr(), addrlen)))
+}
+
+#[inline]
+pub(crate) unsafe fn recvfrom_into(
+    fd: BorrowedFd<'_>,
+    buf: *mut u8,
+    len: usize,
+    flags: RecvFlags,
+    storage: &mut MaybeUninit<sockaddr>,
+) -> io::Result<(usize, usize)> {
+    let mut addrlen = core::mem::size_of::<sockaddr>() as socklen_t;
 
     // `recvfrom` does not write to the storage if the socket is
     // connection-oriented sockets, so we initialize the family field to
@@ -733,7 +974,7 @@ pub(crate) unsafe fn recvfrom(
         buf,
         pass_usize(len),
         flags,
-        &mut storage,
+        storage,
         by_mut(&mut addrlen)
     ))?;
     #[cfg(target_arch = "x86")]
@@ -745,51 +986,50 @@ pub(crate) unsafe fn recvfrom(
             buf.into(),
             pass_usize(len),
             flags.into(),
-            (&mut storage).into(),
+            storage.into(),
             by_mut(&mut addrlen),
         ])
     ))?;
 
-    Ok((
-        nread,
-        maybe_read_sockaddr_os(&storage.assume_init(), addrlen.try_into().unwrap()),
-    ))
+    Ok((nread, addrlen.try_into().unwrap()))
 }
 
 #[inline]
 pub(crate) fn getpeername(fd: BorrowedFd<'_>) -> io::Result<Option<SocketAddrAny>> {
+    let mut storage = MaybeUninit::<sockaddr>::uninit();
+    let addrlen = getpeername_into(fd, &mut storage)?;
+    Ok(unsafe { maybe_read_sockaddr_os(storage.as_ptr(), addrlen) })
+}
+
+#[inline]
+pub(crate) fn getpeername_into(
+    fd: BorrowedFd<'_>,
+    storage: &mut MaybeUninit<sockaddr>,
+) -> io::Result<usize> {
     #[cfg(not(target_arch = "x86"))]
     unsafe {
         let mut addrlen = core::mem::size_of::<sockaddr>() as socklen_t;
-        let mut storage = MaybeUninit::<sockaddr>::uninit();
         ret(syscall!(
             __NR_getpeername,
             fd,
-            &mut storage,
+            storage,
             by_mut(&mut addrlen)
         ))?;
-        Ok(maybe_read_sockaddr_os(
-            &storage.assume_init(),
-            addrlen.try_into().unwrap(),
-        ))
+        Ok(addrlen.try_into().unwrap())
     }
     #[cfg(target_arch = "x86")]
     unsafe {
         let mut addrlen = core::mem::size_of::<sockaddr>() as socklen_t;
-        let mut storage = MaybeUninit::<sockaddr>::uninit();
         ret(syscall!(
             __NR_socketcall,
             x86_sys(SYS_GETPEERNAME),
             slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
                 fd.into(),
-                (&mut storage).into(),
+                storage.into(),
                 by_mut(&mut addrlen),
             ])
         ))?;
-        Ok(maybe_read_sockaddr_os(
-            &storage.assume_init(),
-            addrlen.try_into().unwrap(),
-        ))
+        Ok(addrlen.try_into().unwrap())
     }
 }
 
@@ -931,6 +1171,124 @@ pub(crate) fn bind_xdp(fd: BorrowedFd<'_>, addr: &SocketAddrXdp) -> io::Result<(
     }
 }
 
+#[inline]
+pub(crate) fn bind_raw(fd: BorrowedFd<'_>, addr: &SocketAddrRaw) -> io::Result<()> {
+    let (addr_addr, addr_len) = slice(addr.as_bytes());
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(__NR_bind, fd, addr_addr, addr_len))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_BIND),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[fd.into(), addr_addr, addr_len])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn bind_netlink(fd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_bind,
+            fd,
+            by_ref(&encode_sockaddr_netlink(addr)),
+            size_of::<c::sockaddr_nl, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_BIND),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_netlink(addr)),
+                size_of::<c::sockaddr_nl, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn bind_vsock(fd: BorrowedFd<'_>, addr: &SocketAddrVsock) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_bind,
+            fd,
+            by_ref(&encode_sockaddr_vsock(addr)),
+            size_of::<c::sockaddr_vm, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_BIND),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_vsock(addr)),
+                size_of::<c::sockaddr_vm, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn connect_netlink(fd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_connect,
+            fd,
+            by_ref(&encode_sockaddr_netlink(addr)),
+            size_of::<c::sockaddr_nl, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_CONNECT),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_netlink(addr)),
+                size_of::<c::sockaddr_nl, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn connect_vsock(fd: BorrowedFd<'_>, addr: &SocketAddrVsock) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_connect,
+            fd,
+            by_ref(&encode_sockaddr_vsock(addr)),
+            size_of::<c::sockaddr_vm, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_CONNECT),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_vsock(addr)),
+                size_of::<c::sockaddr_vm, _>(),
+            ])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn connect_v4(fd: BorrowedFd<'_>, addr: &SocketAddrV4) -> io::Result<()> {
     #[cfg(not(target_arch = "x86"))]
@@ -1006,6 +1364,24 @@ pub(crate) fn connect_unix(fd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Res
     }
 }
 
+#[inline]
+pub(crate) fn connect_raw(fd: BorrowedFd<'_>, addr: &SocketAddrRaw) -> io::Result<()> {
+    let (addr_addr, addr_len) = slice(addr.as_bytes());
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(__NR_connect, fd, addr_addr, addr_len))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_CONNECT),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[fd.into(), addr_addr, addr_len])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn connect_unspec(fd: BorrowedFd<'_>) -> io::Result<()> {
     debug_assert_eq!(c::AF_UNSPEC, 0);
@@ -3,6 +3,8 @@
 #![allow(unsafe_code)]
 
 use crate::backend::c;
+use crate::net::netlink::SocketAddrNetlink;
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
 use crate::net::{SocketAddrAny, SocketAddrStorage, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
@@ -18,6 +20,9 @@ pub(crate) unsafe fn write_sockaddr(
         SocketAddrAny::Unix(unix) => write_sockaddr_unix(unix, storage),
         #[cfg(target_os = "linux")]
         SocketAddrAny::Xdp(xdp) => write_sockaddr_xdp(xdp, storage),
+        SocketAddrAny::Netlink(netlink) => write_sockaddr_netlink(netlink, storage),
+        SocketAddrAny::Vsock(vsock) => write_sockaddr_vsock(vsock, storage),
+        SocketAddrAny::Raw(raw) => raw.write(storage),
     }
 }
 
@@ -80,3 +85,38 @@ unsafe fn write_sockaddr_xdp(xdp: &SocketAddrXdp, storage: *mut SocketAddrStorag
     core::ptr::write(storage.cast(), encoded);
     size_of::<c::sockaddr_xdp>()
 }
+
+pub(crate) fn encode_sockaddr_netlink(netlink: &SocketAddrNetlink) -> c::sockaddr_nl {
+    c::sockaddr_nl {
+        nl_family: c::AF_NETLINK as _,
+        nl_pad: 0,
+        nl_pid: netlink.pid(),
+        nl_groups: netlink.groups(),
+    }
+}
+
+unsafe fn write_sockaddr_netlink(
+    netlink: &SocketAddrNetlink,
+    storage: *mut SocketAddrStorage,
+) -> usize {
+    let encoded = encode_sockaddr_netlink(netlink);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_nl>()
+}
+
+pub(crate) fn encode_sockaddr_vsock(vsock: &SocketAddrVsock) -> c::sockaddr_vm {
+    c::sockaddr_vm {
+        svm_family: c::AF_VSOCK as _,
+        svm_reserved1: 0,
+        svm_port: vsock.port(),
+        svm_cid: vsock.cid(),
+        svm_flags: 0,
+        svm_zero: [0; 3],
+    }
+}
+
+unsafe fn write_sockaddr_vsock(vsock: &SocketAddrVsock, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_vsock(vsock);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_vm>()
+}
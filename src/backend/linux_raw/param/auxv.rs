@@ -21,6 +21,9 @@ use core::sync::atomic::AtomicU8;
 use core::sync::atomic::Ordering::Relaxed;
 use core::sync::atomic::{AtomicPtr, AtomicUsize};
 use linux_raw_sys::elf::*;
+// linux-raw-sys doesn't define `EM_CURRENT` for s390x.
+#[cfg(target_arch = "s390x")]
+const EM_CURRENT: u16 = 22; // EM_S390
 use linux_raw_sys::general::{
     AT_BASE, AT_CLKTCK, AT_EXECFN, AT_HWCAP, AT_HWCAP2, AT_MINSIGSTKSZ, AT_NULL, AT_PAGESZ,
     AT_SYSINFO_EHDR,
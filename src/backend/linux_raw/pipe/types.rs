@@ -27,13 +27,29 @@ bitflags! {
     #[repr(transparent)]
     #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct SpliceFlags: c::c_uint {
-        /// `SPLICE_F_MOVE`
+        /// `SPLICE_F_MOVE`—Attempt to move pages instead of copying them.
+        ///
+        /// This is only a hint, and current Linux versions ignore it.
         const MOVE = linux_raw_sys::general::SPLICE_F_MOVE;
-        /// `SPLICE_F_NONBLOCK`
+        /// `SPLICE_F_NONBLOCK`—Don't block on the pipe.
+        ///
+        /// This makes the pipe side of the operation fail with
+        /// [`io::Errno::AGAIN`] instead of blocking. Whether the other side
+        /// blocks is still determined by its own `O_NONBLOCK` flag.
+        ///
+        /// [`io::Errno::AGAIN`]: crate::io::Errno::AGAIN
         const NONBLOCK = linux_raw_sys::general::SPLICE_F_NONBLOCK;
-        /// `SPLICE_F_MORE`
+        /// `SPLICE_F_MORE`—More data will be sent in a subsequent call.
+        ///
+        /// When splicing to a socket, this has the same effect as sending
+        /// with `MSG_MORE`.
         const MORE = linux_raw_sys::general::SPLICE_F_MORE;
-        /// `SPLICE_F_GIFT`
+        /// `SPLICE_F_GIFT`—Gift the pages passed to [`vmsplice`] to the
+        /// kernel.
+        ///
+        /// See the safety requirements of [`vmsplice`].
+        ///
+        /// [`vmsplice`]: crate::pipe::vmsplice
         const GIFT = linux_raw_sys::general::SPLICE_F_GIFT;
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
@@ -1,4 +1,5 @@
 pub(crate) mod cpu_set;
+pub(crate) mod sig_set;
 pub(crate) mod syscalls;
 pub(crate) mod types;
 pub(crate) mod wait;
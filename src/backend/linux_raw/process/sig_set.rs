@@ -0,0 +1,38 @@
+//! Rust implementation of the `sigsetops` API.
+
+use super::types::RawSigSet;
+use crate::backend::c;
+use core::mem::size_of_val;
+
+#[inline]
+pub(crate) fn sigemptyset() -> RawSigSet {
+    RawSigSet {
+        sig: Default::default(),
+    }
+}
+
+#[inline]
+pub(crate) fn sigaddset(set: &mut RawSigSet, sig: c::c_int) {
+    let (idx, offset) = sig_index(set, sig);
+    set.sig[idx] |= 1 << offset
+}
+
+#[inline]
+pub(crate) fn sigdelset(set: &mut RawSigSet, sig: c::c_int) {
+    let (idx, offset) = sig_index(set, sig);
+    set.sig[idx] &= !(1 << offset)
+}
+
+#[inline]
+pub(crate) fn sigismember(set: &RawSigSet, sig: c::c_int) -> bool {
+    let (idx, offset) = sig_index(set, sig);
+    (set.sig[idx] & (1 << offset)) != 0
+}
+
+/// Signal numbers start at 1, so signal `sig` is bit `sig - 1`.
+#[inline]
+fn sig_index(set: &RawSigSet, sig: c::c_int) -> (usize, usize) {
+    let size_in_bits = 8 * size_of_val(&set.sig[0]);
+    let bit = sig as usize - 1;
+    (bit / size_in_bits, bit % size_in_bits)
+}
@@ -5,23 +5,32 @@
 //! See the `rustix::backend` module documentation for details.
 #![allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
 
-use super::types::RawCpuSet;
+use super::types::{RawCpuSet, RawSigSet, SigmaskHow, SignalfdFlags};
 use crate::backend::c;
-#[cfg(all(feature = "alloc", feature = "fs"))]
+#[cfg(feature = "alloc")]
+use crate::backend::conv::slice_just_addr_mut;
+#[cfg(feature = "fs")]
 use crate::backend::conv::slice_mut;
 use crate::backend::conv::{
     by_mut, by_ref, c_int, c_uint, negative_pid, pass_usize, raw_fd, ret, ret_c_int,
-    ret_c_int_infallible, ret_c_uint, ret_infallible, ret_owned_fd, ret_usize, size_of,
-    slice_just_addr, zero,
+    ret_c_int_infallible, ret_c_uint, ret_discarded_fd, ret_infallible, ret_owned_fd, ret_usize,
+    size_of, slice_just_addr, zero,
 };
+#[cfg(all(
+    target_arch = "x86_64",
+    feature = "alloc",
+    feature = "fs",
+    feature = "pipe"
+))]
+use crate::backend::conv::{no_fd, ret_void_star};
 use crate::fd::{AsRawFd, BorrowedFd, OwnedFd, RawFd};
 #[cfg(feature = "fs")]
 use crate::ffi::CStr;
 use crate::io;
 use crate::pid::RawPid;
 use crate::process::{
-    Cpuid, MembarrierCommand, MembarrierQuery, Pid, PidfdFlags, PidfdGetfdFlags, Resource, Rlimit,
-    Uid, WaitId, WaitOptions, WaitStatus, WaitidOptions, WaitidStatus,
+    Cpuid, Gid, MembarrierCommand, MembarrierQuery, Pid, PidfdFlags, PidfdGetfdFlags, Resource,
+    Rlimit, Uid, WaitId, WaitOptions, WaitStatus, WaitidOptions, WaitidStatus,
 };
 use crate::signal::Signal;
 use crate::utils::as_mut_ptr;
@@ -33,8 +42,11 @@ use linux_raw_sys::general::{
 };
 #[cfg(feature = "fs")]
 use {crate::backend::conv::ret_c_uint_infallible, crate::fs::Mode};
-#[cfg(feature = "alloc")]
-use {crate::backend::conv::slice_just_addr_mut, crate::process::Gid};
+#[cfg(all(feature = "alloc", feature = "fs", feature = "pipe"))]
+use {
+    crate::backend::conv::{opt_ref, ret_error},
+    linux_raw_sys::general::kernel_sigaction,
+};
 
 // `sched_getcpu` has special optimizations via the vDSO on some architectures.
 #[cfg(any(
@@ -79,7 +91,7 @@ pub(crate) fn chroot(filename: &CStr) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_chroot, filename)) }
 }
 
-#[cfg(all(feature = "alloc", feature = "fs"))]
+#[cfg(feature = "fs")]
 #[inline]
 pub(crate) fn getcwd(buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
@@ -614,3 +626,193 @@ pub(crate) fn getgroups(buf: &mut [Gid]) -> io::Result<usize> {
         ))
     }
 }
+
+#[inline]
+pub(crate) fn getresuid() -> (Uid, Uid, Uid) {
+    let mut ruid = MaybeUninit::<c::uid_t>::uninit();
+    let mut euid = MaybeUninit::<c::uid_t>::uninit();
+    let mut suid = MaybeUninit::<c::uid_t>::uninit();
+    unsafe {
+        #[cfg(any(target_arch = "arm", target_arch = "sparc", target_arch = "x86"))]
+        ret_infallible(syscall!(__NR_getresuid32, &mut ruid, &mut euid, &mut suid));
+        #[cfg(not(any(target_arch = "arm", target_arch = "sparc", target_arch = "x86")))]
+        ret_infallible(syscall!(__NR_getresuid, &mut ruid, &mut euid, &mut suid));
+        (
+            Uid::from_raw(ruid.assume_init()),
+            Uid::from_raw(euid.assume_init()),
+            Uid::from_raw(suid.assume_init()),
+        )
+    }
+}
+
+#[inline]
+pub(crate) fn getresgid() -> (Gid, Gid, Gid) {
+    let mut rgid = MaybeUninit::<c::gid_t>::uninit();
+    let mut egid = MaybeUninit::<c::gid_t>::uninit();
+    let mut sgid = MaybeUninit::<c::gid_t>::uninit();
+    unsafe {
+        #[cfg(any(target_arch = "arm", target_arch = "sparc", target_arch = "x86"))]
+        ret_infallible(syscall!(__NR_getresgid32, &mut rgid, &mut egid, &mut sgid));
+        #[cfg(not(any(target_arch = "arm", target_arch = "sparc", target_arch = "x86")))]
+        ret_infallible(syscall!(__NR_getresgid, &mut rgid, &mut egid, &mut sgid));
+        (
+            Gid::from_raw(rgid.assume_init()),
+            Gid::from_raw(egid.assume_init()),
+            Gid::from_raw(sgid.assume_init()),
+        )
+    }
+}
+
+#[inline]
+pub(crate) fn sigprocmask(how: SigmaskHow, set: Option<&RawSigSet>) -> io::Result<RawSigSet> {
+    crate::backend::signal::syscalls::sigprocmask(how as c::c_uint, set)
+}
+
+#[inline]
+pub(crate) fn signalfd(mask: &RawSigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_signalfd4,
+            c_int(-1),
+            by_ref(mask),
+            size_of::<RawSigSet, _>(),
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn signalfd_set_mask(fd: BorrowedFd<'_>, mask: &RawSigSet) -> io::Result<()> {
+    unsafe {
+        ret_discarded_fd(syscall_readonly!(
+            __NR_signalfd4,
+            fd,
+            by_ref(mask),
+            size_of::<RawSigSet, _>(),
+            c_uint(0)
+        ))
+    }
+}
+
+/// `clone(SIGCHLD)`—Create a child process, like `fork`.
+///
+/// Returns `None` in the child.
+#[cfg(all(feature = "alloc", feature = "fs", feature = "pipe"))]
+#[inline]
+pub(crate) unsafe fn fork() -> io::Result<Option<Pid>> {
+    let pid = ret_c_int(syscall_readonly!(
+        __NR_clone,
+        c_int(c::SIGCHLD),
+        zero(),
+        zero(),
+        zero(),
+        zero()
+    ))?;
+    Ok(Pid::from_raw(pid))
+}
+
+/// `clone(CLONE_VM | CLONE_VFORK | SIGCHLD, stack)`—Create a child process
+/// which shares the parent's memory and runs `func(arg)` on `stack`.
+///
+/// The calling thread is suspended until the child calls `execve` or exits.
+#[cfg(all(
+    target_arch = "x86_64",
+    feature = "alloc",
+    feature = "fs",
+    feature = "pipe"
+))]
+pub(crate) unsafe fn vfork_with_stack(
+    stack: *mut c::c_void,
+    func: unsafe extern "C" fn(*mut c::c_void) -> !,
+    arg: *mut c::c_void,
+) -> io::Result<Pid> {
+    let pid = ret_c_int(crate::backend::arch::asm::clone_with_stack(
+        crate::backend::reg::nr(linux_raw_sys::general::__NR_clone),
+        c_uint(c::CLONE_VM | c::CLONE_VFORK | c::SIGCHLD as c::c_uint),
+        stack.into(),
+        func,
+        arg,
+    ))?;
+    Ok(Pid::from_raw_unchecked(pid))
+}
+
+/// `mmap(NULL, len, PROT_READ | PROT_WRITE, MAP_PRIVATE | MAP_ANONYMOUS |
+/// MAP_STACK, -1, 0)`—Allocate a stack for [`vfork_with_stack`], with the
+/// lowest `guard` bytes made inaccessible with `mprotect`.
+#[cfg(all(
+    target_arch = "x86_64",
+    feature = "alloc",
+    feature = "fs",
+    feature = "pipe"
+))]
+pub(crate) unsafe fn mmap_stack(len: usize, guard: usize) -> io::Result<*mut c::c_void> {
+    use linux_raw_sys::general::{
+        MAP_ANONYMOUS, MAP_PRIVATE, MAP_STACK, PROT_NONE, PROT_READ, PROT_WRITE,
+    };
+
+    let addr = ret_void_star(syscall!(
+        __NR_mmap,
+        zero(),
+        pass_usize(len),
+        c_uint(PROT_READ | PROT_WRITE),
+        c_uint(MAP_PRIVATE | MAP_ANONYMOUS | MAP_STACK),
+        no_fd(),
+        zero()
+    ))?;
+    if let Err(err) = ret(syscall!(
+        __NR_mprotect,
+        addr,
+        pass_usize(guard),
+        c_uint(PROT_NONE)
+    )) {
+        munmap_stack(addr, len);
+        return Err(err);
+    }
+    Ok(addr)
+}
+
+/// `munmap(addr, len)`—Free a stack allocated with [`mmap_stack`].
+#[cfg(all(
+    target_arch = "x86_64",
+    feature = "alloc",
+    feature = "fs",
+    feature = "pipe"
+))]
+pub(crate) unsafe fn munmap_stack(addr: *mut c::c_void, len: usize) {
+    let _ = ret(syscall!(__NR_munmap, addr, pass_usize(len)));
+}
+
+/// `rt_sigaction(signal, new, &mut old)`, with a raw signal number, so that
+/// it can be used with real-time signals.
+#[cfg(all(feature = "alloc", feature = "fs", feature = "pipe"))]
+#[inline]
+pub(crate) unsafe fn sigaction_raw(
+    signal: c::c_int,
+    new: Option<kernel_sigaction>,
+) -> io::Result<kernel_sigaction> {
+    let mut old = MaybeUninit::<kernel_sigaction>::uninit();
+    ret(syscall!(
+        __NR_rt_sigaction,
+        c_int(signal),
+        opt_ref(new.as_ref()),
+        &mut old,
+        size_of::<RawSigSet, _>()
+    ))?;
+    Ok(old.assume_init())
+}
+
+#[cfg(all(feature = "alloc", feature = "fs", feature = "pipe"))]
+#[inline]
+pub(crate) unsafe fn execve(
+    path: &CStr,
+    args: *const *const u8,
+    env_vars: *const *const u8,
+) -> io::Errno {
+    ret_error(syscall_readonly!(__NR_execve, path, args, env_vars))
+}
+
+#[cfg(all(feature = "alloc", feature = "fs", feature = "pipe"))]
+#[inline]
+pub(crate) fn exit_group(code: c::c_int) -> ! {
+    unsafe { syscall_noreturn!(__NR_exit_group, c_int(code)) }
+}
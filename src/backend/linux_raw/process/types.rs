@@ -1,3 +1,4 @@
+use crate::backend::c;
 use linux_raw_sys::general::membarrier_cmd;
 
 /// A command for use with [`membarrier`] and [`membarrier_cpu`].
@@ -102,3 +103,36 @@ pub(crate) fn raw_cpu_set_new() -> RawCpuSet {
 }
 
 pub(crate) const CPU_SETSIZE: usize = 8 * core::mem::size_of::<RawCpuSet>();
+
+pub(crate) type RawSigSet = linux_raw_sys::general::kernel_sigset_t;
+
+/// `SIG_*` constants for use with [`sigprocmask`].
+///
+/// [`sigprocmask`]: crate::process::sigprocmask
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SigmaskHow {
+    /// `SIG_BLOCK`
+    Block = linux_raw_sys::general::SIG_BLOCK,
+    /// `SIG_UNBLOCK`
+    Unblock = linux_raw_sys::general::SIG_UNBLOCK,
+    /// `SIG_SETMASK`
+    SetMask = linux_raw_sys::general::SIG_SETMASK,
+}
+
+bitflags::bitflags! {
+    /// `SFD_*` flags for use with [`signalfd`].
+    ///
+    /// [`signalfd`]: crate::process::signalfd
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SignalfdFlags: c::c_uint {
+        /// `SFD_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+        /// `SFD_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
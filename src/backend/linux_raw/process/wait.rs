@@ -44,6 +44,8 @@ pub(crate) fn WEXITSTATUS(status: u32) -> u32 {
 pub(crate) trait SiginfoExt {
     fn si_code(&self) -> c_int;
     unsafe fn si_status(&self) -> c_int;
+    unsafe fn si_pid(&self) -> c_int;
+    unsafe fn si_uid(&self) -> u32;
 }
 
 impl SiginfoExt for siginfo_t {
@@ -65,4 +67,28 @@ impl SiginfoExt for siginfo_t {
     unsafe fn si_status(&self) -> c_int {
         self.__bindgen_anon_1.__bindgen_anon_1._sifields._sigchld._status
     }
+
+    /// Return the pid recorded in a `siginfo_t`.
+    ///
+    /// # Safety
+    ///
+    /// `si_signo` must equal `SIGCHLD` (as it is guaranteed to do after a
+    /// `waitid` call).
+    #[inline]
+    #[rustfmt::skip]
+    unsafe fn si_pid(&self) -> c_int {
+        self.__bindgen_anon_1.__bindgen_anon_1._sifields._sigchld._pid
+    }
+
+    /// Return the real user ID recorded in a `siginfo_t`.
+    ///
+    /// # Safety
+    ///
+    /// `si_signo` must equal `SIGCHLD` (as it is guaranteed to do after a
+    /// `waitid` call).
+    #[inline]
+    #[rustfmt::skip]
+    unsafe fn si_uid(&self) -> u32 {
+        self.__bindgen_anon_1.__bindgen_anon_1._sifields._sigchld._uid
+    }
 }
@@ -9,25 +9,30 @@ use crate::backend::conv::{by_ref, c_uint, ret};
 use crate::fd::BorrowedFd;
 use crate::io;
 use linux_raw_sys::ioctl::TIOCSPTLCK;
-#[cfg(feature = "alloc")]
 use {
-    crate::backend::c, crate::ffi::CString, crate::path::DecInt, alloc::vec::Vec,
-    core::mem::MaybeUninit, linux_raw_sys::ioctl::TIOCGPTN,
+    crate::backend::c, crate::path::DecInt, core::mem::MaybeUninit, linux_raw_sys::ioctl::TIOCGPTN,
 };
 
-#[cfg(feature = "alloc")]
 #[inline]
-pub(crate) fn ptsname(fd: BorrowedFd<'_>, mut buffer: Vec<u8>) -> io::Result<CString> {
-    unsafe {
+pub(crate) fn ptsname(fd: BorrowedFd<'_>, buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
+    let n = unsafe {
         let mut n = MaybeUninit::<c::c_int>::uninit();
         ret(syscall!(__NR_ioctl, fd, c_uint(TIOCGPTN), &mut n))?;
+        n.assume_init()
+    };
+
+    let prefix = b"/dev/pts/";
+    let n = DecInt::new(n);
+    let n = n.as_c_str().to_bytes_with_nul();
+    let len = prefix.len() + n.len();
+    if len > buf.len() {
+        return Err(io::Errno::RANGE);
+    }
 
-        buffer.clear();
-        buffer.extend_from_slice(b"/dev/pts/");
-        buffer.extend_from_slice(DecInt::new(n.assume_init()).as_bytes());
-        buffer.push(b'\0');
-        Ok(CString::from_vec_with_nul_unchecked(buffer))
+    for (out, byte) in buf.iter_mut().zip(prefix.iter().chain(n)) {
+        *out = MaybeUninit::new(*byte);
     }
+    Ok(len)
 }
 
 #[inline]
@@ -21,7 +21,7 @@ use crate::ffi::CStr;
 use crate::fs::AtFlags;
 use crate::io;
 use crate::pid::{Pid, RawPid};
-use crate::runtime::{Fork, How, Sigaction, Siginfo, Sigset, Stack};
+use crate::runtime::{Fork, Sigaction, Siginfo, Sigset, Stack};
 use crate::signal::Signal;
 use crate::timespec::Timespec;
 use crate::utils::option_as_ptr;
@@ -70,6 +70,15 @@ pub(crate) unsafe fn fork() -> io::Result<Fork> {
         zero(),
         &mut child_pid
     ))?;
+    #[cfg(target_arch = "s390x")]
+    let pid = ret_c_int(syscall_readonly!(
+        __NR_clone,
+        zero(),
+        c_int(c::SIGCHLD | c::CLONE_CHILD_SETTID),
+        zero(),
+        &mut child_pid,
+        zero()
+    ))?;
 
     Ok(if let Some(pid) = Pid::from_raw(pid) {
         Fork::Parent(pid)
@@ -173,20 +182,6 @@ pub(crate) unsafe fn tkill(tid: Pid, sig: Signal) -> io::Result<()> {
     ret(syscall_readonly!(__NR_tkill, tid, sig))
 }
 
-#[inline]
-pub(crate) unsafe fn sigprocmask(how: How, new: Option<&Sigset>) -> io::Result<Sigset> {
-    let mut old = MaybeUninit::<Sigset>::uninit();
-    let new = option_as_ptr(new);
-    ret(syscall!(
-        __NR_rt_sigprocmask,
-        how,
-        new,
-        &mut old,
-        size_of::<kernel_sigset_t, _>()
-    ))?;
-    Ok(old.assume_init())
-}
-
 #[inline]
 pub(crate) fn sigpending() -> Sigset {
     let mut pending = MaybeUninit::<Sigset>::uninit();
@@ -36,7 +36,7 @@ pub(crate) fn shm_open(name: &CStr, oflags: ShmOFlags, mode: Mode) -> io::Result
     let (path, len) = get_shm_name(name)?;
     open(
         CStr::from_bytes_with_nul(&path[..len]).unwrap(),
-        OFlags::from_bits(oflags.bits()).unwrap() | OFlags::CLOEXEC,
+        OFlags::from_bits_retain(oflags.bits()) | OFlags::CLOEXEC,
         mode,
     )
 }
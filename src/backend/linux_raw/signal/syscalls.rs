@@ -0,0 +1,30 @@
+//! linux_raw syscalls supporting modules that use signal masks.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend` module documentation for details.
+#![allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
+
+use crate::backend::c;
+use crate::backend::conv::{c_uint, opt_ref, ret, size_of};
+use crate::io;
+use core::mem::MaybeUninit;
+use linux_raw_sys::general::kernel_sigset_t;
+
+#[inline]
+pub(crate) fn sigprocmask(
+    how: c::c_uint,
+    set: Option<&kernel_sigset_t>,
+) -> io::Result<kernel_sigset_t> {
+    let mut old = MaybeUninit::<kernel_sigset_t>::uninit();
+    unsafe {
+        ret(syscall!(
+            __NR_rt_sigprocmask,
+            c_uint(how),
+            opt_ref(set),
+            &mut old,
+            size_of::<kernel_sigset_t, _>()
+        ))?;
+        Ok(old.assume_init())
+    }
+}
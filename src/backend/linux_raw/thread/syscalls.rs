@@ -10,6 +10,8 @@ use crate::backend::conv::{
     by_mut, by_ref, c_int, c_uint, ret, ret_c_int, ret_c_int_infallible, ret_usize, slice,
     slice_just_addr, slice_just_addr_mut, zero,
 };
+#[cfg(feature = "procfs")]
+use crate::fd::AsFd;
 use crate::fd::BorrowedFd;
 use crate::io;
 use crate::pid::Pid;
@@ -17,16 +19,13 @@ use crate::thread::{ClockId, FutexFlags, FutexOperation, NanosleepRelativeResult
 use core::mem::MaybeUninit;
 #[cfg(target_pointer_width = "32")]
 use linux_raw_sys::general::timespec as __kernel_old_timespec;
-use linux_raw_sys::general::{__kernel_timespec, TIMER_ABSTIME};
+use linux_raw_sys::general::TIMER_ABSTIME;
 
 #[inline]
-pub(crate) fn clock_nanosleep_relative(
-    id: ClockId,
-    req: &__kernel_timespec,
-) -> NanosleepRelativeResult {
+pub(crate) fn clock_nanosleep_relative(id: ClockId, req: &Timespec) -> NanosleepRelativeResult {
     #[cfg(target_pointer_width = "32")]
     unsafe {
-        let mut rem = MaybeUninit::<__kernel_timespec>::uninit();
+        let mut rem = MaybeUninit::<Timespec>::uninit();
         match ret(syscall!(
             __NR_clock_nanosleep_time64,
             id,
@@ -50,7 +49,7 @@ pub(crate) fn clock_nanosleep_relative(
     }
     #[cfg(target_pointer_width = "64")]
     unsafe {
-        let mut rem = MaybeUninit::<__kernel_timespec>::uninit();
+        let mut rem = MaybeUninit::<Timespec>::uninit();
         match ret(syscall!(
             __NR_clock_nanosleep,
             id,
@@ -68,8 +67,8 @@ pub(crate) fn clock_nanosleep_relative(
 #[cfg(target_pointer_width = "32")]
 unsafe fn clock_nanosleep_relative_old(
     id: ClockId,
-    req: &__kernel_timespec,
-    rem: &mut MaybeUninit<__kernel_timespec>,
+    req: &Timespec,
+    rem: &mut MaybeUninit<Timespec>,
 ) -> io::Result<()> {
     let old_req = __kernel_old_timespec {
         tv_sec: req.tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
@@ -84,7 +83,7 @@ unsafe fn clock_nanosleep_relative_old(
         &mut old_rem
     ))?;
     let old_rem = old_rem.assume_init();
-    rem.write(__kernel_timespec {
+    rem.write(Timespec {
         tv_sec: old_rem.tv_sec.into(),
         tv_nsec: old_rem.tv_nsec.into(),
     });
@@ -92,7 +91,7 @@ unsafe fn clock_nanosleep_relative_old(
 }
 
 #[inline]
-pub(crate) fn clock_nanosleep_absolute(id: ClockId, req: &__kernel_timespec) -> io::Result<()> {
+pub(crate) fn clock_nanosleep_absolute(id: ClockId, req: &Timespec) -> io::Result<()> {
     #[cfg(target_pointer_width = "32")]
     unsafe {
         ret(syscall_readonly!(
@@ -125,7 +124,7 @@ pub(crate) fn clock_nanosleep_absolute(id: ClockId, req: &__kernel_timespec) ->
 }
 
 #[cfg(target_pointer_width = "32")]
-unsafe fn clock_nanosleep_absolute_old(id: ClockId, req: &__kernel_timespec) -> io::Result<()> {
+unsafe fn clock_nanosleep_absolute_old(id: ClockId, req: &Timespec) -> io::Result<()> {
     let old_req = __kernel_old_timespec {
         tv_sec: req.tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
         tv_nsec: req.tv_nsec.try_into().map_err(|_| io::Errno::INVAL)?,
@@ -140,10 +139,10 @@ unsafe fn clock_nanosleep_absolute_old(id: ClockId, req: &__kernel_timespec) ->
 }
 
 #[inline]
-pub(crate) fn nanosleep(req: &__kernel_timespec) -> NanosleepRelativeResult {
+pub(crate) fn nanosleep(req: &Timespec) -> NanosleepRelativeResult {
     #[cfg(target_pointer_width = "32")]
     unsafe {
-        let mut rem = MaybeUninit::<__kernel_timespec>::uninit();
+        let mut rem = MaybeUninit::<Timespec>::uninit();
         match ret(syscall!(
             __NR_clock_nanosleep_time64,
             ClockId::Realtime,
@@ -167,7 +166,7 @@ pub(crate) fn nanosleep(req: &__kernel_timespec) -> NanosleepRelativeResult {
     }
     #[cfg(target_pointer_width = "64")]
     unsafe {
-        let mut rem = MaybeUninit::<__kernel_timespec>::uninit();
+        let mut rem = MaybeUninit::<Timespec>::uninit();
         match ret(syscall!(__NR_nanosleep, by_ref(req), &mut rem)) {
             Ok(()) => NanosleepRelativeResult::Ok,
             Err(io::Errno::INTR) => NanosleepRelativeResult::Interrupted(rem.assume_init()),
@@ -177,10 +176,7 @@ pub(crate) fn nanosleep(req: &__kernel_timespec) -> NanosleepRelativeResult {
 }
 
 #[cfg(target_pointer_width = "32")]
-unsafe fn nanosleep_old(
-    req: &__kernel_timespec,
-    rem: &mut MaybeUninit<__kernel_timespec>,
-) -> io::Result<()> {
+unsafe fn nanosleep_old(req: &Timespec, rem: &mut MaybeUninit<Timespec>) -> io::Result<()> {
     let old_req = __kernel_old_timespec {
         tv_sec: req.tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
         tv_nsec: req.tv_nsec.try_into().map_err(|_| io::Errno::INVAL)?,
@@ -188,7 +184,7 @@ unsafe fn nanosleep_old(
     let mut old_rem = MaybeUninit::<__kernel_old_timespec>::uninit();
     ret(syscall!(__NR_nanosleep, by_ref(&old_req), &mut old_rem))?;
     let old_rem = old_rem.assume_init();
-    rem.write(__kernel_timespec {
+    rem.write(Timespec {
         tv_sec: old_rem.tv_sec.into(),
         tv_nsec: old_rem.tv_nsec.into(),
     });
@@ -257,6 +253,24 @@ unsafe fn futex_old(
     uaddr2: *mut u32,
     val3: u32,
 ) -> io::Result<usize> {
+    // Only some operations use `utime` as a timeout; others use it to pass
+    // an integer `val2`, or ignore it, so pass it through unconverted.
+    let has_timeout = matches!(
+        op,
+        FutexOperation::Wait | FutexOperation::LockPi | FutexOperation::WaitBitset
+    );
+    if !has_timeout || utime.is_null() {
+        return ret_usize(syscall!(
+            __NR_futex,
+            uaddr,
+            (op, flags),
+            c_uint(val),
+            utime,
+            uaddr2,
+            c_uint(val3)
+        ));
+    }
+
     let old_utime = __kernel_old_timespec {
         tv_sec: (*utime).tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
         tv_nsec: (*utime).tv_nsec.try_into().map_err(|_| io::Errno::INVAL)?,
@@ -351,3 +365,81 @@ pub(crate) fn setgroups_thread(gids: &[crate::ugid::Gid]) -> io::Result<()> {
     let (addr, len) = slice(gids);
     unsafe { ret(syscall_readonly!(__NR_setgroups, len, addr)) }
 }
+
+#[inline]
+/// Returns the stack's address range and whether it's currently in use, or
+/// `None` if it's disabled.
+pub(crate) fn sigaltstack_query() -> io::Result<Option<(usize, usize, bool)>> {
+    let mut old = MaybeUninit::<linux_raw_sys::general::stack_t>::uninit();
+    unsafe {
+        ret(syscall!(__NR_sigaltstack, zero(), &mut old))?;
+        let old = old.assume_init();
+        if old.ss_flags & linux_raw_sys::general::SS_DISABLE as c::c_int != 0 {
+            return Ok(None);
+        }
+        let start = old.ss_sp as usize;
+        Ok(Some((
+            start,
+            start + old.ss_size as usize,
+            old.ss_flags & linux_raw_sys::general::SS_ONSTACK as c::c_int != 0,
+        )))
+    }
+}
+
+/// Find the mapping in `/proc/self/maps` which contains the current stack
+/// pointer.
+#[cfg(feature = "procfs")]
+pub(crate) fn stack_bounds() -> io::Result<(usize, usize)> {
+    enum State {
+        Start,
+        End,
+        Rest,
+    }
+
+    // The address of a local variable approximates the stack pointer.
+    let marker = 0_u8;
+    let sp = core::ptr::addr_of!(marker) as usize;
+
+    let maps = crate::procfs::proc_self_maps()?;
+
+    let mut buf = [0_u8; 4096];
+    let (mut state, mut start, mut end) = (State::Start, 0_usize, 0_usize);
+    loop {
+        let n = unsafe {
+            crate::backend::io::syscalls::read(maps.as_fd(), buf.as_mut_ptr(), buf.len())?
+        };
+        if n == 0 {
+            return Err(io::Errno::NOENT);
+        }
+
+        // Each line starts with the address range, as `start-end `, in hex.
+        for byte in &buf[..n] {
+            match (&state, *byte) {
+                (State::Start, b'-') => state = State::End,
+                (State::End, b' ') => {
+                    if start <= sp && sp < end {
+                        return Ok((start, end));
+                    }
+                    state = State::Rest;
+                }
+                (State::Start, digit) => start = push_hex_digit(start, digit)?,
+                (State::End, digit) => end = push_hex_digit(end, digit)?,
+                (State::Rest, b'\n') => (state, start, end) = (State::Start, 0, 0),
+                (State::Rest, _) => {}
+            }
+        }
+    }
+}
+
+#[cfg(feature = "procfs")]
+fn push_hex_digit(value: usize, digit: u8) -> io::Result<usize> {
+    let digit = match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        _ => return Err(io::Errno::IO),
+    };
+    value
+        .checked_mul(16)
+        .map(|value| value | usize::from(digit))
+        .ok_or(io::Errno::IO)
+}
@@ -11,7 +11,7 @@ use core::slice;
 ///
 /// At least `init` bytes must be initialized.
 #[inline]
-pub(super) unsafe fn split_init(
+pub(crate) unsafe fn split_init(
     buf: &mut [MaybeUninit<u8>],
     init: usize,
 ) -> (&mut [u8], &mut [MaybeUninit<u8>]) {
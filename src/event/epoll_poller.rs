@@ -0,0 +1,160 @@
+//! A safe layer over `epoll` with typed tokens.
+
+use crate::backend::c;
+use crate::event::epoll::{self, CreateFlags, EventData, EventFlags, EventVec, Iter};
+use crate::fd::{AsFd, BorrowedFd, OwnedFd};
+use crate::io;
+use core::marker::PhantomData;
+
+/// A token identifying a registration with a [`Poller`].
+///
+/// Tokens are assigned by [`Poller::register`] and are never reused by the
+/// same `Poller`, so events for a source that has since been deregistered
+/// can't be mistaken for events for a newer source which happens to have the
+/// same file descriptor number.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Token(u64);
+
+impl Token {
+    /// Return the integer value of this token.
+    #[inline]
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+/// An `epoll` object which hands out [`Token`]s for registered file
+/// descriptors, instead of exposing raw [`EventData`].
+///
+/// The `'fd` lifetime ties the registered file descriptors to the `Poller`,
+/// so they can't be closed while they're still registered.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[cfg(feature = "pipe")]
+/// # fn main() -> rustix::io::Result<()> {
+/// use rustix::event::epoll::{EventFlags, EventVec, Poller};
+/// use rustix::fd::AsFd;
+/// use rustix::pipe::pipe;
+///
+/// let (reader, writer) = pipe()?;
+///
+/// let mut poller = Poller::new()?;
+/// let token = poller.register(reader.as_fd(), EventFlags::IN)?;
+///
+/// rustix::io::write(&writer, b"hello")?;
+///
+/// let mut events = EventVec::with_capacity(4);
+/// for (ready, flags) in poller.wait(&mut events, -1)? {
+///     assert_eq!(ready, token);
+///     assert!(flags.contains(EventFlags::IN));
+/// }
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "pipe"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug)]
+pub struct Poller<'fd> {
+    epoll: OwnedFd,
+    next_token: u64,
+    _fds: PhantomData<BorrowedFd<'fd>>,
+}
+
+impl<'fd> Poller<'fd> {
+    /// Create a new `Poller`, using an `epoll` object with
+    /// [`CreateFlags::CLOEXEC`].
+    #[inline]
+    pub fn new() -> io::Result<Self> {
+        Ok(Self {
+            epoll: epoll::create(CreateFlags::CLOEXEC)?,
+            next_token: 0,
+            _fds: PhantomData,
+        })
+    }
+
+    /// Register interest in `event_flags` on `source`, returning a new
+    /// [`Token`] which identifies its events.
+    #[doc(alias = "epoll_ctl")]
+    pub fn register(
+        &mut self,
+        source: BorrowedFd<'fd>,
+        event_flags: EventFlags,
+    ) -> io::Result<Token> {
+        let token = Token(self.next_token);
+        epoll::add(
+            &self.epoll,
+            source,
+            EventData::new_u64(token.0),
+            event_flags,
+        )?;
+        self.next_token += 1;
+        Ok(token)
+    }
+
+    /// Change the events of interest for `source`, which must have been
+    /// registered with `token`.
+    #[doc(alias = "epoll_ctl")]
+    #[inline]
+    pub fn modify(
+        &self,
+        source: BorrowedFd<'fd>,
+        token: Token,
+        event_flags: EventFlags,
+    ) -> io::Result<()> {
+        epoll::modify(
+            &self.epoll,
+            source,
+            EventData::new_u64(token.0),
+            event_flags,
+        )
+    }
+
+    /// Remove `source` from this `Poller`.
+    #[doc(alias = "epoll_ctl")]
+    #[inline]
+    pub fn deregister(&self, source: BorrowedFd<'fd>) -> io::Result<()> {
+        epoll::delete(&self.epoll, source)
+    }
+
+    /// Wait for events on the registered sources, and return an iterator
+    /// over the tokens and flags of the events that occurred.
+    #[doc(alias = "epoll_wait")]
+    #[inline]
+    pub fn wait<'a>(
+        &self,
+        event_list: &'a mut EventVec,
+        timeout: c::c_int,
+    ) -> io::Result<PollerEvents<'a>> {
+        epoll::wait(&self.epoll, event_list, timeout)?;
+        Ok(PollerEvents {
+            iter: event_list.iter(),
+        })
+    }
+}
+
+impl AsFd for Poller<'_> {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.epoll.as_fd()
+    }
+}
+
+/// An iterator over the `(Token, EventFlags)` pairs returned by
+/// [`Poller::wait`].
+pub struct PollerEvents<'a> {
+    iter: Iter<'a>,
+}
+
+impl<'a> Iterator for PollerEvents<'a> {
+    type Item = (Token, EventFlags);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|event| {
+            let flags = event.flags;
+            (Token(event.data.u64()), flags)
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::fd::OwnedFd;
+use crate::fd::{AsFd, OwnedFd};
 use crate::{backend, io};
 
 pub use backend::event::types::EventfdFlags;
@@ -18,3 +18,44 @@ pub use backend::event::types::EventfdFlags;
 pub fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd> {
     backend::event::syscalls::eventfd(initval, flags)
 }
+
+/// `eventfd_read(fd)`—Reads the counter of an eventfd.
+///
+/// This reads the 8-byte counter value, resetting the counter to zero, or,
+/// with [`EventfdFlags::SEMAPHORE`], decrementing it by one. If the counter
+/// is zero and the eventfd is non-blocking, this fails with
+/// [`io::Errno::AGAIN`], which event-loop users can treat as "no events".
+///
+/// # References
+///  - [Linux]
+///  - [FreeBSD]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/eventfd_read.3.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?eventfd
+#[inline]
+pub fn eventfd_read<Fd: AsFd>(fd: Fd) -> io::Result<u64> {
+    let mut bytes = [0_u8; 8];
+    match io::read(fd, &mut bytes)? {
+        8 => Ok(u64::from_ne_bytes(bytes)),
+        _ => Err(io::Errno::IO),
+    }
+}
+
+/// `eventfd_write(fd, value)`—Adds to the counter of an eventfd.
+///
+/// If adding `value` would overflow the counter and the eventfd is
+/// non-blocking, this fails with [`io::Errno::AGAIN`].
+///
+/// # References
+///  - [Linux]
+///  - [FreeBSD]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/eventfd_write.3.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?eventfd
+#[inline]
+pub fn eventfd_write<Fd: AsFd>(fd: Fd, value: u64) -> io::Result<()> {
+    match io::write(fd, &value.to_ne_bytes())? {
+        8 => Ok(()),
+        _ => Err(io::Errno::IO),
+    }
+}
@@ -9,6 +9,7 @@ use backend::c::{self, intptr_t, kevent as kevent_t, uintptr_t};
 use backend::event::syscalls;
 
 use alloc::vec::Vec;
+use core::fmt;
 use core::mem::zeroed;
 use core::ptr::slice_from_raw_parts_mut;
 use core::time::Duration;
@@ -155,6 +156,17 @@ impl Event {
     }
 }
 
+impl fmt::Debug for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Event")
+            .field("filter", &self.filter())
+            .field("flags", &self.flags())
+            .field("data", &self.data())
+            .field("udata", &self.udata())
+            .finish()
+    }
+}
+
 /// Bottom 24 bits of a u32.
 #[cfg(any(apple, freebsdlike))]
 const EVFILT_USER_FLAGS: u32 = 0x00ff_ffff;
@@ -162,6 +174,7 @@ const EVFILT_USER_FLAGS: u32 = 0x00ff_ffff;
 /// The possible filters for a `kqueue`.
 #[repr(i16)]
 #[non_exhaustive]
+#[derive(Debug)]
 pub enum EventFilter {
     /// A read filter.
     Read(RawFd),
@@ -1,5 +1,7 @@
 //! Event operations.
 
+#[cfg(all(feature = "alloc", any(linux_kernel, target_os = "redox")))]
+pub(crate) mod epoll_poller;
 #[cfg(any(
     linux_kernel,
     target_os = "freebsd",
@@ -17,13 +19,17 @@ pub mod port;
 
 #[cfg(any(linux_kernel, target_os = "redox"))]
 pub use crate::backend::event::epoll;
+#[cfg(linux_kernel)]
+pub use crate::timespec::Timespec;
 #[cfg(any(
     linux_kernel,
     target_os = "freebsd",
     target_os = "illumos",
     target_os = "espidf"
 ))]
-pub use eventfd::{eventfd, EventfdFlags};
+pub use eventfd::{eventfd, eventfd_read, eventfd_write, EventfdFlags};
 #[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
 pub use pause::*;
-pub use poll::{poll, PollFd, PollFlags};
+#[cfg(linux_kernel)]
+pub use poll::ppoll;
+pub use poll::{is_readable, is_writable, poll, wait_readable, wait_writable, PollFd, PollFlags};
@@ -1,4 +1,8 @@
+use crate::fd::{AsFd, BorrowedFd};
+#[cfg(linux_kernel)]
+use crate::timespec::Timespec;
 use crate::{backend, io};
+use core::time::Duration;
 
 pub use backend::event::poll_fd::{PollFd, PollFlags};
 
@@ -30,3 +34,91 @@ pub use backend::event::poll_fd::{PollFd, PollFlags};
 pub fn poll(fds: &mut [PollFd<'_>], timeout: i32) -> io::Result<usize> {
     backend::event::syscalls::poll(fds, timeout)
 }
+
+/// `ppoll(self.fds, timeout, NULL)`—Like [`poll`], but with a timeout in
+/// nanoseconds.
+///
+/// A `timeout` of `None` waits indefinitely. This doesn't change the signal
+/// mask. On 32-bit platforms, this uses the y2038-safe `ppoll_time64` where
+/// the kernel supports it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ppoll.2.html
+#[cfg(linux_kernel)]
+#[inline]
+pub fn ppoll(fds: &mut [PollFd<'_>], timeout: Option<&Timespec>) -> io::Result<usize> {
+    backend::event::syscalls::ppoll(fds, timeout)
+}
+
+/// Test whether `fd` is ready for reading, without blocking.
+///
+/// This returns `true` if a read on `fd` wouldn't block, which includes the
+/// cases where the peer has hung up or an error is pending.
+///
+/// This is a convenience wrapper around [`poll`] with a single [`PollFd`]
+/// and a timeout of zero.
+#[inline]
+pub fn is_readable<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    poll_one(fd.as_fd(), PollFlags::IN, 0)
+}
+
+/// Test whether `fd` is ready for writing, without blocking.
+///
+/// This returns `true` if a write on `fd` wouldn't block, which includes the
+/// cases where the peer has hung up or an error is pending.
+///
+/// This is a convenience wrapper around [`poll`] with a single [`PollFd`]
+/// and a timeout of zero.
+#[inline]
+pub fn is_writable<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    poll_one(fd.as_fd(), PollFlags::OUT, 0)
+}
+
+/// Wait until `fd` is ready for reading, or until `timeout` elapses.
+///
+/// Returns `true` if `fd` became ready, and `false` if the timeout elapsed
+/// first. A `timeout` of `None` waits indefinitely. Timeouts are rounded up
+/// to whole milliseconds.
+///
+/// Like [`poll`], this fails with [`io::Errno::INTR`] if interrupted by a
+/// signal handler.
+#[inline]
+pub fn wait_readable<Fd: AsFd>(fd: Fd, timeout: Option<Duration>) -> io::Result<bool> {
+    poll_one(fd.as_fd(), PollFlags::IN, timeout_to_millis(timeout))
+}
+
+/// Wait until `fd` is ready for writing, or until `timeout` elapses.
+///
+/// Returns `true` if `fd` became ready, and `false` if the timeout elapsed
+/// first. A `timeout` of `None` waits indefinitely. Timeouts are rounded up
+/// to whole milliseconds.
+///
+/// Like [`poll`], this fails with [`io::Errno::INTR`] if interrupted by a
+/// signal handler.
+#[inline]
+pub fn wait_writable<Fd: AsFd>(fd: Fd, timeout: Option<Duration>) -> io::Result<bool> {
+    poll_one(fd.as_fd(), PollFlags::OUT, timeout_to_millis(timeout))
+}
+
+/// Poll a single file descriptor, and report whether any events occurred.
+fn poll_one(fd: BorrowedFd<'_>, events: PollFlags, timeout: i32) -> io::Result<bool> {
+    let mut fds = [PollFd::from_borrowed_fd(fd, events)];
+    Ok(poll(&mut fds, timeout)? != 0)
+}
+
+/// Convert an optional `Duration` into a `poll` timeout in milliseconds,
+/// rounding up and saturating at `i32::MAX`.
+fn timeout_to_millis(timeout: Option<Duration>) -> i32 {
+    match timeout {
+        None => -1,
+        Some(timeout) => {
+            let millis = timeout
+                .as_secs()
+                .saturating_mul(1000)
+                .saturating_add(u64::from((timeout.subsec_nanos() + 999_999) / 1_000_000));
+            i32::try_from(millis).unwrap_or(i32::MAX)
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! POSIX-style filesystem functions which operate on bare paths.
 
 use crate::fd::OwnedFd;
+use crate::ffi::CStr;
 #[cfg(not(any(target_os = "espidf", target_os = "vita")))]
 use crate::fs::Access;
 #[cfg(not(any(
@@ -17,15 +18,13 @@ use crate::fs::StatFs;
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 use crate::fs::StatVfs;
 use crate::fs::{Mode, OFlags, Stat};
+use crate::path::OutputBuffer;
 #[cfg(not(target_os = "wasi"))]
 use crate::ugid::{Gid, Uid};
 use crate::{backend, io, path};
+use core::mem::MaybeUninit;
 #[cfg(feature = "alloc")]
-use {
-    crate::ffi::{CStr, CString},
-    crate::path::SMALL_PATH_BUFFER_SIZE,
-    alloc::vec::Vec,
-};
+use {crate::ffi::CString, alloc::vec::Vec};
 
 /// `open(path, oflags, mode)`—Opens a file.
 ///
@@ -101,6 +100,9 @@ pub fn lstat<P: path::Arg>(path: P) -> io::Result<Stat> {
 ///
 /// If `reuse` is non-empty, reuse its buffer to store the result if possible.
 ///
+/// To write into a fixed-size buffer without allocating, use
+/// [`readlink_into`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -110,30 +112,55 @@ pub fn lstat<P: path::Arg>(path: P) -> io::Result<Stat> {
 #[cfg(feature = "alloc")]
 #[inline]
 pub fn readlink<P: path::Arg, B: Into<Vec<u8>>>(path: P, reuse: B) -> io::Result<CString> {
-    path.into_with_c_str(|path| _readlink(path, reuse.into()))
+    readlink_into(path, reuse.into())
 }
 
-#[cfg(feature = "alloc")]
-fn _readlink(path: &CStr, mut buffer: Vec<u8>) -> io::Result<CString> {
-    // This code would benefit from having a better way to read into
-    // uninitialized memory, but that requires `unsafe`.
-    buffer.clear();
-    buffer.reserve(SMALL_PATH_BUFFER_SIZE);
-    buffer.resize(buffer.capacity(), 0_u8);
+/// `readlink(path)`—Reads the contents of a symlink into a caller-provided
+/// buffer.
+///
+/// `buf` may be any [`OutputBuffer`], such as a `Vec<u8>` to allocate the
+/// result, or a fixed-size array to avoid allocating.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/readlink.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/readlink.2.html
+#[inline]
+pub fn readlink_into<P: path::Arg, B: OutputBuffer>(path: P, buf: B) -> io::Result<B::Name> {
+    path.into_with_c_str(|path| _readlink(path, buf))
+}
 
-    loop {
-        let nread = backend::fs::syscalls::readlink(path, &mut buffer)?;
+#[allow(unsafe_code)]
+fn _readlink<B: OutputBuffer>(path: &CStr, buf: B) -> io::Result<B::Name> {
+    // SAFETY: `readlink` initializes the first `nread` bytes, and
+    // `nul_terminate_link` initializes one more.
+    unsafe {
+        buf.fill_name(&mut |buf| {
+            if buf.is_empty() {
+                return Err(io::Errno::RANGE);
+            }
+            let nread = backend::fs::syscalls::readlink(path, buf)?;
+            nul_terminate_link(buf, nread)
+        })
+    }
+}
 
-        let nread = nread as usize;
-        assert!(nread <= buffer.len());
-        if nread < buffer.len() {
-            buffer.resize(nread, 0_u8);
-            return Ok(CString::new(buffer).unwrap());
-        }
-        // Use `Vec` reallocation strategy to grow capacity exponentially.
-        buffer.reserve(1);
-        buffer.resize(buffer.capacity(), 0_u8);
+/// Append a NUL terminator to the `nread` bytes of symlink contents at the
+/// start of `buf`, returning the length including the NUL.
+pub(super) fn nul_terminate_link(buf: &mut [MaybeUninit<u8>], nread: usize) -> io::Result<usize> {
+    // If the contents filled the whole buffer, they may have been truncated,
+    // and there's no room for the NUL terminator.
+    if nread >= buf.len() {
+        return Err(io::Errno::RANGE);
     }
+
+    // “readlink does not append a terminating null byte to buf”, and
+    // pathnames never contain NUL bytes, so after this there is exactly one
+    // NUL byte, at the end.
+    buf[nread] = MaybeUninit::new(0);
+    Ok(nread + 1)
 }
 
 /// `rename(old_path, new_path)`—Renames a file or directory.
@@ -7,6 +7,7 @@
 
 use crate::fd::OwnedFd;
 use crate::ffi::CStr;
+use crate::fs::abs::nul_terminate_link;
 #[cfg(not(any(target_os = "espidf", target_os = "vita")))]
 use crate::fs::Access;
 #[cfg(not(target_os = "espidf"))]
@@ -22,12 +23,13 @@ use crate::fs::{Dev, FileType};
 #[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
 use crate::fs::{Gid, Uid};
 use crate::fs::{Mode, OFlags};
+use crate::path::OutputBuffer;
 use crate::{backend, io, path};
 use backend::fd::{AsFd, BorrowedFd};
 use core::mem::MaybeUninit;
 use core::slice;
 #[cfg(feature = "alloc")]
-use {crate::ffi::CString, crate::path::SMALL_PATH_BUFFER_SIZE, alloc::vec::Vec};
+use {crate::ffi::CString, alloc::vec::Vec};
 #[cfg(not(any(target_os = "espidf", target_os = "vita")))]
 use {crate::fs::Timestamps, crate::timespec::Nsecs};
 
@@ -73,6 +75,9 @@ pub fn openat<P: path::Arg, Fd: AsFd>(
 ///
 /// If `reuse` already has available capacity, reuse it if possible.
 ///
+/// To write into a fixed-size buffer without allocating, use
+/// [`readlinkat_into`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -86,49 +91,46 @@ pub fn readlinkat<P: path::Arg, Fd: AsFd, B: Into<Vec<u8>>>(
     path: P,
     reuse: B,
 ) -> io::Result<CString> {
-    path.into_with_c_str(|path| _readlinkat(dirfd.as_fd(), path, reuse.into()))
+    readlinkat_into(dirfd, path, reuse.into())
 }
 
-#[cfg(feature = "alloc")]
-#[allow(unsafe_code)]
-fn _readlinkat(dirfd: BorrowedFd<'_>, path: &CStr, mut buffer: Vec<u8>) -> io::Result<CString> {
-    buffer.clear();
-    buffer.reserve(SMALL_PATH_BUFFER_SIZE);
-
-    loop {
-        let nread =
-            backend::fs::syscalls::readlinkat(dirfd.as_fd(), path, buffer.spare_capacity_mut())?;
-
-        debug_assert!(nread <= buffer.capacity());
-        if nread < buffer.capacity() {
-            // SAFETY: From the [documentation]: “On success, these calls
-            // return the number of bytes placed in buf.”
-            //
-            // [documentation]: https://man7.org/linux/man-pages/man2/readlinkat.2.html
-            unsafe {
-                buffer.set_len(nread);
-            }
+/// `readlinkat(fd, path)`—Reads the contents of a symlink into a
+/// caller-provided buffer.
+///
+/// `buf` may be any [`OutputBuffer`], such as a `Vec<u8>` to allocate the
+/// result, or a fixed-size array to avoid allocating.
+///
+/// On Linux, if `path` is empty, this reads the symlink referred to by
+/// `dirfd` itself, which may be opened with [`OFlags::PATH`] and
+/// [`OFlags::NOFOLLOW`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/readlinkat.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/readlinkat.2.html
+#[inline]
+pub fn readlinkat_into<P: path::Arg, Fd: AsFd, B: OutputBuffer>(
+    dirfd: Fd,
+    path: P,
+    buf: B,
+) -> io::Result<B::Name> {
+    path.into_with_c_str(|path| _readlinkat(dirfd.as_fd(), path, buf))
+}
 
-            // SAFETY:
-            // - “readlink places the contents of the symbolic link pathname in
-            //   the buffer buf”
-            // - [POSIX definition 3.271: Pathname]: “A string that is used to
-            //   identify a file.”
-            // - [POSIX definition 3.375: String]: “A contiguous sequence of
-            //   bytes terminated by and including the first null byte.”
-            // - “readlink does not append a terminating null byte to buf.”
-            //
-            // Thus, there will be no NUL bytes in the string.
-            //
-            // [POSIX definition 3.271: Pathname]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap03.html#tag_03_271
-            // [POSIX definition 3.375: String]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap03.html#tag_03_375
-            unsafe {
-                return Ok(CString::from_vec_unchecked(buffer));
+#[allow(unsafe_code)]
+fn _readlinkat<B: OutputBuffer>(dirfd: BorrowedFd<'_>, path: &CStr, buf: B) -> io::Result<B::Name> {
+    // SAFETY: `readlinkat` initializes the first `nread` bytes, and
+    // `nul_terminate_link` initializes one more.
+    unsafe {
+        buf.fill_name(&mut |buf| {
+            if buf.is_empty() {
+                return Err(io::Errno::RANGE);
             }
-        }
-
-        // Use `Vec` reallocation strategy to grow capacity exponentially.
-        buffer.reserve(buffer.capacity() + 1);
+            let nread = backend::fs::syscalls::readlinkat(dirfd, path, buf)?;
+            nul_terminate_link(buf, nread)
+        })
     }
 }
 
@@ -190,6 +192,17 @@ pub fn mkdirat<P: path::Arg, Fd: AsFd>(dirfd: Fd, path: P, mode: Mode) -> io::Re
 /// `linkat(old_dirfd, old_path, new_dirfd, new_path, flags)`—Creates a hard
 /// link.
 ///
+/// By default, if `old_path` is a symbolic link, the new link refers to the
+/// symbolic link itself; pass [`AtFlags::SYMLINK_FOLLOW`] to link to its
+/// target instead.
+///
+/// On Linux and FreeBSD, with [`AtFlags::EMPTY_PATH`] and an empty
+/// `old_path`, this links the file referred to by `old_dirfd` itself, which
+/// may be a file opened with [`OFlags::TMPFILE`] or [`OFlags::PATH`]. On
+/// Linux, this requires the `CAP_DAC_READ_SEARCH` capability; without it, a
+/// `/proc/self/fd/<fd>` path with [`AtFlags::SYMLINK_FOLLOW`] has the same
+/// effect.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -384,9 +397,13 @@ pub fn utimensat<P: path::Arg, Fd: AsFd>(
 
 /// `fchmodat(dirfd, path, mode, flags)`—Sets file or directory permissions.
 ///
-/// Platform support for flags varies widely, for example on Linux
-/// [`AtFlags::SYMLINK_NOFOLLOW`] is not implemented and therefore
-/// [`io::Errno::OPNOTSUPP`] will be returned.
+/// Platform support for flags varies widely. On Linux, flags are supported
+/// with the `fchmodat2` system call, introduced in Linux 6.6. On older
+/// kernels, [`AtFlags::EMPTY_PATH`] with an empty `path` falls back to
+/// `fchmod`, and [`AtFlags::SYMLINK_NOFOLLOW`] fails with
+/// [`io::Errno::OPNOTSUPP`]. Most filesystems don't support changing the
+/// permissions of symlinks, so [`AtFlags::SYMLINK_NOFOLLOW`] on a symlink
+/// fails with [`io::Errno::OPNOTSUPP`] even on newer kernels.
 ///
 /// # References
 ///  - [POSIX]
@@ -408,6 +425,13 @@ pub fn chmodat<P: path::Arg, Fd: AsFd>(
 
 /// `fclonefileat(src, dst_dir, dst, flags)`—Efficiently copies between files.
 ///
+/// The new file `dst` shares its data blocks with `src` until either is
+/// modified, as on APFS. `dst` must not already exist.
+///
+/// See also [`reflinkat`], which is also available on Linux.
+///
+/// [`reflinkat`]: crate::fs::reflinkat
+///
 /// # References
 ///  - [Apple]
 ///
@@ -425,6 +449,33 @@ pub fn fclonefileat<Fd: AsFd, DstFd: AsFd, P: path::Arg>(
     })
 }
 
+/// `clonefileat(src_dir, src, dst_dir, dst, flags)`—Efficiently copies
+/// between files, by path.
+///
+/// This is like [`fclonefileat`], but takes the source by path. With
+/// [`CloneFlags::NOFOLLOW`], a symlink at `src` is cloned, rather than the file
+/// it refers to.
+///
+/// # References
+///  - [Apple]
+///
+/// [Apple]: https://opensource.apple.com/source/xnu/xnu-3789.21.4/bsd/man/man2/clonefile.2.auto.html
+#[cfg(apple)]
+#[inline]
+pub fn clonefileat<SrcFd: AsFd, P: path::Arg, DstFd: AsFd, Q: path::Arg>(
+    src_dir: SrcFd,
+    src: P,
+    dst_dir: DstFd,
+    dst: Q,
+    flags: CloneFlags,
+) -> io::Result<()> {
+    src.into_with_c_str(|src| {
+        dst.into_with_c_str(|dst| {
+            backend::fs::syscalls::clonefileat(src_dir.as_fd(), src, dst_dir.as_fd(), dst, flags)
+        })
+    })
+}
+
 /// `mknodat(dirfd, path, mode, dev)`—Creates special or normal files.
 ///
 /// # References
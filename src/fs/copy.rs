@@ -0,0 +1,234 @@
+use crate::fd::{AsFd, BorrowedFd};
+#[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+use crate::fs::ioctl_ficlone;
+#[cfg(target_os = "linux")]
+use crate::fs::sendfile;
+use crate::fs::{copy_file_range, fstat, ftruncate, seek, FileType, SeekFrom};
+use crate::io;
+
+/// The largest amount of data passed to a single `copy_file_range` or
+/// `sendfile` call, which keeps lengths within what the kernel accepts on all
+/// architectures.
+const MAX_CHUNK: u64 = 0x7fff_f000;
+
+/// The size of the buffer used when falling back to `read` and `write`.
+const BUFFER_SIZE: usize = 8192;
+
+/// The ways of copying data, in the order they're tried.
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Method {
+    CopyFileRange,
+    #[cfg(target_os = "linux")]
+    Sendfile,
+    ReadWrite,
+}
+
+/// Copy the contents of the file `src` into `dst`, using the fastest method
+/// the kernel and filesystems support.
+///
+/// This copies from the start of `src` to its size at the time of the call,
+/// writing to `dst` at its current file offset, and returns the number of
+/// bytes copied. It tries, in order:
+///
+///  - [`ioctl_ficlone`], to share data between the files without copying it,
+///    if `dst` is an empty regular file at offset 0,
+///  - [`copy_file_range`], which lets the kernel and filesystem copy data
+///    without passing it through userspace,
+///  - [`sendfile`], which works in more cases, such as when `dst` is a pipe
+///    or socket,
+///  - and [`pread`] and [`write`] otherwise,
+///
+/// moving on to the next when one fails with an error such as
+/// [`io::Errno::XDEV`] or [`io::Errno::OPNOTSUPP`] that means it's unsupported
+/// for these files. If `dst` is a regular file with no data past its current
+/// offset, holes in `src` are preserved as holes in `dst` rather than being
+/// filled with zeros.
+///
+/// `src`'s file offset is not used, but it and `dst`'s may be changed. If
+/// `src` is truncated concurrently, this stops at its new end and returns the
+/// number of bytes copied so far.
+///
+/// Pseudo-files don't report their sizes accurately, and `copy_file_range`
+/// and `sendfile` may copy nothing from them, so if `src` has a size of 0,
+/// as files in procfs do, or if nothing can be copied from the start of it
+/// with those, as with files in sysfs, this falls back to [`pread`] and
+/// [`write`], stopping at the end of the file.
+///
+/// [`ioctl_ficlone`]: crate::fs::ioctl_ficlone
+/// [`sendfile`]: crate::fs::sendfile
+/// [`pread`]: crate::io::pread
+/// [`write`]: crate::io::write
+#[doc(alias = "copy")]
+pub fn copy_fd_contents<SrcFd: AsFd, DstFd: AsFd>(src: SrcFd, dst: DstFd) -> io::Result<u64> {
+    let src = src.as_fd();
+    let dst = dst.as_fd();
+
+    let size = fstat(src)?.st_size as u64;
+    if size == 0 {
+        // `src` may be a pseudo-file with contents despite its size, so read
+        // it until the end.
+        return copy_range(src, dst, 0, u64::MAX, &mut Method::ReadWrite);
+    }
+
+    // Holes can only be skipped if `dst` is a regular file which doesn't have
+    // data of its own that would show through them.
+    let dst_stat = fstat(dst)?;
+    let sparse_start = if FileType::from_raw_mode(dst_stat.st_mode) == FileType::RegularFile {
+        let start = seek(dst, SeekFrom::Current(0))?;
+        if start >= dst_stat.st_size as u64 {
+            Some(start)
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
+    #[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+    if sparse_start == Some(0) {
+        match ioctl_ficlone(dst, src) {
+            Ok(()) => {
+                seek(dst, SeekFrom::End(0))?;
+                return Ok(size);
+            }
+            Err(
+                io::Errno::XDEV
+                | io::Errno::OPNOTSUPP
+                | io::Errno::INVAL
+                | io::Errno::NOTTY
+                | io::Errno::NOSYS
+                | io::Errno::BADF
+                | io::Errno::PERM,
+            ) => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    let mut method = Method::CopyFileRange;
+    let mut pos = 0;
+    while pos < size {
+        let (start, end) = if sparse_start.is_some() {
+            match next_data(src, pos, size)? {
+                Some(region) => region,
+                None => break,
+            }
+        } else {
+            (pos, size)
+        };
+
+        if start != pos {
+            seek(dst, SeekFrom::Current((start - pos) as i64))?;
+        }
+        let copied = copy_range(src, dst, start, end, &mut method)?;
+        pos = start + copied;
+        if pos < end {
+            // `src` was truncated.
+            return Ok(pos);
+        }
+    }
+
+    // Extend `dst` over any trailing hole.
+    if let Some(sparse_start) = sparse_start {
+        ftruncate(dst, sparse_start + size)?;
+        seek(dst, SeekFrom::Start(sparse_start + size))?;
+    }
+
+    Ok(size)
+}
+
+/// Find the first region of data in `src` at or after `pos`, returning its
+/// start and end, or `None` if the rest of the file is a hole.
+fn next_data(src: BorrowedFd<'_>, pos: u64, size: u64) -> io::Result<Option<(u64, u64)>> {
+    let start = match seek(src, SeekFrom::Data(pos as i64)) {
+        Ok(start) => start,
+        Err(io::Errno::NXIO) => return Ok(None),
+        // The filesystem doesn't support finding holes, so treat the rest of
+        // the file as data.
+        Err(io::Errno::INVAL | io::Errno::OPNOTSUPP) => return Ok(Some((pos, size))),
+        Err(err) => return Err(err),
+    };
+    if start >= size {
+        return Ok(None);
+    }
+    let end = match seek(src, SeekFrom::Hole(start as i64)) {
+        Ok(end) => end.min(size),
+        Err(io::Errno::NXIO) => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    Ok(Some((start, end)))
+}
+
+/// Copy `start..end` of `src` to `dst` at its current offset, falling back to
+/// slower methods as needed, and return the number of bytes copied, which is
+/// less than requested only if `src` was truncated.
+fn copy_range(
+    src: BorrowedFd<'_>,
+    dst: BorrowedFd<'_>,
+    start: u64,
+    end: u64,
+    method: &mut Method,
+) -> io::Result<u64> {
+    let mut off = start;
+    let mut buf = [0_u8; BUFFER_SIZE];
+    while off < end {
+        let len = (end - off).min(MAX_CHUNK) as usize;
+
+        // A failed call doesn't copy anything, so on an error that means a
+        // method is unsupported, the next can pick up at the same offset.
+        let result = match *method {
+            Method::CopyFileRange => match copy_file_range(src, Some(&mut off), dst, None, len) {
+                Err(
+                    io::Errno::XDEV
+                    | io::Errno::OPNOTSUPP
+                    | io::Errno::INVAL
+                    | io::Errno::NOSYS
+                    | io::Errno::BADF
+                    | io::Errno::PERM,
+                ) => {
+                    *method = next_method(*method);
+                    continue;
+                }
+                result => result,
+            },
+            #[cfg(target_os = "linux")]
+            Method::Sendfile => match sendfile(dst, src, Some(&mut off), len) {
+                Err(io::Errno::INVAL | io::Errno::NOSYS | io::Errno::OPNOTSUPP) => {
+                    *method = Method::ReadWrite;
+                    continue;
+                }
+                result => result,
+            },
+            Method::ReadWrite => {
+                let len = len.min(buf.len());
+                match io::pread(src, &mut buf[..len], off) {
+                    Ok(n) => {
+                        io::write_all(dst, &buf[..n])?;
+                        off += n as u64;
+                        Ok(n)
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+        };
+        match result {
+            // Pseudo-files, such as those in sysfs, can have contents which
+            // `copy_file_range` and `sendfile` don't see, so if they copy
+            // nothing from the start of the file, use `pread` instead.
+            Ok(0) if off == 0 && *method != Method::ReadWrite => *method = Method::ReadWrite,
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(io::Errno::INTR) => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(off - start)
+}
+
+/// Return the method to try after `method` fails.
+fn next_method(method: Method) -> Method {
+    match method {
+        #[cfg(target_os = "linux")]
+        Method::CopyFileRange => Method::Sendfile,
+        _ => Method::ReadWrite,
+    }
+}
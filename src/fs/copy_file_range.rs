@@ -4,10 +4,23 @@ use backend::fd::AsFd;
 /// `copy_file_range(fd_in, off_in, fd_out, off_out, len, 0)`—Copies data
 /// from one file to another.
 ///
+/// If `off_in` is `Some`, data is read starting at that offset, which is
+/// then advanced by the number of bytes copied, and `fd_in`'s file offset
+/// is neither used nor changed. If it's `None`, data is read starting at
+/// `fd_in`'s file offset, which is advanced instead. `off_out` and `fd_out`
+/// work the same way.
+///
+/// This returns the number of bytes copied, which may be less than `len`,
+/// and is 0 at the end of `fd_in`. It fails with [`io::Errno::XDEV`] on
+/// older kernels if the files are on different filesystems, and with
+/// [`io::Errno::NOSYS`] on FreeBSD before 13.
+///
 /// # References
 ///  - [Linux]
+///  - [FreeBSD]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/copy_file_range.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=copy_file_range&sektion=2
 #[inline]
 pub fn copy_file_range<InFd: AsFd, OutFd: AsFd>(
     fd_in: InFd,
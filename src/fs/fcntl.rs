@@ -12,6 +12,15 @@
     target_os = "wasi"
 )))]
 use crate::fs::FlockOperation;
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+use crate::pid::Pid;
 use crate::{backend, io};
 use backend::fd::AsFd;
 use backend::fs::types::OFlags;
@@ -19,6 +28,8 @@ use backend::fs::types::OFlags;
 // These `fcntl` functions live in the `io` module because they're not specific
 // to files, directories, or memfd objects. We re-export them here in the `fs`
 // module because the other the `fcntl` functions are here.
+#[cfg(not(target_os = "wasi"))]
+pub use crate::io::fcntl_dupfd;
 #[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
 pub use crate::io::fcntl_dupfd_cloexec;
 pub use crate::io::{fcntl_getfd, fcntl_setfd};
@@ -80,11 +91,71 @@ pub fn fcntl_add_seals<Fd: AsFd>(fd: Fd, seals: SealFlags) -> io::Result<()> {
     backend::fs::syscalls::fcntl_add_seals(fd.as_fd(), seals)
 }
 
+/// `fcntl(fd, F_SETLEASE, lease)`—Acquire, change, or release a file lease.
+///
+/// Passing `None` releases any lease held on `fd`.
+///
+/// When another process opens or truncates the file in a way that conflicts
+/// with the lease, the lease holder is sent `SIGIO` and has a limited time to
+/// clean up and release or downgrade the lease.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "F_SETLEASE")]
+pub fn fcntl_setlease<Fd: AsFd>(fd: Fd, lease: Option<LeaseType>) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_setlease(fd.as_fd(), lease)
+}
+
+/// `fcntl(fd, F_GETLEASE)`—Query the type of lease held on a file.
+///
+/// Returns `None` if no lease is held.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "F_GETLEASE")]
+pub fn fcntl_getlease<Fd: AsFd>(fd: Fd) -> io::Result<Option<LeaseType>> {
+    backend::fs::syscalls::fcntl_getlease(fd.as_fd())
+}
+
+#[cfg(linux_kernel)]
+use backend::fs::types::{DnotifyFlags, LeaseType};
+
+/// `fcntl(fd, F_NOTIFY, flags)`—Request notification of changes to a
+/// directory.
+///
+/// Notifications are delivered as `SIGIO` signals to the calling process.
+/// Unless [`DnotifyFlags::MULTISHOT`] is set, the notification is removed
+/// after the first event. Passing empty flags removes all notifications for
+/// `fd`.
+///
+/// New code should generally prefer [`inotify`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+/// [`inotify`]: crate::fs::inotify
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "F_NOTIFY")]
+#[doc(alias = "dnotify")]
+pub fn fcntl_notify<Fd: AsFd>(fd: Fd, flags: DnotifyFlags) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_notify(fd.as_fd(), flags)
+}
+
 /// `fcntl(fd, F_SETLK)`—Acquire or release an `fcntl`-style lock.
 ///
-/// This function doesn't currently have an offset or len; it currently always
-/// sets the `l_len` field to 0, which is a special case that means the entire
-/// file should be locked.
+/// This always sets the `l_len` field to 0, which is a special case that
+/// means the entire file should be locked. To lock a range of bytes, use
+/// [`fcntl_setlk`] or [`fcntl_setlkw`].
 ///
 /// Unlike `flock`-style locks, `fcntl`-style locks are process-associated,
 /// meaning that they don't guard against being acquired by two threads in the
@@ -110,3 +181,222 @@ pub fn fcntl_add_seals<Fd: AsFd>(fd: Fd, seals: SealFlags) -> io::Result<()> {
 pub fn fcntl_lock<Fd: AsFd>(fd: Fd, operation: FlockOperation) -> io::Result<()> {
     backend::fs::syscalls::fcntl_lock(fd.as_fd(), operation)
 }
+
+/// The type of a record lock, for use with [`Flock`].
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FlockType {
+    /// `F_RDLCK`—A shared lock, which requires the file to be open for
+    /// reading.
+    ReadLock,
+    /// `F_WRLCK`—An exclusive lock, which requires the file to be open for
+    /// writing.
+    WriteLock,
+    /// `F_UNLCK`—No lock. This releases any locks held in the range.
+    Unlocked,
+}
+
+/// `struct flock`—A record lock on a range of bytes in a file, for use with
+/// [`fcntl_setlk`], [`fcntl_setlkw`], and [`fcntl_getlk`].
+///
+/// The range starts at the absolute offset `start`, and covers `len` bytes.
+/// If `len` is zero, it extends to the end of the file, including any bytes
+/// later appended to it.
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Flock {
+    /// The type of the lock.
+    pub typ: FlockType,
+
+    /// The offset of the first byte in the range.
+    pub start: u64,
+
+    /// The number of bytes in the range, or zero to extend to the end of
+    /// the file.
+    pub len: u64,
+
+    /// The process holding the lock.
+    ///
+    /// This is only set in locks returned by [`fcntl_getlk`], and is `None`
+    /// for open file description locks. It's ignored on input.
+    pub pid: Option<Pid>,
+}
+
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+impl Flock {
+    /// Construct a new `Flock` of type `typ` covering `len` bytes starting at
+    /// `start`.
+    #[inline]
+    pub const fn new(typ: FlockType, start: u64, len: u64) -> Self {
+        Self {
+            typ,
+            start,
+            len,
+            pid: None,
+        }
+    }
+}
+
+/// `fcntl(fd, F_SETLK, lock)`—Acquire or release a record lock, without
+/// blocking.
+///
+/// If a conflicting lock is held by another process, this fails with
+/// [`io::Errno::AGAIN`] or [`io::Errno::ACCESS`].
+///
+/// Record locks are associated with the process, and with the file rather
+/// than the file descriptor. They aren't inherited by child processes, and
+/// closing *any* file descriptor for the file releases all of the process'
+/// locks on it. On Linux, [`fcntl_ofd_setlk`] avoids these problems.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+/// [`fcntl_ofd_setlk`]: https://docs.rs/rustix/*/x86_64-unknown-linux-gnu/rustix/fs/fn.fcntl_ofd_setlk.html
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+#[inline]
+#[doc(alias = "F_SETLK")]
+pub fn fcntl_setlk<Fd: AsFd>(fd: Fd, lock: &Flock) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_setlk(fd.as_fd(), lock)
+}
+
+/// `fcntl(fd, F_SETLKW, lock)`—Acquire or release a record lock, waiting
+/// for any conflicting locks to be released.
+///
+/// If waiting would deadlock with another process, this fails with
+/// [`io::Errno::DEADLK`].
+///
+/// See [`fcntl_setlk`] for more.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+#[inline]
+#[doc(alias = "F_SETLKW")]
+pub fn fcntl_setlkw<Fd: AsFd>(fd: Fd, lock: &Flock) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_setlkw(fd.as_fd(), lock)
+}
+
+/// `fcntl(fd, F_GETLK, lock)`—Test for a record lock which would conflict
+/// with `lock`.
+///
+/// This returns the first lock held by another process which would prevent
+/// `lock` from being acquired, or `None` if there are none.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "espidf",
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "vita",
+    target_os = "wasi"
+)))]
+#[inline]
+#[doc(alias = "F_GETLK")]
+pub fn fcntl_getlk<Fd: AsFd>(fd: Fd, lock: &Flock) -> io::Result<Option<Flock>> {
+    backend::fs::syscalls::fcntl_getlk(fd.as_fd(), lock)
+}
+
+/// `fcntl(fd, F_OFD_SETLK, lock)`—Acquire or release an open file
+/// description lock, without blocking.
+///
+/// Open file description locks are like the record locks of
+/// [`fcntl_setlk`], except that they're associated with the open file
+/// description rather than the process. So they conflict with locks acquired
+/// through other open file descriptions in the same process, they're shared
+/// by duplicated file descriptors, and they're only released when the last
+/// file descriptor for the open file description is closed.
+///
+/// If a conflicting lock is held, this fails with [`io::Errno::AGAIN`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "F_OFD_SETLK")]
+pub fn fcntl_ofd_setlk<Fd: AsFd>(fd: Fd, lock: &Flock) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_ofd_setlk(fd.as_fd(), lock)
+}
+
+/// `fcntl(fd, F_OFD_SETLKW, lock)`—Acquire or release an open file
+/// description lock, waiting for any conflicting locks to be released.
+///
+/// See [`fcntl_ofd_setlk`] for more.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "F_OFD_SETLKW")]
+pub fn fcntl_ofd_setlkw<Fd: AsFd>(fd: Fd, lock: &Flock) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_ofd_setlkw(fd.as_fd(), lock)
+}
+
+/// `fcntl(fd, F_OFD_GETLK, lock)`—Test for a lock which would conflict with
+/// the open file description lock `lock`.
+///
+/// This returns the first lock, either a record lock or an open file
+/// description lock, which would prevent `lock` from being acquired through
+/// `fd`, or `None` if there are none.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "F_OFD_GETLK")]
+pub fn fcntl_ofd_getlk<Fd: AsFd>(fd: Fd, lock: &Flock) -> io::Result<Option<Flock>> {
+    backend::fs::syscalls::fcntl_ofd_getlk(fd.as_fd(), lock)
+}
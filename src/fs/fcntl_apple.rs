@@ -13,18 +13,44 @@ pub fn fcntl_rdadvise<Fd: AsFd>(fd: Fd, offset: u64, len: u64) -> io::Result<()>
     backend::fs::syscalls::fcntl_rdadvise(fd.as_fd(), offset, len)
 }
 
-/// `fcntl(fd, F_FULLFSYNC)`
+/// `fcntl(fd, F_FULLFSYNC)`—Flush all data in a file, and ask the drive to
+/// flush its own cache to persistent storage.
+///
+/// This is what makes data durable on Apple platforms, where [`fsync`] only
+/// sends the data to the drive. It's also much slower. Not all filesystems
+/// support it; see [`fsync_full`] for a function which falls back to
+/// [`fsync`].
 ///
 /// # References
 ///  - [Apple]
 ///
 /// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/fcntl.2.html
-#[doc(alias = "F_FULLSYNC")]
+/// [`fsync`]: crate::fs::fsync
+/// [`fsync_full`]: crate::fs::fsync_full
+#[doc(alias = "F_FULLFSYNC")]
 #[inline]
 pub fn fcntl_fullfsync<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     backend::fs::syscalls::fcntl_fullfsync(fd.as_fd())
 }
 
+/// `fcntl(fd, F_BARRIERFSYNC)`—Flush all data in a file, with a barrier
+/// ordering it before any later writes.
+///
+/// This is like [`fcntl_fullfsync`], but instead of waiting for the drive to
+/// flush its cache, it only ensures that the drive won't persist later writes
+/// before these. That's enough for write-ahead logs and similar, and it's much
+/// faster.
+///
+/// # References
+///  - [Apple]
+///
+/// [Apple]: https://developer.apple.com/documentation/xcode/reducing-disk-writes#Minimize-explicit-storage-synchronization
+#[doc(alias = "F_BARRIERFSYNC")]
+#[inline]
+pub fn fcntl_barrierfsync<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_barrierfsync(fd.as_fd())
+}
+
 /// `fcntl(fd, F_NOCACHE, value)`—Turn data caching off or on for a file
 /// descriptor.
 ///
@@ -231,14 +231,21 @@ pub fn futimens<Fd: AsFd>(fd: Fd, times: &Timestamps) -> io::Result<()> {
 /// `posix_fallocate` and not the more general form, no `FallocateFlags` values
 /// are defined so it will always be empty.
 ///
+/// With empty `mode`, this never shrinks the file, and extends it to
+/// `offset + len` bytes if it is shorter. On Apple platforms, which don't
+/// have `posix_fallocate`, this is implemented with `fcntl(F_PREALLOCATE)`
+/// followed by `ftruncate`.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux `fallocate`]
 ///  - [Linux `posix_fallocate`]
+///  - [Apple]
 ///
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/posix_fallocate.html
 /// [Linux `fallocate`]: https://man7.org/linux/man-pages/man2/fallocate.2.html
 /// [Linux `posix_fallocate`]: https://man7.org/linux/man-pages/man3/posix_fallocate.3.html
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/fcntl.2.html
 #[cfg(not(any(
     netbsdlike,
     solarish,
@@ -288,7 +295,8 @@ pub(crate) fn _is_file_read_write(fd: BorrowedFd<'_>) -> io::Result<(bool, bool)
 /// underlying storage device.
 ///
 /// On iOS and macOS this isn't sufficient to ensure that data has reached
-/// persistent storage; use [`fcntl_fullfsync`] to ensure that.
+/// persistent storage; use [`fcntl_fullfsync`] or [`fsync_full`] to ensure
+/// that.
 ///
 /// # References
 ///  - [POSIX]
@@ -302,6 +310,28 @@ pub fn fsync<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     backend::fs::syscalls::fsync(fd.as_fd())
 }
 
+/// Ensures that file data and metadata has reached persistent storage, using
+/// the strongest flush available on the platform.
+///
+/// On iOS and macOS, this uses [`fcntl_fullfsync`], falling back to
+/// [`fsync`] on filesystems that don't support it. Elsewhere, `fsync` already
+/// asks the drive to flush its cache, so this is just `fsync`.
+///
+/// [`fcntl_fullfsync`]: https://docs.rs/rustix/*/x86_64-apple-darwin/rustix/fs/fn.fcntl_fullfsync.html
+#[doc(alias = "F_FULLFSYNC")]
+#[inline]
+pub fn fsync_full<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    let fd = fd.as_fd();
+
+    #[cfg(apple)]
+    match backend::fs::syscalls::fcntl_fullfsync(fd) {
+        Err(io::Errno::NOTSUP) | Err(io::Errno::OPNOTSUPP) | Err(io::Errno::INVAL) => {}
+        result => return result,
+    }
+
+    backend::fs::syscalls::fsync(fd)
+}
+
 /// `fdatasync(fd)`—Ensures that file data is written to the underlying
 /// storage device.
 ///
@@ -0,0 +1,188 @@
+//! inotify support for working with inotifies
+
+pub use crate::backend::fs::inotify::{CreateFlags, ReadFlags, WatchFlags};
+use crate::backend::fs::syscalls;
+use crate::fd::{BorrowedFd, OwnedFd};
+use crate::ffi::CStr;
+use crate::io;
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// `inotify_init1(flags)`—Creates a new inotify object.
+///
+/// Use the [`CreateFlags::CLOEXEC`] flag to prevent the resulting file
+/// descriptor from being implicitly passed across `exec` boundaries.
+#[doc(alias = "inotify_init1")]
+#[inline]
+pub fn inotify_init(flags: CreateFlags) -> io::Result<OwnedFd> {
+    syscalls::inotify_init1(flags)
+}
+
+/// `inotify_add_watch(self, path, flags)`—Adds a watch to inotify.
+///
+/// This registers or updates a watch for the filesystem path `path` and
+/// returns a watch descriptor corresponding to this watch.
+///
+/// Note: Due to the existence of hardlinks, providing two different paths to
+/// this method may result in it returning the same watch descriptor. An
+/// application should keep track of this externally to avoid logic errors.
+#[inline]
+pub fn inotify_add_watch<P: crate::path::Arg>(
+    inot: BorrowedFd<'_>,
+    path: P,
+    flags: WatchFlags,
+) -> io::Result<i32> {
+    path.into_with_c_str(|path| syscalls::inotify_add_watch(inot, path, flags))
+}
+
+/// `inotify_rm_watch(self, wd)`—Removes a watch from this inotify.
+///
+/// The watch descriptor provided should have previously been returned by
+/// [`inotify_add_watch`] and not previously have been removed.
+#[doc(alias = "inotify_rm_watch")]
+#[inline]
+pub fn inotify_remove_watch(inot: BorrowedFd<'_>, wd: i32) -> io::Result<()> {
+    syscalls::inotify_rm_watch(inot, wd)
+}
+
+/// The size of the fixed part of `struct inotify_event`: `wd`, `mask`,
+/// `cookie`, and `len`.
+const HEADER_LEN: usize = 16;
+
+/// An event read from an inotify file descriptor, parsed by
+/// [`InotifyEvents`].
+#[doc(alias = "inotify_event")]
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub struct InotifyEvent<'a> {
+    wd: i32,
+    events: ReadFlags,
+    cookie: u32,
+    file_name: Option<&'a CStr>,
+}
+
+impl<'a> InotifyEvent<'a> {
+    /// Returns the watch descriptor this event is for, as returned by
+    /// [`inotify_add_watch`].
+    #[inline]
+    pub fn wd(&self) -> i32 {
+        self.wd
+    }
+
+    /// Returns a description of the events.
+    #[inline]
+    #[doc(alias = "mask")]
+    pub fn events(&self) -> ReadFlags {
+        self.events
+    }
+
+    /// Returns the unique cookie associating related events, such as the
+    /// [`ReadFlags::MOVED_FROM`] and [`ReadFlags::MOVED_TO`] events of a
+    /// rename.
+    #[inline]
+    pub fn cookie(&self) -> u32 {
+        self.cookie
+    }
+
+    /// Returns the name of the file, for events on files inside a watched
+    /// directory.
+    #[inline]
+    #[doc(alias = "name")]
+    pub fn file_name(&self) -> Option<&'a CStr> {
+        self.file_name
+    }
+}
+
+impl<'a> fmt::Debug for InotifyEvent<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InotifyEvent")
+            .field("wd", &self.wd)
+            .field("events", &self.events)
+            .field("cookie", &self.cookie)
+            .field("file_name", &self.file_name)
+            .finish()
+    }
+}
+
+/// An iterator over the `struct inotify_event` records in a buffer filled by
+/// reading from an inotify file descriptor.
+///
+/// The buffer doesn't need any particular alignment. Iteration stops at the
+/// first record which doesn't fit in the buffer, which the kernel never
+/// produces.
+///
+/// # Examples
+///
+/// ```
+/// # use rustix::fs::inotify::{self, InotifyEvents};
+/// # fn example(inot: rustix::fd::BorrowedFd<'_>) -> rustix::io::Result<()> {
+/// let mut buf = [0_u8; 4096];
+/// let len = rustix::io::read(inot, &mut buf)?;
+/// for event in InotifyEvents::new(&buf[..len]) {
+///     println!("{:?} {:?}", event.events(), event.file_name());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/inotify.7.html
+#[derive(Clone)]
+pub struct InotifyEvents<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> InotifyEvents<'a> {
+    /// Constructs a new `InotifyEvents` over the bytes read from an inotify
+    /// file descriptor.
+    #[inline]
+    pub const fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'a> Iterator for InotifyEvents<'a> {
+    type Item = InotifyEvent<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let field = |index: usize| {
+            let bytes = self.buf.get(index * 4..index * 4 + 4)?;
+            Some(u32::from_ne_bytes(bytes.try_into().unwrap()))
+        };
+        let wd = field(0)? as i32;
+        let mask = field(1)?;
+        let cookie = field(2)?;
+        let len = field(3)? as usize;
+
+        let name = match self.buf.get(HEADER_LEN..HEADER_LEN + len) {
+            Some(name) => name,
+            None => {
+                self.buf = &[];
+                return None;
+            }
+        };
+        self.buf = &self.buf[HEADER_LEN + len..];
+
+        // The name is NUL-terminated, and padded with more NULs.
+        let file_name = match name.iter().position(|b| *b == b'\0') {
+            Some(0) | None => None,
+            Some(end) => Some(CStr::from_bytes_with_nul(&name[..=end]).unwrap()),
+        };
+
+        Some(InotifyEvent {
+            wd,
+            events: ReadFlags::from_bits_retain(mask),
+            cookie,
+            file_name,
+        })
+    }
+}
+
+impl FusedIterator for InotifyEvents<'_> {}
+
+impl fmt::Debug for InotifyEvents<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
@@ -12,7 +12,15 @@ use {
 use bitflags::bitflags;
 
 #[cfg(all(linux_kernel, not(any(target_arch = "sparc", target_arch = "sparc64"))))]
-use crate::fd::{AsRawFd, BorrowedFd};
+use crate::fd::AsRawFd;
+#[cfg(all(
+    linux_kernel,
+    any(
+        feature = "alloc",
+        not(any(target_arch = "sparc", target_arch = "sparc64"))
+    )
+))]
+use crate::fd::BorrowedFd;
 
 /// `ioctl(fd, BLKSSZGET)`—Returns the logical block size of a block device.
 ///
@@ -163,3 +171,256 @@ pub fn ioctl_setflags<Fd: AsFd>(fd: Fd, flags: IFlags) -> io::Result<()> {
         ioctl::ioctl(fd, ctl)
     }
 }
+
+#[cfg(linux_kernel)]
+bitflags! {
+    /// `FIEMAP_FLAG_*` constants for use with [`ioctl_fiemap`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct FiemapFlags: u32 {
+        /// `FIEMAP_FLAG_SYNC`—Sync the file before mapping it.
+        const SYNC = linux_raw_sys::ioctl::FIEMAP_FLAG_SYNC;
+        /// `FIEMAP_FLAG_XATTR`—Map the extended attribute tree instead of
+        /// the file data.
+        const XATTR = linux_raw_sys::ioctl::FIEMAP_FLAG_XATTR;
+        /// `FIEMAP_FLAG_CACHE`—Request caching of the extents.
+        const CACHE = linux_raw_sys::ioctl::FIEMAP_FLAG_CACHE;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+#[cfg(linux_kernel)]
+bitflags! {
+    /// `FIEMAP_EXTENT_*` constants describing a [`FiemapExtent`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct FiemapExtentFlags: u32 {
+        /// `FIEMAP_EXTENT_LAST`—This is the last extent in the file.
+        const LAST = linux_raw_sys::ioctl::FIEMAP_EXTENT_LAST;
+        /// `FIEMAP_EXTENT_UNKNOWN`—The location of the data is unknown.
+        const UNKNOWN = linux_raw_sys::ioctl::FIEMAP_EXTENT_UNKNOWN;
+        /// `FIEMAP_EXTENT_DELALLOC`—The data is pending delayed allocation,
+        /// which also sets `UNKNOWN`.
+        const DELALLOC = linux_raw_sys::ioctl::FIEMAP_EXTENT_DELALLOC;
+        /// `FIEMAP_EXTENT_ENCODED`—The data is compressed or otherwise
+        /// encoded, so the physical length may differ.
+        const ENCODED = linux_raw_sys::ioctl::FIEMAP_EXTENT_ENCODED;
+        /// `FIEMAP_EXTENT_DATA_ENCRYPTED`—The data is encrypted, which also
+        /// sets `ENCODED`.
+        const DATA_ENCRYPTED = linux_raw_sys::ioctl::FIEMAP_EXTENT_DATA_ENCRYPTED;
+        /// `FIEMAP_EXTENT_NOT_ALIGNED`—The extent isn't block-aligned.
+        const NOT_ALIGNED = linux_raw_sys::ioctl::FIEMAP_EXTENT_NOT_ALIGNED;
+        /// `FIEMAP_EXTENT_DATA_INLINE`—The data is stored in a metadata
+        /// block, which also sets `NOT_ALIGNED`.
+        const DATA_INLINE = linux_raw_sys::ioctl::FIEMAP_EXTENT_DATA_INLINE;
+        /// `FIEMAP_EXTENT_DATA_TAIL`—The data is packed with other files'
+        /// tails, which also sets `NOT_ALIGNED`.
+        const DATA_TAIL = linux_raw_sys::ioctl::FIEMAP_EXTENT_DATA_TAIL;
+        /// `FIEMAP_EXTENT_UNWRITTEN`—The space is allocated but not yet
+        /// written, so it reads as zeros.
+        const UNWRITTEN = linux_raw_sys::ioctl::FIEMAP_EXTENT_UNWRITTEN;
+        /// `FIEMAP_EXTENT_MERGED`—The filesystem doesn't track extents, so
+        /// this is a run of contiguous blocks.
+        const MERGED = linux_raw_sys::ioctl::FIEMAP_EXTENT_MERGED;
+        /// `FIEMAP_EXTENT_SHARED`—The space may be shared with other files.
+        const SHARED = linux_raw_sys::ioctl::FIEMAP_EXTENT_SHARED;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// A `struct fiemap_extent`, describing where a range of a file's data is
+/// stored, as returned by [`ioctl_fiemap`].
+#[cfg(linux_kernel)]
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct FiemapExtent {
+    fe_logical: u64,
+    fe_physical: u64,
+    fe_length: u64,
+    fe_reserved64: [u64; 2],
+    fe_flags: u32,
+    fe_reserved: [u32; 3],
+}
+
+#[cfg(linux_kernel)]
+impl FiemapExtent {
+    /// The byte offset of the extent within the file.
+    #[inline]
+    pub const fn logical(&self) -> u64 {
+        self.fe_logical
+    }
+
+    /// The byte offset of the extent on the device.
+    ///
+    /// This isn't meaningful if [`FiemapExtentFlags::UNKNOWN`] is set.
+    #[inline]
+    pub const fn physical(&self) -> u64 {
+        self.fe_physical
+    }
+
+    /// The length of the extent, in bytes.
+    #[inline]
+    pub const fn length(&self) -> u64 {
+        self.fe_length
+    }
+
+    /// The flags describing the extent.
+    #[inline]
+    pub const fn flags(&self) -> FiemapExtentFlags {
+        FiemapExtentFlags::from_bits_retain(self.fe_flags)
+    }
+}
+
+/// The header of a `struct fiemap`, which is followed by its extents.
+#[cfg(all(linux_kernel, feature = "alloc"))]
+#[repr(C)]
+struct FiemapHeader {
+    fm_start: u64,
+    fm_length: u64,
+    fm_flags: u32,
+    fm_mapped_extents: u32,
+    fm_extent_count: u32,
+    fm_reserved: u32,
+}
+
+/// `ioctl(fd, FS_IOC_FIEMAP)`—Returns the extents mapping the byte range
+/// `start..start + length` of a file to the underlying device.
+///
+/// `extents` is cleared and filled with the extents overlapping the range,
+/// growing it as needed. Pass `u64::MAX` as the `length` to map the whole
+/// file from `start`. Holes in the file have no extents.
+///
+/// Fails with [`io::Errno::OPNOTSUPP`] on filesystems which don't support
+/// `FIEMAP`, and with [`io::Errno::BADR`] if `flags` contains flags the
+/// filesystem doesn't support.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/filesystems/fiemap.html
+#[cfg(all(linux_kernel, feature = "alloc"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[doc(alias = "FS_IOC_FIEMAP")]
+pub fn ioctl_fiemap<Fd: AsFd>(
+    fd: Fd,
+    start: u64,
+    length: u64,
+    flags: FiemapFlags,
+    extents: &mut alloc::vec::Vec<FiemapExtent>,
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    let end = start.saturating_add(length);
+    extents.clear();
+
+    // Ask how many extents there are, so that we can usually get them all in
+    // one more call.
+    let mut buf = FiemapBuf::new(0);
+    let count = buf.map(fd, start, end, flags)?.fm_mapped_extents as usize;
+    if count == 0 {
+        return Ok(());
+    }
+    let mut buf = FiemapBuf::new(count);
+
+    // The file may have changed since, so keep going until the kernel says
+    // it has no more extents.
+    let mut pos = start;
+    loop {
+        let mapped = buf.map(fd, pos, end, flags)?.fm_mapped_extents as usize;
+        extents.extend_from_slice(buf.extents(mapped));
+
+        let last = match extents.last() {
+            Some(last) if mapped == count => last,
+            _ => return Ok(()),
+        };
+        if last.flags().contains(FiemapExtentFlags::LAST) {
+            return Ok(());
+        }
+        pos = last.logical().saturating_add(last.length());
+        if pos >= end {
+            return Ok(());
+        }
+    }
+}
+
+/// A buffer holding a `struct fiemap` with room for a number of extents.
+#[cfg(all(linux_kernel, feature = "alloc"))]
+struct FiemapBuf {
+    // Use `u64` for the alignment of the header and extents.
+    words: alloc::vec::Vec<u64>,
+    count: usize,
+}
+
+#[cfg(all(linux_kernel, feature = "alloc"))]
+impl FiemapBuf {
+    const HEADER_WORDS: usize = core::mem::size_of::<FiemapHeader>() / 8;
+    const EXTENT_WORDS: usize = core::mem::size_of::<FiemapExtent>() / 8;
+
+    fn new(count: usize) -> Self {
+        Self {
+            words: alloc::vec![0; Self::HEADER_WORDS + Self::EXTENT_WORDS * count],
+            count,
+        }
+    }
+
+    /// Perform the `ioctl` for `pos..end`, and return the resulting header.
+    fn map(
+        &mut self,
+        fd: BorrowedFd<'_>,
+        pos: u64,
+        end: u64,
+        flags: FiemapFlags,
+    ) -> io::Result<&FiemapHeader> {
+        let header = FiemapHeader {
+            fm_start: pos,
+            fm_length: end - pos,
+            fm_flags: flags.bits(),
+            fm_mapped_extents: 0,
+            fm_extent_count: self.count as u32,
+            fm_reserved: 0,
+        };
+        // SAFETY: The buffer starts with a header, is suitably aligned, and
+        // has room for `fm_extent_count` extents after it.
+        unsafe {
+            self.words.as_mut_ptr().cast::<FiemapHeader>().write(header);
+            ioctl::ioctl(fd, Fiemap(self.words.as_mut_ptr().cast()))?;
+            Ok(&*self.words.as_ptr().cast::<FiemapHeader>())
+        }
+    }
+
+    /// Return the first `mapped` extents.
+    fn extents(&self, mapped: usize) -> &[FiemapExtent] {
+        let mapped = mapped.min(self.count);
+        // SAFETY: The extents follow the header, and are initialized, either
+        // to zero or by the kernel.
+        unsafe {
+            core::slice::from_raw_parts(self.words.as_ptr().add(Self::HEADER_WORDS).cast(), mapped)
+        }
+    }
+}
+
+#[cfg(all(linux_kernel, feature = "alloc"))]
+struct Fiemap(*mut c::c_void);
+
+#[cfg(all(linux_kernel, feature = "alloc"))]
+unsafe impl ioctl::Ioctl for Fiemap {
+    type Output = ();
+
+    const IS_MUTATING: bool = true;
+    const OPCODE: ioctl::Opcode =
+        ioctl::Opcode::old(linux_raw_sys::ioctl::FS_IOC_FIEMAP as ioctl::RawOpcode);
+
+    fn as_ptr(&mut self) -> *mut c::c_void {
+        self.0
+    }
+
+    unsafe fn output_from_ptr(
+        _: ioctl::IoctlOutput,
+        _: *mut c::c_void,
+    ) -> io::Result<Self::Output> {
+        Ok(())
+    }
+}
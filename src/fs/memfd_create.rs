@@ -2,7 +2,26 @@ use crate::fd::OwnedFd;
 use crate::{backend, io, path};
 use backend::fs::types::MemfdFlags;
 
-/// `memfd_create(name, flags)`
+/// `memfd_create(name, flags)`—Create an anonymous file.
+///
+/// The file lives in memory and behaves like a regular file. `name` is used
+/// only for debugging, and appears as the target of the `/proc/self/fd`
+/// symlink. With [`MemfdFlags::ALLOW_SEALING`], seals may be added with
+/// [`fcntl_add_seals`], for example to share a buffer with another process
+/// which can then rely on its contents not changing.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn example() -> rustix::io::Result<()> {
+/// use rustix::fs::{fcntl_add_seals, ftruncate, memfd_create, MemfdFlags, SealFlags};
+///
+/// let fd = memfd_create("buffer", MemfdFlags::CLOEXEC | MemfdFlags::ALLOW_SEALING)?;
+/// ftruncate(&fd, 4096)?;
+/// fcntl_add_seals(&fd, SealFlags::SHRINK | SealFlags::GROW | SealFlags::SEAL)?;
+/// # Ok(())
+/// # }
+/// ```
 ///
 /// # References
 ///  - [Linux]
@@ -12,6 +31,7 @@ use backend::fs::types::MemfdFlags;
 /// [Linux]: https://man7.org/linux/man-pages/man2/memfd_create.2.html
 /// [glibc]: https://www.gnu.org/software/libc/manual/html_node/Memory_002dmapped-I_002fO.html#index-memfd_005fcreate
 /// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?memfd_create
+/// [`fcntl_add_seals`]: crate::fs::fcntl_add_seals
 #[inline]
 pub fn memfd_create<P: path::Arg>(name: P, flags: MemfdFlags) -> io::Result<OwnedFd> {
     name.into_with_c_str(|name| backend::fs::syscalls::memfd_create(name, flags))
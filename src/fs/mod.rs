@@ -5,6 +5,8 @@ mod abs;
 mod at;
 mod constants;
 #[cfg(linux_kernel)]
+mod copy;
+#[cfg(any(linux_kernel, target_os = "freebsd"))]
 mod copy_file_range;
 #[cfg(not(any(target_os = "espidf", target_os = "redox")))]
 #[cfg(not(target_os = "haiku"))] // Haiku needs <https://github.com/rust-lang/rust/pull/112371>
@@ -33,6 +35,8 @@ mod getpath;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
 #[cfg(linux_kernel)]
+pub mod inotify;
+#[cfg(linux_kernel)]
 mod ioctl;
 #[cfg(not(any(
     target_os = "espidf",
@@ -44,15 +48,28 @@ mod ioctl;
 mod makedev;
 #[cfg(any(linux_kernel, target_os = "freebsd"))]
 mod memfd_create;
+#[cfg(not(any(target_os = "espidf", target_os = "vita", target_os = "wasi")))]
+mod mode;
 #[cfg(linux_kernel)]
 #[cfg(feature = "fs")]
 mod mount;
+#[cfg(not(target_os = "redox"))]
+mod open_options;
 #[cfg(linux_kernel)]
 mod openat2;
 #[cfg(linux_kernel)]
 mod raw_dir;
+#[cfg(any(
+    apple,
+    all(linux_kernel, not(any(target_arch = "sparc", target_arch = "sparc64")))
+))]
+mod reflink;
+#[cfg(all(feature = "alloc", any(linux_kernel, bsd)))]
+mod remove;
+#[cfg(any(all(linux_kernel, feature = "procfs"), target_os = "freebsd"))]
+mod reopen;
 mod seek_from;
-#[cfg(target_os = "linux")]
+#[cfg(any(apple, freebsdlike, target_os = "linux"))]
 mod sendfile;
 #[cfg(linux_kernel)]
 mod statx;
@@ -63,16 +80,20 @@ mod statx;
     target_os = "wasi"
 )))]
 mod sync;
+#[cfg(all(linux_kernel, feature = "rand"))]
+mod temp;
+#[cfg(all(feature = "alloc", any(linux_kernel, bsd)))]
+mod walk;
 #[cfg(any(apple, linux_kernel))]
 mod xattr;
 
-#[cfg(linux_kernel)]
-pub use crate::backend::fs::inotify;
 pub use abs::*;
 #[cfg(not(target_os = "redox"))]
 pub use at::*;
 pub use constants::*;
 #[cfg(linux_kernel)]
+pub use copy::copy_fd_contents;
+#[cfg(any(linux_kernel, target_os = "freebsd"))]
 pub use copy_file_range::copy_file_range;
 #[cfg(not(any(target_os = "espidf", target_os = "redox")))]
 #[cfg(not(target_os = "haiku"))] // Haiku needs <https://github.com/rust-lang/rust/pull/112371>
@@ -112,18 +133,31 @@ pub use ioctl::*;
 pub use makedev::*;
 #[cfg(any(linux_kernel, target_os = "freebsd"))]
 pub use memfd_create::memfd_create;
+#[cfg(not(any(target_os = "espidf", target_os = "vita", target_os = "wasi")))]
+pub use mode::SymbolicMode;
 #[cfg(linux_kernel)]
 #[cfg(feature = "fs")]
 pub use mount::*;
+#[cfg(not(target_os = "redox"))]
+pub use open_options::OpenOptionsAt;
 #[cfg(linux_kernel)]
 pub use openat2::openat2;
 #[cfg(linux_kernel)]
 pub use raw_dir::{RawDir, RawDirEntry};
+#[cfg(any(
+    apple,
+    all(linux_kernel, not(any(target_arch = "sparc", target_arch = "sparc64")))
+))]
+pub use reflink::reflinkat;
+#[cfg(all(feature = "alloc", any(linux_kernel, bsd)))]
+pub use remove::remove_dir_all_at;
+#[cfg(any(all(linux_kernel, feature = "procfs"), target_os = "freebsd"))]
+pub use reopen::reopen;
 pub use seek_from::SeekFrom;
-#[cfg(target_os = "linux")]
+#[cfg(any(apple, freebsdlike, target_os = "linux"))]
 pub use sendfile::sendfile;
 #[cfg(linux_kernel)]
-pub use statx::statx;
+pub use statx::{direct_io_alignment, statx, DirectIoAlignment};
 #[cfg(not(any(
     target_os = "espidf",
     target_os = "redox",
@@ -131,6 +165,10 @@ pub use statx::statx;
     target_os = "wasi"
 )))]
 pub use sync::sync;
+#[cfg(all(linux_kernel, feature = "rand"))]
+pub use temp::{create_temp_dir_in, create_temp_file_in, create_unnamed_temp_file_in, TempName};
+#[cfg(all(feature = "alloc", any(linux_kernel, bsd)))]
+pub use walk::{WalkEntry, Walker};
 #[cfg(any(apple, linux_kernel))]
 pub use xattr::*;
 
@@ -142,7 +180,55 @@ pub use std::os::unix::fs::{DirEntryExt, FileExt, FileTypeExt, MetadataExt, Open
 #[cfg(all(wasi_ext, target_os = "wasi"))]
 pub use std::os::wasi::fs::{DirEntryExt, FileExt, FileTypeExt, MetadataExt, OpenOptionsExt};
 
-/// Extension trait for accessing timestamp fields of `Stat`.
+/// `dev_t`—The ID of the device containing a file, from the `st_dev` field
+/// of a `Stat`.
+///
+/// Use [`major`] and [`minor`] on [`DeviceId::as_raw`] to decompose it.
+#[cfg(all(unix, not(any(target_os = "aix", target_os = "nto"))))]
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct DeviceId(Dev);
+
+#[cfg(all(unix, not(any(target_os = "aix", target_os = "nto"))))]
+impl DeviceId {
+    /// Converts a `Dev` into a `DeviceId`.
+    #[inline]
+    pub const fn from_raw(raw: Dev) -> Self {
+        Self(raw)
+    }
+
+    /// Converts a `DeviceId` into a `Dev`.
+    #[inline]
+    pub const fn as_raw(self) -> Dev {
+        self.0
+    }
+}
+
+/// `ino_t`—The number of a file within its device, from the `st_ino` field
+/// of a `Stat`.
+///
+/// Together with a [`DeviceId`], this uniquely identifies a file.
+#[cfg(all(unix, not(any(target_os = "aix", target_os = "nto"))))]
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct Inode(u64);
+
+#[cfg(all(unix, not(any(target_os = "aix", target_os = "nto"))))]
+impl Inode {
+    /// Converts a `u64` into an `Inode`.
+    #[inline]
+    pub const fn from_raw(raw: u64) -> Self {
+        Self(raw)
+    }
+
+    /// Converts an `Inode` into a `u64`.
+    #[inline]
+    pub const fn as_raw(self) -> u64 {
+        self.0
+    }
+}
+
+/// Extension trait for accessing fields of `Stat` portably.
 ///
 /// Rustix's `Stat` type on some platforms has unsigned `st_mtime`,
 /// `st_atime`, and `st_ctime` fields. This is incorrect, as Unix defines
@@ -150,14 +236,51 @@ pub use std::os::wasi::fs::{DirEntryExt, FileExt, FileTypeExt, MetadataExt, Open
 /// the Unix epoch. Until the next semver bump, these unsigned fields are
 /// deprecated, and this trait provides accessors which return their values
 /// as signed integers.
+///
+/// The field names and widths of `Stat` also differ between platforms, so
+/// this trait provides typed accessors for the commonly used fields.
+///
+/// This trait is sealed, so that accessors can be added to it without
+/// breaking implementations outside of rustix.
 #[cfg(all(unix, not(any(target_os = "aix", target_os = "nto"))))]
-pub trait StatExt {
+pub trait StatExt: private::Sealed {
     /// Return the value of the `st_atime` field, casted to the correct type.
     fn atime(&self) -> i64;
     /// Return the value of the `st_mtime` field, casted to the correct type.
     fn mtime(&self) -> i64;
     /// Return the value of the `st_ctime` field, casted to the correct type.
     fn ctime(&self) -> i64;
+
+    /// Return the last access time, as a `Timespec`.
+    fn accessed(&self) -> Timespec;
+    /// Return the last modification time, as a `Timespec`.
+    fn modified(&self) -> Timespec;
+    /// Return the last status change time, as a `Timespec`.
+    fn status_changed(&self) -> Timespec;
+    /// Return the creation time, as a `Timespec`, on platforms which record
+    /// it in `Stat`.
+    ///
+    /// On Linux, use `statx` to query the creation time.
+    fn created(&self) -> Option<Timespec>;
+
+    /// Return the file type, decoded from the `st_mode` field.
+    fn file_type(&self) -> FileType;
+    /// Return the permission bits, decoded from the `st_mode` field.
+    fn mode(&self) -> Mode;
+    /// Return the value of the `st_size` field.
+    fn size(&self) -> u64;
+    /// Return the value of the `st_blocks` field, in 512-byte units.
+    fn blocks(&self) -> u64;
+    /// Return the value of the `st_nlink` field.
+    fn nlink(&self) -> u64;
+    /// Return the device containing the file, from the `st_dev` field.
+    fn dev(&self) -> DeviceId;
+    /// Return the file's inode number, from the `st_ino` field.
+    fn ino(&self) -> Inode;
+    /// Return the owning user, from the `st_uid` field.
+    fn uid(&self) -> Uid;
+    /// Return the owning group, from the `st_gid` field.
+    fn gid(&self) -> Gid;
 }
 
 #[cfg(all(unix, not(any(target_os = "aix", target_os = "nto"))))]
@@ -177,4 +300,141 @@ impl StatExt for Stat {
     fn ctime(&self) -> i64 {
         self.st_ctime as i64
     }
+
+    #[cfg(not(target_os = "netbsd"))]
+    #[inline]
+    fn accessed(&self) -> Timespec {
+        Timespec {
+            tv_sec: self.atime(),
+            tv_nsec: self.st_atime_nsec as _,
+        }
+    }
+
+    #[cfg(target_os = "netbsd")]
+    #[inline]
+    fn accessed(&self) -> Timespec {
+        Timespec {
+            tv_sec: self.atime(),
+            tv_nsec: self.st_atimensec as _,
+        }
+    }
+
+    #[cfg(not(target_os = "netbsd"))]
+    #[inline]
+    fn modified(&self) -> Timespec {
+        Timespec {
+            tv_sec: self.mtime(),
+            tv_nsec: self.st_mtime_nsec as _,
+        }
+    }
+
+    #[cfg(target_os = "netbsd")]
+    #[inline]
+    fn modified(&self) -> Timespec {
+        Timespec {
+            tv_sec: self.mtime(),
+            tv_nsec: self.st_mtimensec as _,
+        }
+    }
+
+    #[cfg(not(target_os = "netbsd"))]
+    #[inline]
+    fn status_changed(&self) -> Timespec {
+        Timespec {
+            tv_sec: self.ctime(),
+            tv_nsec: self.st_ctime_nsec as _,
+        }
+    }
+
+    #[cfg(target_os = "netbsd")]
+    #[inline]
+    fn status_changed(&self) -> Timespec {
+        Timespec {
+            tv_sec: self.ctime(),
+            tv_nsec: self.st_ctimensec as _,
+        }
+    }
+
+    #[cfg(any(apple, target_os = "freebsd"))]
+    #[inline]
+    fn created(&self) -> Option<Timespec> {
+        Some(Timespec {
+            tv_sec: self.st_birthtime as i64,
+            tv_nsec: self.st_birthtime_nsec as _,
+        })
+    }
+
+    #[cfg(target_os = "netbsd")]
+    #[inline]
+    fn created(&self) -> Option<Timespec> {
+        Some(Timespec {
+            tv_sec: self.st_birthtime as i64,
+            tv_nsec: self.st_birthtimensec as _,
+        })
+    }
+
+    #[cfg(not(any(apple, target_os = "freebsd", target_os = "netbsd")))]
+    #[inline]
+    fn created(&self) -> Option<Timespec> {
+        None
+    }
+
+    #[inline]
+    fn file_type(&self) -> FileType {
+        FileType::from_raw_mode(self.st_mode as RawMode)
+    }
+
+    #[inline]
+    fn mode(&self) -> Mode {
+        let st_mode = self.st_mode as RawMode;
+
+        // Clear the file-type bits, leaving just the permission bits.
+        Mode::from_raw_mode(st_mode & !FileType::from_raw_mode(st_mode).as_raw_mode())
+    }
+
+    #[inline]
+    fn size(&self) -> u64 {
+        self.st_size as u64
+    }
+
+    #[inline]
+    fn blocks(&self) -> u64 {
+        self.st_blocks as u64
+    }
+
+    #[inline]
+    fn nlink(&self) -> u64 {
+        self.st_nlink as u64
+    }
+
+    #[inline]
+    fn dev(&self) -> DeviceId {
+        DeviceId(self.st_dev as Dev)
+    }
+
+    #[inline]
+    fn ino(&self) -> Inode {
+        Inode(self.st_ino as u64)
+    }
+
+    #[allow(unsafe_code)]
+    #[inline]
+    fn uid(&self) -> Uid {
+        // SAFETY: The kernel filled in `st_uid` with a valid user ID.
+        unsafe { Uid::from_raw(self.st_uid as _) }
+    }
+
+    #[allow(unsafe_code)]
+    #[inline]
+    fn gid(&self) -> Gid {
+        // SAFETY: The kernel filled in `st_gid` with a valid group ID.
+        unsafe { Gid::from_raw(self.st_gid as _) }
+    }
+}
+
+#[cfg(all(unix, not(any(target_os = "aix", target_os = "nto"))))]
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Stat {}
 }
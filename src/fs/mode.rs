@@ -0,0 +1,147 @@
+//! Helpers for decomposing and rendering `Mode` and `FileType` values.
+
+use crate::fs::{FileType, Mode};
+use core::fmt;
+
+impl Mode {
+    /// Return just the permission bits of this `Mode`, including the
+    /// setuid, setgid, and sticky bits, with any file-type bits cleared.
+    #[inline]
+    pub const fn permissions(self) -> Self {
+        Self::from_bits_retain(
+            self.bits()
+                & (Self::RWXU.bits()
+                    | Self::RWXG.bits()
+                    | Self::RWXO.bits()
+                    | Self::SUID.bits()
+                    | Self::SGID.bits()
+                    | Self::SVTX.bits()),
+        )
+    }
+
+    /// Test whether the setuid bit, `S_ISUID`, is set.
+    #[inline]
+    pub const fn is_setuid(self) -> bool {
+        self.bits() & Self::SUID.bits() != 0
+    }
+
+    /// Test whether the setgid bit, `S_ISGID`, is set.
+    #[inline]
+    pub const fn is_setgid(self) -> bool {
+        self.bits() & Self::SGID.bits() != 0
+    }
+
+    /// Test whether the sticky bit, `S_ISVTX`, is set.
+    #[inline]
+    pub const fn is_sticky(self) -> bool {
+        self.bits() & Self::SVTX.bits() != 0
+    }
+
+    /// Render the permission bits of this `Mode` in the symbolic form used
+    /// by `ls -l`, such as `rwxr-x---`.
+    ///
+    /// The setuid, setgid, and sticky bits are rendered as `s`, `s`, and `t`
+    /// in the corresponding execute position, or as `S`, `S`, and `T` if the
+    /// execute bit is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustix::fs::Mode;
+    ///
+    /// assert_eq!(Mode::from(0o750).symbolic().as_str(), "rwxr-x---");
+    /// assert_eq!(Mode::from(0o4755).symbolic().as_str(), "rwsr-xr-x");
+    /// assert_eq!(Mode::from(0o1777).symbolic().to_string(), "rwxrwxrwt");
+    /// ```
+    pub const fn symbolic(self) -> SymbolicMode {
+        const fn triple(
+            mode: Mode,
+            r: Mode,
+            w: Mode,
+            x: Mode,
+            special: Mode,
+            special_char: u8,
+        ) -> [u8; 3] {
+            [
+                if mode.bits() & r.bits() != 0 {
+                    b'r'
+                } else {
+                    b'-'
+                },
+                if mode.bits() & w.bits() != 0 {
+                    b'w'
+                } else {
+                    b'-'
+                },
+                match (
+                    mode.bits() & x.bits() != 0,
+                    mode.bits() & special.bits() != 0,
+                ) {
+                    (false, false) => b'-',
+                    (true, false) => b'x',
+                    (false, true) => special_char.to_ascii_uppercase(),
+                    (true, true) => special_char,
+                },
+            ]
+        }
+
+        let u = triple(self, Self::RUSR, Self::WUSR, Self::XUSR, Self::SUID, b's');
+        let g = triple(self, Self::RGRP, Self::WGRP, Self::XGRP, Self::SGID, b's');
+        let o = triple(self, Self::ROTH, Self::WOTH, Self::XOTH, Self::SVTX, b't');
+        SymbolicMode([u[0], u[1], u[2], g[0], g[1], g[2], o[0], o[1], o[2]])
+    }
+}
+
+/// The symbolic rendering of a [`Mode`], as returned by [`Mode::symbolic`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SymbolicMode([u8; 9]);
+
+impl SymbolicMode {
+    /// Return the symbolic rendering as a string, such as `rwxr-x---`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // The contents are always ASCII.
+        core::str::from_utf8(&self.0).unwrap()
+    }
+}
+
+impl fmt::Display for SymbolicMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for SymbolicMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl FileType {
+    /// Return the character `ls -l` uses for this file type, such as `-` for
+    /// a regular file or `d` for a directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustix::fs::{FileType, Mode};
+    ///
+    /// let st_mode = 0o040755;
+    /// let file_type = FileType::from_raw_mode(st_mode);
+    /// let mode = Mode::from_raw_mode(st_mode).permissions();
+    /// assert_eq!(format!("{}{}", file_type.as_symbol(), mode.symbolic()), "drwxr-xr-x");
+    /// ```
+    #[inline]
+    pub const fn as_symbol(self) -> char {
+        match self {
+            Self::RegularFile => '-',
+            Self::Directory => 'd',
+            Self::Symlink => 'l',
+            Self::Fifo => 'p',
+            Self::Socket => 's',
+            Self::CharacterDevice => 'c',
+            Self::BlockDevice => 'b',
+            Self::Unknown => '?',
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! A builder for opening files relative to a directory.
+
+use crate::fd::{AsFd, OwnedFd};
+use crate::fs::{openat, Mode, OFlags};
+#[cfg(linux_kernel)]
+use crate::fs::{openat2, ResolveFlags};
+use crate::{io, path};
+
+/// Options for opening a file relative to a directory, in the style of
+/// `std::fs::OpenOptions`.
+///
+/// This accumulates [`OFlags`], a [`Mode`], and, on Linux, [`ResolveFlags`],
+/// and computes the access mode and creation flags from them when the file
+/// is opened. [`OFlags::CLOEXEC`] is set by default.
+///
+/// When any `ResolveFlags` are set, the file is opened with [`openat2`],
+/// which fails with [`io::Errno::NOSYS`] on kernels which lack it, rather
+/// than falling back to opening the file without the resolution
+/// restrictions. Otherwise it's opened with [`openat`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> rustix::io::Result<()> {
+/// use rustix::fs::{OpenOptionsAt, CWD};
+///
+/// // Open "data/log.txt" for appending, creating it if it doesn't exist.
+/// let file = OpenOptionsAt::new()
+///     .append(true)
+///     .create(true)
+///     .open(CWD, "data/log.txt")?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// On Linux, open a file beneath a directory without following any
+/// symlinks:
+///
+/// ```no_run
+/// # #[cfg(linux_kernel)]
+/// # fn main() -> rustix::io::Result<()> {
+/// use rustix::fs::{OpenOptionsAt, ResolveFlags, CWD};
+///
+/// let file = OpenOptionsAt::new()
+///     .read(true)
+///     .resolve(ResolveFlags::BENEATH | ResolveFlags::NO_SYMLINKS)
+///     .open(CWD, "config.toml")?;
+/// # Ok(())
+/// # }
+/// # #[cfg(not(linux_kernel))]
+/// # fn main() {}
+/// ```
+///
+/// [`openat2`]: crate::fs::openat2
+#[derive(Debug, Clone)]
+pub struct OpenOptionsAt {
+    read: bool,
+    write: bool,
+    append: bool,
+    truncate: bool,
+    create: bool,
+    create_new: bool,
+    custom_flags: OFlags,
+    mode: Mode,
+    #[cfg(linux_kernel)]
+    resolve: ResolveFlags,
+}
+
+impl OpenOptionsAt {
+    /// Create a new set of options, with nothing enabled except
+    /// [`OFlags::CLOEXEC`], and a creation mode of `0o666`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            read: false,
+            write: false,
+            append: false,
+            truncate: false,
+            create: false,
+            create_new: false,
+            custom_flags: OFlags::CLOEXEC,
+            mode: Mode::from_bits_retain(0o666),
+            #[cfg(linux_kernel)]
+            resolve: ResolveFlags::empty(),
+        }
+    }
+
+    /// Set whether the file should be readable.
+    #[inline]
+    pub fn read(&mut self, read: bool) -> &mut Self {
+        self.read = read;
+        self
+    }
+
+    /// Set whether the file should be writable.
+    #[inline]
+    pub fn write(&mut self, write: bool) -> &mut Self {
+        self.write = write;
+        self
+    }
+
+    /// Set whether writes should append to the file, with [`OFlags::APPEND`].
+    ///
+    /// This implies `write(true)`.
+    #[inline]
+    pub fn append(&mut self, append: bool) -> &mut Self {
+        self.append = append;
+        self
+    }
+
+    /// Set whether an existing file should be truncated, with
+    /// [`OFlags::TRUNC`].
+    ///
+    /// This requires `write(true)`, and can't be combined with `append`.
+    #[inline]
+    pub fn truncate(&mut self, truncate: bool) -> &mut Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// Set whether the file should be created if it doesn't exist, with
+    /// [`OFlags::CREATE`].
+    ///
+    /// This requires `write(true)` or `append(true)`.
+    #[inline]
+    pub fn create(&mut self, create: bool) -> &mut Self {
+        self.create = create;
+        self
+    }
+
+    /// Set whether a new file must be created, failing if it already exists,
+    /// with [`OFlags::CREATE`] and [`OFlags::EXCL`].
+    ///
+    /// This requires `write(true)` or `append(true)`, and overrides
+    /// `create` and `truncate`.
+    #[inline]
+    pub fn create_new(&mut self, create_new: bool) -> &mut Self {
+        self.create_new = create_new;
+        self
+    }
+
+    /// Set additional `OFlags`, such as [`OFlags::DIRECTORY`] or
+    /// [`OFlags::NOFOLLOW`].
+    ///
+    /// This replaces any previously set custom flags, including the default
+    /// [`OFlags::CLOEXEC`]. The access mode and creation flags are computed
+    /// from the other options and are masked out of `flags`.
+    #[inline]
+    pub fn custom_flags(&mut self, flags: OFlags) -> &mut Self {
+        self.custom_flags = flags;
+        self
+    }
+
+    /// Set the mode to create new files with, before the umask is applied.
+    #[inline]
+    pub fn mode(&mut self, mode: Mode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the path resolution flags, which cause the file to be opened with
+    /// `openat2`.
+    #[cfg(linux_kernel)]
+    #[inline]
+    pub fn resolve(&mut self, resolve: ResolveFlags) -> &mut Self {
+        self.resolve = resolve;
+        self
+    }
+
+    /// Open `path` relative to `dirfd` with these options.
+    ///
+    /// This fails with [`io::Errno::INVAL`] if the options are inconsistent,
+    /// such as when neither reading nor writing is enabled, when creation or
+    /// truncation is requested without writing, or when truncation is
+    /// combined with appending.
+    pub fn open<Fd: AsFd, P: path::Arg>(&self, dirfd: Fd, path: P) -> io::Result<OwnedFd> {
+        let oflags = self.oflags()?;
+
+        #[cfg(linux_kernel)]
+        if !self.resolve.is_empty() {
+            // `openat2` rejects a non-zero mode when not creating a file.
+            let mode = if oflags.intersects(OFlags::CREATE | OFlags::TMPFILE) {
+                self.mode
+            } else {
+                Mode::empty()
+            };
+            return openat2(dirfd, path, oflags, mode, self.resolve);
+        }
+
+        openat(dirfd, path, oflags, self.mode)
+    }
+
+    /// Compute the `OFlags` to open with.
+    fn oflags(&self) -> io::Result<OFlags> {
+        let writing = self.write || self.append;
+
+        let mut oflags = match (self.read, writing) {
+            (true, false) => OFlags::RDONLY,
+            (false, true) => OFlags::WRONLY,
+            (true, true) => OFlags::RDWR,
+            (false, false) => return Err(io::Errno::INVAL),
+        };
+
+        if self.append {
+            oflags |= OFlags::APPEND;
+        }
+
+        if self.create_new {
+            if !writing {
+                return Err(io::Errno::INVAL);
+            }
+            oflags |= OFlags::CREATE | OFlags::EXCL;
+        } else {
+            if (self.create || self.truncate) && !writing {
+                return Err(io::Errno::INVAL);
+            }
+            if self.truncate && self.append {
+                return Err(io::Errno::INVAL);
+            }
+            if self.create {
+                oflags |= OFlags::CREATE;
+            }
+            if self.truncate {
+                oflags |= OFlags::TRUNC;
+            }
+        }
+
+        let reserved =
+            OFlags::RWMODE | OFlags::APPEND | OFlags::CREATE | OFlags::EXCL | OFlags::TRUNC;
+        Ok(oflags | (self.custom_flags - reserved))
+    }
+}
+
+impl Default for OpenOptionsAt {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,14 +1,41 @@
-use crate::fd::OwnedFd;
+use crate::fd::{BorrowedFd, OwnedFd};
+use crate::ffi::CStr;
 use crate::{backend, io, path};
 use backend::fd::AsFd;
 use backend::fs::types::{Mode, OFlags, ResolveFlags};
+use core::sync::atomic::{AtomicU8, Ordering};
 
 /// `openat2(dirfd, path, OpenHow { oflags, mode, resolve }, sizeof(OpenHow))`
 ///
+/// `resolve` restricts how `path` is resolved, such as
+/// [`ResolveFlags::BENEATH`] to fail rather than resolve to anything outside
+/// of `dirfd`, or [`ResolveFlags::NO_SYMLINKS`] to fail rather than follow
+/// any symlinks. These checks are done by the kernel as part of the lookup,
+/// so they aren't subject to races with concurrent renames.
+///
+/// `mode` must be empty unless `oflags` includes [`OFlags::CREATE`] or
+/// [`OFlags::TMPFILE`], and unknown `oflags` or `resolve` flags are rejected
+/// with [`io::Errno::INVAL`] rather than ignored.
+///
+/// This function returns [`io::Errno::NOSYS`] if `openat2` is not available,
+/// such as on Linux before 5.6. This also includes seccomp filters which
+/// fail unknown syscalls with other error codes, such as in older Docker
+/// versions; rustix detects these and translates them into `NOSYS`. The
+/// result of this detection is cached, so once `openat2` is found to be
+/// unavailable, later calls fail without making a syscall. Callers which need
+/// the restrictions of `resolve` shouldn't fall back to [`openat`] without
+/// emulating them.
+///
+/// Some flags, such as [`ResolveFlags::CACHED`], are newer than `openat2`
+/// itself, and fail with [`io::Errno::INVAL`] on kernels which don't support
+/// them. With `CACHED`, this fails with [`io::Errno::AGAIN`] if the lookup
+/// can't be completed from the cache.
+///
 /// # References
 ///  - [Linux]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/openat2.2.html
+/// [`openat`]: crate::fs::openat
 #[inline]
 pub fn openat2<Fd: AsFd, P: path::Arg>(
     dirfd: Fd,
@@ -17,7 +44,52 @@ pub fn openat2<Fd: AsFd, P: path::Arg>(
     mode: Mode,
     resolve: ResolveFlags,
 ) -> io::Result<OwnedFd> {
-    path.into_with_c_str(|path| {
-        backend::fs::syscalls::openat2(dirfd.as_fd(), path, oflags, mode, resolve)
-    })
+    path.into_with_c_str(|path| _openat2(dirfd.as_fd(), path, oflags, mode, resolve))
+}
+
+// Linux kernels prior to 5.6 and seccomp filters which don't know about it
+// don't support `openat2`. We store the availability in a global to avoid
+// unnecessary syscalls.
+//
+// 0: Unknown
+// 1: Not available
+// 2: Available
+static OPENAT2_STATE: AtomicU8 = AtomicU8::new(0);
+
+#[inline]
+fn _openat2(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    oflags: OFlags,
+    mode: Mode,
+    resolve: ResolveFlags,
+) -> io::Result<OwnedFd> {
+    match OPENAT2_STATE.load(Ordering::Relaxed) {
+        1 => Err(io::Errno::NOSYS),
+        2 => backend::fs::syscalls::openat2(dirfd, path, oflags, mode, resolve),
+        _ => match backend::fs::syscalls::openat2(dirfd, path, oflags, mode, resolve) {
+            Err(err) => openat2_error(err),
+            result => {
+                OPENAT2_STATE.store(2, Ordering::Relaxed);
+                result
+            }
+        },
+    }
+}
+
+/// An `openat2` call failed before we knew whether `openat2` is available.
+/// We can get a variety of error codes from seccomp filters, so we don't
+/// trust `ENOSYS` or `EPERM` to tell us whether `openat2` is available.
+#[cold]
+fn openat2_error(err: io::Errno) -> io::Result<OwnedFd> {
+    if backend::fs::syscalls::is_openat2_available() {
+        // `openat2` is available. Record this, and fail with the error code
+        // of the initial `openat2` call.
+        OPENAT2_STATE.store(2, Ordering::Relaxed);
+        Err(err)
+    } else {
+        // `openat2` is not available. Record this, and fail with `NOSYS`.
+        OPENAT2_STATE.store(1, Ordering::Relaxed);
+        Err(io::Errno::NOSYS)
+    }
 }
@@ -0,0 +1,60 @@
+use crate::fd::AsFd;
+#[cfg(apple)]
+use crate::fs::{fclonefileat, CloneFlags};
+#[cfg(linux_kernel)]
+use crate::fs::{fstat, ioctl_ficlone, openat, unlinkat, AtFlags, Mode, OFlags};
+use crate::{io, path};
+
+/// Create a new file `dst` in `dst_dir` which shares its data with `src`.
+///
+/// The new file initially has the same contents as `src`, but uses no extra
+/// space until either file is modified, as with `cp --reflink` on Linux or
+/// `cp -c` on macOS. `dst` must not already exist.
+///
+/// On Apple platforms this is [`fclonefileat`], which also copies metadata
+/// such as extended attributes. On Linux, this creates `dst` with the
+/// permissions of `src`, less the umask, and uses [`ioctl_ficlone`], removing
+/// `dst` again if that fails.
+///
+/// Fails with [`io::Errno::XDEV`] if `src` and `dst_dir` are on different
+/// filesystems, and with [`io::Errno::OPNOTSUPP`] on Linux, or
+/// [`io::Errno::NOTSUP`] on Apple platforms, if the filesystem doesn't
+/// support sharing data between files. In those cases, the data can be copied
+/// instead, for example with [`copy_file_range`] or [`fcopyfile`].
+///
+/// [`fclonefileat`]: https://docs.rs/rustix/*/x86_64-apple-darwin/rustix/fs/fn.fclonefileat.html
+/// [`ioctl_ficlone`]: crate::fs::ioctl_ficlone
+/// [`copy_file_range`]: crate::fs::copy_file_range
+/// [`fcopyfile`]: https://docs.rs/rustix/*/x86_64-apple-darwin/rustix/fs/fn.fcopyfile.html
+#[doc(alias = "reflink")]
+#[doc(alias = "clonefile")]
+#[doc(alias = "FICLONE")]
+pub fn reflinkat<SrcFd: AsFd, DstFd: AsFd, P: path::Arg>(
+    src: SrcFd,
+    dst_dir: DstFd,
+    dst: P,
+) -> io::Result<()> {
+    #[cfg(apple)]
+    {
+        fclonefileat(src, dst_dir, dst, CloneFlags::empty())
+    }
+
+    #[cfg(linux_kernel)]
+    {
+        let src = src.as_fd();
+        let dst_dir = dst_dir.as_fd();
+        dst.into_with_c_str(|dst| {
+            let mode = Mode::from_raw_mode(fstat(src)?.st_mode);
+            let fd = openat(
+                dst_dir,
+                dst,
+                OFlags::WRONLY | OFlags::CREATE | OFlags::EXCL | OFlags::CLOEXEC,
+                mode,
+            )?;
+            ioctl_ficlone(&fd, src).map_err(|err| {
+                let _ = unlinkat(dst_dir, dst, AtFlags::empty());
+                err
+            })
+        })
+    }
+}
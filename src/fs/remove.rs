@@ -0,0 +1,133 @@
+//! Recursively removing directories.
+
+use super::walk::open_dir;
+use crate::fd::AsFd;
+use crate::ffi::CString;
+use crate::fs::{unlinkat, AtFlags, Dir, FileType};
+use crate::{io, path};
+use alloc::borrow::ToOwned;
+use alloc::vec::Vec;
+
+/// A directory being emptied by [`remove_dir_all_at`].
+struct Level {
+    /// The open directory.
+    dir: Dir,
+
+    /// The name of the directory within its parent, or the `path` passed to
+    /// [`remove_dir_all_at`] for the top level.
+    name: CString,
+
+    /// Whether anything has been removed from the directory during the
+    /// current pass over its entries.
+    removed: bool,
+}
+
+/// `remove_dir_all_at(dirfd, path)`—Removes a directory and everything in it.
+///
+/// `path` is opened relative to `dirfd` with `O_NOFOLLOW` and
+/// `O_DIRECTORY`, so a symbolic link in its last component isn't followed,
+/// though symbolic links in its earlier components are. Each directory below
+/// it is then opened with `openat` relative to its parent, using only its
+/// single-component name with `O_NOFOLLOW` and `O_DIRECTORY`, and its
+/// entries are removed with `unlinkat` relative to that file descriptor. So
+/// symbolic links within the tree are removed rather than followed, and
+/// replacing a directory in the tree with a symbolic link while this is
+/// running can't make it remove anything outside of `path`. Finally, `path`
+/// itself is removed with `unlinkat` relative to `dirfd`.
+///
+/// If the last component of `path` is a symbolic link, or isn't a
+/// directory, this fails without removing anything. Entries which are
+/// removed by someone else while this is running are ignored.
+///
+/// This holds a file descriptor open for each level of the tree below
+/// `path`, so it fails with [`io::Errno::MFILE`] on trees deeper than the
+/// file descriptor limit.
+pub fn remove_dir_all_at<P: path::Arg, Fd: AsFd>(dirfd: Fd, path: P) -> io::Result<()> {
+    let dirfd = dirfd.as_fd();
+    let name = path.into_with_c_str(|path| Ok(path.to_owned()))?;
+    let dir = Dir::new(open_dir(dirfd, &name)?)?;
+
+    let mut stack = Vec::new();
+    stack.push(Level {
+        dir,
+        name,
+        removed: false,
+    });
+
+    while let Some(level) = stack.last_mut() {
+        let entry = match level.dir.read() {
+            Some(entry) => entry?,
+            None => {
+                let level = stack.pop().unwrap();
+                let parent = match stack.last() {
+                    Some(parent) => parent.dir.fd()?,
+                    None => dirfd,
+                };
+                match unlinkat(parent, &level.name, AtFlags::REMOVEDIR) {
+                    Ok(()) | Err(io::Errno::NOENT) => {}
+
+                    // Some filesystems skip entries when a directory is
+                    // modified while it's being read, so if we removed
+                    // anything, make another pass over what's left.
+                    Err(io::Errno::NOTEMPTY) if level.removed => {
+                        let mut level = level;
+                        level.dir.rewind();
+                        level.removed = false;
+                        stack.push(level);
+                        continue;
+                    }
+                    Err(err) => return Err(err),
+                }
+                if let Some(parent) = stack.last_mut() {
+                    parent.removed = true;
+                }
+                continue;
+            }
+        };
+
+        let name = entry.file_name();
+        if name.to_bytes() == b"." || name.to_bytes() == b".." {
+            continue;
+        }
+
+        let fd = level.dir.fd()?;
+        if !matches!(entry.file_type(), FileType::Directory | FileType::Unknown) {
+            match unlinkat(fd, name, AtFlags::empty()) {
+                Ok(()) => {
+                    level.removed = true;
+                    continue;
+                }
+                Err(io::Errno::NOENT) => continue,
+
+                // It was replaced by a directory after we read its entry.
+                // Linux fails with `EISDIR` and POSIX with `EPERM`.
+                Err(io::Errno::ISDIR) | Err(io::Errno::PERM) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        match open_dir(fd, name) {
+            Ok(child) => {
+                let name = name.to_owned();
+                stack.push(Level {
+                    dir: Dir::new(child)?,
+                    name,
+                    removed: false,
+                });
+            }
+            Err(io::Errno::NOENT) => {}
+
+            // It isn't a directory, or it's a symbolic link.
+            Err(io::Errno::NOTDIR) | Err(io::Errno::LOOP) => {
+                match unlinkat(fd, name, AtFlags::empty()) {
+                    Ok(()) => level.removed = true,
+                    Err(io::Errno::NOENT) => {}
+                    Err(err) => return Err(err),
+                }
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! Reopening file descriptors, such as [`OFlags::PATH`] file descriptors,
+//! with new flags.
+
+use crate::fd::{AsFd, BorrowedFd, OwnedFd};
+#[cfg(linux_kernel)]
+use crate::fs::fstat;
+use crate::fs::{openat, Mode, OFlags};
+use crate::io;
+#[cfg(linux_kernel)]
+use crate::path::DecInt;
+
+/// `openat(proc_self_fd, fd, flags)`—Reopens the file referred to by a file
+/// descriptor, with new flags.
+///
+/// This is how an [`OFlags::PATH`] file descriptor, which can only be used to
+/// identify a file, can be converted into one which can be used to read or
+/// write it. It can also be used to open a file with a different access mode
+/// than the original file descriptor, if the file's permissions allow it.
+///
+/// The access checks are performed again, against the file's current
+/// permissions, as if it were opened by path. Unlike opening it by path,
+/// this always opens the same file, even if it has been renamed or unlinked.
+///
+/// `flags` shouldn't include [`OFlags::CREATE`]. To open a directory, include
+/// [`OFlags::DIRECTORY`].
+///
+/// On Linux, this opens the `/proc/self/fd/<fd>` entry for `fd`, using
+/// [`proc_self_fd`] to check that `/proc` is really `procfs`. Sockets can't
+/// be reopened this way, and fail with [`io::Errno::NXIO`].
+/// [`OFlags::NOFOLLOW`] is ignored, because the entries of `/proc/self/fd`
+/// are symlinks. On FreeBSD, this uses `O_EMPTY_PATH`.
+///
+/// Unlike [`dup`], the new file descriptor has its own file offset and
+/// status flags.
+///
+/// # References
+///  - [Linux `open`]
+///  - [Linux `proc`]
+///  - [FreeBSD]
+///
+/// [`proc_self_fd`]: crate::procfs::proc_self_fd
+/// [`dup`]: crate::io::dup
+/// [Linux `open`]: https://man7.org/linux/man-pages/man2/open.2.html
+/// [Linux `proc`]: https://man7.org/linux/man-pages/man5/proc.5.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=openat&sektion=2
+#[cfg_attr(all(linux_kernel, doc_cfg), doc(cfg(feature = "procfs")))]
+#[inline]
+pub fn reopen<Fd: AsFd>(fd: Fd, flags: OFlags) -> io::Result<OwnedFd> {
+    _reopen(fd.as_fd(), flags)
+}
+
+#[cfg(linux_kernel)]
+fn _reopen(fd: BorrowedFd<'_>, flags: OFlags) -> io::Result<OwnedFd> {
+    let proc_self_fd = crate::procfs::proc_self_fd()?;
+
+    // The `/proc/self/fd/<fd>` entries are symlinks which must be followed,
+    // so don't pass `O_NOFOLLOW`.
+    let new = openat(
+        proc_self_fd,
+        DecInt::from_fd(fd).as_c_str(),
+        flags & !OFlags::NOFOLLOW,
+        Mode::empty(),
+    )?;
+
+    // Check that we opened the same file.
+    let (old_stat, new_stat) = (fstat(fd)?, fstat(&new)?);
+    if old_stat.st_dev != new_stat.st_dev || old_stat.st_ino != new_stat.st_ino {
+        return Err(io::Errno::NOTSUP);
+    }
+
+    Ok(new)
+}
+
+#[cfg(target_os = "freebsd")]
+fn _reopen(fd: BorrowedFd<'_>, flags: OFlags) -> io::Result<OwnedFd> {
+    openat(fd, cstr!(""), flags | OFlags::EMPTY_PATH, Mode::empty())
+}
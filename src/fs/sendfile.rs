@@ -1,13 +1,33 @@
 use crate::{backend, io};
 use backend::fd::AsFd;
 
-/// `sendfile(out_fd, in_fd, offset, count)`
+/// `sendfile(out_fd, in_fd, offset, count)`—Copies data from a file to a
+/// socket or another file, without passing it through userspace.
+///
+/// If `offset` is `Some`, data is read starting at that offset, which is then
+/// advanced by the number of bytes copied, and `in_fd`'s file offset is
+/// neither used nor changed. If it's `None`, data is read starting at
+/// `in_fd`'s file offset, which is advanced instead.
+///
+/// This returns the number of bytes copied, which may be less than `count`,
+/// and is 0 at the end of `in_fd`.
+///
+/// On Linux, `out_fd` may be any file. On other platforms, it must be a
+/// stream socket. On those platforms, the `sendfile` system call has a
+/// different signature, always taking an explicit offset; when `offset` is
+/// `None`, this reads and then updates `in_fd`'s file offset around the
+/// call, so it isn't atomic with respect to other users of the file offset.
 ///
 /// # References
 ///  - [Linux]
+///  - [Apple]
+///  - [FreeBSD]
+///  - [DragonFly BSD]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/sendfile.2.html
-#[cfg(linux_kernel)]
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/sendfile.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=sendfile&sektion=2
+/// [DragonFly BSD]: https://man.dragonflybsd.org/?command=sendfile&section=2
 #[inline]
 pub fn sendfile<OutFd: AsFd, InFd: AsFd>(
     out_fd: OutFd,
@@ -1,7 +1,7 @@
 //! Linux `statx`.
 
 use crate::fd::AsFd;
-use crate::fs::AtFlags;
+use crate::fs::{AtFlags, FileType};
 use crate::{backend, io, path};
 use backend::fs::types::{Statx, StatxFlags};
 
@@ -17,6 +17,11 @@ use compat::statx as _statx;
 /// versions where the actual syscall fails with different error codes; rustix
 /// handles this and translates them into `NOSYS`.
 ///
+/// The `stx_mask` field of the result says which of the fields requested in
+/// `mask` were filled in. Filesystems may omit fields they don't record,
+/// such as the creation time requested by [`StatxFlags::BTIME`], and may
+/// fill in fields that weren't requested.
+///
 /// # References
 ///  - [Linux]
 ///
@@ -61,6 +66,67 @@ pub fn statx<P: path::Arg, Fd: AsFd>(
     path.into_with_c_str(|path| _statx(dirfd.as_fd(), path, flags, mask))
 }
 
+/// Alignment requirements for I/O on a file opened with [`OFlags::DIRECT`].
+///
+/// [`OFlags::DIRECT`]: crate::fs::OFlags::DIRECT
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DirectIoAlignment {
+    /// The required alignment, in bytes, of user memory buffers.
+    pub mem_align: u32,
+
+    /// The required alignment, in bytes, of file offsets and I/O lengths.
+    pub offset_align: u32,
+}
+
+/// Query the alignment required for direct I/O on `fd`.
+///
+/// This uses `statx` with [`StatxFlags::DIOALIGN`] where the kernel supports
+/// it, which is Linux 6.1 and later. Otherwise, if `fd` is a block device,
+/// it falls back to the logical block size reported by
+/// [`ioctl_blksszget`], which is the traditional alignment for direct I/O on
+/// block devices.
+///
+/// Returns `Ok(None)` if the file doesn't support direct I/O, or if its
+/// alignment requirements can't be determined.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/statx.2.html
+/// [`ioctl_blksszget`]: crate::fs::ioctl_blksszget
+#[inline]
+#[doc(alias = "STATX_DIOALIGN")]
+pub fn direct_io_alignment<Fd: AsFd>(fd: Fd) -> io::Result<Option<DirectIoAlignment>> {
+    let fd = fd.as_fd();
+
+    match _statx(fd, cstr!(""), AtFlags::EMPTY_PATH, StatxFlags::DIOALIGN) {
+        Ok(statx)
+            if StatxFlags::from_bits_retain(statx.stx_mask).contains(StatxFlags::DIOALIGN) =>
+        {
+            // The kernel reports zeros if direct I/O isn't supported.
+            if statx.stx_dio_mem_align == 0 || statx.stx_dio_offset_align == 0 {
+                return Ok(None);
+            }
+            return Ok(Some(DirectIoAlignment {
+                mem_align: statx.stx_dio_mem_align,
+                offset_align: statx.stx_dio_offset_align,
+            }));
+        }
+        Ok(_) | Err(io::Errno::NOSYS) => (),
+        Err(err) => return Err(err),
+    }
+
+    if FileType::from_raw_mode(crate::fs::fstat(fd)?.st_mode as _) != FileType::BlockDevice {
+        return Ok(None);
+    }
+
+    let block_size = crate::fs::ioctl_blksszget(fd)?;
+    Ok(Some(DirectIoAlignment {
+        mem_align: block_size,
+        offset_align: block_size,
+    }))
+}
+
 #[cfg(not(feature = "linux_4_11"))]
 mod compat {
     use crate::fd::BorrowedFd;
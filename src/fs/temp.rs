@@ -0,0 +1,188 @@
+//! Creating temporary files and directories with unique names.
+
+use crate::fd::{AsFd, BorrowedFd, OwnedFd};
+use crate::ffi::CStr;
+use crate::fs::{mkdirat, openat, unlinkat, AtFlags, Mode, OFlags};
+use crate::rand::{getrandom, GetRandomFlags};
+use crate::{io, path};
+use core::fmt;
+
+/// The number of random characters appended to the prefix.
+const SUFFIX_LEN: usize = 12;
+
+/// The characters random suffixes are made from.
+const SUFFIX_CHARS: &[u8; 62] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// How many names to try before giving up, if they all already exist.
+const ATTEMPTS: usize = 128;
+
+/// The largest name a `TempName` can hold, which is `NAME_MAX` on Linux.
+const NAME_MAX: usize = 255;
+
+/// The name of a file or directory created by [`create_temp_file_in`] or
+/// [`create_temp_dir_in`].
+///
+/// This is stored inline, so creating one doesn't allocate.
+#[derive(Clone)]
+pub struct TempName {
+    buf: [u8; NAME_MAX + 1],
+    len: usize,
+}
+
+impl TempName {
+    /// Return the name as a `&CStr`, which can be passed to functions like
+    /// [`unlinkat`] and [`renameat`].
+    ///
+    /// [`renameat`]: crate::fs::renameat
+    #[inline]
+    pub fn as_c_str(&self) -> &CStr {
+        CStr::from_bytes_with_nul(&self.buf[..=self.len]).unwrap()
+    }
+
+    /// Return the name as bytes, without a NUL terminator.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Construct a name consisting of `prefix` followed by a random suffix.
+    fn new(prefix: &[u8]) -> io::Result<Self> {
+        if prefix.contains(&b'/') {
+            return Err(io::Errno::INVAL);
+        }
+        if prefix.len() + SUFFIX_LEN > NAME_MAX {
+            return Err(io::Errno::NAMETOOLONG);
+        }
+
+        let mut random = [0_u8; SUFFIX_LEN];
+        let mut filled = 0;
+        while filled < random.len() {
+            match getrandom(&mut random[filled..], GetRandomFlags::empty()) {
+                Ok(n) => filled += n,
+                Err(io::Errno::INTR) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        let mut name = Self {
+            buf: [0; NAME_MAX + 1],
+            len: prefix.len() + SUFFIX_LEN,
+        };
+        name.buf[..prefix.len()].copy_from_slice(prefix);
+        for (out, byte) in name.buf[prefix.len()..name.len].iter_mut().zip(random) {
+            *out = SUFFIX_CHARS[usize::from(byte) % SUFFIX_CHARS.len()];
+        }
+        Ok(name)
+    }
+}
+
+impl fmt::Debug for TempName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_c_str().fmt(f)
+    }
+}
+
+/// Create a new file in `dirfd` with a unique name starting with `prefix`.
+///
+/// This is similar to `mkostemp`, except that it creates the file relative
+/// to a directory file descriptor, and takes a prefix rather than a template
+/// to fill in. The file is opened for reading and writing with
+/// [`OFlags::CLOEXEC`], and is created with permissions `0o600` and with
+/// [`OFlags::EXCL`], so it's always a new file. This returns the file and
+/// its name within `dirfd`.
+///
+/// Fails with [`io::Errno::INVAL`] if `prefix` contains a `/`.
+///
+/// To create a file with no name, which is removed automatically when it's
+/// closed, use [`create_unnamed_temp_file_in`].
+#[doc(alias = "mkstemp")]
+#[doc(alias = "mkostemp")]
+pub fn create_temp_file_in<Fd: AsFd, P: path::Arg>(
+    dirfd: Fd,
+    prefix: P,
+) -> io::Result<(OwnedFd, TempName)> {
+    let dirfd = dirfd.as_fd();
+    prefix.into_with_c_str(|prefix| _create_temp_file_in(dirfd, prefix.to_bytes()))
+}
+
+fn _create_temp_file_in(dirfd: BorrowedFd<'_>, prefix: &[u8]) -> io::Result<(OwnedFd, TempName)> {
+    retry_on_exist(prefix, |name| {
+        openat(
+            dirfd,
+            name.as_c_str(),
+            OFlags::RDWR | OFlags::CREATE | OFlags::EXCL | OFlags::CLOEXEC,
+            Mode::RUSR | Mode::WUSR,
+        )
+    })
+}
+
+/// Create a new directory in `dirfd` with a unique name starting with
+/// `prefix`.
+///
+/// This is similar to `mkdtemp`, except that it creates the directory
+/// relative to a directory file descriptor, and takes a prefix rather than a
+/// template to fill in. The directory is created with permissions `0o700`.
+/// This returns its name within `dirfd`.
+///
+/// Fails with [`io::Errno::INVAL`] if `prefix` contains a `/`.
+#[doc(alias = "mkdtemp")]
+pub fn create_temp_dir_in<Fd: AsFd, P: path::Arg>(dirfd: Fd, prefix: P) -> io::Result<TempName> {
+    let dirfd = dirfd.as_fd();
+    prefix.into_with_c_str(|prefix| {
+        retry_on_exist(prefix.to_bytes(), |name| {
+            mkdirat(dirfd, name.as_c_str(), Mode::RWXU)
+        })
+        .map(|((), name)| name)
+    })
+}
+
+/// Create a new file in `dirfd` which has no name, and which is removed
+/// automatically when it's closed.
+///
+/// This uses [`OFlags::TMPFILE`] if the kernel and filesystem support it.
+/// Otherwise it creates a file with [`create_temp_file_in`] and immediately
+/// removes it. The file is opened for reading and writing with
+/// [`OFlags::CLOEXEC`].
+///
+/// A file created with `OFlags::TMPFILE` can be given a name later with
+/// [`linkat`] and [`AtFlags::EMPTY_PATH`].
+///
+/// [`linkat`]: crate::fs::linkat
+#[doc(alias = "O_TMPFILE")]
+#[doc(alias = "tmpfile")]
+pub fn create_unnamed_temp_file_in<Fd: AsFd>(dirfd: Fd) -> io::Result<OwnedFd> {
+    let dirfd = dirfd.as_fd();
+    match openat(
+        dirfd,
+        cstr!("."),
+        OFlags::RDWR | OFlags::TMPFILE | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    ) {
+        Ok(fd) => return Ok(fd),
+        // Kernels without `O_TMPFILE` fail with `EISDIR`, because it includes
+        // `O_DIRECTORY`, and filesystems without it fail with `EOPNOTSUPP`.
+        Err(io::Errno::ISDIR | io::Errno::OPNOTSUPP | io::Errno::INVAL) => {}
+        Err(err) => return Err(err),
+    }
+
+    let (fd, name) = _create_temp_file_in(dirfd, b".tmp")?;
+    unlinkat(dirfd, name.as_c_str(), AtFlags::empty())?;
+    Ok(fd)
+}
+
+/// Call `f` with new random names until it succeeds, or fails with an error
+/// other than [`io::Errno::EXIST`].
+fn retry_on_exist<T>(
+    prefix: &[u8],
+    mut f: impl FnMut(&TempName) -> io::Result<T>,
+) -> io::Result<(T, TempName)> {
+    for _ in 0..ATTEMPTS {
+        let name = TempName::new(prefix)?;
+        match f(&name) {
+            Ok(t) => return Ok((t, name)),
+            Err(io::Errno::EXIST) => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Err(io::Errno::EXIST)
+}
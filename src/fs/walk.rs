@@ -0,0 +1,355 @@
+//! Recursive directory traversal.
+
+use crate::fd::{AsFd, BorrowedFd, OwnedFd};
+use crate::ffi::{CStr, CString};
+use crate::fs::{fstat, openat, statat, AtFlags, Dir, DirEntry, FileType, Mode, OFlags};
+use crate::io;
+use alloc::borrow::ToOwned;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A directory on the [`Walker`]'s stack.
+struct Level {
+    /// The open directory, or `None` if it was closed to free up a file
+    /// descriptor.
+    dir: Option<Dir>,
+
+    /// The name of the directory within its parent, used to reopen it.
+    name: CString,
+
+    /// The device and inode numbers of the directory, used to check that a
+    /// reopened directory is the same one.
+    dev: u64,
+    ino: u64,
+
+    /// The number of entries read from the directory so far, used to resume
+    /// reading a reopened directory.
+    consumed: usize,
+}
+
+/// A recursive directory walker which never leaves the directory it starts
+/// in.
+///
+/// This yields every entry under a directory, in pre-order, with the depth of
+/// the entry and a file descriptor for the directory containing it. Entries
+/// directly within the root have depth 0.
+///
+/// Each subdirectory is opened with `openat` relative to its parent, using
+/// only its single-component name with `O_NOFOLLOW` and `O_DIRECTORY`, so
+/// symbolic links are never followed, and renaming directories or replacing
+/// them with symbolic links during the walk can't make it escape the root.
+/// Symbolic links are yielded as entries, but not descended into.
+///
+/// If opening a subdirectory fails with [`io::Errno::MFILE`] or
+/// [`io::Errno::NFILE`], the walker closes the file descriptors of its
+/// shallowest ancestors, and reopens them by name when it returns to them,
+/// so it can walk trees deeper than the file descriptor limit. A reopened
+/// directory is checked to be the same directory, by its device and inode
+/// numbers, and the walk fails with [`io::Errno::STALE`] if it isn't.
+/// Reading resumes after the number of entries previously read, so entries
+/// may be skipped or repeated if the directory was modified in the meantime.
+///
+/// This doesn't implement [`Iterator`], because each [`WalkEntry`] borrows
+/// the walker; use a `while let` loop with [`Walker::next`] instead.
+///
+/// # Examples
+///
+/// ```
+/// # use rustix::fs::{Walker, CWD};
+/// # fn example() -> rustix::io::Result<()> {
+/// let mut walker = Walker::new(CWD)?;
+/// while let Some(entry) = walker.next() {
+///     let entry = entry?;
+///     println!("{} {:?}", entry.depth(), entry.file_name());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Walker {
+    /// The directories being read, starting with the root.
+    stack: Vec<Level>,
+
+    /// The most recently yielded entry.
+    entry: Option<DirEntry>,
+
+    /// The name of the most recently yielded entry, if it's a directory to be
+    /// descended into.
+    pending: Option<CString>,
+}
+
+impl Walker {
+    /// Construct a new `Walker` which walks the directory `root`.
+    ///
+    /// `root` itself is not yielded, and it is not closed during the walk.
+    pub fn new<Fd: AsFd>(root: Fd) -> io::Result<Self> {
+        let fd = openat(
+            root,
+            cstr!("."),
+            OFlags::RDONLY | OFlags::DIRECTORY | OFlags::CLOEXEC,
+            Mode::empty(),
+        )?;
+        let dir = Dir::new(fd)?;
+        let stat = dir.stat()?;
+        Ok(Self {
+            stack: alloc::vec![Level {
+                dir: Some(dir),
+                name: CString::default(),
+                dev: stat.st_dev as u64,
+                ino: stat.st_ino as u64,
+                consumed: 0,
+            }],
+            entry: None,
+            pending: None,
+        })
+    }
+
+    /// Don't descend into the directory most recently yielded by
+    /// [`Walker::next`].
+    ///
+    /// This has no effect if the most recently yielded entry isn't a
+    /// directory.
+    #[inline]
+    pub fn skip_dir(&mut self) {
+        self.pending = None;
+    }
+
+    /// Return the next entry, or `None` at the end of the walk.
+    ///
+    /// If the most recently yielded entry is a directory, and
+    /// [`Walker::skip_dir`] wasn't called, this first descends into it.
+    ///
+    /// If this returns an error, the walk may be continued by calling this
+    /// again. An error reading a directory ends the iteration of that
+    /// directory.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<io::Result<WalkEntry<'_>>> {
+        if let Some(name) = self.pending.take() {
+            match self.descend(name) {
+                // The directory was removed after we read its entry.
+                Ok(()) | Err(io::Errno::NOENT) => {}
+                Err(err) => return Some(Err(err)),
+            }
+        }
+
+        let entry = loop {
+            let top = self.stack.len() - 1;
+            if let Err(err) = self.reopen(top) {
+                // Don't try to reopen it again.
+                self.stack.pop();
+                return Some(Err(err));
+            }
+
+            let level = &mut self.stack[top];
+            match level.dir.as_mut().unwrap().read() {
+                Some(Ok(entry)) => {
+                    level.consumed += 1;
+                    let name = entry.file_name().to_bytes();
+                    if name != b"." && name != b".." {
+                        break entry;
+                    }
+                }
+                Some(Err(err)) => return Some(Err(err)),
+                None => {
+                    self.stack.pop();
+                    if self.stack.is_empty() {
+                        return None;
+                    }
+                }
+            }
+        };
+
+        let depth = self.stack.len() - 1;
+        let dirfd = match self.stack[depth].dir.as_ref().unwrap().fd() {
+            Ok(dirfd) => dirfd,
+            Err(err) => return Some(Err(err)),
+        };
+
+        let is_dir = match entry.file_type() {
+            FileType::Directory => true,
+            FileType::Unknown => {
+                match statat(dirfd, entry.file_name(), AtFlags::SYMLINK_NOFOLLOW) {
+                    Ok(stat) => FileType::from_raw_mode(stat.st_mode) == FileType::Directory,
+                    Err(io::Errno::NOENT) => false,
+                    Err(err) => return Some(Err(err)),
+                }
+            }
+            _ => false,
+        };
+        if is_dir {
+            self.pending = Some(entry.file_name().to_owned());
+        }
+
+        Some(Ok(WalkEntry {
+            depth,
+            dirfd,
+            entry: self.entry.insert(entry),
+        }))
+    }
+
+    /// Open the directory `name` within the top of the stack, and push it.
+    fn descend(&mut self, name: CString) -> io::Result<()> {
+        let top = self.stack.len() - 1;
+        let dir = loop {
+            let parent = self.stack[top].dir.as_ref().unwrap().fd()?;
+            match open_dir(parent, &name) {
+                Err(io::Errno::MFILE) | Err(io::Errno::NFILE) => self.close_ancestor()?,
+                otherwise => break Dir::new(otherwise?)?,
+            }
+        };
+
+        let stat = dir.stat()?;
+        self.stack.push(Level {
+            dir: Some(dir),
+            name,
+            dev: stat.st_dev as u64,
+            ino: stat.st_ino as u64,
+            consumed: 0,
+        });
+        Ok(())
+    }
+
+    /// Close the shallowest open directory, other than the root and the top
+    /// of the stack, to free up a file descriptor.
+    fn close_ancestor(&mut self) -> io::Result<()> {
+        let top = self.stack.len() - 1;
+        match self
+            .stack
+            .iter_mut()
+            .take(top)
+            .skip(1)
+            .find(|level| level.dir.is_some())
+        {
+            Some(level) => {
+                level.dir = None;
+                Ok(())
+            }
+            None => Err(io::Errno::MFILE),
+        }
+    }
+
+    /// Ensure the directory at `index` in the stack is open, reopening it if
+    /// needed.
+    ///
+    /// Closed ancestors are passed through with temporary file descriptors,
+    /// and left closed.
+    fn reopen(&mut self, index: usize) -> io::Result<()> {
+        loop {
+            match self.try_reopen(index) {
+                Err(io::Errno::MFILE) | Err(io::Errno::NFILE) => self.close_ancestor()?,
+                otherwise => return otherwise,
+            }
+        }
+    }
+
+    fn try_reopen(&mut self, index: usize) -> io::Result<()> {
+        if self.stack[index].dir.is_some() {
+            return Ok(());
+        }
+
+        // The root is never closed, so there's always an open ancestor.
+        let base = (0..index)
+            .rev()
+            .find(|i| self.stack[*i].dir.is_some())
+            .unwrap();
+
+        let mut fd: Option<OwnedFd> = None;
+        for level in &self.stack[base + 1..=index] {
+            let parent = match &fd {
+                Some(fd) => fd.as_fd(),
+                None => self.stack[base].dir.as_ref().unwrap().fd()?,
+            };
+            let child = open_dir(parent, &level.name)?;
+            let stat = fstat(&child)?;
+            if stat.st_dev as u64 != level.dev || stat.st_ino as u64 != level.ino {
+                return Err(io::Errno::STALE);
+            }
+            fd = Some(child);
+        }
+
+        // Skip the entries we've already read.
+        let mut dir = Dir::new(fd.unwrap())?;
+        for _ in 0..self.stack[index].consumed {
+            match dir.read() {
+                Some(Ok(_)) => {}
+                Some(Err(err)) => return Err(err),
+                None => break,
+            }
+        }
+
+        self.stack[index].dir = Some(dir);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Walker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Walker")
+            .field("depth", &(self.stack.len() - 1))
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+/// Open the subdirectory `name` of `parent`, without following symlinks.
+pub(super) fn open_dir(parent: BorrowedFd<'_>, name: &CStr) -> io::Result<OwnedFd> {
+    openat(
+        parent,
+        name,
+        OFlags::RDONLY | OFlags::DIRECTORY | OFlags::NOFOLLOW | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+}
+
+/// An entry yielded by a [`Walker`].
+pub struct WalkEntry<'a> {
+    depth: usize,
+    dirfd: BorrowedFd<'a>,
+    entry: &'a DirEntry,
+}
+
+impl<'a> WalkEntry<'a> {
+    /// Returns the depth of this entry, where entries directly within the
+    /// root have depth 0.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns a file descriptor for the directory containing this entry,
+    /// which can be used with functions like [`statat`] and [`unlinkat`]
+    /// along with [`WalkEntry::file_name`].
+    ///
+    /// [`unlinkat`]: crate::fs::unlinkat
+    #[inline]
+    pub fn dirfd(&self) -> BorrowedFd<'a> {
+        self.dirfd
+    }
+
+    /// Returns the directory entry.
+    #[inline]
+    pub fn entry(&self) -> &'a DirEntry {
+        self.entry
+    }
+
+    /// Returns the file name of this entry.
+    #[inline]
+    pub fn file_name(&self) -> &'a CStr {
+        self.entry.file_name()
+    }
+
+    /// Returns the type of this entry.
+    #[inline]
+    pub fn file_type(&self) -> FileType {
+        self.entry.file_type()
+    }
+}
+
+impl fmt::Debug for WalkEntry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WalkEntry")
+            .field("depth", &self.depth)
+            .field("dirfd", &self.dirfd)
+            .field("entry", self.entry)
+            .finish()
+    }
+}
@@ -1,7 +1,13 @@
+use crate::ffi::CStr;
+use crate::path::OutputBuffer;
 use crate::{backend, io, path};
 use backend::c;
 use backend::fd::AsFd;
 use bitflags::bitflags;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem::MaybeUninit;
+use core::slice;
 
 bitflags! {
     /// `XATTR_*` constants for use with [`setxattr`], and other `*setxattr`
@@ -23,6 +29,10 @@ bitflags! {
 /// `getxattr(path, name, value.as_ptr(), value.len())`—Get extended
 /// filesystem attributes.
 ///
+/// With an empty `value`, this returns the size of the attribute without
+/// reading it. To get the value without knowing its size in advance, use
+/// [`getxattr_into`].
+///
 /// # References
 ///  - [Linux]
 ///
@@ -34,7 +44,34 @@ pub fn getxattr<P: path::Arg, Name: path::Arg>(
     value: &mut [u8],
 ) -> io::Result<usize> {
     path.into_with_c_str(|path| {
-        name.into_with_c_str(|name| backend::fs::syscalls::getxattr(path, name, value))
+        name.into_with_c_str(|name| backend::fs::syscalls::getxattr(path, name, as_uninit(value)))
+    })
+}
+
+/// `getxattr(path, name, value.as_ptr(), value.len())`—Get extended
+/// filesystem attributes.
+///
+/// `value` may be any [`OutputBuffer`], such as a `Vec<u8>` to allocate the
+/// result, reusing any capacity it already has, or a fixed-size array to
+/// avoid allocating. A fixed-size buffer which is too small fails with
+/// [`io::Errno::RANGE`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getxattr.2.html
+#[inline]
+pub fn getxattr_into<P: path::Arg, Name: path::Arg, B: OutputBuffer>(
+    path: P,
+    name: Name,
+    value: B,
+) -> io::Result<B::Bytes> {
+    path.into_with_c_str(|path| {
+        name.into_with_c_str(|name| {
+            fill_xattr(value, |buf| {
+                backend::fs::syscalls::getxattr(path, name, buf)
+            })
+        })
     })
 }
 
@@ -42,6 +79,10 @@ pub fn getxattr<P: path::Arg, Name: path::Arg>(
 /// filesystem attributes, without following symlinks in the last path
 /// component.
 ///
+/// With an empty `value`, this returns the size of the attribute without
+/// reading it. To get the value without knowing its size in advance, use
+/// [`lgetxattr_into`].
+///
 /// # References
 ///  - [Linux]
 ///
@@ -53,13 +94,45 @@ pub fn lgetxattr<P: path::Arg, Name: path::Arg>(
     value: &mut [u8],
 ) -> io::Result<usize> {
     path.into_with_c_str(|path| {
-        name.into_with_c_str(|name| backend::fs::syscalls::lgetxattr(path, name, value))
+        name.into_with_c_str(|name| backend::fs::syscalls::lgetxattr(path, name, as_uninit(value)))
+    })
+}
+
+/// `lgetxattr(path, name, value.as_ptr(), value.len())`—Get extended
+/// filesystem attributes, without following symlinks in the last path
+/// component.
+///
+/// `value` may be any [`OutputBuffer`], such as a `Vec<u8>` to allocate the
+/// result, reusing any capacity it already has, or a fixed-size array to
+/// avoid allocating. A fixed-size buffer which is too small fails with
+/// [`io::Errno::RANGE`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/lgetxattr.2.html
+#[inline]
+pub fn lgetxattr_into<P: path::Arg, Name: path::Arg, B: OutputBuffer>(
+    path: P,
+    name: Name,
+    value: B,
+) -> io::Result<B::Bytes> {
+    path.into_with_c_str(|path| {
+        name.into_with_c_str(|name| {
+            fill_xattr(value, |buf| {
+                backend::fs::syscalls::lgetxattr(path, name, buf)
+            })
+        })
     })
 }
 
 /// `fgetxattr(fd, name, value.as_ptr(), value.len())`—Get extended
 /// filesystem attributes on an open file descriptor.
 ///
+/// With an empty `value`, this returns the size of the attribute without
+/// reading it. To get the value without knowing its size in advance, use
+/// [`fgetxattr_into`].
+///
 /// # References
 ///  - [Linux]
 ///
@@ -70,7 +143,33 @@ pub fn fgetxattr<Fd: AsFd, Name: path::Arg>(
     name: Name,
     value: &mut [u8],
 ) -> io::Result<usize> {
-    name.into_with_c_str(|name| backend::fs::syscalls::fgetxattr(fd.as_fd(), name, value))
+    name.into_with_c_str(|name| {
+        backend::fs::syscalls::fgetxattr(fd.as_fd(), name, as_uninit(value))
+    })
+}
+
+/// `fgetxattr(fd, name, value.as_ptr(), value.len())`—Get extended
+/// filesystem attributes on an open file descriptor.
+///
+/// `value` may be any [`OutputBuffer`], such as a `Vec<u8>` to allocate the
+/// result, reusing any capacity it already has, or a fixed-size array to
+/// avoid allocating. A fixed-size buffer which is too small fails with
+/// [`io::Errno::RANGE`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fgetxattr.2.html
+#[inline]
+pub fn fgetxattr_into<Fd: AsFd, Name: path::Arg, B: OutputBuffer>(
+    fd: Fd,
+    name: Name,
+    value: B,
+) -> io::Result<B::Bytes> {
+    let fd = fd.as_fd();
+    name.into_with_c_str(|name| {
+        fill_xattr(value, |buf| backend::fs::syscalls::fgetxattr(fd, name, buf))
+    })
 }
 
 /// `setxattr(path, name, value.as_ptr(), value.len(), flags)`—Set extended
@@ -132,37 +231,199 @@ pub fn fsetxattr<Fd: AsFd, Name: path::Arg>(
 /// `listxattr(path, list.as_ptr(), list.len())`—List extended filesystem
 /// attributes.
 ///
+/// With an empty `list`, this returns the size of the list without reading
+/// it. To get the list without knowing its size in advance, use
+/// [`listxattr_into`].
+///
 /// # References
 ///  - [Linux]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/listxattr.2.html
 #[inline]
 pub fn listxattr<P: path::Arg>(path: P, list: &mut [c::c_char]) -> io::Result<usize> {
-    path.into_with_c_str(|path| backend::fs::syscalls::listxattr(path, list))
+    path.into_with_c_str(|path| backend::fs::syscalls::listxattr(path, list_as_uninit(list)))
+}
+
+/// `listxattr(path, list.as_ptr(), list.len())`—List extended filesystem
+/// attributes.
+///
+/// The names are returned as a sequence of NUL-terminated strings. `list` may
+/// be any [`OutputBuffer`], such as a `Vec<u8>` to allocate the result, or a
+/// fixed-size array to avoid allocating. Use [`XattrNames`] to iterate over
+/// the names.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/listxattr.2.html
+#[inline]
+pub fn listxattr_into<P: path::Arg, B: OutputBuffer>(path: P, list: B) -> io::Result<B::Bytes> {
+    path.into_with_c_str(|path| fill_xattr(list, |buf| backend::fs::syscalls::listxattr(path, buf)))
 }
 
 /// `llistxattr(path, list.as_ptr(), list.len())`—List extended filesystem
 /// attributes, without following symlinks in the last path component.
 ///
+/// With an empty `list`, this returns the size of the list without reading
+/// it. To get the list without knowing its size in advance, use
+/// [`llistxattr_into`].
+///
 /// # References
 ///  - [Linux]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/llistxattr.2.html
 #[inline]
 pub fn llistxattr<P: path::Arg>(path: P, list: &mut [c::c_char]) -> io::Result<usize> {
-    path.into_with_c_str(|path| backend::fs::syscalls::llistxattr(path, list))
+    path.into_with_c_str(|path| backend::fs::syscalls::llistxattr(path, list_as_uninit(list)))
+}
+
+/// `llistxattr(path, list.as_ptr(), list.len())`—List extended filesystem
+/// attributes, without following symlinks in the last path component.
+///
+/// The names are returned as a sequence of NUL-terminated strings. `list` may
+/// be any [`OutputBuffer`], such as a `Vec<u8>` to allocate the result, or a
+/// fixed-size array to avoid allocating. Use [`XattrNames`] to iterate over
+/// the names.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/llistxattr.2.html
+#[inline]
+pub fn llistxattr_into<P: path::Arg, B: OutputBuffer>(path: P, list: B) -> io::Result<B::Bytes> {
+    path.into_with_c_str(|path| {
+        fill_xattr(list, |buf| backend::fs::syscalls::llistxattr(path, buf))
+    })
 }
 
 /// `flistxattr(fd, list.as_ptr(), list.len())`—List extended filesystem
 /// attributes on an open file descriptor.
 ///
+/// With an empty `list`, this returns the size of the list without reading
+/// it. To get the list without knowing its size in advance, use
+/// [`flistxattr_into`].
+///
 /// # References
 ///  - [Linux]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/flistxattr.2.html
 #[inline]
 pub fn flistxattr<Fd: AsFd>(fd: Fd, list: &mut [c::c_char]) -> io::Result<usize> {
-    backend::fs::syscalls::flistxattr(fd.as_fd(), list)
+    backend::fs::syscalls::flistxattr(fd.as_fd(), list_as_uninit(list))
+}
+
+/// `flistxattr(fd, list.as_ptr(), list.len())`—List extended filesystem
+/// attributes on an open file descriptor.
+///
+/// The names are returned as a sequence of NUL-terminated strings. `list` may
+/// be any [`OutputBuffer`], such as a `Vec<u8>` to allocate the result, or a
+/// fixed-size array to avoid allocating. Use [`XattrNames`] to iterate over
+/// the names.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/flistxattr.2.html
+#[inline]
+pub fn flistxattr_into<Fd: AsFd, B: OutputBuffer>(fd: Fd, list: B) -> io::Result<B::Bytes> {
+    let fd = fd.as_fd();
+    fill_xattr(list, |buf| backend::fs::syscalls::flistxattr(fd, buf))
+}
+
+/// An iterator over the names in a list returned by [`listxattr_into`],
+/// [`llistxattr_into`], or [`flistxattr_into`].
+///
+/// This parses the NUL-separated list in place, without allocating.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn example() -> rustix::io::Result<()> {
+/// use rustix::fs::{listxattr_into, XattrNames};
+///
+/// let mut buf = [0_u8; 1024];
+/// for name in XattrNames::new(listxattr_into("/tmp", &mut buf)?) {
+///     println!("{:?}", name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct XattrNames<'a> {
+    list: &'a [u8],
+}
+
+impl<'a> XattrNames<'a> {
+    /// Construct a new `XattrNames` iterating over the NUL-terminated names
+    /// in `list`.
+    ///
+    /// Any trailing bytes which aren't NUL-terminated are ignored.
+    #[inline]
+    pub const fn new(list: &'a [u8]) -> Self {
+        Self { list }
+    }
+}
+
+impl<'a> Iterator for XattrNames<'a> {
+    type Item = &'a CStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.list.iter().position(|b| *b == b'\0')? + 1;
+        let (name, rest) = self.list.split_at(len);
+        self.list = rest;
+        CStr::from_bytes_with_nul(name).ok()
+    }
+}
+
+impl FusedIterator for XattrNames<'_> {}
+
+impl fmt::Debug for XattrNames<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// View an initialized buffer as possibly uninitialized, for the backend.
+#[allow(unsafe_code)]
+fn as_uninit(buf: &mut [u8]) -> &mut [MaybeUninit<u8>] {
+    // SAFETY: `MaybeUninit<u8>` has the same layout as `u8`, and the
+    // `*getxattr` backend functions only write initialized bytes, so `buf`
+    // stays initialized.
+    unsafe { slice::from_raw_parts_mut(buf.as_mut_ptr().cast(), buf.len()) }
+}
+
+/// View an initialized list buffer as possibly uninitialized bytes, for the
+/// backend.
+#[allow(unsafe_code)]
+fn list_as_uninit(list: &mut [c::c_char]) -> &mut [MaybeUninit<u8>] {
+    // SAFETY: `MaybeUninit<u8>` has the same layout as `c_char`, and the
+    // `*listxattr` backend functions only write initialized bytes, so
+    // `list` stays initialized.
+    unsafe { slice::from_raw_parts_mut(list.as_mut_ptr().cast(), list.len()) }
+}
+
+/// Fill `value` with a `*getxattr` or `*listxattr` function.
+///
+/// With a zero-length buffer, these functions return the size needed rather
+/// than the data, so handle that case separately.
+#[allow(unsafe_code)]
+fn fill_xattr<B: OutputBuffer>(
+    value: B,
+    mut get: impl FnMut(&mut [MaybeUninit<u8>]) -> io::Result<usize>,
+) -> io::Result<B::Bytes> {
+    // SAFETY: The `*getxattr` and `*listxattr` backend functions return the
+    // number of bytes they initialized.
+    unsafe {
+        value.fill_bytes(&mut |buf| {
+            if buf.is_empty() {
+                return match get(buf)? {
+                    0 => Ok(0),
+                    _ => Err(io::Errno::RANGE),
+                };
+            }
+            get(buf)
+        })
+    }
 }
 
 /// `removexattr(path, name)`—Remove an extended filesystem attribute.
@@ -1,8 +1,12 @@
 //! Functions which duplicate file descriptors.
 
 use crate::fd::OwnedFd;
+#[cfg(not(target_os = "wasi"))]
+use crate::fd::{FromRawFd, RawFd};
 use crate::{backend, io};
 use backend::fd::AsFd;
+#[cfg(not(target_os = "wasi"))]
+use core::mem::forget;
 
 #[cfg(not(target_os = "wasi"))]
 pub use backend::io::types::DupFlags;
@@ -90,6 +94,51 @@ pub fn dup2<Fd: AsFd>(fd: Fd, new: &mut OwnedFd) -> io::Result<()> {
     backend::io::syscalls::dup2(fd.as_fd(), new)
 }
 
+/// `dup2(fd, new)`—Like [`dup2`], but takes the target as a raw file
+/// descriptor number, and returns an `OwnedFd` for it.
+///
+/// This is for placing a file descriptor at a specific number, such as after
+/// `fork` when wiring up a child's file descriptors, or when passing sockets
+/// at fixed numbers starting at 3, as with systemd socket activation. `new`
+/// doesn't need to be open beforehand. For stdin, stdout, and stderr, the safe
+/// [`stdio::dup2_stdin`], [`stdio::dup2_stdout`], and [`stdio::dup2_stderr`]
+/// may be used instead.
+///
+/// As with [`dup2`], the returned file descriptor doesn't have the
+/// `O_CLOEXEC` flag set. If `fd` already is `new`, this does nothing, and the
+/// `O_CLOEXEC` flag is left as it is.
+///
+/// # Safety
+///
+/// If `new` is open, it must not be owned by anything else, because it is
+/// silently closed and replaced, and ownership of it is returned to the
+/// caller.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/dup2.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/dup2.2.html
+/// [`stdio::dup2_stdin`]: https://docs.rs/rustix/*/rustix/stdio/fn.dup2_stdin.html
+/// [`stdio::dup2_stdout`]: https://docs.rs/rustix/*/rustix/stdio/fn.dup2_stdout.html
+/// [`stdio::dup2_stderr`]: https://docs.rs/rustix/*/rustix/stdio/fn.dup2_stderr.html
+#[cfg(not(target_os = "wasi"))]
+#[allow(unsafe_code)]
+#[allow(clippy::mem_forget)]
+#[doc(alias = "dup2")]
+pub unsafe fn dup2_raw<Fd: AsFd>(fd: Fd, new: RawFd) -> io::Result<OwnedFd> {
+    let mut target = OwnedFd::from_raw_fd(new);
+    match backend::io::syscalls::dup2(fd.as_fd(), &mut target) {
+        Ok(()) => Ok(target),
+        Err(err) => {
+            // Don't close `new` if we didn't replace it.
+            forget(target);
+            Err(err)
+        }
+    }
+}
+
 /// `dup3(fd, new, flags)`—Changes the [file description] of a file
 /// descriptor, with flags.
 ///
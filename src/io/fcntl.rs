@@ -113,6 +113,9 @@ pub fn fcntl_dupfd_cloexec<Fd: AsFd>(fd: Fd, min: RawFd) -> io::Result<OwnedFd>
 /// as file descriptors may be unexpectedly allocated on other threads or in
 /// libraries.
 ///
+/// Unlike [`fcntl_dupfd_cloexec`], the new file descriptor does not have the
+/// `FD_CLOEXEC` flag set, so it will be inherited by child processes.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -133,7 +136,7 @@ pub fn fcntl_dupfd_cloexec<Fd: AsFd>(fd: Fd, min: RawFd) -> io::Result<OwnedFd>
 /// [DragonFly BSD]: https://man.dragonflybsd.org/?command=fcntl&section=2
 /// [illumos]: https://illumos.org/man/2/fcntl
 /// [glibc]: https://www.gnu.org/software/libc/manual/html_node/Control-Operations.html#index-fcntl-function
-#[cfg(target_os = "espidf")]
+#[cfg(not(target_os = "wasi"))]
 #[inline]
 #[doc(alias = "F_DUPFD")]
 pub fn fcntl_dupfd<Fd: AsFd>(fd: Fd, min: RawFd) -> io::Result<OwnedFd> {
@@ -11,7 +11,20 @@ use backend::fd::AsFd;
 /// Unlike [`is_file_read_write`], this correctly detects whether sockets
 /// have been shutdown, partially or completely.
 ///
+/// For sockets, this does a non-blocking `recv` with `MSG_PEEK` for one byte
+/// to test the read side, and a non-blocking `send` of zero bytes with
+/// `MSG_NOSIGNAL` to test the write side, so it doesn't consume any data or
+/// raise `SIGPIPE`. On Apple platforms, which lack `MSG_NOSIGNAL`, it
+/// temporarily sets `SO_NOSIGPIPE` on the socket instead. TCP sockets which aren't connected, including listening
+/// sockets, are reported as neither readable nor writable, on Linux as well
+/// as on macOS and the BSDs, which report `ENOTCONN` rather than `EPIPE` for
+/// them.
+///
+/// A pending socket error, such as a connection reset, is returned as an
+/// error, and may be cleared, as with [`get_socket_error`].
+///
 /// [`is_file_read_write`]: crate::fs::is_file_read_write
+/// [`get_socket_error`]: crate::net::sockopt::get_socket_error
 #[inline]
 #[cfg_attr(doc_cfg, doc(cfg(all(feature = "fs", feature = "net"))))]
 pub fn is_read_write<Fd: AsFd>(fd: Fd) -> io::Result<(bool, bool)> {
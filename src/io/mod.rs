@@ -2,8 +2,14 @@
 //!
 //! If you're looking for [`SeekFrom`], that's in the [`fs`] module.
 //!
+//! If you're looking for [`epoll`], or other ways of waiting for I/O readiness
+//! such as [`poll`], they're in the [`event`] module.
+//!
 //! [`SeekFrom`]: https://docs.rs/rustix/*/rustix/fs/enum.SeekFrom.html
 //! [`fs`]: https://docs.rs/rustix/*/rustix/fs/index.html
+//! [`epoll`]: https://docs.rs/rustix/*/x86_64-unknown-linux-gnu/rustix/event/epoll/index.html
+//! [`poll`]: https://docs.rs/rustix/*/rustix/event/fn.poll.html
+//! [`event`]: https://docs.rs/rustix/*/rustix/event/index.html
 
 mod close;
 #[cfg(not(windows))]
@@ -59,6 +59,79 @@ pub fn read_uninit<Fd: AsFd>(
     Ok(unsafe { split_init(buf, length?) })
 }
 
+/// Read from `fd` until end-of-file, appending the data to `buf`.
+///
+/// This grows `buf` as needed, reading directly into its spare capacity, and
+/// retries reads interrupted by signal handlers. It returns the number of
+/// bytes read. If an error occurs, the data read before it remains in `buf`.
+///
+/// This is similar to [`std::io::Read::read_to_end`], but doesn't depend on
+/// `std`. Use [`read_to_vec_capped`] to limit how much is read.
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[doc(alias = "read_to_end")]
+pub fn read_to_vec<Fd: AsFd>(fd: Fd, buf: &mut alloc::vec::Vec<u8>) -> io::Result<usize> {
+    read_to_vec_capped(fd, buf, usize::MAX)
+}
+
+/// Read from `fd` until end-of-file or until `limit` bytes have been read,
+/// appending the data to `buf`.
+///
+/// This is like [`read_to_vec`], but stops after reading `limit` bytes, so
+/// that a misbehaving or malicious peer can't cause unbounded allocation.
+/// Reaching the limit isn't an error; if it returns `limit`, there may be more
+/// data to read.
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub fn read_to_vec_capped<Fd: AsFd>(
+    fd: Fd,
+    buf: &mut alloc::vec::Vec<u8>,
+    limit: usize,
+) -> io::Result<usize> {
+    /// The size of the buffer used to check for end-of-file before growing
+    /// `buf`, which avoids reallocating a `buf` that's exactly the right size.
+    const PROBE_SIZE: usize = 32;
+
+    let fd = fd.as_fd();
+    let mut total = 0;
+    while total < limit {
+        let max = limit - total;
+
+        let n = if buf.len() == buf.capacity() {
+            let mut probe = [0_u8; PROBE_SIZE];
+            let len = max.min(PROBE_SIZE);
+            match read(fd, &mut probe[..len]) {
+                Ok(n) => {
+                    buf.extend_from_slice(&probe[..n]);
+                    n
+                }
+                Err(io::Errno::INTR) => continue,
+                Err(err) => return Err(err),
+            }
+        } else {
+            let spare = buf.spare_capacity_mut();
+            let len = max.min(spare.len());
+            match read_uninit(fd, &mut spare[..len]) {
+                Ok((init, _)) => {
+                    let n = init.len();
+                    // SAFETY: `read_uninit` initialized the first `n` bytes
+                    // of the spare capacity.
+                    unsafe { buf.set_len(buf.len() + n) };
+                    n
+                }
+                Err(io::Errno::INTR) => continue,
+                Err(err) => return Err(err),
+            }
+        };
+
+        if n == 0 {
+            break;
+        }
+        total += n;
+    }
+    Ok(total)
+}
+
 /// `write(fd, buf)`—Writes to a stream.
 ///
 /// # References
@@ -86,6 +159,48 @@ pub fn write<Fd: AsFd>(fd: Fd, buf: &[u8]) -> io::Result<usize> {
     backend::io::syscalls::write(fd.as_fd(), buf)
 }
 
+/// Write all of `buf` to `fd`.
+///
+/// This calls [`write()`] repeatedly until all of `buf` has been written,
+/// retrying writes interrupted by signal handlers. If `write` returns 0
+/// without writing anything, this fails with [`io::Errno::IO`].
+///
+/// If an error occurs, an unknown amount of `buf` may have been written.
+#[doc(alias = "write")]
+pub fn write_all<Fd: AsFd>(fd: Fd, mut buf: &[u8]) -> io::Result<()> {
+    let fd = fd.as_fd();
+    while !buf.is_empty() {
+        match write(fd, buf) {
+            Ok(0) => return Err(io::Errno::IO),
+            Ok(n) => buf = &buf[n..],
+            Err(io::Errno::INTR) => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
+/// Read from `fd` until `buf` is full.
+///
+/// This calls [`read`] repeatedly until `buf` has been filled, retrying reads
+/// interrupted by signal handlers. If end-of-file is reached first, this
+/// fails with [`io::Errno::IO`].
+///
+/// If an error occurs, the contents of `buf` are unspecified.
+#[doc(alias = "read")]
+pub fn read_exact<Fd: AsFd>(fd: Fd, mut buf: &mut [u8]) -> io::Result<()> {
+    let fd = fd.as_fd();
+    while !buf.is_empty() {
+        match read(fd, buf) {
+            Ok(0) => return Err(io::Errno::IO),
+            Ok(n) => buf = &mut buf[n..],
+            Err(io::Errno::INTR) => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
 /// `pread(fd, buf, offset)`—Reads from a file at a given position.
 ///
 /// # References
@@ -209,6 +324,109 @@ pub fn writev<Fd: AsFd>(fd: Fd, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
     backend::io::syscalls::writev(fd.as_fd(), bufs)
 }
 
+/// Write all of the data in `bufs` to `fd`.
+///
+/// This calls [`writev`] repeatedly until all of the data has been written,
+/// retrying writes interrupted by signal handlers. `bufs` is used to track
+/// progress, so its contents are unspecified afterwards. If `writev` returns
+/// 0 without writing anything, this fails with [`io::Errno::IO`].
+#[cfg(not(any(target_os = "espidf", target_os = "horizon")))]
+#[doc(alias = "writev")]
+pub fn write_all_vectored<Fd: AsFd>(fd: Fd, mut bufs: &mut [IoSlice<'_>]) -> io::Result<()> {
+    let fd = fd.as_fd();
+
+    // Skip any leading empty buffers.
+    advance_slices(&mut bufs, 0);
+    while !bufs.is_empty() {
+        match writev(fd, bufs) {
+            Ok(0) => return Err(io::Errno::IO),
+            Ok(n) => advance_slices(&mut bufs, n),
+            Err(io::Errno::INTR) => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
+/// Read from `fd` until all of the buffers in `bufs` are full.
+///
+/// This calls [`readv`] repeatedly until all of the buffers have been filled,
+/// retrying reads interrupted by signal handlers. `bufs` is used to track
+/// progress, so its contents, though not the data in the buffers, are
+/// unspecified afterwards. If end-of-file is reached first, this fails with
+/// [`io::Errno::IO`].
+#[cfg(not(any(target_os = "espidf", target_os = "horizon")))]
+#[doc(alias = "readv")]
+pub fn read_exact_vectored<Fd: AsFd>(fd: Fd, mut bufs: &mut [IoSliceMut<'_>]) -> io::Result<()> {
+    let fd = fd.as_fd();
+
+    // Skip any leading empty buffers.
+    advance_slices_mut(&mut bufs, 0);
+    while !bufs.is_empty() {
+        match readv(fd, bufs) {
+            Ok(0) => return Err(io::Errno::IO),
+            Ok(n) => advance_slices_mut(&mut bufs, n),
+            Err(io::Errno::INTR) => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
+/// Advance `bufs` past the first `n` bytes of data, removing buffers which
+/// are fully consumed, including any empty buffers at the new start.
+///
+/// This is like `IoSlice::advance_slices`, which is newer than our MSRV.
+#[cfg(not(any(target_os = "espidf", target_os = "horizon")))]
+fn advance_slices<'a>(bufs: &mut &mut [IoSlice<'a>], n: usize) {
+    let mut left = n;
+    let mut remove = 0;
+    for buf in bufs.iter() {
+        if buf.len() > left {
+            break;
+        }
+        left -= buf.len();
+        remove += 1;
+    }
+
+    *bufs = &mut core::mem::take(bufs)[remove..];
+    match bufs.first_mut() {
+        // SAFETY: `first` refers to data borrowed for `'a`, and `left` is
+        // less than its length.
+        Some(first) => unsafe {
+            let rest = core::slice::from_raw_parts(first.as_ptr().add(left), first.len() - left);
+            *first = IoSlice::new(rest);
+        },
+        None => assert_eq!(left, 0, "advancing io slices beyond their length"),
+    }
+}
+
+/// Like [`advance_slices`], but for `IoSliceMut`.
+#[cfg(not(any(target_os = "espidf", target_os = "horizon")))]
+fn advance_slices_mut<'a>(bufs: &mut &mut [IoSliceMut<'a>], n: usize) {
+    let mut left = n;
+    let mut remove = 0;
+    for buf in bufs.iter() {
+        if buf.len() > left {
+            break;
+        }
+        left -= buf.len();
+        remove += 1;
+    }
+
+    *bufs = &mut core::mem::take(bufs)[remove..];
+    match bufs.first_mut() {
+        // SAFETY: `first` refers to data exclusively borrowed for `'a`, and
+        // `left` is less than its length.
+        Some(first) => unsafe {
+            let rest =
+                core::slice::from_raw_parts_mut(first.as_mut_ptr().add(left), first.len() - left);
+            *first = IoSliceMut::new(rest);
+        },
+        None => assert_eq!(left, 0, "advancing io slices beyond their length"),
+    }
+}
+
 /// `preadv(fd, bufs, offset)`—Reads from a file at a given position into
 /// multiple buffers.
 ///
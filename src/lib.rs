@@ -281,6 +281,9 @@ pub mod thread;
 #[cfg(feature = "time")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "time")))]
 pub mod time;
+#[cfg(feature = "unsafe_fd")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "unsafe_fd")))]
+pub mod unsafe_fd;
 
 // "runtime" is also a public API module, but it's only for libc-like users.
 #[cfg(not(windows))]
@@ -338,13 +341,24 @@ pub(crate) mod path;
 mod clockid;
 #[cfg(not(any(windows, target_os = "wasi")))]
 #[cfg(any(
+    feature = "fs",
     feature = "procfs",
     feature = "process",
     feature = "runtime",
     feature = "termios",
     feature = "thread",
     all(bsd, feature = "event"),
-    all(linux_kernel, feature = "net")
+    all(
+        any(
+            linux_kernel,
+            apple,
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ),
+        feature = "net"
+    ),
+    all(linux_raw, any(feature = "time", target_arch = "x86"))
 ))]
 mod pid;
 #[cfg(any(feature = "process", feature = "thread"))]
@@ -360,6 +374,7 @@ mod signal;
     feature = "runtime",
     feature = "thread",
     feature = "time",
+    all(linux_kernel, feature = "event"),
     all(
         linux_raw,
         not(feature = "use-libc-auxv"),
@@ -390,6 +405,15 @@ mod timespec;
             target_arch = "x86",
         )
     ),
-    all(linux_kernel, feature = "net")
+    all(
+        any(
+            linux_kernel,
+            apple,
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ),
+        feature = "net"
+    )
 ))]
 mod ugid;
@@ -0,0 +1,91 @@
+//! Generic netlink (genetlink) helpers.
+//!
+//! Generic netlink multiplexes many kernel interfaces, such as taskstats,
+//! WireGuard, and nl80211, over a single [`netlink::GENERIC`] protocol. Each
+//! interface is a "family" with a dynamically assigned id, which must be
+//! looked up by name before messages can be sent to it.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn main() -> rustix::io::Result<()> {
+//! use rustix::net::{genetlink, netlink, socket, AddressFamily, SocketType};
+//!
+//! let fd = socket(AddressFamily::NETLINK, SocketType::RAW, Some(netlink::GENERIC))?;
+//! let taskstats = genetlink::resolve_family(&fd, "TASKSTATS")?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [`netlink::GENERIC`]: crate::net::netlink::GENERIC
+//! [Linux]: https://docs.kernel.org/userspace-api/netlink/intro.html#generic-netlink
+
+use crate::fd::AsFd;
+use crate::io;
+use crate::net::nlmsg::{self, NLA_HDRLEN};
+
+// Constants from `<linux/genetlink.h>`, which isn't in the version of
+// linux-raw-sys we use.
+const GENL_HDRLEN: usize = 4;
+const GENL_NAMSIZ: usize = 16;
+const GENL_ID_CTRL: u16 = 0x10;
+const CTRL_CMD_GETFAMILY: u8 = 3;
+const CTRL_ATTR_FAMILY_ID: u16 = 1;
+const CTRL_ATTR_FAMILY_NAME: u16 = 2;
+
+/// The id of the `nlctrl` family, which is used to resolve other families.
+pub const ID_CTRL: u16 = GENL_ID_CTRL;
+
+/// Send a `CTRL_CMD_GETFAMILY` request on the [`netlink::GENERIC`] socket
+/// `fd`, and return the id of the family named `name`.
+///
+/// Fails with [`io::Errno::NOENT`] if the family isn't registered, which can
+/// happen if the module providing it hasn't been loaded, and with
+/// [`io::Errno::INVAL`] if `name` is too long to be a family name.
+///
+/// # References
+///  - [Linux]
+///
+/// [`netlink::GENERIC`]: crate::net::netlink::GENERIC
+/// [Linux]: https://docs.kernel.org/networking/generic_netlink.html
+#[doc(alias = "CTRL_CMD_GETFAMILY")]
+pub fn resolve_family<Fd: AsFd>(fd: Fd, name: &str) -> io::Result<u16> {
+    let fd = fd.as_fd();
+    let name = name.as_bytes();
+    if name.len() >= GENL_NAMSIZ || name.contains(&b'\0') {
+        return Err(io::Errno::INVAL);
+    }
+
+    // The request is a `genlmsghdr` followed by a NUL-terminated
+    // `CTRL_ATTR_FAMILY_NAME` attribute.
+    let mut req = [0_u8; GENL_HDRLEN + NLA_HDRLEN + GENL_NAMSIZ];
+    let attr_len = NLA_HDRLEN + name.len() + 1;
+    req[0] = CTRL_CMD_GETFAMILY;
+    req[1] = 1;
+    req[4..6].copy_from_slice(&(attr_len as u16).to_ne_bytes());
+    req[6..8].copy_from_slice(&CTRL_ATTR_FAMILY_NAME.to_ne_bytes());
+    req[8..8 + name.len()].copy_from_slice(name);
+    nlmsg::send_request(
+        fd,
+        GENL_ID_CTRL,
+        0,
+        &req[..GENL_HDRLEN + nlmsg::align(attr_len)],
+    )?;
+
+    nlmsg::recv_replies(fd, |msg_type, payload| {
+        if msg_type != GENL_ID_CTRL {
+            return Ok(None);
+        }
+        nlmsg::find_attr(
+            payload.get(GENL_HDRLEN..).unwrap_or(&[]),
+            CTRL_ATTR_FAMILY_ID,
+        )
+        .filter(|id| id.len() >= 2)
+        .map(|id| Some(nlmsg::read_u16(id)))
+        .ok_or(io::Errno::IO)
+    })?
+    .ok_or(io::Errno::IO)
+}
@@ -7,17 +7,27 @@
 //! [`wsa_startup`]: https://docs.rs/rustix/*/x86_64-pc-windows-msvc/rustix/net/fn.wsa_startup.html
 //! [`wsa_cleanup`]: https://docs.rs/rustix/*/x86_64-pc-windows-msvc/rustix/net/fn.wsa_cleanup.html
 
+#[cfg(linux_kernel)]
+mod nlmsg;
 mod send_recv;
 mod socket;
 mod socket_addr_any;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod socketpair;
 mod types;
+#[cfg(linux_kernel)]
+mod uevent;
 #[cfg(windows)]
 mod wsa;
 
+#[cfg(linux_kernel)]
+pub mod genetlink;
 #[cfg(linux_kernel)]
 pub mod netdevice;
+#[cfg(linux_kernel)]
+pub mod rtnetlink;
+#[cfg(linux_kernel)]
+pub mod sock_diag;
 pub mod sockopt;
 
 pub use crate::maybe_polyfill::net::{
@@ -25,7 +35,7 @@ pub use crate::maybe_polyfill::net::{
 };
 pub use send_recv::*;
 pub use socket::*;
-pub use socket_addr_any::{SocketAddrAny, SocketAddrStorage};
+pub use socket_addr_any::{SocketAddrAny, SocketAddrBuf, SocketAddrRaw, SocketAddrStorage};
 #[cfg(not(any(windows, target_os = "wasi")))]
 pub use socketpair::socketpair;
 pub use types::*;
@@ -0,0 +1,284 @@
+//! Framing for netlink request and reply messages.
+//!
+//! This implements just enough of `<linux/netlink.h>` to send a request to
+//! the kernel and walk the replies, for use by [`genetlink`], [`rtnetlink`],
+//! and [`sock_diag`].
+//!
+//! [`genetlink`]: crate::net::genetlink
+//! [`rtnetlink`]: crate::net::rtnetlink
+//! [`sock_diag`]: crate::net::sock_diag
+
+use crate::fd::BorrowedFd;
+use crate::io::{self, IoSlice};
+use crate::net::{recv, recv_datagram_len, sendmsg, RecvFlags, SendAncillaryBuffer, SendFlags};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::mem::size_of;
+use linux_raw_sys::netlink::{self, nlattr, nlmsghdr};
+
+pub(crate) const NLMSG_HDRLEN: usize = align(size_of::<nlmsghdr>());
+pub(crate) const NLM_F_REQUEST: u16 = netlink::NLM_F_REQUEST as u16;
+pub(crate) const NLM_F_ACK: u16 = netlink::NLM_F_ACK as u16;
+pub(crate) const NLM_F_REPLACE: u16 = netlink::NLM_F_REPLACE as u16;
+pub(crate) const NLM_F_EXCL: u16 = netlink::NLM_F_EXCL as u16;
+pub(crate) const NLM_F_CREATE: u16 = netlink::NLM_F_CREATE as u16;
+pub(crate) const NLM_F_DUMP: u16 = netlink::NLM_F_DUMP as u16;
+pub(crate) const NLMSG_ERROR: u16 = netlink::NLMSG_ERROR as u16;
+pub(crate) const NLMSG_DONE: u16 = netlink::NLMSG_DONE as u16;
+pub(crate) const NLA_HDRLEN: usize = align(size_of::<nlattr>());
+const NLA_TYPE_MASK: u16 = netlink::NLA_TYPE_MASK as u16;
+
+/// The sequence number used for requests sent by this crate.
+const SEQ: u32 = 1;
+
+/// The size of the buffer replies are usually received into.
+///
+/// The kernel limits most dump replies to a page or to 8 KiB, but replies
+/// with large attributes can exceed that, so [`recv_replies`] checks each
+/// reply's length before receiving it.
+const RECV_BUFFER_SIZE: usize = 8192;
+
+/// Send a request message of type `msg_type` to the kernel, with the
+/// `NLM_F_REQUEST` flag and any extra `flags`.
+///
+/// `payload` should already be padded to a multiple of 4 bytes.
+pub(crate) fn send_request(
+    fd: BorrowedFd<'_>,
+    msg_type: u16,
+    flags: u16,
+    payload: &[u8],
+) -> io::Result<()> {
+    let mut header = [0_u8; NLMSG_HDRLEN];
+    header[0..4].copy_from_slice(&((NLMSG_HDRLEN + payload.len()) as u32).to_ne_bytes());
+    header[4..6].copy_from_slice(&msg_type.to_ne_bytes());
+    header[6..8].copy_from_slice(&(NLM_F_REQUEST | flags).to_ne_bytes());
+    header[8..12].copy_from_slice(&SEQ.to_ne_bytes());
+
+    sendmsg(
+        fd,
+        &[IoSlice::new(&header), IoSlice::new(payload)],
+        &mut SendAncillaryBuffer::default(),
+        SendFlags::empty(),
+    )?;
+    Ok(())
+}
+
+/// Receive the replies to a request sent with [`send_request`], calling `f`
+/// with the type and payload of each message.
+///
+/// This returns the first `Some` returned by `f`, or `None` when the kernel
+/// ends a dump with `NLMSG_DONE`. An `NLMSG_ERROR` reply is returned as the
+/// error it contains.
+///
+/// Replies larger than [`RECV_BUFFER_SIZE`] are received into a heap buffer
+/// if the `alloc` feature is enabled, and fail with [`io::Errno::MSGSIZE`]
+/// otherwise, rather than being truncated.
+pub(crate) fn recv_replies<T>(
+    fd: BorrowedFd<'_>,
+    mut f: impl FnMut(u16, &[u8]) -> io::Result<Option<T>>,
+) -> io::Result<Option<T>> {
+    let mut stack_buf = [0_u8; RECV_BUFFER_SIZE];
+    #[cfg(feature = "alloc")]
+    let mut heap_buf = Vec::new();
+    loop {
+        // Peek at the length of the next reply, so that we never receive it
+        // into a buffer which is too small for it.
+        let len = recv_datagram_len(fd, RecvFlags::empty())?;
+        let buf: &mut [u8] = if len <= stack_buf.len() {
+            &mut stack_buf
+        } else {
+            #[cfg(feature = "alloc")]
+            {
+                heap_buf.resize(len, 0);
+                &mut heap_buf
+            }
+            #[cfg(not(feature = "alloc"))]
+            return Err(io::Errno::MSGSIZE);
+        };
+        let n = recv(fd, buf, RecvFlags::empty())?;
+        let mut msgs = &buf[..n];
+
+        while msgs.len() >= NLMSG_HDRLEN {
+            let msg_len = read_u32(&msgs[0..4]) as usize;
+            let msg_type = read_u16(&msgs[4..6]);
+            let seq = read_u32(&msgs[8..12]);
+            if msg_len < NLMSG_HDRLEN || msg_len > msgs.len() {
+                return Err(io::Errno::IO);
+            }
+
+            let payload = &msgs[NLMSG_HDRLEN..msg_len];
+            if seq == SEQ {
+                match msg_type {
+                    NLMSG_ERROR | NLMSG_DONE => {
+                        // Both carry an `int` error, which is 0 for an
+                        // acknowledgement or the successful end of a dump.
+                        let error = payload.get(0..4).map_or(0, |e| read_u32(e) as i32);
+                        return if error < 0 {
+                            Err(io::Errno::from_raw_os_error(-error))
+                        } else {
+                            Ok(None)
+                        };
+                    }
+                    _ => {
+                        if let Some(result) = f(msg_type, payload)? {
+                            return Ok(Some(result));
+                        }
+                    }
+                }
+            }
+
+            msgs = msgs.get(align(msg_len)..).unwrap_or(&[]);
+        }
+    }
+}
+
+/// Receive the replies to a dump request sent with [`send_request`], calling
+/// `f` with the type and payload of each message until `NLMSG_DONE`.
+///
+/// If `f` fails, the rest of the dump is still received and discarded before
+/// the error is returned, so that it isn't mistaken for the replies to a
+/// later request on `fd`.
+pub(crate) fn recv_dump(
+    fd: BorrowedFd<'_>,
+    mut f: impl FnMut(u16, &[u8]) -> io::Result<()>,
+) -> io::Result<()> {
+    let mut result = Ok(());
+    let done = recv_replies(fd, |msg_type, payload| {
+        if result.is_ok() {
+            result = f(msg_type, payload);
+        }
+        Ok(None::<()>)
+    });
+    result?;
+    done?;
+    Ok(())
+}
+
+/// A writer for a request payload, in a fixed-size buffer.
+pub(crate) struct Writer<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> Writer<'a> {
+    /// Construct a writer which writes into `buf`, which must be large enough
+    /// for the whole payload.
+    pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Append `bytes`, padded to a multiple of 4 bytes.
+    pub(crate) fn push(&mut self, bytes: &[u8]) {
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len = align(self.len + bytes.len());
+    }
+
+    /// Append an `nlattr` of type `attr_type` containing `payload`.
+    pub(crate) fn push_attr(&mut self, attr_type: u16, payload: &[u8]) {
+        let mut header = [0_u8; NLA_HDRLEN];
+        header[0..2].copy_from_slice(&((NLA_HDRLEN + payload.len()) as u16).to_ne_bytes());
+        header[2..4].copy_from_slice(&attr_type.to_ne_bytes());
+        self.push(&header);
+        self.push(payload);
+    }
+
+    /// Return the payload written.
+    pub(crate) fn into_bytes(self) -> &'a [u8] {
+        &self.buf[..self.len]
+    }
+}
+
+/// An iterator over the `(type, payload)` pairs of a sequence of `nlattr`s.
+///
+/// Iteration stops at the first malformed attribute.
+#[derive(Clone)]
+pub(crate) struct Attrs<'a>(pub(crate) &'a [u8]);
+
+impl<'a> Iterator for Attrs<'a> {
+    type Item = (u16, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.len() < NLA_HDRLEN {
+            return None;
+        }
+        let len = read_u16(&self.0[0..2]) as usize;
+        let attr_type = read_u16(&self.0[2..4]) & NLA_TYPE_MASK;
+        if len < NLA_HDRLEN || len > self.0.len() {
+            self.0 = &[];
+            return None;
+        }
+        let payload = &self.0[NLA_HDRLEN..len];
+        self.0 = self.0.get(align(len)..).unwrap_or(&[]);
+        Some((attr_type, payload))
+    }
+}
+
+/// Find the payload of the attribute of type `attr_type`.
+pub(crate) fn find_attr(attrs: &[u8], attr_type: u16) -> Option<&[u8]> {
+    Attrs(attrs)
+        .find(|(type_, _payload)| *type_ == attr_type)
+        .map(|(_type, payload)| payload)
+}
+
+/// Round `len` up to the 4-byte alignment of netlink messages and attributes.
+pub(crate) const fn align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+/// Read a native-endian `u16` from the start of `bytes`.
+pub(crate) fn read_u16(bytes: &[u8]) -> u16 {
+    u16::from_ne_bytes(bytes[..2].try_into().unwrap())
+}
+
+/// Read a native-endian `u32` from the start of `bytes`.
+pub(crate) fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_ne_bytes(bytes[..4].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fd::AsFd;
+    use crate::net::{send, socketpair, AddressFamily, SocketFlags, SocketType};
+
+    fn push_msg(fd: BorrowedFd<'_>, msg_type: u16, payload: &[u8]) {
+        let mut msg = [0_u8; NLMSG_HDRLEN + 8];
+        let len = NLMSG_HDRLEN + payload.len();
+        msg[0..4].copy_from_slice(&(len as u32).to_ne_bytes());
+        msg[4..6].copy_from_slice(&msg_type.to_ne_bytes());
+        msg[8..12].copy_from_slice(&SEQ.to_ne_bytes());
+        msg[NLMSG_HDRLEN..len].copy_from_slice(payload);
+        send(fd, &msg[..len], SendFlags::empty()).unwrap();
+    }
+
+    #[test]
+    fn test_recv_dump_drains_after_error() {
+        // Stand in for the kernel with a datagram socket pair.
+        let (kernel, fd) = socketpair(
+            AddressFamily::UNIX,
+            SocketType::DGRAM,
+            SocketFlags::CLOEXEC | SocketFlags::NONBLOCK,
+            None,
+        )
+        .unwrap();
+        let fd = fd.as_fd();
+        push_msg(kernel.as_fd(), 16, &[1, 0, 0, 0]);
+        push_msg(kernel.as_fd(), 16, &[2, 0, 0, 0]);
+        push_msg(kernel.as_fd(), NLMSG_DONE, &[0, 0, 0, 0]);
+
+        let mut seen = Vec::new();
+        let result = recv_dump(fd, |msg_type, payload| {
+            assert_eq!(msg_type, 16);
+            seen.push(payload[0]);
+            Err(io::Errno::IO)
+        });
+        assert_eq!(result, Err(io::Errno::IO));
+        assert_eq!(seen, [1]);
+
+        // The rest of the dump, including `NLMSG_DONE`, was consumed.
+        assert_eq!(
+            recv_datagram_len(fd, RecvFlags::empty()),
+            Err(io::Errno::WOULDBLOCK)
+        );
+    }
+}
@@ -0,0 +1,389 @@
+//! Routing table manipulation with `NETLINK_ROUTE`.
+//!
+//! This implements the `RTM_NEWROUTE`, `RTM_DELROUTE`, and `RTM_GETROUTE`
+//! messages, which let programs such as VPN daemons add and remove routes
+//! without running `ip route`. Requests are sent on a netlink socket opened
+//! with a protocol of `None`, because `NETLINK_ROUTE` is 0. Modifying routes
+//! requires the `CAP_NET_ADMIN` capability.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn main() -> rustix::io::Result<()> {
+//! use rustix::net::rtnetlink::{self, Route};
+//! use rustix::net::{socket, AddressFamily, Ipv4Addr, SocketType};
+//!
+//! let fd = socket(AddressFamily::NETLINK, SocketType::RAW, None)?;
+//!
+//! // Route 10.8.0.0/16 through interface 7.
+//! let mut route = Route::new(Ipv4Addr::new(10, 8, 0, 0).into(), 16);
+//! route.output_interface = Some(7);
+//! rtnetlink::add_route(&fd, &route)?;
+//!
+//! // Ask the kernel which route it would use for 10.8.1.1.
+//! let chosen = rtnetlink::get_route(&fd, Ipv4Addr::new(10, 8, 1, 1).into())?;
+//! assert_eq!(chosen.output_interface, Some(7));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man7/rtnetlink.7.html
+
+use crate::fd::{AsFd, BorrowedFd};
+use crate::io;
+use crate::net::nlmsg::{
+    self, Attrs, Writer, NLA_HDRLEN, NLM_F_ACK, NLM_F_CREATE, NLM_F_DUMP, NLM_F_EXCL, NLM_F_REPLACE,
+};
+use crate::net::{AddressFamily, IpAddr, Ipv4Addr, Ipv6Addr};
+use core::mem::size_of;
+use linux_raw_sys::netlink::{self, rt_class_t, rt_scope_t, rtattr_type_t};
+
+const RTM_NEWROUTE: u16 = netlink::RTM_NEWROUTE as u16;
+const RTM_DELROUTE: u16 = netlink::RTM_DELROUTE as u16;
+const RTM_GETROUTE: u16 = netlink::RTM_GETROUTE as u16;
+const RTMSG_LEN: usize = size_of::<netlink::rtmsg>();
+const RTA_DST: u16 = rtattr_type_t::RTA_DST as u16;
+const RTA_SRC: u16 = rtattr_type_t::RTA_SRC as u16;
+const RTA_IIF: u16 = rtattr_type_t::RTA_IIF as u16;
+const RTA_OIF: u16 = rtattr_type_t::RTA_OIF as u16;
+const RTA_GATEWAY: u16 = rtattr_type_t::RTA_GATEWAY as u16;
+const RTA_PRIORITY: u16 = rtattr_type_t::RTA_PRIORITY as u16;
+const RTA_PREFSRC: u16 = rtattr_type_t::RTA_PREFSRC as u16;
+const RTA_TABLE: u16 = rtattr_type_t::RTA_TABLE as u16;
+const RT_TABLE_COMPAT: u8 = rt_class_t::RT_TABLE_COMPAT as u8;
+
+/// `RT_TABLE_UNSPEC`
+pub const RT_TABLE_UNSPEC: u32 = rt_class_t::RT_TABLE_UNSPEC as u32;
+/// `RT_TABLE_DEFAULT`
+pub const RT_TABLE_DEFAULT: u32 = rt_class_t::RT_TABLE_DEFAULT as u32;
+/// `RT_TABLE_MAIN`
+pub const RT_TABLE_MAIN: u32 = rt_class_t::RT_TABLE_MAIN as u32;
+/// `RT_TABLE_LOCAL`
+pub const RT_TABLE_LOCAL: u32 = rt_class_t::RT_TABLE_LOCAL as u32;
+
+/// `RTPROT_UNSPEC`
+pub const RTPROT_UNSPEC: u8 = netlink::RTPROT_UNSPEC as u8;
+/// `RTPROT_REDIRECT`
+pub const RTPROT_REDIRECT: u8 = netlink::RTPROT_REDIRECT as u8;
+/// `RTPROT_KERNEL`
+pub const RTPROT_KERNEL: u8 = netlink::RTPROT_KERNEL as u8;
+/// `RTPROT_BOOT`
+pub const RTPROT_BOOT: u8 = netlink::RTPROT_BOOT as u8;
+/// `RTPROT_STATIC`
+pub const RTPROT_STATIC: u8 = netlink::RTPROT_STATIC as u8;
+
+/// `RT_SCOPE_UNIVERSE`
+pub const RT_SCOPE_UNIVERSE: u8 = rt_scope_t::RT_SCOPE_UNIVERSE as u8;
+/// `RT_SCOPE_SITE`
+pub const RT_SCOPE_SITE: u8 = rt_scope_t::RT_SCOPE_SITE as u8;
+/// `RT_SCOPE_LINK`
+pub const RT_SCOPE_LINK: u8 = rt_scope_t::RT_SCOPE_LINK as u8;
+/// `RT_SCOPE_HOST`
+pub const RT_SCOPE_HOST: u8 = rt_scope_t::RT_SCOPE_HOST as u8;
+/// `RT_SCOPE_NOWHERE`
+pub const RT_SCOPE_NOWHERE: u8 = rt_scope_t::RT_SCOPE_NOWHERE as u8;
+
+/// `RTN_UNSPEC`
+pub const RTN_UNSPEC: u8 = netlink::RTN_UNSPEC as u8;
+/// `RTN_UNICAST`
+pub const RTN_UNICAST: u8 = netlink::RTN_UNICAST as u8;
+/// `RTN_LOCAL`
+pub const RTN_LOCAL: u8 = netlink::RTN_LOCAL as u8;
+/// `RTN_BROADCAST`
+pub const RTN_BROADCAST: u8 = netlink::RTN_BROADCAST as u8;
+/// `RTN_ANYCAST`
+pub const RTN_ANYCAST: u8 = netlink::RTN_ANYCAST as u8;
+/// `RTN_MULTICAST`
+pub const RTN_MULTICAST: u8 = netlink::RTN_MULTICAST as u8;
+/// `RTN_BLACKHOLE`
+pub const RTN_BLACKHOLE: u8 = netlink::RTN_BLACKHOLE as u8;
+/// `RTN_UNREACHABLE`
+pub const RTN_UNREACHABLE: u8 = netlink::RTN_UNREACHABLE as u8;
+/// `RTN_PROHIBIT`
+pub const RTN_PROHIBIT: u8 = netlink::RTN_PROHIBIT as u8;
+
+/// A route, as described by a `struct rtmsg` and its `RTA_*` attributes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[doc(alias = "rtmsg")]
+#[non_exhaustive]
+pub struct Route {
+    /// The address family, [`AddressFamily::INET`] or
+    /// [`AddressFamily::INET6`].
+    pub family: AddressFamily,
+    /// `RTA_DST`—The destination prefix, or `None` for a default route.
+    pub destination: Option<IpAddr>,
+    /// The length of the destination prefix, in bits.
+    pub destination_len: u8,
+    /// `RTA_SRC`—The source prefix, for policy routing.
+    pub source: Option<IpAddr>,
+    /// The length of the source prefix, in bits.
+    pub source_len: u8,
+    /// `RTA_GATEWAY`—The next hop.
+    pub gateway: Option<IpAddr>,
+    /// `RTA_OIF`—The index of the output interface.
+    pub output_interface: Option<u32>,
+    /// `RTA_IIF`—The index of the input interface.
+    pub input_interface: Option<u32>,
+    /// `RTA_PREFSRC`—The preferred source address for packets sent using
+    /// this route.
+    pub preferred_source: Option<IpAddr>,
+    /// `RTA_PRIORITY`—The route's metric.
+    pub priority: Option<u32>,
+    /// `RTA_TABLE`—The routing table, such as [`RT_TABLE_MAIN`].
+    pub table: u32,
+    /// The origin of the route, such as [`RTPROT_STATIC`].
+    pub protocol: u8,
+    /// The distance to the destination, such as [`RT_SCOPE_LINK`].
+    pub scope: u8,
+    /// The kind of route, such as [`RTN_UNICAST`].
+    pub type_: u8,
+    /// The type of service.
+    pub tos: u8,
+    /// `RTM_F_*` flags.
+    pub flags: u32,
+}
+
+impl Route {
+    /// Construct a unicast route to `destination/destination_len` in
+    /// [`RT_TABLE_MAIN`], with no gateway or interface.
+    ///
+    /// The route's protocol is [`RTPROT_BOOT`], as with `ip route add`.
+    pub const fn new(destination: IpAddr, destination_len: u8) -> Self {
+        Self {
+            family: match destination {
+                IpAddr::V4(_) => AddressFamily::INET,
+                IpAddr::V6(_) => AddressFamily::INET6,
+            },
+            destination: Some(destination),
+            destination_len,
+            source: None,
+            source_len: 0,
+            gateway: None,
+            output_interface: None,
+            input_interface: None,
+            preferred_source: None,
+            priority: None,
+            table: RT_TABLE_MAIN,
+            protocol: RTPROT_BOOT,
+            scope: RT_SCOPE_UNIVERSE,
+            type_: RTN_UNICAST,
+            tos: 0,
+            flags: 0,
+        }
+    }
+
+    fn encode<'a>(&self, buf: &'a mut [u8; RTMSG_LEN + 7 * (NLA_HDRLEN + 16)]) -> &'a [u8] {
+        let mut rtmsg = [0_u8; RTMSG_LEN];
+        rtmsg[0] = self.family.as_raw() as u8;
+        rtmsg[1] = self.destination_len;
+        rtmsg[2] = self.source_len;
+        rtmsg[3] = self.tos;
+        rtmsg[4] = if self.table < 256 {
+            self.table as u8
+        } else {
+            RT_TABLE_COMPAT
+        };
+        rtmsg[5] = self.protocol;
+        rtmsg[6] = self.scope;
+        rtmsg[7] = self.type_;
+        rtmsg[8..12].copy_from_slice(&self.flags.to_ne_bytes());
+
+        let mut writer = Writer::new(buf);
+        writer.push(&rtmsg);
+        push_addr_attr(&mut writer, RTA_DST, self.destination);
+        push_addr_attr(&mut writer, RTA_SRC, self.source);
+        push_addr_attr(&mut writer, RTA_GATEWAY, self.gateway);
+        push_addr_attr(&mut writer, RTA_PREFSRC, self.preferred_source);
+        push_u32_attr(&mut writer, RTA_OIF, self.output_interface);
+        push_u32_attr(&mut writer, RTA_IIF, self.input_interface);
+        push_u32_attr(&mut writer, RTA_PRIORITY, self.priority);
+        push_u32_attr(&mut writer, RTA_TABLE, Some(self.table));
+        writer.into_bytes()
+    }
+
+    fn parse(payload: &[u8]) -> io::Result<Self> {
+        if payload.len() < RTMSG_LEN {
+            return Err(io::Errno::IO);
+        }
+
+        let mut route = Self {
+            family: AddressFamily::from_raw(payload[0].into()),
+            destination: None,
+            destination_len: payload[1],
+            source: None,
+            source_len: payload[2],
+            gateway: None,
+            output_interface: None,
+            input_interface: None,
+            preferred_source: None,
+            priority: None,
+            tos: payload[3],
+            table: payload[4].into(),
+            protocol: payload[5],
+            scope: payload[6],
+            type_: payload[7],
+            flags: nlmsg::read_u32(&payload[8..]),
+        };
+
+        for (attr_type, value) in Attrs(&payload[RTMSG_LEN..]) {
+            match attr_type {
+                RTA_DST => route.destination = read_addr(value),
+                RTA_SRC => route.source = read_addr(value),
+                RTA_GATEWAY => route.gateway = read_addr(value),
+                RTA_PREFSRC => route.preferred_source = read_addr(value),
+                RTA_OIF => route.output_interface = read_u32_attr(value),
+                RTA_IIF => route.input_interface = read_u32_attr(value),
+                RTA_PRIORITY => route.priority = read_u32_attr(value),
+                RTA_TABLE => {
+                    if let Some(table) = read_u32_attr(value) {
+                        route.table = table;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(route)
+    }
+}
+
+/// `RTM_NEWROUTE` with `NLM_F_CREATE | NLM_F_EXCL`—Adds a route.
+///
+/// Fails with [`io::Errno::EXIST`] if an identical route already exists.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/rtnetlink.7.html
+#[doc(alias = "RTM_NEWROUTE")]
+pub fn add_route<Fd: AsFd>(fd: Fd, route: &Route) -> io::Result<()> {
+    modify_route(fd.as_fd(), RTM_NEWROUTE, NLM_F_CREATE | NLM_F_EXCL, route)
+}
+
+/// `RTM_NEWROUTE` with `NLM_F_CREATE | NLM_F_REPLACE`—Adds a route, or
+/// replaces an existing route to the same destination.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/rtnetlink.7.html
+#[doc(alias = "RTM_NEWROUTE")]
+pub fn replace_route<Fd: AsFd>(fd: Fd, route: &Route) -> io::Result<()> {
+    modify_route(
+        fd.as_fd(),
+        RTM_NEWROUTE,
+        NLM_F_CREATE | NLM_F_REPLACE,
+        route,
+    )
+}
+
+/// `RTM_DELROUTE`—Deletes a route.
+///
+/// Fails with [`io::Errno::SRCH`] if no matching route exists.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/rtnetlink.7.html
+#[doc(alias = "RTM_DELROUTE")]
+pub fn delete_route<Fd: AsFd>(fd: Fd, route: &Route) -> io::Result<()> {
+    modify_route(fd.as_fd(), RTM_DELROUTE, 0, route)
+}
+
+/// `RTM_GETROUTE`—Returns the route the kernel would use to reach
+/// `destination`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/rtnetlink.7.html
+#[doc(alias = "RTM_GETROUTE")]
+pub fn get_route<Fd: AsFd>(fd: Fd, destination: IpAddr) -> io::Result<Route> {
+    let fd = fd.as_fd();
+    let mut query = Route::new(
+        destination,
+        match destination {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        },
+    );
+    query.table = RT_TABLE_UNSPEC;
+    query.protocol = RTPROT_UNSPEC;
+    query.type_ = RTN_UNSPEC;
+
+    let mut buf = [0_u8; RTMSG_LEN + 7 * (NLA_HDRLEN + 16)];
+    nlmsg::send_request(fd, RTM_GETROUTE, 0, query.encode(&mut buf))?;
+    nlmsg::recv_replies(fd, |msg_type, payload| {
+        if msg_type == RTM_NEWROUTE {
+            Route::parse(payload).map(Some)
+        } else {
+            Ok(None)
+        }
+    })?
+    .ok_or(io::Errno::IO)
+}
+
+/// `RTM_GETROUTE` with `NLM_F_DUMP`—Calls `f` with each route of address
+/// family `family`, in all routing tables.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/rtnetlink.7.html
+#[doc(alias = "RTM_GETROUTE")]
+pub fn list_routes<Fd: AsFd>(
+    fd: Fd,
+    family: AddressFamily,
+    mut f: impl FnMut(&Route),
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    let mut rtmsg = [0_u8; RTMSG_LEN];
+    rtmsg[0] = family.as_raw() as u8;
+    nlmsg::send_request(fd, RTM_GETROUTE, NLM_F_DUMP, &rtmsg)?;
+    nlmsg::recv_dump(fd, |msg_type, payload| {
+        if msg_type == RTM_NEWROUTE {
+            f(&Route::parse(payload)?);
+        }
+        Ok(())
+    })
+}
+
+fn modify_route(fd: BorrowedFd<'_>, msg_type: u16, flags: u16, route: &Route) -> io::Result<()> {
+    let mut buf = [0_u8; RTMSG_LEN + 7 * (NLA_HDRLEN + 16)];
+    nlmsg::send_request(fd, msg_type, NLM_F_ACK | flags, route.encode(&mut buf))?;
+    nlmsg::recv_replies(fd, |_msg_type, _payload| Ok(None::<()>))?;
+    Ok(())
+}
+
+fn push_addr_attr(writer: &mut Writer<'_>, attr_type: u16, addr: Option<IpAddr>) {
+    match addr {
+        Some(IpAddr::V4(v4)) => writer.push_attr(attr_type, &v4.octets()),
+        Some(IpAddr::V6(v6)) => writer.push_attr(attr_type, &v6.octets()),
+        None => {}
+    }
+}
+
+fn push_u32_attr(writer: &mut Writer<'_>, attr_type: u16, value: Option<u32>) {
+    if let Some(value) = value {
+        writer.push_attr(attr_type, &value.to_ne_bytes());
+    }
+}
+
+fn read_addr(value: &[u8]) -> Option<IpAddr> {
+    match value.len() {
+        4 => Some(Ipv4Addr::new(value[0], value[1], value[2], value[3]).into()),
+        16 => Some(Ipv6Addr::from(<[u8; 16]>::try_from(value).unwrap()).into()),
+        _ => None,
+    }
+}
+
+fn read_u32_attr(value: &[u8]) -> Option<u32> {
+    if value.len() >= 4 {
+        Some(nlmsg::read_u32(value))
+    } else {
+        None
+    }
+}
@@ -3,11 +3,15 @@
 #![allow(unsafe_code)]
 
 use crate::buffer::split_init;
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
 #[cfg(unix)]
 use crate::net::SocketAddrUnix;
-use crate::net::{SocketAddr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
+use crate::net::{SocketAddr, SocketAddrAny, SocketAddrBuf, SocketAddrV4, SocketAddrV6};
 use crate::{backend, io};
 use backend::fd::{AsFd, BorrowedFd};
 use core::mem::MaybeUninit;
@@ -58,6 +62,11 @@ pub use msg::*;
 /// [DragonFly BSD]: https://man.dragonflybsd.org/?command=recv&section=2
 /// [illumos]: https://illumos.org/man/3SOCKET/recv
 /// [glibc]: https://www.gnu.org/software/libc/manual/html_node/Receiving-Data.html
+///
+/// On Linux, if `flags` contains [`RecvFlags::TRUNC`], this returns the full
+/// length of the datagram or packet, even when it was longer than `buf` and
+/// was truncated. Callers can detect truncation by comparing the return value
+/// with `buf.len()`.
 #[inline]
 pub fn recv<Fd: AsFd>(fd: Fd, buf: &mut [u8], flags: RecvFlags) -> io::Result<usize> {
     unsafe { backend::net::syscalls::recv(fd.as_fd(), buf.as_mut_ptr(), buf.len(), flags) }
@@ -68,6 +77,9 @@ pub fn recv<Fd: AsFd>(fd: Fd, buf: &mut [u8], flags: RecvFlags) -> io::Result<us
 /// This is equivalent to [`recv`], except that it can read into uninitialized
 /// memory. It returns the slice that was initialized by this function and the
 /// slice that remains uninitialized.
+///
+/// If [`RecvFlags::TRUNC`] reports a length longer than `buf`, the
+/// initialized slice is all of `buf`.
 #[inline]
 pub fn recv_uninit<Fd: AsFd>(
     fd: Fd,
@@ -78,7 +90,37 @@ pub fn recv_uninit<Fd: AsFd>(
         backend::net::syscalls::recv(fd.as_fd(), buf.as_mut_ptr() as *mut u8, buf.len(), flags)
     };
 
-    Ok(unsafe { split_init(buf, length?) })
+    // `MSG_TRUNC` can return a length longer than the buffer.
+    let length = core::cmp::min(length?, buf.len());
+    Ok(unsafe { split_init(buf, length) })
+}
+
+/// `recv(fd, NULL, 0, MSG_PEEK | MSG_TRUNC)`—Returns the length of the next
+/// datagram or packet on a socket, without consuming it.
+///
+/// This allows callers to allocate an exactly-sized buffer before receiving.
+/// `flags` may contain additional flags, such as [`RecvFlags::DONTWAIT`].
+///
+/// This depends on the Linux-specific behavior of `MSG_TRUNC` on datagram,
+/// raw, and netlink sockets. On stream sockets, `MSG_TRUNC` has a different
+/// meaning.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/recv.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "MSG_TRUNC")]
+pub fn recv_datagram_len<Fd: AsFd>(fd: Fd, flags: RecvFlags) -> io::Result<usize> {
+    unsafe {
+        backend::net::syscalls::recv(
+            fd.as_fd(),
+            core::ptr::null_mut(),
+            0,
+            flags | RecvFlags::PEEK | RecvFlags::TRUNC,
+        )
+    }
 }
 
 /// `send(fd, buf, flags)`—Writes data to a socket.
@@ -164,10 +206,37 @@ pub fn recvfrom_uninit<Fd: AsFd>(
     let (length, addr) = unsafe {
         backend::net::syscalls::recvfrom(fd.as_fd(), buf.as_mut_ptr() as *mut u8, buf.len(), flags)?
     };
+    // `MSG_TRUNC` can return a length longer than the buffer.
+    let length = core::cmp::min(length, buf.len());
     let (init, uninit) = unsafe { split_init(buf, length) };
     Ok((init, uninit, addr))
 }
 
+/// `recvfrom(fd, buf, flags, addr, len)`—Reads data from a socket and
+/// writes the sender address into caller-provided storage.
+///
+/// This is equivalent to [`recvfrom`], except that the OS writes the sender
+/// address directly into `from`, instead of it being decoded into a new
+/// [`SocketAddrAny`]. If the socket is connection-oriented, no address is
+/// stored.
+#[inline]
+pub fn recvfrom_into<Fd: AsFd>(
+    fd: Fd,
+    buf: &mut [u8],
+    flags: RecvFlags,
+    from: &mut SocketAddrBuf,
+) -> io::Result<usize> {
+    from.fill(|storage| unsafe {
+        backend::net::syscalls::recvfrom_into(
+            fd.as_fd(),
+            buf.as_mut_ptr(),
+            buf.len(),
+            flags,
+            storage,
+        )
+    })
+}
+
 /// `sendto(fd, buf, flags, addr)`—Writes data to a socket to a specific IP
 /// address.
 ///
@@ -265,6 +334,13 @@ fn _sendto_any(
         SocketAddrAny::Unix(unix) => backend::net::syscalls::sendto_unix(fd, buf, flags, unix),
         #[cfg(target_os = "linux")]
         SocketAddrAny::Xdp(xdp) => backend::net::syscalls::sendto_xdp(fd, buf, flags, xdp),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Netlink(netlink) => {
+            backend::net::syscalls::sendto_netlink(fd, buf, flags, netlink)
+        }
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Vsock(vsock) => backend::net::syscalls::sendto_vsock(fd, buf, flags, vsock),
+        SocketAddrAny::Raw(raw) => backend::net::syscalls::sendto_raw(fd, buf, flags, raw),
     }
 }
 
@@ -401,3 +477,41 @@ pub fn sendto_xdp<Fd: AsFd>(
 ) -> io::Result<usize> {
     backend::net::syscalls::sendto_xdp(fd.as_fd(), buf, flags, addr)
 }
+
+/// `sendto(fd, buf, flags, addr, sizeof(struct sockaddr_nl))`—Writes data
+/// to a socket to a specific netlink address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/netlink.7.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "sendto")]
+pub fn sendto_netlink<Fd: AsFd>(
+    fd: Fd,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrNetlink,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendto_netlink(fd.as_fd(), buf, flags, addr)
+}
+
+/// `sendto(fd, buf, flags, addr, sizeof(struct sockaddr_vm))`—Writes data to
+/// a socket to a specific vsock address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/vsock.7.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "sendto")]
+pub fn sendto_vsock<Fd: AsFd>(
+    fd: Fd,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrVsock,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendto_vsock(fd.as_fd(), buf, flags, addr)
+}
@@ -6,7 +6,7 @@ use crate::backend::{self, c};
 use crate::fd::{AsFd, BorrowedFd, OwnedFd};
 use crate::io::{self, IoSlice, IoSliceMut};
 #[cfg(linux_kernel)]
-use crate::net::UCred;
+use crate::net::{IpPktInfo, Ipv6PktInfo, SockExtendedErr, SockExtendedErrOrigin, UCred};
 
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
@@ -45,6 +45,16 @@ use super::{RecvFlags, SendFlags, SocketAddrAny, SocketAddrV4, SocketAddrV6};
 /// let mut space = [0; rustix::cmsg_space!(ScmRights(2), ScmCredentials(1))];
 /// # }
 /// ```
+///
+/// Allocate a buffer for the IPv4 TOS and IPv6 traffic class of a received
+/// packet:
+/// ```
+/// # #[cfg(linux_kernel)]
+/// # {
+/// # use rustix::cmsg_space;
+/// let mut space = [0; rustix::cmsg_space!(IpTos(1), Ipv6TClass(1))];
+/// # }
+/// ```
 #[macro_export]
 macro_rules! cmsg_space {
     // Base Rules
@@ -58,6 +68,35 @@ macro_rules! cmsg_space {
             $len * ::core::mem::size_of::<$crate::net::UCred>(),
         )
     };
+    (ScmSecurity($len:expr)) => {
+        $crate::net::__cmsg_space($len)
+    };
+    (IpTos($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<i32>(),
+        )
+    };
+    (Ipv6TClass($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<i32>(),
+        )
+    };
+    (IpPktInfo($len:expr)) => {
+        $crate::net::__cmsg_space($len * 12)
+    };
+    (Ipv6PktInfo($len:expr)) => {
+        $crate::net::__cmsg_space($len * 20)
+    };
+    (IpRecvErr($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * (16 + ::core::mem::size_of::<$crate::net::SocketAddrStorage>()),
+        )
+    };
+    (Ipv6RecvErr($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * (16 + ::core::mem::size_of::<$crate::net::SocketAddrStorage>()),
+        )
+    };
 
     // Combo Rules
     ($firstid:ident($firstex:expr), $($restid:ident($restex:expr)),*) => {{
@@ -86,6 +125,35 @@ macro_rules! cmsg_aligned_space {
             $len * ::core::mem::size_of::<$crate::net::UCred>(),
         )
     };
+    (ScmSecurity($len:expr)) => {
+        $crate::net::__cmsg_aligned_space($len)
+    };
+    (IpTos($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * ::core::mem::size_of::<i32>(),
+        )
+    };
+    (Ipv6TClass($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * ::core::mem::size_of::<i32>(),
+        )
+    };
+    (IpPktInfo($len:expr)) => {
+        $crate::net::__cmsg_aligned_space($len * 12)
+    };
+    (Ipv6PktInfo($len:expr)) => {
+        $crate::net::__cmsg_aligned_space($len * 20)
+    };
+    (IpRecvErr($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * (16 + ::core::mem::size_of::<$crate::net::SocketAddrStorage>()),
+        )
+    };
+    (Ipv6RecvErr($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * (16 + ::core::mem::size_of::<$crate::net::SocketAddrStorage>()),
+        )
+    };
 
     // Combo Rules
     ($firstid:ident($firstex:expr), $($restid:ident($restex:expr)),*) => {{
@@ -129,6 +197,32 @@ pub enum SendAncillaryMessage<'slice, 'fd> {
     #[cfg(linux_kernel)]
     #[doc(alias = "SCM_CREDENTIAL")]
     ScmCredentials(UCred),
+    /// Send a packet with the given IPv4 type-of-service byte, overriding
+    /// the socket's [`set_ip_tos`] setting.
+    ///
+    /// [`set_ip_tos`]: crate::net::sockopt::set_ip_tos
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IP_TOS")]
+    IpTos(u8),
+    /// Send a packet with the given IPv6 traffic class, overriding the
+    /// socket's [`set_ipv6_tclass`] setting.
+    ///
+    /// [`set_ipv6_tclass`]: crate::net::sockopt::set_ipv6_tclass
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IPV6_TCLASS")]
+    Ipv6TClass(u32),
+    /// Send a packet from the given source address, or out of the given
+    /// interface, such as to reply from the address a packet was received
+    /// on.
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IP_PKTINFO")]
+    IpPktInfo(IpPktInfo),
+    /// Send a packet from the given source address, or out of the given
+    /// interface, such as to reply from the address a packet was received
+    /// on.
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IPV6_PKTINFO")]
+    Ipv6PktInfo(Ipv6PktInfo),
 }
 
 impl SendAncillaryMessage<'_, '_> {
@@ -140,6 +234,14 @@ impl SendAncillaryMessage<'_, '_> {
             Self::ScmRights(slice) => cmsg_space!(ScmRights(slice.len())),
             #[cfg(linux_kernel)]
             Self::ScmCredentials(_) => cmsg_space!(ScmCredentials(1)),
+            #[cfg(linux_kernel)]
+            Self::IpTos(_) => cmsg_space!(IpTos(1)),
+            #[cfg(linux_kernel)]
+            Self::Ipv6TClass(_) => cmsg_space!(Ipv6TClass(1)),
+            #[cfg(linux_kernel)]
+            Self::IpPktInfo(_) => cmsg_space!(IpPktInfo(1)),
+            #[cfg(linux_kernel)]
+            Self::Ipv6PktInfo(_) => cmsg_space!(Ipv6PktInfo(1)),
         }
     }
 }
@@ -154,6 +256,60 @@ pub enum RecvAncillaryMessage<'a> {
     #[cfg(linux_kernel)]
     #[doc(alias = "SCM_CREDENTIALS")]
     ScmCredentials(UCred),
+    /// The LSM security label of the sender, such as an SELinux context,
+    /// enabled with [`set_socket_passsec`].
+    ///
+    /// This doesn't include a NUL terminator. In [`cmsg_space`], the
+    /// argument of `ScmSecurity` is the maximum label length to allow for.
+    ///
+    /// [`set_socket_passsec`]: crate::net::sockopt::set_socket_passsec
+    #[cfg(linux_kernel)]
+    #[doc(alias = "SCM_SECURITY")]
+    ScmSecurity(&'a [u8]),
+    /// The IPv4 type-of-service byte of a received packet, enabled with
+    /// [`set_ip_recvtos`].
+    ///
+    /// The DSCP is the upper six bits, and the ECN is the lower two.
+    ///
+    /// [`set_ip_recvtos`]: crate::net::sockopt::set_ip_recvtos
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IP_TOS")]
+    IpTos(u8),
+    /// The IPv6 traffic class of a received packet, enabled with
+    /// [`set_ipv6_recvtclass`].
+    ///
+    /// [`set_ipv6_recvtclass`]: crate::net::sockopt::set_ipv6_recvtclass
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IPV6_TCLASS")]
+    Ipv6TClass(u32),
+    /// An extended error report for an IPv4 socket, received with
+    /// [`RecvFlags::ERRQUEUE`] after enabling [`set_ip_recverr`].
+    ///
+    /// [`set_ip_recverr`]: crate::net::sockopt::set_ip_recverr
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IP_RECVERR")]
+    IpRecvErr(SockExtendedErr),
+    /// An extended error report for an IPv6 socket, received with
+    /// [`RecvFlags::ERRQUEUE`] after enabling [`set_ipv6_recverr`].
+    ///
+    /// [`set_ipv6_recverr`]: crate::net::sockopt::set_ipv6_recverr
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IPV6_RECVERR")]
+    Ipv6RecvErr(SockExtendedErr),
+    /// The destination address and interface of a received IPv4 packet,
+    /// enabled with [`set_ip_pktinfo`].
+    ///
+    /// [`set_ip_pktinfo`]: crate::net::sockopt::set_ip_pktinfo
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IP_PKTINFO")]
+    IpPktInfo(IpPktInfo),
+    /// The destination address and interface of a received IPv6 packet,
+    /// enabled with [`set_ipv6_recvpktinfo`].
+    ///
+    /// [`set_ipv6_recvpktinfo`]: crate::net::sockopt::set_ipv6_recvpktinfo
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IPV6_PKTINFO")]
+    Ipv6PktInfo(Ipv6PktInfo),
 }
 
 /// Buffer for sending ancillary messages with [`sendmsg`], [`sendmsg_v4`],
@@ -278,6 +434,39 @@ impl<'buf, 'slice, 'fd> SendAncillaryBuffer<'buf, 'slice, 'fd> {
                 };
                 self.push_ancillary(ucred_bytes, c::SOL_SOCKET as _, c::SCM_CREDENTIALS as _)
             }
+            #[cfg(linux_kernel)]
+            SendAncillaryMessage::IpTos(tos) => {
+                let tos = c::c_int::from(tos);
+                self.push_ancillary(&tos.to_ne_bytes(), c::IPPROTO_IP as _, c::IP_TOS as _)
+            }
+            #[cfg(linux_kernel)]
+            SendAncillaryMessage::Ipv6TClass(tclass) => {
+                let tclass = tclass as c::c_int;
+                self.push_ancillary(
+                    &tclass.to_ne_bytes(),
+                    c::IPPROTO_IPV6 as _,
+                    c::IPV6_TCLASS as _,
+                )
+            }
+            #[cfg(linux_kernel)]
+            SendAncillaryMessage::IpPktInfo(pktinfo) => {
+                // `struct in_pktinfo` has `int` `ipi_ifindex`, and `struct
+                // in_addr` `ipi_spec_dst` and `ipi_addr`.
+                let mut bytes = [0_u8; 12];
+                bytes[..4].copy_from_slice(&pktinfo.ifindex.to_ne_bytes());
+                bytes[4..8].copy_from_slice(&pktinfo.spec_dst.octets());
+                bytes[8..].copy_from_slice(&pktinfo.addr.octets());
+                self.push_ancillary(&bytes, c::IPPROTO_IP as _, c::IP_PKTINFO as _)
+            }
+            #[cfg(linux_kernel)]
+            SendAncillaryMessage::Ipv6PktInfo(pktinfo) => {
+                // `struct in6_pktinfo` has `struct in6_addr` `ipi6_addr` and
+                // `unsigned int` `ipi6_ifindex`.
+                let mut bytes = [0_u8; 20];
+                bytes[..16].copy_from_slice(&pktinfo.addr.octets());
+                bytes[16..].copy_from_slice(&pktinfo.ifindex.to_ne_bytes());
+                self.push_ancillary(&bytes, c::IPPROTO_IPV6 as _, c::IPV6_PKTINFO as _)
+            }
         }
     }
 
@@ -533,12 +722,114 @@ impl<'buf> AncillaryDrain<'buf> {
                         None
                     }
                 }
+                #[cfg(linux_kernel)]
+                (c::SOL_SOCKET, c::SCM_SECURITY) => {
+                    // Some LSMs include a NUL terminator, and some don't.
+                    let len = payload.iter().position(|b| *b == b'\0');
+                    let label = &payload[..len.unwrap_or(payload_len)];
+                    Some(RecvAncillaryMessage::ScmSecurity(label))
+                }
+                // Linux delivers the TOS as a single byte.
+                #[cfg(linux_kernel)]
+                (c::IPPROTO_IP, c::IP_TOS) => {
+                    payload.first().map(|tos| RecvAncillaryMessage::IpTos(*tos))
+                }
+                #[cfg(linux_kernel)]
+                (c::IPPROTO_IPV6, c::IPV6_TCLASS) => {
+                    if payload_len >= size_of::<c::c_int>() {
+                        let tclass = payload.as_ptr().cast::<c::c_int>().read_unaligned();
+                        Some(RecvAncillaryMessage::Ipv6TClass(tclass as u32))
+                    } else {
+                        None
+                    }
+                }
+                #[cfg(linux_kernel)]
+                (c::IPPROTO_IP, c::IP_RECVERR) => {
+                    read_sock_extended_err(payload).map(RecvAncillaryMessage::IpRecvErr)
+                }
+                #[cfg(linux_kernel)]
+                (c::IPPROTO_IPV6, c::IPV6_RECVERR) => {
+                    read_sock_extended_err(payload).map(RecvAncillaryMessage::Ipv6RecvErr)
+                }
+                #[cfg(linux_kernel)]
+                (c::IPPROTO_IP, c::IP_PKTINFO) => {
+                    read_ip_pktinfo(payload).map(RecvAncillaryMessage::IpPktInfo)
+                }
+                #[cfg(linux_kernel)]
+                (c::IPPROTO_IPV6, c::IPV6_PKTINFO) => {
+                    read_ipv6_pktinfo(payload).map(RecvAncillaryMessage::Ipv6PktInfo)
+                }
                 _ => None,
             }
         }
     }
 }
 
+/// Decode a `struct sock_extended_err`, and the offender address which
+/// follows it, from the payload of an `IP_RECVERR` or `IPV6_RECVERR` message.
+#[cfg(linux_kernel)]
+fn read_sock_extended_err(payload: &[u8]) -> Option<SockExtendedErr> {
+    // `struct sock_extended_err` has `u32` `ee_errno`, `u8` `ee_origin`,
+    // `ee_type`, `ee_code`, and `ee_pad`, and `u32` `ee_info` and `ee_data`.
+    const SIZE: usize = 16;
+
+    let (header, offender) = (payload.get(..SIZE)?, &payload[SIZE..]);
+    let u32_at = |i: usize| u32::from_ne_bytes(header[i..i + 4].try_into().unwrap());
+
+    // The kernel zeroes the offender address when there is none, such as for
+    // `SO_EE_ORIGIN_LOCAL` errors, which leaves its family as `AF_UNSPEC`.
+    let family = offender
+        .get(..2)
+        .map(|family| u16::from_ne_bytes([family[0], family[1]]));
+    let offender = if family.map_or(true, |family| family == c::AF_UNSPEC as u16) {
+        None
+    } else {
+        // SAFETY: `offender` is the initialized tail of a control message
+        // payload, and `read` checks `len` against the address family.
+        unsafe { SocketAddrAny::read(offender.as_ptr().cast(), offender.len()) }.ok()
+    };
+
+    Some(SockExtendedErr {
+        errno: io::Errno::from_raw_os_error(u32_at(0) as i32),
+        origin: SockExtendedErrOrigin::from_raw(header[4]),
+        type_: header[5],
+        code: header[6],
+        info: u32_at(8),
+        data: u32_at(12),
+        offender,
+    })
+}
+
+/// Decode a `struct in_pktinfo` from the payload of an `IP_PKTINFO`
+/// message.
+#[cfg(linux_kernel)]
+fn read_ip_pktinfo(payload: &[u8]) -> Option<IpPktInfo> {
+    let payload: [u8; 12] = payload.get(..12)?.try_into().unwrap();
+    let ipv4_at = |i: usize| {
+        let octets: [u8; 4] = payload[i..i + 4].try_into().unwrap();
+        octets.into()
+    };
+
+    Some(IpPktInfo {
+        ifindex: u32::from_ne_bytes(payload[..4].try_into().unwrap()),
+        spec_dst: ipv4_at(4),
+        addr: ipv4_at(8),
+    })
+}
+
+/// Decode a `struct in6_pktinfo` from the payload of an `IPV6_PKTINFO`
+/// message.
+#[cfg(linux_kernel)]
+fn read_ipv6_pktinfo(payload: &[u8]) -> Option<Ipv6PktInfo> {
+    let payload: [u8; 20] = payload.get(..20)?.try_into().unwrap();
+    let octets: [u8; 16] = payload[..16].try_into().unwrap();
+
+    Some(Ipv6PktInfo {
+        addr: octets.into(),
+        ifindex: u32::from_ne_bytes(payload[16..].try_into().unwrap()),
+    })
+}
+
 impl<'buf> Iterator for AncillaryDrain<'buf> {
     type Item = RecvAncillaryMessage<'buf>;
 
@@ -732,6 +1023,43 @@ pub fn sendmsg_xdp(
     backend::net::syscalls::sendmsg_xdp(socket.as_fd(), addr, iov, control, flags)
 }
 
+/// `sendmsg(msghdr)`—Sends a message on a socket to a specific netlink
+/// address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmsg.2.html
+#[inline]
+#[cfg(linux_kernel)]
+pub fn sendmsg_netlink(
+    socket: impl AsFd,
+    addr: &crate::net::netlink::SocketAddrNetlink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    flags: SendFlags,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendmsg_netlink(socket.as_fd(), addr, iov, control, flags)
+}
+
+/// `sendmsg(msghdr)`—Sends a message on a socket to a specific vsock address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmsg.2.html
+#[inline]
+#[cfg(linux_kernel)]
+pub fn sendmsg_vsock(
+    socket: impl AsFd,
+    addr: &crate::net::vsock::SocketAddrVsock,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    flags: SendFlags,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendmsg_vsock(socket.as_fd(), addr, iov, control, flags)
+}
+
 /// `sendmsg(msghdr)`—Sends a message on a socket to a specific address.
 ///
 /// # References
@@ -776,6 +1104,17 @@ pub fn sendmsg_any(
         Some(SocketAddrAny::Xdp(addr)) => {
             backend::net::syscalls::sendmsg_xdp(socket.as_fd(), addr, iov, control, flags)
         }
+        #[cfg(linux_kernel)]
+        Some(SocketAddrAny::Netlink(addr)) => {
+            backend::net::syscalls::sendmsg_netlink(socket.as_fd(), addr, iov, control, flags)
+        }
+        #[cfg(linux_kernel)]
+        Some(SocketAddrAny::Vsock(addr)) => {
+            backend::net::syscalls::sendmsg_vsock(socket.as_fd(), addr, iov, control, flags)
+        }
+        Some(SocketAddrAny::Raw(addr)) => {
+            backend::net::syscalls::sendmsg_raw(socket.as_fd(), addr, iov, control, flags)
+        }
     }
 }
 
@@ -815,6 +1154,10 @@ pub struct RecvMsgReturn {
     pub bytes: usize,
 
     /// The flags received.
+    ///
+    /// If this contains [`RecvFlags::CTRUNC`], the control buffer was too
+    /// small and some ancillary data, such as passed file descriptors, was
+    /// discarded.
     pub flags: RecvFlags,
 
     /// The address of the socket we received from, if any.
@@ -0,0 +1,390 @@
+//! Socket enumeration with `NETLINK_SOCK_DIAG`.
+//!
+//! The kernel's sock_diag interface reports the sockets on the system, with
+//! their addresses, states, queue lengths, and owners, which is how tools
+//! such as `ss` work without parsing `/proc/net`. Requests are sent on a
+//! [`netlink::SOCK_DIAG`] socket.
+//!
+//! # Examples
+//!
+//! List listening TCP sockets:
+//!
+//! ```no_run
+//! # fn main() -> rustix::io::Result<()> {
+//! use rustix::net::sock_diag::{self, InetDiagReq, TCP_LISTEN};
+//! use rustix::net::{ipproto, netlink, socket, AddressFamily, SocketType};
+//!
+//! let fd = socket(AddressFamily::NETLINK, SocketType::RAW, Some(netlink::SOCK_DIAG))?;
+//!
+//! let mut req = InetDiagReq::new(AddressFamily::INET, ipproto::TCP);
+//! req.states = 1 << TCP_LISTEN;
+//! sock_diag::inet_diag(&fd, &req, |msg| {
+//!     println!("{} inode {}", msg.source, msg.inode);
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [`netlink::SOCK_DIAG`]: crate::net::netlink::SOCK_DIAG
+//! [Linux]: https://man7.org/linux/man-pages/man7/sock_diag.7.html
+
+use crate::fd::AsFd;
+use crate::io;
+use crate::net::nlmsg::{self, Attrs, NLM_F_DUMP};
+use crate::net::{
+    AddressFamily, Ipv4Addr, Ipv6Addr, Protocol, SocketAddr, SocketAddrV4, SocketAddrV6, SocketType,
+};
+
+// Constants from `<linux/sock_diag.h>`, `<linux/inet_diag.h>`, and
+// `<linux/unix_diag.h>`, which aren't in the version of linux-raw-sys we use.
+const SOCK_DIAG_BY_FAMILY: u16 = 20;
+const INET_DIAG_REQ_V2_LEN: usize = 56;
+const INET_DIAG_MSG_LEN: usize = 72;
+const UNIX_DIAG_REQ_LEN: usize = 24;
+const UNIX_DIAG_MSG_LEN: usize = 16;
+const UNIX_DIAG_NAME: u16 = 0;
+const UNIX_DIAG_PEER: u16 = 2;
+const UNIX_DIAG_RQLEN: u16 = 4;
+const UNIX_DIAG_UID: u16 = 7;
+
+/// A mask of all socket states, for use in [`InetDiagReq::states`] and
+/// [`UnixDiagReq::states`].
+pub const ALL_STATES: u32 = !0;
+
+/// `TCP_ESTABLISHED`
+pub const TCP_ESTABLISHED: u8 = 1;
+/// `TCP_SYN_SENT`
+pub const TCP_SYN_SENT: u8 = 2;
+/// `TCP_SYN_RECV`
+pub const TCP_SYN_RECV: u8 = 3;
+/// `TCP_FIN_WAIT1`
+pub const TCP_FIN_WAIT1: u8 = 4;
+/// `TCP_FIN_WAIT2`
+pub const TCP_FIN_WAIT2: u8 = 5;
+/// `TCP_TIME_WAIT`
+pub const TCP_TIME_WAIT: u8 = 6;
+/// `TCP_CLOSE`
+pub const TCP_CLOSE: u8 = 7;
+/// `TCP_CLOSE_WAIT`
+pub const TCP_CLOSE_WAIT: u8 = 8;
+/// `TCP_LAST_ACK`
+pub const TCP_LAST_ACK: u8 = 9;
+/// `TCP_LISTEN`
+pub const TCP_LISTEN: u8 = 10;
+/// `TCP_CLOSING`
+pub const TCP_CLOSING: u8 = 11;
+
+/// `struct inet_diag_req_v2`—A request for IPv4 or IPv6 sockets.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[doc(alias = "inet_diag_req_v2")]
+pub struct InetDiagReq {
+    /// The address family, [`AddressFamily::INET`] or
+    /// [`AddressFamily::INET6`].
+    pub family: AddressFamily,
+
+    /// The protocol, such as [`ipproto::TCP`] or [`ipproto::UDP`].
+    ///
+    /// [`ipproto::TCP`]: crate::net::ipproto::TCP
+    /// [`ipproto::UDP`]: crate::net::ipproto::UDP
+    pub protocol: Protocol,
+
+    /// A mask of `1 << state` for each state to report, such as
+    /// `1 << TCP_LISTEN`.
+    pub states: u32,
+}
+
+impl InetDiagReq {
+    /// Construct a request for all sockets of `family` and `protocol`.
+    #[inline]
+    pub const fn new(family: AddressFamily, protocol: Protocol) -> Self {
+        Self {
+            family,
+            protocol,
+            states: ALL_STATES,
+        }
+    }
+
+    fn encode(&self) -> [u8; INET_DIAG_REQ_V2_LEN] {
+        let mut req = [0_u8; INET_DIAG_REQ_V2_LEN];
+        req[0] = self.family.as_raw() as u8;
+        req[1] = self.protocol.as_raw().get() as u8;
+        req[4..8].copy_from_slice(&self.states.to_ne_bytes());
+        req
+    }
+}
+
+/// `struct inet_diag_msg`—A description of an IPv4 or IPv6 socket.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[doc(alias = "inet_diag_msg")]
+pub struct InetDiagMsg {
+    /// The address family.
+    pub family: AddressFamily,
+    /// The socket state, such as [`TCP_ESTABLISHED`].
+    pub state: u8,
+    /// The kind of timer which is pending, if any.
+    pub timer: u8,
+    /// The number of retransmits, for the pending timer.
+    pub retrans: u8,
+    /// The local address.
+    pub source: SocketAddr,
+    /// The remote address.
+    pub destination: SocketAddr,
+    /// The index of the interface the socket is bound to, or 0.
+    pub interface: u32,
+    /// An opaque identifier for the socket.
+    pub cookie: u64,
+    /// The time until the pending timer expires, in milliseconds.
+    pub expires: u32,
+    /// For listening sockets, the number of pending connections. Otherwise,
+    /// the number of bytes in the receive queue.
+    pub rqueue: u32,
+    /// For listening sockets, the backlog length. Otherwise, the number of
+    /// bytes in the send queue.
+    pub wqueue: u32,
+    /// The user ID of the socket's owner.
+    pub uid: u32,
+    /// The socket's inode number.
+    pub inode: u32,
+}
+
+impl InetDiagMsg {
+    fn parse(payload: &[u8]) -> io::Result<Self> {
+        if payload.len() < INET_DIAG_MSG_LEN {
+            return Err(io::Errno::IO);
+        }
+
+        let family = AddressFamily::from_raw(payload[0].into());
+        let sport = u16::from_be_bytes([payload[4], payload[5]]);
+        let dport = u16::from_be_bytes([payload[6], payload[7]]);
+        let src: [u8; 16] = payload[8..24].try_into().unwrap();
+        let dst: [u8; 16] = payload[24..40].try_into().unwrap();
+        let (source, destination) = if family == AddressFamily::INET6 {
+            (
+                SocketAddrV6::new(Ipv6Addr::from(src), sport, 0, 0).into(),
+                SocketAddrV6::new(Ipv6Addr::from(dst), dport, 0, 0).into(),
+            )
+        } else {
+            (
+                SocketAddrV4::new(Ipv4Addr::new(src[0], src[1], src[2], src[3]), sport).into(),
+                SocketAddrV4::new(Ipv4Addr::new(dst[0], dst[1], dst[2], dst[3]), dport).into(),
+            )
+        };
+
+        Ok(Self {
+            family,
+            state: payload[1],
+            timer: payload[2],
+            retrans: payload[3],
+            source,
+            destination,
+            interface: nlmsg::read_u32(&payload[40..]),
+            cookie: read_cookie(&payload[44..52]),
+            expires: nlmsg::read_u32(&payload[52..]),
+            rqueue: nlmsg::read_u32(&payload[56..]),
+            wqueue: nlmsg::read_u32(&payload[60..]),
+            uid: nlmsg::read_u32(&payload[64..]),
+            inode: nlmsg::read_u32(&payload[68..]),
+        })
+    }
+}
+
+bitflags::bitflags! {
+    /// `UDIAG_SHOW_*` constants for use in [`UnixDiagReq::show`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct UnixDiagShow: u32 {
+        /// `UDIAG_SHOW_NAME`
+        const NAME = 0x1;
+        /// `UDIAG_SHOW_VFS`
+        const VFS = 0x2;
+        /// `UDIAG_SHOW_PEER`
+        const PEER = 0x4;
+        /// `UDIAG_SHOW_ICONS`
+        const ICONS = 0x8;
+        /// `UDIAG_SHOW_RQLEN`
+        const RQLEN = 0x10;
+        /// `UDIAG_SHOW_MEMINFO`
+        const MEMINFO = 0x20;
+        /// `UDIAG_SHOW_UID`
+        const UID = 0x40;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `struct unix_diag_req`—A request for Unix-domain sockets.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[doc(alias = "unix_diag_req")]
+pub struct UnixDiagReq {
+    /// A mask of `1 << state` for each state to report, such as
+    /// `1 << TCP_LISTEN`.
+    pub states: u32,
+
+    /// Which optional attributes to report.
+    pub show: UnixDiagShow,
+}
+
+impl UnixDiagReq {
+    /// Construct a request for all Unix-domain sockets, reporting the
+    /// attributes in `show`.
+    #[inline]
+    pub const fn new(show: UnixDiagShow) -> Self {
+        Self {
+            states: ALL_STATES,
+            show,
+        }
+    }
+
+    fn encode(&self) -> [u8; UNIX_DIAG_REQ_LEN] {
+        let mut req = [0_u8; UNIX_DIAG_REQ_LEN];
+        req[0] = AddressFamily::UNIX.as_raw() as u8;
+        req[4..8].copy_from_slice(&self.states.to_ne_bytes());
+        req[12..16].copy_from_slice(&self.show.bits().to_ne_bytes());
+        req
+    }
+}
+
+/// `struct unix_diag_msg`—A description of a Unix-domain socket, and the
+/// attributes requested with [`UnixDiagReq::show`].
+#[derive(Clone, Debug)]
+#[doc(alias = "unix_diag_msg")]
+pub struct UnixDiagMsg<'a> {
+    /// The socket type.
+    pub type_: SocketType,
+    /// The socket state, such as [`TCP_ESTABLISHED`] or [`TCP_LISTEN`].
+    pub state: u8,
+    /// The socket's inode number.
+    pub inode: u32,
+    /// An opaque identifier for the socket.
+    pub cookie: u64,
+    attrs: &'a [u8],
+}
+
+impl<'a> UnixDiagMsg<'a> {
+    fn parse(payload: &'a [u8]) -> io::Result<Self> {
+        if payload.len() < UNIX_DIAG_MSG_LEN {
+            return Err(io::Errno::IO);
+        }
+
+        Ok(Self {
+            type_: SocketType::from_raw(payload[1].into()),
+            state: payload[2],
+            inode: nlmsg::read_u32(&payload[4..]),
+            cookie: read_cookie(&payload[8..16]),
+            attrs: &payload[nlmsg::align(UNIX_DIAG_MSG_LEN)..],
+        })
+    }
+
+    /// The address the socket is bound to, as in
+    /// [`SocketAddrUnix::path`] or [`SocketAddrUnix::abstract_name`] with
+    /// a leading NUL byte, if [`UnixDiagShow::NAME`] was requested and the
+    /// socket is bound.
+    ///
+    /// [`SocketAddrUnix::path`]: crate::net::SocketAddrUnix::path
+    /// [`SocketAddrUnix::abstract_name`]: crate::net::SocketAddrUnix::abstract_name
+    #[inline]
+    pub fn name(&self) -> Option<&'a [u8]> {
+        nlmsg::find_attr(self.attrs, UNIX_DIAG_NAME)
+    }
+
+    /// The inode number of the connected peer, if [`UnixDiagShow::PEER`]
+    /// was requested and the socket is connected.
+    #[inline]
+    pub fn peer(&self) -> Option<u32> {
+        self.u32_attr(UNIX_DIAG_PEER)
+    }
+
+    /// The lengths of the receive and send queues, if
+    /// [`UnixDiagShow::RQLEN`] was requested.
+    ///
+    /// For listening sockets, these are the number of pending connections
+    /// and the backlog length.
+    #[inline]
+    pub fn queue_lengths(&self) -> Option<(u32, u32)> {
+        nlmsg::find_attr(self.attrs, UNIX_DIAG_RQLEN)
+            .filter(|rqlen| rqlen.len() >= 8)
+            .map(|rqlen| (nlmsg::read_u32(rqlen), nlmsg::read_u32(&rqlen[4..])))
+    }
+
+    /// The user ID of the socket's owner, if [`UnixDiagShow::UID`] was
+    /// requested.
+    #[inline]
+    pub fn uid(&self) -> Option<u32> {
+        self.u32_attr(UNIX_DIAG_UID)
+    }
+
+    /// Return an iterator over the raw `(UNIX_DIAG_*, payload)` attributes
+    /// of this message.
+    #[inline]
+    pub fn attrs(&self) -> impl Iterator<Item = (u16, &'a [u8])> + Clone {
+        Attrs(self.attrs)
+    }
+
+    fn u32_attr(&self, attr_type: u16) -> Option<u32> {
+        nlmsg::find_attr(self.attrs, attr_type)
+            .filter(|value| value.len() >= 4)
+            .map(nlmsg::read_u32)
+    }
+}
+
+/// `SOCK_DIAG_BY_FAMILY`—Call `f` with each IPv4 or IPv6 socket matching
+/// `req`.
+///
+/// `fd` must be a [`netlink::SOCK_DIAG`] socket.
+///
+/// # References
+///  - [Linux]
+///
+/// [`netlink::SOCK_DIAG`]: crate::net::netlink::SOCK_DIAG
+/// [Linux]: https://man7.org/linux/man-pages/man7/sock_diag.7.html
+#[doc(alias = "SOCK_DIAG_BY_FAMILY")]
+pub fn inet_diag<Fd: AsFd>(
+    fd: Fd,
+    req: &InetDiagReq,
+    mut f: impl FnMut(&InetDiagMsg),
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    nlmsg::send_request(fd, SOCK_DIAG_BY_FAMILY, NLM_F_DUMP, &req.encode())?;
+    nlmsg::recv_dump(fd, |msg_type, payload| {
+        if msg_type == SOCK_DIAG_BY_FAMILY {
+            f(&InetDiagMsg::parse(payload)?);
+        }
+        Ok(())
+    })
+}
+
+/// `SOCK_DIAG_BY_FAMILY`—Call `f` with each Unix-domain socket matching
+/// `req`.
+///
+/// `fd` must be a [`netlink::SOCK_DIAG`] socket.
+///
+/// # References
+///  - [Linux]
+///
+/// [`netlink::SOCK_DIAG`]: crate::net::netlink::SOCK_DIAG
+/// [Linux]: https://man7.org/linux/man-pages/man7/sock_diag.7.html
+#[doc(alias = "SOCK_DIAG_BY_FAMILY")]
+pub fn unix_diag<Fd: AsFd>(
+    fd: Fd,
+    req: &UnixDiagReq,
+    mut f: impl FnMut(&UnixDiagMsg<'_>),
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    nlmsg::send_request(fd, SOCK_DIAG_BY_FAMILY, NLM_F_DUMP, &req.encode())?;
+    nlmsg::recv_dump(fd, |msg_type, payload| {
+        if msg_type == SOCK_DIAG_BY_FAMILY {
+            f(&UnixDiagMsg::parse(payload)?);
+        }
+        Ok(())
+    })
+}
+
+/// Read a socket cookie, which the kernel stores as two native-endian `u32`s
+/// with the low half first.
+fn read_cookie(bytes: &[u8]) -> u64 {
+    u64::from(nlmsg::read_u32(bytes)) | (u64::from(nlmsg::read_u32(&bytes[4..])) << 32)
+}
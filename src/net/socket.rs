@@ -1,8 +1,12 @@
 use crate::fd::OwnedFd;
-use crate::net::{SocketAddr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
+use crate::net::{SocketAddr, SocketAddrAny, SocketAddrBuf, SocketAddrV4, SocketAddrV6};
 use crate::{backend, io};
 use backend::fd::{AsFd, BorrowedFd};
 
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
 pub use crate::net::{AddressFamily, Protocol, Shutdown, SocketFlags, SocketType};
@@ -172,6 +176,11 @@ fn _bind_any(sockfd: BorrowedFd<'_>, addr: &SocketAddrAny) -> io::Result<()> {
         SocketAddrAny::Unix(unix) => backend::net::syscalls::bind_unix(sockfd, unix),
         #[cfg(target_os = "linux")]
         SocketAddrAny::Xdp(xdp) => backend::net::syscalls::bind_xdp(sockfd, xdp),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Netlink(netlink) => backend::net::syscalls::bind_netlink(sockfd, netlink),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Vsock(vsock) => backend::net::syscalls::bind_vsock(sockfd, vsock),
+        SocketAddrAny::Raw(raw) => backend::net::syscalls::bind_raw(sockfd, raw),
     }
 }
 
@@ -289,6 +298,68 @@ pub fn bind_xdp<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrXdp) -> io::Result<()> {
     backend::net::syscalls::bind_xdp(sockfd.as_fd(), addr)
 }
 
+/// `bind(sockfd, addr, sizeof(struct sockaddr_nl))`—Binds a socket to a
+/// netlink address.
+///
+/// This is used to pick a port ID and to subscribe to multicast groups, such
+/// as [`netlink::UEVENT_KERNEL_GROUP`] on a [`netlink::KOBJECT_UEVENT`]
+/// socket.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/netlink.7.html
+/// [`netlink::UEVENT_KERNEL_GROUP`]: crate::net::netlink::UEVENT_KERNEL_GROUP
+/// [`netlink::KOBJECT_UEVENT`]: crate::net::netlink::KOBJECT_UEVENT
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "bind")]
+pub fn bind_netlink<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrNetlink) -> io::Result<()> {
+    backend::net::syscalls::bind_netlink(sockfd.as_fd(), addr)
+}
+
+/// `connect(sockfd, addr, sizeof(struct sockaddr_nl))`—Sets the default
+/// destination of a netlink socket.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/netlink.7.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "connect")]
+pub fn connect_netlink<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrNetlink) -> io::Result<()> {
+    backend::net::syscalls::connect_netlink(sockfd.as_fd(), addr)
+}
+
+/// `bind(sockfd, addr, sizeof(struct sockaddr_vm))`—Binds a socket to a
+/// vsock address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/vsock.7.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "bind")]
+pub fn bind_vsock<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrVsock) -> io::Result<()> {
+    backend::net::syscalls::bind_vsock(sockfd.as_fd(), addr)
+}
+
+/// `connect(sockfd, addr, sizeof(struct sockaddr_vm))`—Initiates a
+/// connection to a vsock address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/vsock.7.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "connect")]
+pub fn connect_vsock<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrVsock) -> io::Result<()> {
+    backend::net::syscalls::connect_vsock(sockfd.as_fd(), addr)
+}
+
 /// `connect(sockfd, addr)`—Initiates a connection to an IP address.
 ///
 /// On Windows, a non-blocking socket returns [`Errno::WOULDBLOCK`] if the
@@ -370,6 +441,11 @@ fn _connect_any(sockfd: BorrowedFd<'_>, addr: &SocketAddrAny) -> io::Result<()>
         SocketAddrAny::Unix(unix) => backend::net::syscalls::connect_unix(sockfd, unix),
         #[cfg(target_os = "linux")]
         SocketAddrAny::Xdp(_) => Err(io::Errno::OPNOTSUPP),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Netlink(netlink) => backend::net::syscalls::connect_netlink(sockfd, netlink),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Vsock(vsock) => backend::net::syscalls::connect_vsock(sockfd, vsock),
+        SocketAddrAny::Raw(raw) => backend::net::syscalls::connect_raw(sockfd, raw),
     }
 }
 
@@ -511,6 +587,81 @@ pub fn connect_unspec<Fd: AsFd>(sockfd: Fd) -> io::Result<()> {
     backend::net::syscalls::connect_unspec(sockfd.as_fd())
 }
 
+/// Initiate a connection, and wait up to `timeout` for it to complete.
+///
+/// This puts `sockfd` in non-blocking mode, calls [`connect_any`], waits for
+/// the socket to become writable, and then reports the connection's outcome
+/// from [`get_socket_error`]. A `connect` interrupted by a signal handler
+/// continues in the background, and a wait interrupted by a signal handler is
+/// resumed with the remaining time, so this never fails with
+/// [`io::Errno::INTR`]. It fails with [`io::Errno::TIMEDOUT`] if the
+/// connection doesn't complete in time.
+///
+/// If `sockfd` was in blocking mode, it's returned to blocking mode
+/// afterwards, whether or not the connection succeeds. On Windows, where
+/// there's no way to query whether a socket is in non-blocking mode, it's
+/// always left in blocking mode, as with
+/// [`std::net::TcpStream::connect_timeout`].
+///
+/// [`get_socket_error`]: crate::net::sockopt::get_socket_error
+#[cfg(all(feature = "event", feature = "std"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "event", feature = "std"))))]
+#[doc(alias = "connect")]
+pub fn connect_with_timeout<Fd: AsFd>(
+    sockfd: Fd,
+    addr: &SocketAddrAny,
+    timeout: core::time::Duration,
+) -> io::Result<()> {
+    let sockfd = sockfd.as_fd();
+    #[cfg(not(windows))]
+    let nonblocking = backend::io::syscalls::fcntl_getfl_nonblock(sockfd)?;
+    #[cfg(windows)]
+    let nonblocking = false;
+
+    if nonblocking {
+        return _connect_with_timeout(sockfd, addr, timeout);
+    }
+
+    io::ioctl_fionbio(sockfd, true)?;
+    let result = _connect_with_timeout(sockfd, addr, timeout);
+    // Report the outcome of the connection in preference to a failure to
+    // restore blocking mode.
+    let restored = io::ioctl_fionbio(sockfd, false);
+    result.and(restored)
+}
+
+#[cfg(all(feature = "event", feature = "std"))]
+fn _connect_with_timeout(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrAny,
+    timeout: core::time::Duration,
+) -> io::Result<()> {
+    use std::time::Instant;
+
+    // If the deadline isn't representable, wait indefinitely.
+    let deadline = Instant::now().checked_add(timeout);
+
+    match _connect_any(sockfd, addr) {
+        Ok(()) => return Ok(()),
+        Err(io::Errno::INPROGRESS | io::Errno::INTR) => {}
+        #[cfg(windows)]
+        Err(io::Errno::WOULDBLOCK) => {}
+        Err(err) => return Err(err),
+    }
+
+    loop {
+        let remaining = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+        match crate::event::wait_writable(sockfd, remaining) {
+            Ok(true) => break,
+            Ok(false) => return Err(io::Errno::TIMEDOUT),
+            Err(io::Errno::INTR) => continue,
+            Err(err) => return Err(err),
+        }
+    }
+
+    crate::net::sockopt::get_socket_error(sockfd)?
+}
+
 /// `listen(fd, backlog)`—Enables listening for incoming connections.
 ///
 /// # References
@@ -677,6 +828,24 @@ pub fn acceptfrom_with<Fd: AsFd>(
     backend::net::syscalls::acceptfrom_with(sockfd.as_fd(), flags)
 }
 
+/// `accept4(fd, &addr, &len, flags)`—Accepts an incoming connection and
+/// writes the peer address into caller-provided storage, with flags.
+///
+/// This is equivalent to [`acceptfrom_with`], except that the OS writes the
+/// peer address directly into `from`, instead of it being decoded into a new
+/// [`SocketAddrAny`].
+#[inline]
+#[doc(alias = "accept4")]
+pub fn acceptfrom_into<Fd: AsFd>(
+    sockfd: Fd,
+    flags: SocketFlags,
+    from: &mut SocketAddrBuf,
+) -> io::Result<OwnedFd> {
+    from.fill(|storage| {
+        backend::net::syscalls::acceptfrom_with_into(sockfd.as_fd(), flags, storage)
+    })
+}
+
 /// `shutdown(fd, how)`—Closes the read and/or write sides of a stream.
 ///
 /// # References
@@ -768,3 +937,19 @@ pub fn getsockname<Fd: AsFd>(sockfd: Fd) -> io::Result<SocketAddrAny> {
 pub fn getpeername<Fd: AsFd>(sockfd: Fd) -> io::Result<Option<SocketAddrAny>> {
     backend::net::syscalls::getpeername(sockfd.as_fd())
 }
+
+/// `getpeername(fd, addr, len)`—Writes the address of a connected socket's
+/// peer into caller-provided storage.
+///
+/// This is equivalent to [`getpeername`], except that the OS writes the peer
+/// address directly into `addr`, instead of it being decoded into a new
+/// [`SocketAddrAny`].
+#[inline]
+pub fn getpeername_into<Fd: AsFd>(sockfd: Fd, addr: &mut SocketAddrBuf) -> io::Result<()> {
+    addr.fill(|storage| {
+        Ok((
+            (),
+            backend::net::syscalls::getpeername_into(sockfd.as_fd(), storage)?,
+        ))
+    })
+}
@@ -9,14 +9,21 @@
 //! OS-specific socket address representations in memory.
 #![allow(unsafe_code)]
 
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
 #[cfg(unix)]
 use crate::net::SocketAddrUnix;
-use crate::net::{AddressFamily, SocketAddr, SocketAddrV4, SocketAddrV6};
+use crate::net::{AddressFamily, RawAddressFamily, SocketAddr, SocketAddrV4, SocketAddrV6};
 use crate::{backend, io};
+use backend::net::read_sockaddr;
 #[cfg(feature = "std")]
 use core::fmt;
+use core::mem::{size_of, MaybeUninit};
+use core::{ptr, slice};
 
 pub use backend::net::addr::SocketAddrStorage;
 
@@ -35,6 +42,14 @@ pub enum SocketAddrAny {
     /// `struct sockaddr_xdp`
     #[cfg(target_os = "linux")]
     Xdp(SocketAddrXdp),
+    /// `struct sockaddr_nl`
+    #[cfg(linux_kernel)]
+    Netlink(SocketAddrNetlink),
+    /// `struct sockaddr_vm`
+    #[cfg(linux_kernel)]
+    Vsock(SocketAddrVsock),
+    /// A socket address in an address family which rustix doesn't decode.
+    Raw(SocketAddrRaw),
 }
 
 impl From<SocketAddr> for SocketAddrAny {
@@ -69,6 +84,22 @@ impl From<SocketAddrUnix> for SocketAddrAny {
     }
 }
 
+#[cfg(linux_kernel)]
+impl From<SocketAddrNetlink> for SocketAddrAny {
+    #[inline]
+    fn from(from: SocketAddrNetlink) -> Self {
+        Self::Netlink(from)
+    }
+}
+
+#[cfg(linux_kernel)]
+impl From<SocketAddrVsock> for SocketAddrAny {
+    #[inline]
+    fn from(from: SocketAddrVsock) -> Self {
+        Self::Vsock(from)
+    }
+}
+
 impl SocketAddrAny {
     /// Return the address family of this socket address.
     #[inline]
@@ -80,6 +111,11 @@ impl SocketAddrAny {
             Self::Unix(_) => AddressFamily::UNIX,
             #[cfg(target_os = "linux")]
             Self::Xdp(_) => AddressFamily::XDP,
+            #[cfg(linux_kernel)]
+            Self::Netlink(_) => AddressFamily::NETLINK,
+            #[cfg(linux_kernel)]
+            Self::Vsock(_) => AddressFamily::VSOCK,
+            Self::Raw(raw) => raw.address_family(),
         }
     }
 
@@ -98,6 +134,9 @@ impl SocketAddrAny {
     /// Reads a platform-specific encoding of a socket address from
     /// the memory pointed to by `storage`, which uses `len` bytes.
     ///
+    /// Addresses in families rustix doesn't otherwise decode are returned as
+    /// [`SocketAddrAny::Raw`].
+    ///
     /// # Safety
     ///
     /// `storage` must point to valid memory for decoding a socket
@@ -117,6 +156,263 @@ impl fmt::Debug for SocketAddrAny {
             Self::Unix(unix) => unix.fmt(fmt),
             #[cfg(target_os = "linux")]
             Self::Xdp(xdp) => xdp.fmt(fmt),
+            #[cfg(linux_kernel)]
+            Self::Netlink(netlink) => netlink.fmt(fmt),
+            #[cfg(linux_kernel)]
+            Self::Vsock(vsock) => vsock.fmt(fmt),
+            Self::Raw(raw) => raw.fmt(fmt),
+        }
+    }
+}
+
+/// A socket address in an address family which rustix doesn't decode, in its
+/// platform-specific encoding.
+///
+/// This is produced when reading a socket address, such as from [`recvfrom`]
+/// or [`getpeername`], and can be passed back to functions such as
+/// [`sendto_any`] and [`connect_any`] unchanged.
+///
+/// [`recvfrom`]: crate::net::recvfrom
+/// [`getpeername`]: crate::net::getpeername
+/// [`sendto_any`]: crate::net::sendto_any
+/// [`connect_any`]: crate::net::connect_any
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct SocketAddrRaw {
+    family: RawAddressFamily,
+    len: usize,
+    bytes: [u8; size_of::<SocketAddrStorage>()],
+}
+
+impl SocketAddrRaw {
+    /// Copy `len` bytes of an encoded socket address in address family
+    /// `family` from `storage`.
+    ///
+    /// If the OS reported a length longer than `SocketAddrStorage`, the
+    /// address was truncated, and only the bytes that were stored are kept.
+    ///
+    /// # Safety
+    ///
+    /// `storage` must point to valid socket address storage.
+    pub(crate) unsafe fn read(
+        family: RawAddressFamily,
+        storage: *const SocketAddrStorage,
+        len: usize,
+    ) -> Self {
+        let len = len.min(size_of::<SocketAddrStorage>());
+        let mut bytes = [0_u8; size_of::<SocketAddrStorage>()];
+        ptr::copy_nonoverlapping(storage.cast::<u8>(), bytes.as_mut_ptr(), len);
+        Self { family, len, bytes }
+    }
+
+    /// Return the address family of this socket address.
+    #[inline]
+    pub const fn address_family(&self) -> AddressFamily {
+        AddressFamily::from_raw(self.family)
+    }
+
+    /// Return the encoded socket address, including the address family
+    /// header.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Writes this socket address to the memory pointed to by `storage`, and
+    /// returns the number of bytes used.
+    ///
+    /// # Safety
+    ///
+    /// `storage` must point to valid memory for encoding the socket address.
+    pub(crate) unsafe fn write(&self, storage: *mut SocketAddrStorage) -> usize {
+        ptr::copy_nonoverlapping(self.bytes.as_ptr(), storage.cast::<u8>(), self.len);
+        self.len
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for SocketAddrRaw {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SocketAddrRaw")
+            .field("address_family", &self.address_family())
+            .field("bytes", &self.as_bytes())
+            .finish()
+    }
+}
+
+/// Caller-provided storage for a socket address written by the OS.
+///
+/// [`recvfrom_into`], [`acceptfrom_into`], and [`getpeername_into`] have the
+/// OS write the address directly into a `SocketAddrBuf`, rather than decoding
+/// it into a new [`SocketAddrAny`]. The address can be inspected in place
+/// with [`address_family`] and [`as_bytes`], or decoded with [`decode`].
+///
+/// [`recvfrom_into`]: crate::net::recvfrom_into
+/// [`acceptfrom_into`]: crate::net::acceptfrom_into
+/// [`getpeername_into`]: crate::net::getpeername_into
+/// [`address_family`]: Self::address_family
+/// [`as_bytes`]: Self::as_bytes
+/// [`decode`]: Self::decode
+pub struct SocketAddrBuf {
+    storage: MaybeUninit<SocketAddrStorage>,
+    len: usize,
+}
+
+impl SocketAddrBuf {
+    /// Construct a new empty `SocketAddrBuf`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            storage: MaybeUninit::uninit(),
+            len: 0,
+        }
+    }
+
+    /// Return the address family of the stored socket address, or `None` if
+    /// the OS didn't store an address.
+    #[inline]
+    pub fn address_family(&self) -> Option<AddressFamily> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // SAFETY: The OS initialized `len` bytes, which include the family.
+        let family = unsafe { read_sockaddr::read_ss_family(self.storage.as_ptr()) };
+        match AddressFamily::from_raw(family as RawAddressFamily) {
+            AddressFamily::UNSPEC => None,
+            family => Some(family),
+        }
+    }
+
+    /// Return the encoded socket address, including the address family
+    /// header, or an empty slice if the OS didn't store an address.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: The OS initialized `len` bytes.
+        unsafe { slice::from_raw_parts(self.storage.as_ptr().cast::<u8>(), self.len) }
+    }
+
+    /// Decode the stored socket address into a [`SocketAddrAny`], or return
+    /// `None` if the OS didn't store an address.
+    #[inline]
+    pub fn decode(&self) -> Option<SocketAddrAny> {
+        self.address_family()?;
+
+        // SAFETY: The OS stored a socket address of `len` bytes.
+        unsafe { read_sockaddr::maybe_read_sockaddr_os(self.storage.as_ptr(), self.len) }
+    }
+
+    /// Call `f` to have the OS write a socket address into this buffer, and
+    /// record the length it returns.
+    pub(crate) fn fill<T>(
+        &mut self,
+        f: impl FnOnce(&mut MaybeUninit<SocketAddrStorage>) -> io::Result<(T, usize)>,
+    ) -> io::Result<T> {
+        self.len = 0;
+        let (result, len) = f(&mut self.storage)?;
+
+        // If the address was truncated, the OS reports its full length.
+        self.len = len.min(size_of::<SocketAddrStorage>());
+        Ok(result)
+    }
+}
+
+impl Default for SocketAddrBuf {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for SocketAddrBuf {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SocketAddrBuf")
+            .field("address_family", &self.address_family())
+            .field("bytes", &self.as_bytes())
+            .finish()
+    }
+}
+
+/// Convert a [`SocketAddrUnix`] into a `std::os::unix::net::SocketAddr`.
+///
+/// This fails with [`io::Errno::INVAL`] if the address can't be represented
+/// in std, such as an abstract address on a Rust version which lacks support
+/// for them.
+#[cfg(all(unix, feature = "std"))]
+impl TryFrom<&SocketAddrUnix> for std::os::unix::net::SocketAddr {
+    type Error = io::Errno;
+
+    fn try_from(from: &SocketAddrUnix) -> io::Result<Self> {
+        use std::os::unix::ffi::OsStrExt;
+
+        if let Some(path) = from.path() {
+            let path = std::ffi::OsStr::from_bytes(path.to_bytes());
+            return Self::from_pathname(path).map_err(|_| io::Errno::INVAL);
         }
+
+        #[cfg(all(linux_kernel, unix_socket_abstract))]
+        if let Some(name) = from.abstract_name() {
+            #[cfg(target_os = "android")]
+            use std::os::android::net::SocketAddrExt;
+            #[cfg(target_os = "linux")]
+            use std::os::linux::net::SocketAddrExt;
+
+            return Self::from_abstract_name(name).map_err(|_| io::Errno::INVAL);
+        }
+
+        Err(io::Errno::INVAL)
+    }
+}
+
+#[cfg(all(unix, feature = "std"))]
+impl TryFrom<SocketAddrUnix> for std::os::unix::net::SocketAddr {
+    type Error = io::Errno;
+
+    #[inline]
+    fn try_from(from: SocketAddrUnix) -> io::Result<Self> {
+        Self::try_from(&from)
+    }
+}
+
+/// Convert a `std::os::unix::net::SocketAddr` into a [`SocketAddrUnix`].
+///
+/// This fails with [`io::Errno::INVAL`] if the address is an abstract address
+/// and the Rust version lacks support for reading them.
+#[cfg(all(unix, feature = "std"))]
+impl TryFrom<&std::os::unix::net::SocketAddr> for SocketAddrUnix {
+    type Error = io::Errno;
+
+    fn try_from(from: &std::os::unix::net::SocketAddr) -> io::Result<Self> {
+        if let Some(path) = from.as_pathname() {
+            return Self::new(path);
+        }
+
+        if from.is_unnamed() {
+            return Ok(Self::new_unnamed());
+        }
+
+        #[cfg(all(linux_kernel, unix_socket_abstract))]
+        {
+            #[cfg(target_os = "android")]
+            use std::os::android::net::SocketAddrExt;
+            #[cfg(target_os = "linux")]
+            use std::os::linux::net::SocketAddrExt;
+
+            if let Some(name) = from.as_abstract_name() {
+                return Self::new_abstract_name(name);
+            }
+        }
+
+        Err(io::Errno::INVAL)
+    }
+}
+
+#[cfg(all(unix, feature = "std"))]
+impl TryFrom<std::os::unix::net::SocketAddr> for SocketAddrUnix {
+    type Error = io::Errno;
+
+    #[inline]
+    fn try_from(from: std::os::unix::net::SocketAddr) -> io::Result<Self> {
+        Self::try_from(&from)
     }
 }
@@ -167,11 +167,15 @@ use crate::net::AddressFamily;
     target_env = "newlib"
 ))]
 use crate::net::Protocol;
+#[cfg(target_os = "linux")]
+use crate::net::SockFilter;
 #[cfg(any(linux_kernel, target_os = "fuchsia"))]
 use crate::net::SocketAddrV4;
-#[cfg(linux_kernel)]
-use crate::net::SocketAddrV6;
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketType};
+#[cfg(linux_kernel)]
+use crate::net::{SocketAddrAny, SocketAddrV6};
+#[cfg(linux_kernel)]
+use crate::path::OutputBuffer;
 use crate::{backend, io};
 #[cfg(feature = "alloc")]
 #[cfg(any(
@@ -197,6 +201,37 @@ pub enum Timeout {
     Send = c::SO_SNDTIMEO as _,
 }
 
+/// Path MTU discovery mode for use with [`set_ip_mtu_discover`] and
+/// [`set_ipv6_mtu_discover`].
+#[cfg(linux_kernel)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum PathMtuDiscovery {
+    /// `IP_PMTUDISC_DONT`—Never set the Don't Fragment flag, and let
+    /// packets be fragmented.
+    Dont = c::IP_PMTUDISC_DONT as _,
+
+    /// `IP_PMTUDISC_WANT`—Use per-route path MTU discovery, fragmenting
+    /// packets which are larger than the path MTU.
+    Want = c::IP_PMTUDISC_WANT as _,
+
+    /// `IP_PMTUDISC_DO`—Always set the Don't Fragment flag, and fail with
+    /// [`io::Errno::MSGSIZE`] on packets larger than the path MTU.
+    Do = c::IP_PMTUDISC_DO as _,
+
+    /// `IP_PMTUDISC_PROBE`—Set the Don't Fragment flag, but ignore the path
+    /// MTU and only limit packets to the interface MTU.
+    Probe = c::IP_PMTUDISC_PROBE as _,
+
+    /// `IP_PMTUDISC_INTERFACE`—Limit packets to the interface MTU, never
+    /// set the Don't Fragment flag, and ignore path MTU updates.
+    Interface = c::IP_PMTUDISC_INTERFACE as _,
+
+    /// `IP_PMTUDISC_OMIT`—Like `Interface`, but allow fragmentation of
+    /// packets larger than the interface MTU.
+    Omit = c::IP_PMTUDISC_OMIT as _,
+}
+
 /// `getsockopt(fd, SOL_SOCKET, SO_TYPE)`—Returns the type of a socket.
 ///
 /// See the [module-level documentation] for more.
@@ -253,7 +288,19 @@ pub fn get_socket_broadcast<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::sockopt::get_socket_broadcast(fd.as_fd())
 }
 
-/// `setsockopt(fd, SOL_SOCKET, SO_LINGER, value)`
+/// `setsockopt(fd, SOL_SOCKET, SO_LINGER, value)`—Set how closing the
+/// socket handles unsent data.
+///
+/// With `None`, the default, closing the socket returns immediately, and the
+/// system tries to send any remaining data in the background. With
+/// `Some(timeout)`, closing the socket blocks until the remaining data is
+/// sent and acknowledged, or until `timeout` elapses, after which the data is
+/// discarded. With `Some(Duration::ZERO)`, closing the socket discards any
+/// remaining data immediately, and TCP sockets send an RST to the peer
+/// instead of the normal shutdown sequence.
+///
+/// `timeout` is rounded up to a whole number of seconds. On Apple platforms,
+/// this uses `SO_LINGER_SEC`, since `SO_LINGER` there is in clock ticks.
 ///
 /// See the [module-level documentation] for more.
 ///
@@ -299,6 +346,36 @@ pub fn get_socket_passcred<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::sockopt::get_socket_passcred(fd.as_fd())
 }
 
+/// `setsockopt(fd, SOL_SOCKET, SO_PASSSEC, value)`—Enable receiving the
+/// security label of the sender of each message.
+///
+/// When enabled on a Unix-domain socket, [`recvmsg`] receives the sender's
+/// LSM security label as a [`RecvAncillaryMessage::ScmSecurity`] message.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+/// [`recvmsg`]: crate::net::recvmsg
+/// [`RecvAncillaryMessage::ScmSecurity`]: crate::net::RecvAncillaryMessage::ScmSecurity
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_PASSSEC")]
+pub fn set_socket_passsec<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_socket_passsec(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_PASSSEC)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_PASSSEC")]
+pub fn get_socket_passsec<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::get_socket_passsec(fd.as_fd())
+}
+
 /// `setsockopt(fd, SOL_SOCKET, id, value)`—Set the sending or receiving
 /// timeout.
 ///
@@ -330,11 +407,21 @@ pub fn get_socket_timeout<Fd: AsFd>(fd: Fd, id: Timeout) -> io::Result<Option<Du
 
 /// `getsockopt(fd, SOL_SOCKET, SO_ERROR)`
 ///
+/// The outer `Result` reports whether the `getsockopt` call itself failed.
+/// The inner `Result` reports the socket's pending error, if any, such as
+/// the outcome of a nonblocking [`connect`] once the socket becomes
+/// writable.
+///
+/// Reading the pending error clears it, so a second call returns `Ok(Ok(()))`
+/// unless another error has occurred in the meantime.
+///
 /// See the [module-level documentation] for more.
 ///
 /// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+/// [`connect`]: crate::net::connect
 #[inline]
 #[doc(alias = "SO_ERROR")]
+#[doc(alias = "take_socket_error")]
 pub fn get_socket_error<Fd: AsFd>(fd: Fd) -> io::Result<Result<(), io::Errno>> {
     backend::net::sockopt::get_socket_error(fd.as_fd())
 }
@@ -385,7 +472,13 @@ pub fn get_socket_keepalive<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::sockopt::get_socket_keepalive(fd.as_fd())
 }
 
-/// `setsockopt(fd, SOL_SOCKET, SO_RCVBUF, value)`
+/// `setsockopt(fd, SOL_SOCKET, SO_RCVBUF, value)`—Set the size of the
+/// socket's receive buffer.
+///
+/// On Linux, the kernel doubles `value` to leave room for its bookkeeping
+/// overhead, and [`get_socket_recv_buffer_size`] returns the doubled value.
+/// `value` is silently capped at the `net.core.rmem_max` sysctl; use
+/// [`set_socket_recv_buffer_size_force`] to exceed it.
 ///
 /// See the [module-level documentation] for more.
 ///
@@ -407,7 +500,13 @@ pub fn get_socket_recv_buffer_size<Fd: AsFd>(fd: Fd) -> io::Result<usize> {
     backend::net::sockopt::get_socket_recv_buffer_size(fd.as_fd())
 }
 
-/// `setsockopt(fd, SOL_SOCKET, SO_SNDBUF, value)`
+/// `setsockopt(fd, SOL_SOCKET, SO_SNDBUF, value)`—Set the size of the
+/// socket's send buffer.
+///
+/// On Linux, the kernel doubles `value` to leave room for its bookkeeping
+/// overhead, and [`get_socket_send_buffer_size`] returns the doubled value.
+/// `value` is silently capped at the `net.core.wmem_max` sysctl; use
+/// [`set_socket_send_buffer_size_force`] to exceed it.
 ///
 /// See the [module-level documentation] for more.
 ///
@@ -429,6 +528,38 @@ pub fn get_socket_send_buffer_size<Fd: AsFd>(fd: Fd) -> io::Result<usize> {
     backend::net::sockopt::get_socket_send_buffer_size(fd.as_fd())
 }
 
+/// `setsockopt(fd, SOL_SOCKET, SO_RCVBUFFORCE, value)`—Set the size of the
+/// socket's receive buffer, ignoring the `net.core.rmem_max` limit.
+///
+/// This requires the `CAP_NET_ADMIN` capability. Like
+/// [`set_socket_recv_buffer_size`], the kernel doubles `value`.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_RCVBUFFORCE")]
+pub fn set_socket_recv_buffer_size_force<Fd: AsFd>(fd: Fd, value: usize) -> io::Result<()> {
+    backend::net::sockopt::set_socket_recv_buffer_size_force(fd.as_fd(), value)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_SNDBUFFORCE, value)`—Set the size of the
+/// socket's send buffer, ignoring the `net.core.wmem_max` limit.
+///
+/// This requires the `CAP_NET_ADMIN` capability. Like
+/// [`set_socket_send_buffer_size`], the kernel doubles `value`.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_SNDBUFFORCE")]
+pub fn set_socket_send_buffer_size_force<Fd: AsFd>(fd: Fd, value: usize) -> io::Result<()> {
+    backend::net::sockopt::set_socket_send_buffer_size_force(fd.as_fd(), value)
+}
+
 /// `getsockopt(fd, SOL_SOCKET, SO_DOMAIN)`
 ///
 /// See the [module-level documentation] for more.
@@ -590,6 +721,149 @@ pub fn set_socket_incoming_cpu<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
     backend::net::sockopt::set_socket_incoming_cpu(fd.as_fd(), value)
 }
 
+/// `setsockopt(fd, SOL_SOCKET, SO_BUSY_POLL, value)`—Sets the number of
+/// microseconds to busy-poll the device queue for, when receiving with no
+/// data available.
+///
+/// The default is the `net.core.busy_read` sysctl, and increasing the value
+/// requires `CAP_NET_ADMIN`. This fails with [`io::Errno::NOPROTOOPT`] if the kernel
+/// was built without `CONFIG_NET_RX_BUSY_POLL`.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "SO_BUSY_POLL")]
+pub fn set_socket_busy_poll<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
+    backend::net::sockopt::set_socket_busy_poll(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_BUSY_POLL)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "SO_BUSY_POLL")]
+pub fn get_socket_busy_poll<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::get_socket_busy_poll(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_PREFER_BUSY_POLL, value)`—Sets whether
+/// busy-polling is preferred over interrupt-driven processing of the device
+/// queue.
+///
+/// Enabling this requires `CAP_NET_ADMIN`, and Linux 5.11.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "SO_PREFER_BUSY_POLL")]
+pub fn set_socket_prefer_busy_poll<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_socket_prefer_busy_poll(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_PREFER_BUSY_POLL)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "SO_PREFER_BUSY_POLL")]
+pub fn get_socket_prefer_busy_poll<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::get_socket_prefer_busy_poll(fd.as_fd())
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_INCOMING_NAPI_ID)`—Returns the ID of the
+/// NAPI context of the device queue which last received a packet for this
+/// socket.
+///
+/// This is 0 if no packet has been received yet, or if the device doesn't use
+/// NAPI. Servers can use this to steer connections to threads which
+/// busy-poll the same device queue.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "SO_INCOMING_NAPI_ID")]
+pub fn get_socket_incoming_napi_id<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::get_socket_incoming_napi_id(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_ATTACH_REUSEPORT_CBPF, program)`—Attaches
+/// a classic BPF program which selects the socket in `fd`'s
+/// [`SO_REUSEPORT`] group to receive each packet or connection.
+///
+/// The program returns the index of the socket to select, in the order the
+/// sockets were added to the group. If it returns an index which is out of
+/// range, the socket is selected by hash as if there were no program. This
+/// fails with [`io::Errno::INVAL`] if `program` has more than `u16::MAX`
+/// instructions.
+///
+/// A program which returns the CPU that received the packet, combined with a
+/// socket per CPU and [`set_socket_incoming_cpu`], keeps each connection's
+/// processing on one CPU.
+///
+/// See the [module-level documentation] for more.
+///
+/// [`SO_REUSEPORT`]: set_socket_reuseport
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "SO_ATTACH_REUSEPORT_CBPF")]
+pub fn set_socket_attach_reuseport_cbpf<Fd: AsFd>(
+    fd: Fd,
+    program: &[SockFilter],
+) -> io::Result<()> {
+    backend::net::sockopt::set_socket_attach_reuseport_cbpf(fd.as_fd(), program)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_ATTACH_REUSEPORT_EBPF, program)`—Attaches
+/// an eBPF program which selects the socket in `fd`'s [`SO_REUSEPORT`] group
+/// to receive each packet or connection.
+///
+/// `program` is a file descriptor for a loaded eBPF program of type
+/// `BPF_PROG_TYPE_SOCKET_FILTER` or `BPF_PROG_TYPE_SK_REUSEPORT`. This fails
+/// with [`io::Errno::INVAL`] if it isn't one.
+///
+/// See the [module-level documentation] for more.
+///
+/// [`SO_REUSEPORT`]: set_socket_reuseport
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "SO_ATTACH_REUSEPORT_EBPF")]
+pub fn set_socket_attach_reuseport_ebpf<Fd: AsFd, ProgramFd: AsFd>(
+    fd: Fd,
+    program: ProgramFd,
+) -> io::Result<()> {
+    backend::net::sockopt::set_socket_attach_reuseport_ebpf(fd.as_fd(), program.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_DETACH_REUSEPORT_BPF, 0)`—Detaches the
+/// program attached to `fd`'s [`SO_REUSEPORT`] group.
+///
+/// This fails with [`io::Errno::NOENT`] if no program is attached. It requires
+/// Linux 5.3.
+///
+/// See the [module-level documentation] for more.
+///
+/// [`SO_REUSEPORT`]: set_socket_reuseport
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "SO_DETACH_REUSEPORT_BPF")]
+pub fn set_socket_detach_reuseport_bpf<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    backend::net::sockopt::set_socket_detach_reuseport_bpf(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_IP, IP_TTL, value)`
 ///
 /// See the [module-level documentation] for more.
@@ -983,6 +1257,132 @@ pub fn get_ip_recvtos<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::sockopt::get_ip_recvtos(fd.as_fd())
 }
 
+/// `setsockopt(fd, IPPROTO_IP, IP_RECVERR, value)`—Queue extended error
+/// reports for this socket.
+///
+/// When enabled, errors such as ICMP "destination unreachable" and
+/// "fragmentation needed" messages are queued on the socket, and can be read
+/// with [`recvmsg`] and [`RecvFlags::ERRQUEUE`], as
+/// [`RecvAncillaryMessage::IpRecvErr`] messages.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+/// [`recvmsg`]: crate::net::recvmsg
+/// [`RecvFlags::ERRQUEUE`]: crate::net::RecvFlags::ERRQUEUE
+/// [`RecvAncillaryMessage::IpRecvErr`]: crate::net::RecvAncillaryMessage::IpRecvErr
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_RECVERR")]
+pub fn set_ip_recverr<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ip_recverr(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_RECVERR)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_RECVERR")]
+pub fn get_ip_recverr<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::get_ip_recverr(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_RECVERR, value)`—Queue extended error
+/// reports for this socket.
+///
+/// This is the IPv6 counterpart of [`set_ip_recverr`]; errors are read as
+/// [`RecvAncillaryMessage::Ipv6RecvErr`] messages.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+/// [`RecvAncillaryMessage::Ipv6RecvErr`]: crate::net::RecvAncillaryMessage::Ipv6RecvErr
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_RECVERR")]
+pub fn set_ipv6_recverr<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ipv6_recverr(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_RECVERR)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_RECVERR")]
+pub fn get_ipv6_recverr<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::get_ipv6_recverr(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_PKTINFO, value)`—Receive the destination
+/// address and interface of each packet.
+///
+/// When enabled, [`recvmsg`] returns a [`RecvAncillaryMessage::IpPktInfo`]
+/// message with each packet. This lets a server bound to a wildcard address
+/// tell which of its addresses a packet was sent to, and reply from that
+/// address by sending a [`SendAncillaryMessage::IpPktInfo`] message.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+/// [`recvmsg`]: crate::net::recvmsg
+/// [`RecvAncillaryMessage::IpPktInfo`]: crate::net::RecvAncillaryMessage::IpPktInfo
+/// [`SendAncillaryMessage::IpPktInfo`]: crate::net::SendAncillaryMessage::IpPktInfo
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_PKTINFO")]
+pub fn set_ip_pktinfo<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ip_pktinfo(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_PKTINFO)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_PKTINFO")]
+pub fn get_ip_pktinfo<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::get_ip_pktinfo(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_RECVPKTINFO, value)`—Receive the
+/// destination address and interface of each packet.
+///
+/// This is the IPv6 counterpart of [`set_ip_pktinfo`]; the information is
+/// received as [`RecvAncillaryMessage::Ipv6PktInfo`] messages, and can be
+/// sent with [`SendAncillaryMessage::Ipv6PktInfo`] messages.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+/// [`RecvAncillaryMessage::Ipv6PktInfo`]: crate::net::RecvAncillaryMessage::Ipv6PktInfo
+/// [`SendAncillaryMessage::Ipv6PktInfo`]: crate::net::SendAncillaryMessage::Ipv6PktInfo
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_RECVPKTINFO")]
+pub fn set_ipv6_recvpktinfo<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ipv6_recvpktinfo(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_RECVPKTINFO)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_RECVPKTINFO")]
+pub fn get_ipv6_recvpktinfo<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::get_ipv6_recvpktinfo(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_IPV6, IPV6_RECVTCLASS, value)`
 ///
 /// See the [module-level documentation] for more.
@@ -1019,11 +1419,17 @@ pub fn get_ipv6_recvtclass<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::sockopt::get_ipv6_recvtclass(fd.as_fd())
 }
 
-/// `setsockopt(fd, IPPROTO_IP, IP_FREEBIND, value)`
+/// `setsockopt(fd, IPPROTO_IP, IP_FREEBIND, value)`—Allow binding to
+/// addresses which aren't assigned to any local interface.
+///
+/// This lets a daemon bind to an address before the interface carrying it
+/// comes up, such as a virtual address which is moved between hosts on
+/// failover. Unlike [`set_ip_transparent`], this doesn't require any
+/// privileges.
 ///
 /// See the [module-level documentation] for more.
 ///
-/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
 #[cfg(any(linux_kernel, target_os = "fuchsia"))]
 #[inline]
 #[doc(alias = "IP_FREEBIND")]
@@ -1035,7 +1441,7 @@ pub fn set_ip_freebind<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
 ///
 /// See the [module-level documentation] for more.
 ///
-/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
 #[cfg(any(linux_kernel, target_os = "fuchsia"))]
 #[inline]
 #[doc(alias = "IP_FREEBIND")]
@@ -1043,7 +1449,10 @@ pub fn get_ip_freebind<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::sockopt::get_ip_freebind(fd.as_fd())
 }
 
-/// `setsockopt(fd, IPPROTO_IPV6, IPV6_FREEBIND, value)`
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_FREEBIND, value)`—Allow binding to
+/// addresses which aren't assigned to any local interface.
+///
+/// This is the IPv6 counterpart of [`set_ip_freebind`].
 ///
 /// See the [module-level documentation] for more.
 ///
@@ -1067,14 +1476,84 @@ pub fn get_ipv6_freebind<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::sockopt::get_ipv6_freebind(fd.as_fd())
 }
 
-/// `getsockopt(fd, IPPROTO_IP, SO_ORIGINAL_DST)`
+/// `setsockopt(fd, IPPROTO_IP, IP_TRANSPARENT, value)`—Enable transparent
+/// proxying.
 ///
-/// Even though this corresponnds to a `SO_*` constant, it is an `IPPROTO_IP`
-/// option.
+/// This allows the socket to bind to any address, including non-local
+/// addresses, and to accept connections and packets redirected to it by an
+/// iptables or nftables `TPROXY` rule. For accepted connections,
+/// [`getsockname`] returns the address the client originally connected to.
+///
+/// This requires the `CAP_NET_ADMIN` or `CAP_NET_RAW` capability, and
+/// otherwise fails with [`io::Errno::PERM`].
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+/// [`getsockname`]: crate::net::getsockname
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_TRANSPARENT")]
+pub fn set_ip_transparent<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ip_transparent(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_TRANSPARENT)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_TRANSPARENT")]
+pub fn get_ip_transparent<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::get_ip_transparent(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_TRANSPARENT, value)`—Enable
+/// transparent proxying.
+///
+/// This is the IPv6 counterpart of [`set_ip_transparent`].
 ///
 /// See the [module-level documentation] for more.
 ///
 /// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_TRANSPARENT")]
+pub fn set_ipv6_transparent<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ipv6_transparent(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_TRANSPARENT)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_TRANSPARENT")]
+pub fn get_ipv6_transparent<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::get_ipv6_transparent(fd.as_fd())
+}
+
+/// `getsockopt(fd, IPPROTO_IP, SO_ORIGINAL_DST)`—Get the original
+/// destination of a connection redirected by netfilter.
+///
+/// Even though this corresponds to a `SO_*` constant, it is an `IPPROTO_IP`
+/// option.
+///
+/// For a connection accepted by a transparent proxy after an iptables or
+/// nftables `REDIRECT` or `DNAT` rule, this returns the address the client
+/// originally connected to, rather than the proxy's own address. It fails
+/// with [`io::Errno::NOENT`] if the connection has no netfilter connection
+/// tracking entry.
+///
+/// See also [`get_original_dst`], which handles IPv6 sockets too.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
 #[cfg(any(linux_kernel, target_os = "fuchsia"))]
 #[inline]
 #[doc(alias = "SO_ORIGINAL_DST")]
@@ -1082,11 +1561,17 @@ pub fn get_ip_original_dst<Fd: AsFd>(fd: Fd) -> io::Result<SocketAddrV4> {
     backend::net::sockopt::get_ip_original_dst(fd.as_fd())
 }
 
-/// `getsockopt(fd, IPPROTO_IPV6, IP6T_SO_ORIGINAL_DST)`
+/// `getsockopt(fd, IPPROTO_IPV6, IP6T_SO_ORIGINAL_DST)`—Get the original
+/// destination of a connection redirected by netfilter.
 ///
-/// Even though this corresponnds to a `IP6T_*` constant, it is an
+/// Even though this corresponds to a `IP6T_*` constant, it is an
 /// `IPPROTO_IPV6` option.
 ///
+/// This is the IPv6 counterpart of [`get_ip_original_dst`]. Connections to
+/// an IPv6 socket from IPv4 clients are tracked as IPv4 connections, so for
+/// those, use `get_ip_original_dst`, or [`get_original_dst`] which handles
+/// both.
+///
 /// See the [module-level documentation] for more.
 ///
 /// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
@@ -1097,6 +1582,42 @@ pub fn get_ipv6_original_dst<Fd: AsFd>(fd: Fd) -> io::Result<SocketAddrV6> {
     backend::net::sockopt::get_ipv6_original_dst(fd.as_fd())
 }
 
+/// Get the original destination of a connection redirected by netfilter, on
+/// an IPv4 or IPv6 socket.
+///
+/// This uses [`get_ipv6_original_dst`] on IPv6 sockets, and falls back to
+/// [`get_ip_original_dst`] for IPv4 sockets and for IPv4 clients of
+/// dual-stack IPv6 sockets.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> std::io::Result<()> {
+/// use rustix::net::sockopt::get_original_dst;
+///
+/// let listener = std::net::TcpListener::bind("[::]:3129")?;
+/// let (conn, _peer) = listener.accept()?;
+/// let original_dst = get_original_dst(&conn)?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(linux_kernel)]
+#[doc(alias = "SO_ORIGINAL_DST")]
+#[doc(alias = "IP6T_SO_ORIGINAL_DST")]
+pub fn get_original_dst<Fd: AsFd>(fd: Fd) -> io::Result<SocketAddrAny> {
+    let fd = fd.as_fd();
+
+    if backend::net::sockopt::get_socket_domain(fd)? == AddressFamily::INET6 {
+        match backend::net::sockopt::get_ipv6_original_dst(fd) {
+            // IPv4 clients of a dual-stack socket are tracked as IPv4.
+            Err(io::Errno::NOENT) => (),
+            result => return result.map(SocketAddrAny::V6),
+        }
+    }
+
+    backend::net::sockopt::get_ip_original_dst(fd).map(SocketAddrAny::V4)
+}
+
 /// `setsockopt(fd, IPPROTO_IPV6, IPV6_TCLASS, value)`
 ///
 /// See the [module-level documentation] for more.
@@ -1133,10 +1654,123 @@ pub fn get_ipv6_tclass<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
     backend::net::sockopt::get_ipv6_tclass(fd.as_fd())
 }
 
+/// `setsockopt(fd, IPPROTO_IP, IP_MTU_DISCOVER, value)`—Set the path MTU
+/// discovery mode.
+///
+/// With [`PathMtuDiscovery::Do`] or [`PathMtuDiscovery::Probe`], sending a
+/// datagram which is larger than the MTU fails with
+/// [`io::Errno::MSGSIZE`], so the caller can do its own packetization, as
+/// QUIC and tunneling implementations do.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_MTU_DISCOVER")]
+pub fn set_ip_mtu_discover<Fd: AsFd>(fd: Fd, value: PathMtuDiscovery) -> io::Result<()> {
+    backend::net::sockopt::set_ip_mtu_discover(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_MTU_DISCOVER)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_MTU_DISCOVER")]
+pub fn get_ip_mtu_discover<Fd: AsFd>(fd: Fd) -> io::Result<PathMtuDiscovery> {
+    backend::net::sockopt::get_ip_mtu_discover(fd.as_fd())
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_MTU)`—Returns the current known path MTU.
+///
+/// The socket must be connected; otherwise this fails with
+/// [`io::Errno::NOTCONN`].
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_MTU")]
+pub fn get_ip_mtu<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::get_ip_mtu(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_MTU_DISCOVER, value)`—Set the path
+/// MTU discovery mode.
+///
+/// This is the IPv6 counterpart of [`set_ip_mtu_discover`]. IPv6 routers
+/// never fragment packets, so [`PathMtuDiscovery::Dont`] only permits
+/// fragmentation by the sending host.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_MTU_DISCOVER")]
+pub fn set_ipv6_mtu_discover<Fd: AsFd>(fd: Fd, value: PathMtuDiscovery) -> io::Result<()> {
+    backend::net::sockopt::set_ipv6_mtu_discover(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_MTU_DISCOVER)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_MTU_DISCOVER")]
+pub fn get_ipv6_mtu_discover<Fd: AsFd>(fd: Fd) -> io::Result<PathMtuDiscovery> {
+    backend::net::sockopt::get_ipv6_mtu_discover(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_MTU, value)`—Set the MTU to use for
+/// outgoing packets, instead of the path MTU.
+///
+/// A value of 0 restores the default of using the path MTU. Values below the
+/// IPv6 minimum MTU of 1280 fail with [`io::Errno::INVAL`].
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_MTU")]
+pub fn set_ipv6_mtu<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
+    backend::net::sockopt::set_ipv6_mtu(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_MTU)`—Returns the current known path
+/// MTU.
+///
+/// The socket must be connected; otherwise this fails with
+/// [`io::Errno::NOTCONN`].
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_MTU")]
+pub fn get_ipv6_mtu<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::get_ipv6_mtu(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_TCP, TCP_NODELAY, value)`
 ///
+/// This disables Nagle's algorithm, so that small writes are sent
+/// immediately instead of being coalesced while earlier data is
+/// unacknowledged. To batch specific writes on a `TCP_NODELAY` socket, use
+/// `TCP_CORK` or [`SendFlags::MORE`].
+///
 /// See the [module-level documentation] for more.
 ///
+/// [`SendFlags::MORE`]: crate::net::SendFlags::MORE
+///
 /// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
 #[inline]
 #[doc(alias = "TCP_NODELAY")]
@@ -1155,6 +1789,30 @@ pub fn get_tcp_nodelay<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::sockopt::get_tcp_nodelay(fd.as_fd())
 }
 
+/// `setsockopt(fd, IPPROTO_TCP, TCP_MAXSEG, value)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(not(windows))]
+#[inline]
+#[doc(alias = "TCP_MAXSEG")]
+pub fn set_tcp_maxseg<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
+    backend::net::sockopt::set_tcp_maxseg(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_MAXSEG)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(not(windows))]
+#[inline]
+#[doc(alias = "TCP_MAXSEG")]
+pub fn get_tcp_maxseg<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::get_tcp_maxseg(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_TCP, TCP_KEEPCNT, value)`
 ///
 /// See the [module-level documentation] for more.
@@ -1340,9 +1998,21 @@ pub fn get_tcp_thin_linear_timeouts<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
 
 /// `setsockopt(fd, IPPROTO_TCP, TCP_CORK, value)`
 ///
+/// While a socket is corked, the kernel holds back partial segments, so that
+/// several writes, such as an HTTP response header followed by its body, are
+/// sent in as few packets as possible. Uncorking, by setting this to `false`,
+/// flushes any pending data immediately.
+///
+/// For a single batch of writes, [`SendFlags::MORE`] has the same effect
+/// without the extra system calls to cork and uncork. Corking takes
+/// precedence over [`set_tcp_nodelay`]. On BSD-family platforms, see
+/// [`set_tcp_nopush`] instead.
+///
 /// See the [module-level documentation] for more.
 ///
 /// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+/// [`SendFlags::MORE`]: crate::net::SendFlags::MORE
+/// [`set_tcp_nopush`]: https://docs.rs/rustix/*/x86_64-apple-darwin/rustix/net/sockopt/fn.set_tcp_nopush.html
 #[cfg(any(linux_like, solarish, target_os = "fuchsia"))]
 #[inline]
 #[doc(alias = "TCP_CORK")]
@@ -1362,18 +2032,100 @@ pub fn get_tcp_cork<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::sockopt::get_tcp_cork(fd.as_fd())
 }
 
+/// `setsockopt(fd, IPPROTO_TCP, TCP_NOPUSH, value)`
+///
+/// This is the BSD-family counterpart of `TCP_CORK`. While it's set, the
+/// kernel only sends full segments. Unlike uncorking on Linux, clearing it
+/// doesn't flush pending data until the next write on some platforms.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(any(apple, freebsdlike, target_os = "openbsd"))]
+#[inline]
+#[doc(alias = "TCP_NOPUSH")]
+pub fn set_tcp_nopush<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_tcp_nopush(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_NOPUSH)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(any(apple, freebsdlike, target_os = "openbsd"))]
+#[inline]
+#[doc(alias = "TCP_NOPUSH")]
+pub fn get_tcp_nopush<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::get_tcp_nopush(fd.as_fd())
+}
+
 /// Get credentials of Unix domain socket peer process
 ///
+/// This uses `SO_PEERCRED` on Linux and OpenBSD, `LOCAL_PEERCRED` and
+/// `LOCAL_PEERPID` on macOS, `LOCAL_PEERCRED` on FreeBSD, and
+/// `LOCAL_PEEREID` on NetBSD. The credentials are those of the peer when it
+/// called [`connect`] or [`listen`], or [`socketpair`].
+///
+/// On FreeBSD before 13.0, which doesn't report the peer's process ID, this
+/// fails with [`io::Errno::NOTSUP`]. On other BSDs, `getpeereid` can be used
+/// to get just the user and group IDs.
+///
 /// # References
 ///  - [Linux `unix`]
+///  - [Apple `unix`]
+///  - [FreeBSD `unix`]
+///  - [NetBSD `unix`]
+///  - [OpenBSD `getsockopt`]
 ///
 /// [Linux `unix`]: https://man7.org/linux/man-pages/man7/unix.7.html
-#[cfg(linux_kernel)]
+/// [Apple `unix`]: https://github.com/apple-oss-distributions/xnu/blob/main/bsd/sys/un.h
+/// [FreeBSD `unix`]: https://man.freebsd.org/cgi/man.cgi?query=unix&sektion=4
+/// [NetBSD `unix`]: https://man.netbsd.org/unix.4
+/// [OpenBSD `getsockopt`]: https://man.openbsd.org/getsockopt.2
+/// [`connect`]: crate::net::connect
+/// [`listen`]: crate::net::listen
+/// [`socketpair`]: crate::net::socketpair()
+#[cfg(any(
+    linux_kernel,
+    apple,
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
 #[doc(alias = "SO_PEERCRED")]
+#[doc(alias = "LOCAL_PEERCRED")]
+#[doc(alias = "LOCAL_PEEREID")]
+#[doc(alias = "getpeereid")]
 pub fn get_socket_peercred<Fd: AsFd>(fd: Fd) -> io::Result<super::UCred> {
     backend::net::sockopt::get_socket_peercred(fd.as_fd())
 }
 
+/// `getsockopt(fd, SOL_SOCKET, SO_PEERSEC)`—Get the security label of a
+/// Unix-domain or TCP socket peer.
+///
+/// This returns the peer's label from the active Linux Security Module, such
+/// as an SELinux context or an AppArmor profile name, as it was when the
+/// connection was established. It fails with [`io::Errno::NOPROTOOPT`] if no
+/// LSM providing labels is active.
+///
+/// `buf` may be any [`OutputBuffer`], such as a `Vec<u8>` to allocate the
+/// result, or a fixed-size array to avoid allocating.
+///
+/// # References
+///  - [Linux `unix`]
+///
+/// [Linux `unix`]: https://man7.org/linux/man-pages/man7/unix.7.html
+#[cfg(linux_kernel)]
+#[doc(alias = "SO_PEERSEC")]
+#[allow(unsafe_code)]
+pub fn get_socket_peersec<Fd: AsFd, B: OutputBuffer>(fd: Fd, buf: B) -> io::Result<B::Name> {
+    let fd = fd.as_fd();
+
+    // SAFETY: The backend returns the number of bytes it initialized.
+    unsafe { buf.fill_name(&mut |buf| backend::net::sockopt::get_socket_peersec(fd, buf)) }
+}
+
 /// `setsockopt(fd, SOL_XDP, XDP_UMEM_REG, value)`
 ///
 /// On kernel versions only supporting v1, the flags are ignored.
@@ -46,6 +46,20 @@ impl SocketType {
     }
 }
 
+impl From<RawSocketType> for SocketType {
+    #[inline]
+    fn from(raw: RawSocketType) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl From<SocketType> for RawSocketType {
+    #[inline]
+    fn from(type_: SocketType) -> Self {
+        type_.as_raw()
+    }
+}
+
 /// A type for holding raw integer address families.
 pub type RawAddressFamily = c::sa_family_t;
 
@@ -586,7 +600,12 @@ impl AddressFamily {
     #[cfg(apple)]
     pub const UTUN: Self = Self(c::AF_UTUN as _);
     /// `AF_VSOCK`
-    #[cfg(any(apple, target_os = "emscripten", target_os = "fuchsia"))]
+    #[cfg(any(
+        apple,
+        linux_kernel,
+        target_os = "emscripten",
+        target_os = "fuchsia"
+    ))]
     pub const VSOCK: Self = Self(c::AF_VSOCK as _);
     /// `AF_XDP`
     #[cfg(target_os = "linux")]
@@ -605,6 +624,20 @@ impl AddressFamily {
     }
 }
 
+impl From<RawAddressFamily> for AddressFamily {
+    #[inline]
+    fn from(raw: RawAddressFamily) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl From<AddressFamily> for RawAddressFamily {
+    #[inline]
+    fn from(family: AddressFamily) -> Self {
+        family.as_raw()
+    }
+}
+
 /// A type for holding raw integer protocols.
 pub type RawProtocol = core::num::NonZeroU32;
 
@@ -1024,6 +1057,127 @@ pub mod netlink {
     /// `NETLINK_GET_STRICT_CHK`
     #[cfg(linux_kernel)]
     pub const GET_STRICT_CHK: Protocol = Protocol(new_raw_protocol(c::NETLINK_GET_STRICT_CHK as _));
+
+    #[cfg(linux_kernel)]
+    pub use crate::net::uevent::{Uevent, UeventVars, UEVENT_KERNEL_GROUP, UEVENT_UDEV_GROUP};
+
+    /// A netlink socket address.
+    ///
+    /// Used to bind a netlink socket to a port ID and a set of multicast
+    /// groups.
+    ///
+    /// Not ABI compatible with `struct sockaddr_nl`
+    #[cfg(linux_kernel)]
+    #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+    #[doc(alias = "sockaddr_nl")]
+    pub struct SocketAddrNetlink {
+        /// Port ID.
+        nl_pid: u32,
+        /// Multicast groups mask.
+        nl_groups: u32,
+    }
+
+    #[cfg(linux_kernel)]
+    impl SocketAddrNetlink {
+        /// Construct a new netlink address.
+        ///
+        /// A `pid` of 0 lets the kernel assign a port ID when binding.
+        /// `groups` is a bitmask of multicast groups to join, where group
+        /// `n` is bit `n - 1`.
+        #[inline]
+        pub const fn new(pid: u32, groups: u32) -> Self {
+            Self {
+                nl_pid: pid,
+                nl_groups: groups,
+            }
+        }
+
+        /// Return port ID.
+        #[inline]
+        pub const fn pid(&self) -> u32 {
+            self.nl_pid
+        }
+
+        /// Set port ID.
+        #[inline]
+        pub fn set_pid(&mut self, pid: u32) {
+            self.nl_pid = pid;
+        }
+
+        /// Return multicast groups mask.
+        #[inline]
+        pub const fn groups(&self) -> u32 {
+            self.nl_groups
+        }
+
+        /// Set multicast groups mask.
+        #[inline]
+        pub fn set_groups(&mut self, groups: u32) {
+            self.nl_groups = groups;
+        }
+    }
+}
+
+/// `AF_VSOCK` related types and constants.
+#[cfg(linux_kernel)]
+pub mod vsock {
+    /// `VMADDR_CID_ANY`—Bind to any CID.
+    pub const VMADDR_CID_ANY: u32 = u32::MAX;
+    /// `VMADDR_CID_HYPERVISOR`—The hypervisor.
+    pub const VMADDR_CID_HYPERVISOR: u32 = 0;
+    /// `VMADDR_CID_LOCAL`—Local communication, via loopback.
+    pub const VMADDR_CID_LOCAL: u32 = 1;
+    /// `VMADDR_CID_HOST`—The host, from within a guest.
+    pub const VMADDR_CID_HOST: u32 = 2;
+    /// `VMADDR_PORT_ANY`—Bind to any available port.
+    pub const VMADDR_PORT_ANY: u32 = u32::MAX;
+
+    /// A vsock socket address.
+    ///
+    /// Not ABI compatible with `struct sockaddr_vm`
+    #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+    #[doc(alias = "sockaddr_vm")]
+    pub struct SocketAddrVsock {
+        /// Context ID.
+        svm_cid: u32,
+        /// Port.
+        svm_port: u32,
+    }
+
+    impl SocketAddrVsock {
+        /// Construct a new vsock address.
+        #[inline]
+        pub const fn new(cid: u32, port: u32) -> Self {
+            Self {
+                svm_cid: cid,
+                svm_port: port,
+            }
+        }
+
+        /// Return context ID.
+        #[inline]
+        pub const fn cid(&self) -> u32 {
+            self.svm_cid
+        }
+
+        /// Set context ID.
+        #[inline]
+        pub fn set_cid(&mut self, cid: u32) {
+            self.svm_cid = cid;
+        }
+
+        /// Return port.
+        #[inline]
+        pub const fn port(&self) -> u32 {
+            self.svm_port
+        }
+
+        /// Set port.
+        #[inline]
+        pub fn set_port(&mut self, port: u32) {
+            self.svm_port = port;
+        }
+    }
 }
 
 /// `ETH_P_*` constants.
@@ -1402,6 +1556,20 @@ impl Protocol {
     }
 }
 
+impl From<RawProtocol> for Protocol {
+    #[inline]
+    fn from(raw: RawProtocol) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl From<Protocol> for RawProtocol {
+    #[inline]
+    fn from(protocol: Protocol) -> Self {
+        protocol.as_raw()
+    }
+}
+
 /// `SHUT_*` constants for use with [`shutdown`].
 ///
 /// [`shutdown`]: crate::net::shutdown
@@ -1416,6 +1584,49 @@ pub enum Shutdown {
     ReadWrite = c::SHUT_RDWR as _,
 }
 
+impl Shutdown {
+    /// Convert a raw `SHUT_*` value into a `Shutdown`, if possible.
+    #[inline]
+    pub const fn from_raw(raw: c::c_int) -> Option<Self> {
+        match raw {
+            raw if raw == Self::Read as c::c_int => Some(Self::Read),
+            raw if raw == Self::Write as c::c_int => Some(Self::Write),
+            raw if raw == Self::ReadWrite as c::c_int => Some(Self::ReadWrite),
+            _ => None,
+        }
+    }
+
+    /// Returns the raw `SHUT_*` value for this `Shutdown`.
+    #[inline]
+    pub const fn as_raw(self) -> c::c_int {
+        self as c::c_int
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::net::Shutdown> for Shutdown {
+    #[inline]
+    fn from(how: std::net::Shutdown) -> Self {
+        match how {
+            std::net::Shutdown::Read => Self::Read,
+            std::net::Shutdown::Write => Self::Write,
+            std::net::Shutdown::Both => Self::ReadWrite,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Shutdown> for std::net::Shutdown {
+    #[inline]
+    fn from(how: Shutdown) -> Self {
+        match how {
+            Shutdown::Read => Self::Read,
+            Shutdown::Write => Self::Write,
+            Shutdown::ReadWrite => Self::Both,
+        }
+    }
+}
+
 bitflags! {
     /// `SOCK_*` constants for use with [`socket_with`], [`accept_with`] and
     /// [`acceptfrom_with`].
@@ -1462,6 +1673,9 @@ pub mod xdp {
         pub struct XdpOptionsFlags: u32 {
             /// `XDP_OPTIONS_ZEROCOPY`
             const XDP_OPTIONS_ZEROCOPY = bitcast!(c::XDP_OPTIONS_ZEROCOPY);
+
+            /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+            const _ = !0;
         }
     }
 
@@ -1483,6 +1697,9 @@ pub mod xdp {
             // requires kernel 6.6
             /// `XDP_USE_SG`
             const XDP_USE_SG = bitcast!(c::XDP_USE_SG as u16);
+
+            /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+            const _ = !0;
         }
     }
 
@@ -1493,6 +1710,9 @@ pub mod xdp {
         pub struct XdpRingFlags: u32 {
             /// `XDP_RING_NEED_WAKEUP`
             const XDP_RING_NEED_WAKEUP = bitcast!(c::XDP_RING_NEED_WAKEUP);
+
+            /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+            const _ = !0;
         }
     }
 
@@ -1503,6 +1723,9 @@ pub mod xdp {
         pub struct XdpUmemRegFlags: u32 {
             /// `XDP_UMEM_UNALIGNED_CHUNK_FLAG`
             const XDP_UMEM_UNALIGNED_CHUNK_FLAG = bitcast!(c::XDP_UMEM_UNALIGNED_CHUNK_FLAG);
+
+            /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+            const _ = !0;
         }
     }
 
@@ -1527,7 +1750,7 @@ pub mod xdp {
     impl SocketAddrXdp {
         /// Construct a new XDP address.
         #[inline]
-        pub fn new(
+        pub const fn new(
             flags: SockaddrXdpFlags,
             interface_index: u32,
             queue_id: u32,
@@ -1543,7 +1766,7 @@ pub mod xdp {
 
         /// Return flags.
         #[inline]
-        pub fn flags(&self) -> SockaddrXdpFlags {
+        pub const fn flags(&self) -> SockaddrXdpFlags {
             self.sxdp_flags
         }
 
@@ -1555,7 +1778,7 @@ pub mod xdp {
 
         /// Return interface index.
         #[inline]
-        pub fn interface_index(&self) -> u32 {
+        pub const fn interface_index(&self) -> u32 {
             self.sxdp_ifindex
         }
 
@@ -1567,7 +1790,7 @@ pub mod xdp {
 
         /// Return queue ID.
         #[inline]
-        pub fn queue_id(&self) -> u32 {
+        pub const fn queue_id(&self) -> u32 {
             self.sxdp_queue_id
         }
 
@@ -1579,7 +1802,7 @@ pub mod xdp {
 
         /// Return shared UMEM file descriptor.
         #[inline]
-        pub fn shared_umem_fd(&self) -> u32 {
+        pub const fn shared_umem_fd(&self) -> u32 {
             self.sxdp_shared_umem_fd
         }
 
@@ -1710,6 +1933,9 @@ pub mod xdp {
         pub struct XdpDescOptions: u32 {
             /// `XDP_PKT_CONTD`
             const XDP_PKT_CONTD = bitcast!(c::XDP_PKT_CONTD);
+
+            /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+            const _ = !0;
         }
     }
 
@@ -1731,14 +1957,20 @@ pub mod xdp {
     pub const XSK_UNALIGNED_BUF_ADDR_MASK: u64 = c::XSK_UNALIGNED_BUF_ADDR_MASK;
 }
 
-/// UNIX credentials of socket peer, for use with [`get_socket_peercred`]
-/// [`SendAncillaryMessage::ScmCredentials`] and
+/// UNIX credentials of socket peer, for use with [`get_socket_peercred`],
+/// and on Linux, [`SendAncillaryMessage::ScmCredentials`] and
 /// [`RecvAncillaryMessage::ScmCredentials`].
 ///
 /// [`get_socket_peercred`]: crate::net::sockopt::get_socket_peercred
-/// [`SendAncillaryMessage::ScmCredentials`]: crate::net::SendAncillaryMessage::ScmCredentials
-/// [`RecvAncillaryMessage::ScmCredentials`]: crate::net::RecvAncillaryMessage::ScmCredentials
-#[cfg(linux_kernel)]
+/// [`SendAncillaryMessage::ScmCredentials`]: https://docs.rs/rustix/*/x86_64-unknown-linux-gnu/rustix/net/enum.SendAncillaryMessage.html#variant.ScmCredentials
+/// [`RecvAncillaryMessage::ScmCredentials`]: https://docs.rs/rustix/*/x86_64-unknown-linux-gnu/rustix/net/enum.RecvAncillaryMessage.html#variant.ScmCredentials
+#[cfg(any(
+    linux_kernel,
+    apple,
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 #[repr(C)]
 pub struct UCred {
@@ -1750,6 +1982,141 @@ pub struct UCred {
     pub gid: crate::ugid::Gid,
 }
 
+/// `struct in_pktinfo`—The destination address and interface of an IPv4
+/// packet, for use with [`RecvAncillaryMessage::IpPktInfo`] and
+/// [`SendAncillaryMessage::IpPktInfo`].
+///
+/// [`RecvAncillaryMessage::IpPktInfo`]: crate::net::RecvAncillaryMessage::IpPktInfo
+/// [`SendAncillaryMessage::IpPktInfo`]: crate::net::SendAncillaryMessage::IpPktInfo
+#[cfg(linux_kernel)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[doc(alias = "in_pktinfo")]
+pub struct IpPktInfo {
+    /// The index of the interface the packet was received on, or to send
+    /// it on, or 0 for any.
+    pub ifindex: u32,
+    /// When receiving, the local address the packet is routed to, which is
+    /// the address to reply from. When sending, the source address to use,
+    /// or [`Ipv4Addr::UNSPECIFIED`] to choose one automatically.
+    ///
+    /// [`Ipv4Addr::UNSPECIFIED`]: crate::net::Ipv4Addr::UNSPECIFIED
+    pub spec_dst: crate::net::Ipv4Addr,
+    /// When receiving, the destination address in the packet's header.
+    /// This is ignored when sending.
+    pub addr: crate::net::Ipv4Addr,
+}
+
+/// `struct in6_pktinfo`—The destination address and interface of an IPv6
+/// packet, for use with [`RecvAncillaryMessage::Ipv6PktInfo`] and
+/// [`SendAncillaryMessage::Ipv6PktInfo`].
+///
+/// [`RecvAncillaryMessage::Ipv6PktInfo`]: crate::net::RecvAncillaryMessage::Ipv6PktInfo
+/// [`SendAncillaryMessage::Ipv6PktInfo`]: crate::net::SendAncillaryMessage::Ipv6PktInfo
+#[cfg(linux_kernel)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[doc(alias = "in6_pktinfo")]
+pub struct Ipv6PktInfo {
+    /// When receiving, the destination address in the packet's header. When
+    /// sending, the source address to use, or [`Ipv6Addr::UNSPECIFIED`] to
+    /// choose one automatically.
+    ///
+    /// [`Ipv6Addr::UNSPECIFIED`]: crate::net::Ipv6Addr::UNSPECIFIED
+    pub addr: crate::net::Ipv6Addr,
+    /// The index of the interface the packet was received on, or to send
+    /// it on, or 0 for any.
+    pub ifindex: u32,
+}
+
+/// `struct sock_extended_err`, and the address of the node that reported the
+/// error, for use with [`RecvAncillaryMessage::IpRecvErr`] and
+/// [`RecvAncillaryMessage::Ipv6RecvErr`].
+///
+/// [`RecvAncillaryMessage::IpRecvErr`]: crate::net::RecvAncillaryMessage::IpRecvErr
+/// [`RecvAncillaryMessage::Ipv6RecvErr`]: crate::net::RecvAncillaryMessage::Ipv6RecvErr
+#[cfg(linux_kernel)]
+#[derive(Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "std", derive(Debug))]
+#[doc(alias = "sock_extended_err")]
+pub struct SockExtendedErr {
+    /// The error, such as [`io::Errno::CONNREFUSED`] or
+    /// [`io::Errno::MSGSIZE`].
+    ///
+    /// [`io::Errno::CONNREFUSED`]: crate::io::Errno::CONNREFUSED
+    /// [`io::Errno::MSGSIZE`]: crate::io::Errno::MSGSIZE
+    pub errno: crate::io::Errno,
+    /// Where the error came from.
+    pub origin: SockExtendedErrOrigin,
+    /// The ICMP type, for errors with an ICMP origin.
+    pub type_: u8,
+    /// The ICMP code, for errors with an ICMP origin.
+    pub code: u8,
+    /// Additional information, such as the path MTU for
+    /// [`io::Errno::MSGSIZE`] errors.
+    ///
+    /// [`io::Errno::MSGSIZE`]: crate::io::Errno::MSGSIZE
+    pub info: u32,
+    /// Additional data, depending on the origin.
+    pub data: u32,
+    /// The address of the node that reported the error, if any.
+    #[doc(alias = "SO_EE_OFFENDER")]
+    pub offender: Option<crate::net::SocketAddrAny>,
+}
+
+/// `SO_EE_ORIGIN_*` constants for [`SockExtendedErr::origin`].
+#[cfg(linux_kernel)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct SockExtendedErrOrigin(u8);
+
+#[cfg(linux_kernel)]
+impl SockExtendedErrOrigin {
+    /// `SO_EE_ORIGIN_NONE`
+    pub const NONE: Self = Self(0);
+    /// `SO_EE_ORIGIN_LOCAL`
+    pub const LOCAL: Self = Self(1);
+    /// `SO_EE_ORIGIN_ICMP`
+    pub const ICMP: Self = Self(2);
+    /// `SO_EE_ORIGIN_ICMP6`
+    pub const ICMP6: Self = Self(3);
+    /// `SO_EE_ORIGIN_TXSTATUS`
+    pub const TXSTATUS: Self = Self(4);
+    /// `SO_EE_ORIGIN_ZEROCOPY`
+    pub const ZEROCOPY: Self = Self(5);
+    /// `SO_EE_ORIGIN_TXTIME`
+    pub const TXTIME: Self = Self(6);
+
+    /// Constructs a `SockExtendedErrOrigin` from a raw integer.
+    #[inline]
+    pub const fn from_raw(raw: u8) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `SockExtendedErrOrigin`.
+    #[inline]
+    pub const fn as_raw(self) -> u8 {
+        self.0
+    }
+}
+
+/// `struct sock_filter`—An instruction in a classic BPF program, for use
+/// with [`set_socket_attach_reuseport_cbpf`].
+///
+/// [`set_socket_attach_reuseport_cbpf`]: crate::net::sockopt::set_socket_attach_reuseport_cbpf
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(C)]
+#[doc(alias = "sock_filter")]
+pub struct SockFilter {
+    /// The opcode.
+    pub code: u16,
+    /// The offset to jump to if a conditional jump is taken.
+    pub jt: u8,
+    /// The offset to jump to if a conditional jump isn't taken.
+    pub jf: u8,
+    /// The operand.
+    pub k: u32,
+}
+
 #[test]
 fn test_sizes() {
     use crate::backend::c;
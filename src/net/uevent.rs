@@ -0,0 +1,128 @@
+//! Parsing for `NETLINK_KOBJECT_UEVENT` messages.
+//!
+//! The kernel broadcasts a message on a [`netlink::KOBJECT_UEVENT`] socket
+//! whenever a device is added, removed, or changed. Each message is a header
+//! of the form `ACTION@DEVPATH`, followed by a sequence of `KEY=VALUE`
+//! variables, each terminated by a NUL byte.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [`netlink::KOBJECT_UEVENT`]: crate::net::netlink::KOBJECT_UEVENT
+//! [Linux]: https://www.kernel.org/doc/html/latest/core-api/kobject.html#uevents
+
+use core::iter::FusedIterator;
+
+/// The multicast group the kernel sends uevents to.
+///
+/// Pass this as the groups mask to [`SocketAddrNetlink::new`] and bind a
+/// [`netlink::KOBJECT_UEVENT`] socket with [`bind_netlink`] to receive
+/// device events directly from the kernel.
+///
+/// [`SocketAddrNetlink::new`]: crate::net::netlink::SocketAddrNetlink::new
+/// [`netlink::KOBJECT_UEVENT`]: crate::net::netlink::KOBJECT_UEVENT
+/// [`bind_netlink`]: crate::net::bind_netlink
+pub const UEVENT_KERNEL_GROUP: u32 = 1;
+
+/// The multicast group udev re-broadcasts uevents to, after processing them.
+///
+/// Messages sent to this group use udev's own binary format, which
+/// [`Uevent::parse`] doesn't accept.
+pub const UEVENT_UDEV_GROUP: u32 = 2;
+
+/// A kernel uevent message, borrowed from a receive buffer.
+///
+/// # Examples
+///
+/// ```
+/// use rustix::net::netlink::Uevent;
+///
+/// let msg = b"add@/devices/virtual/misc/tun\0ACTION=add\0SUBSYSTEM=misc\0SEQNUM=42\0";
+/// let event = Uevent::parse(msg).unwrap();
+/// assert_eq!(event.action(), b"add");
+/// assert_eq!(event.devpath(), b"/devices/virtual/misc/tun");
+/// assert_eq!(event.get(b"SUBSYSTEM"), Some(&b"misc"[..]));
+/// assert_eq!(event.vars().count(), 3);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Uevent<'a> {
+    action: &'a [u8],
+    devpath: &'a [u8],
+    vars: &'a [u8],
+}
+
+impl<'a> Uevent<'a> {
+    /// Parse a uevent message, as received from a
+    /// [`netlink::KOBJECT_UEVENT`] socket.
+    ///
+    /// Returns `None` if `buf` doesn't start with an `ACTION@DEVPATH`
+    /// header, which includes messages in udev's binary format.
+    ///
+    /// [`netlink::KOBJECT_UEVENT`]: crate::net::netlink::KOBJECT_UEVENT
+    pub fn parse(buf: &'a [u8]) -> Option<Self> {
+        let (header, vars) = match buf.iter().position(|b| *b == b'\0') {
+            Some(nul) => (&buf[..nul], &buf[nul + 1..]),
+            None => (buf, &buf[buf.len()..]),
+        };
+        let at = header.iter().position(|b| *b == b'@')?;
+        Some(Self {
+            action: &header[..at],
+            devpath: &header[at + 1..],
+            vars,
+        })
+    }
+
+    /// Return the action from the header, such as `add`, `remove`, or
+    /// `change`.
+    #[inline]
+    pub const fn action(&self) -> &'a [u8] {
+        self.action
+    }
+
+    /// Return the device path from the header, relative to `/sys`.
+    #[inline]
+    pub const fn devpath(&self) -> &'a [u8] {
+        self.devpath
+    }
+
+    /// Return an iterator over the `KEY=VALUE` variables in this message.
+    #[inline]
+    pub const fn vars(&self) -> UeventVars<'a> {
+        UeventVars { rest: self.vars }
+    }
+
+    /// Return the value of the variable named `key`, if present.
+    pub fn get(&self, key: &[u8]) -> Option<&'a [u8]> {
+        self.vars()
+            .find(|(name, _value)| *name == key)
+            .map(|(_name, value)| value)
+    }
+}
+
+/// An iterator over the `(KEY, VALUE)` pairs of a [`Uevent`].
+///
+/// Entries without a `=` are skipped.
+#[derive(Clone, Debug)]
+pub struct UeventVars<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Iterator for UeventVars<'a> {
+    type Item = (&'a [u8], &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.rest.is_empty() {
+            let (entry, rest) = match self.rest.iter().position(|b| *b == b'\0') {
+                Some(nul) => (&self.rest[..nul], &self.rest[nul + 1..]),
+                None => (self.rest, &self.rest[self.rest.len()..]),
+            };
+            self.rest = rest;
+            if let Some(eq) = entry.iter().position(|b| *b == b'=') {
+                return Some((&entry[..eq], &entry[eq + 1..]));
+            }
+        }
+        None
+    }
+}
+
+impl FusedIterator for UeventVars<'_> {}
@@ -221,7 +221,7 @@ impl Arg for String {
         Self: Sized,
         F: FnOnce(&CStr) -> io::Result<T>,
     {
-        f(&CString::new(self).map_err(|_cstr_err| io::Errno::INVAL)?)
+        with_c_str(self.as_bytes(), f)
     }
 }
 
@@ -346,7 +346,7 @@ impl Arg for OsString {
         Self: Sized,
         F: FnOnce(&CStr) -> io::Result<T>,
     {
-        f(&CString::new(self.into_vec()).map_err(|_cstr_err| io::Errno::INVAL)?)
+        with_c_str(self.as_bytes(), f)
     }
 }
 
@@ -474,10 +474,7 @@ impl Arg for PathBuf {
         Self: Sized,
         F: FnOnce(&CStr) -> io::Result<T>,
     {
-        f(
-            &CString::new(self.into_os_string().into_vec())
-                .map_err(|_cstr_err| io::Errno::INVAL)?,
-        )
+        with_c_str(self.as_os_str().as_bytes(), f)
     }
 }
 
@@ -726,6 +723,95 @@ impl<'a> Arg for Cow<'a, CStr> {
     }
 }
 
+#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
+impl<'a> Arg for Cow<'a, Path> {
+    #[inline]
+    fn as_str(&self) -> io::Result<&str> {
+        self.as_os_str().to_str().ok_or(io::Errno::INVAL)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn to_string_lossy(&self) -> Cow<'_, str> {
+        Path::to_string_lossy(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn as_cow_c_str(&self) -> io::Result<Cow<'_, CStr>> {
+        Ok(Cow::Owned(
+            CString::new(self.as_os_str().as_bytes()).map_err(|_cstr_err| io::Errno::INVAL)?,
+        ))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn into_c_str<'b>(self) -> io::Result<Cow<'b, CStr>>
+    where
+        Self: 'b,
+    {
+        Ok(Cow::Owned(
+            match self {
+                Cow::Owned(path) => CString::new(path.into_os_string().into_vec()),
+                Cow::Borrowed(path) => CString::new(path.as_os_str().as_bytes()),
+            }
+            .map_err(|_cstr_err| io::Errno::INVAL)?,
+        ))
+    }
+
+    #[inline]
+    fn into_with_c_str<T, F>(self, f: F) -> io::Result<T>
+    where
+        Self: Sized,
+        F: FnOnce(&CStr) -> io::Result<T>,
+    {
+        with_c_str(self.as_os_str().as_bytes(), f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Arg for Cow<'a, [u8]> {
+    #[inline]
+    fn as_str(&self) -> io::Result<&str> {
+        str::from_utf8(self).map_err(|_utf8_err| io::Errno::INVAL)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn to_string_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn as_cow_c_str(&self) -> io::Result<Cow<'_, CStr>> {
+        Ok(Cow::Owned(
+            CString::new(&**self).map_err(|_cstr_err| io::Errno::INVAL)?,
+        ))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn into_c_str<'b>(self) -> io::Result<Cow<'b, CStr>>
+    where
+        Self: 'b,
+    {
+        Ok(Cow::Owned(
+            CString::new(self.into_owned()).map_err(|_cstr_err| io::Errno::INVAL)?,
+        ))
+    }
+
+    #[inline]
+    fn into_with_c_str<T, F>(self, f: F) -> io::Result<T>
+    where
+        Self: Sized,
+        F: FnOnce(&CStr) -> io::Result<T>,
+    {
+        with_c_str(&self, f)
+    }
+}
+
 #[cfg(feature = "std")]
 impl<'a> Arg for Component<'a> {
     #[inline]
@@ -977,7 +1063,7 @@ impl Arg for Vec<u8> {
         Self: Sized,
         F: FnOnce(&CStr) -> io::Result<T>,
     {
-        f(&CString::new(self).map_err(|_cstr_err| io::Errno::INVAL)?)
+        with_c_str(&self, f)
     }
 }
 
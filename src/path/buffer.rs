@@ -0,0 +1,278 @@
+//! Output buffers for functions which return names or other bytes.
+//!
+//! This module defines the `OutputBuffer` trait and implements it for several
+//! common buffer types. This allows users to choose whether functions such as
+//! [`getcwd_into`] and [`getxattr_into`] allocate a new buffer, reuse an
+//! existing allocation, or write into a fixed-size buffer without allocating
+//! at all.
+//!
+//! [`getcwd_into`]: https://docs.rs/rustix/*/rustix/process/fn.getcwd_into.html
+//! [`getxattr_into`]: https://docs.rs/rustix/*/rustix/fs/fn.getxattr_into.html
+
+#![allow(unsafe_code)]
+
+use crate::buffer::split_init;
+use crate::ffi::CStr;
+use crate::io;
+use core::mem::MaybeUninit;
+use core::slice;
+#[cfg(feature = "std")]
+use std::ffi::OsString;
+#[cfg(all(feature = "std", target_os = "hermit"))]
+use std::os::hermit::ext::ffi::OsStringExt;
+#[cfg(all(feature = "std", unix))]
+use std::os::unix::ffi::OsStringExt;
+#[cfg(all(feature = "std", target_os = "vxworks"))]
+use std::os::vxworks::ext::ffi::OsStringExt;
+#[cfg(all(feature = "std", target_os = "wasi"))]
+use std::os::wasi::ffi::OsStringExt;
+#[cfg(feature = "alloc")]
+use {crate::ffi::CString, crate::path::SMALL_PATH_BUFFER_SIZE, alloc::vec::Vec};
+
+/// The fill callback passed to [`OutputBuffer`] methods.
+///
+/// The callback writes into the given buffer and returns the number of bytes
+/// it initialized, or fails with [`io::Errno::RANGE`] if the buffer is too
+/// small.
+#[doc(hidden)]
+pub type Fill<'a> = dyn FnMut(&mut [MaybeUninit<u8>]) -> io::Result<usize> + 'a;
+
+/// A trait for buffers that functions returning names, or other variable-length
+/// bytes, can write into.
+///
+/// Growable buffers, such as [`Vec`]`<u8>` and [`OsString`], are resized as
+/// needed, reusing any capacity they already have. Fixed-size buffers, such
+/// as `&mut [u8]`, `&mut [u8; N]`, and `&mut [MaybeUninit<u8>]`, never
+/// allocate, and the function fails with [`io::Errno::RANGE`] if the buffer
+/// is too small.
+///
+/// This trait is sealed; it can't be implemented outside of rustix.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "process")]
+/// # fn example() -> rustix::io::Result<()> {
+/// use rustix::process::getcwd_into;
+///
+/// // Allocate a new `CString`.
+/// let cwd = getcwd_into(Vec::new())?;
+///
+/// // Write into a stack buffer, borrowing the result.
+/// let mut buf = [0_u8; 4096];
+/// let cwd = getcwd_into(&mut buf)?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait OutputBuffer: Sized + private::Sealed {
+    /// The type returned for a NUL-terminated name.
+    type Name;
+
+    /// The type returned for bytes with no particular structure, such as an
+    /// extended attribute value or a sequence of NUL-terminated names.
+    type Bytes;
+
+    /// Run `fill` on this buffer, and return the NUL-terminated name it
+    /// produced.
+    ///
+    /// Fails with [`io::Errno::INVAL`] if the output doesn't contain exactly
+    /// one NUL byte, at the end.
+    ///
+    /// # Safety
+    ///
+    /// `fill` must only write initialized bytes into the buffer it's given,
+    /// and it must initialize at least as many bytes as the length it
+    /// returns.
+    #[doc(hidden)]
+    unsafe fn fill_name(self, fill: &mut Fill<'_>) -> io::Result<Self::Name>;
+
+    /// Run `fill` on this buffer, and return the bytes it produced.
+    ///
+    /// # Safety
+    ///
+    /// `fill` must only write initialized bytes into the buffer it's given,
+    /// and it must initialize at least as many bytes as the length it
+    /// returns.
+    #[doc(hidden)]
+    unsafe fn fill_bytes(self, fill: &mut Fill<'_>) -> io::Result<Self::Bytes>;
+}
+
+impl<'a> OutputBuffer for &'a mut [MaybeUninit<u8>] {
+    type Name = &'a CStr;
+    type Bytes = &'a [u8];
+
+    #[inline]
+    unsafe fn fill_name(self, fill: &mut Fill<'_>) -> io::Result<Self::Name> {
+        let bytes = self.fill_bytes(fill)?;
+        CStr::from_bytes_with_nul(bytes).map_err(|_| io::Errno::INVAL)
+    }
+
+    #[inline]
+    unsafe fn fill_bytes(self, fill: &mut Fill<'_>) -> io::Result<Self::Bytes> {
+        let len = fill(self)?;
+        assert!(len <= self.len());
+
+        // SAFETY: The caller promises that `fill` initialized `len` bytes.
+        let (init, _uninit) = split_init(self, len);
+        Ok(init)
+    }
+}
+
+impl<'a> OutputBuffer for &'a mut [u8] {
+    type Name = &'a CStr;
+    type Bytes = &'a [u8];
+
+    #[inline]
+    unsafe fn fill_name(self, fill: &mut Fill<'_>) -> io::Result<Self::Name> {
+        let bytes = self.fill_bytes(fill)?;
+        CStr::from_bytes_with_nul(bytes).map_err(|_| io::Errno::INVAL)
+    }
+
+    #[inline]
+    unsafe fn fill_bytes(self, fill: &mut Fill<'_>) -> io::Result<Self::Bytes> {
+        // SAFETY: `MaybeUninit<u8>` has the same layout as `u8`, and the
+        // caller promises that `fill` only writes initialized bytes, so
+        // `self` stays initialized.
+        let buf =
+            slice::from_raw_parts_mut(self.as_mut_ptr().cast::<MaybeUninit<u8>>(), self.len());
+        let len = fill(buf)?;
+        assert!(len <= self.len());
+        Ok(&self[..len])
+    }
+}
+
+impl<'a, const N: usize> OutputBuffer for &'a mut [u8; N] {
+    type Name = &'a CStr;
+    type Bytes = &'a [u8];
+
+    #[inline]
+    unsafe fn fill_name(self, fill: &mut Fill<'_>) -> io::Result<Self::Name> {
+        self[..].fill_name(fill)
+    }
+
+    #[inline]
+    unsafe fn fill_bytes(self, fill: &mut Fill<'_>) -> io::Result<Self::Bytes> {
+        self[..].fill_bytes(fill)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> OutputBuffer for &'a mut Vec<u8> {
+    type Name = &'a CStr;
+    type Bytes = &'a [u8];
+
+    #[inline]
+    unsafe fn fill_name(self, fill: &mut Fill<'_>) -> io::Result<Self::Name> {
+        let bytes = self.fill_bytes(fill)?;
+        CStr::from_bytes_with_nul(bytes).map_err(|_| io::Errno::INVAL)
+    }
+
+    #[inline]
+    unsafe fn fill_bytes(self, fill: &mut Fill<'_>) -> io::Result<Self::Bytes> {
+        fill_vec(self, fill)?;
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl OutputBuffer for Vec<u8> {
+    type Name = CString;
+    type Bytes = Vec<u8>;
+
+    #[inline]
+    unsafe fn fill_name(self, fill: &mut Fill<'_>) -> io::Result<Self::Name> {
+        let bytes = self.fill_bytes(fill)?;
+        CString::from_vec_with_nul(bytes).map_err(|_| io::Errno::INVAL)
+    }
+
+    #[inline]
+    unsafe fn fill_bytes(mut self, fill: &mut Fill<'_>) -> io::Result<Self::Bytes> {
+        fill_vec(&mut self, fill)?;
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl OutputBuffer for CString {
+    type Name = CString;
+    type Bytes = Vec<u8>;
+
+    #[inline]
+    unsafe fn fill_name(self, fill: &mut Fill<'_>) -> io::Result<Self::Name> {
+        self.into_bytes().fill_name(fill)
+    }
+
+    #[inline]
+    unsafe fn fill_bytes(self, fill: &mut Fill<'_>) -> io::Result<Self::Bytes> {
+        self.into_bytes().fill_bytes(fill)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(any(unix, target_os = "hermit", target_os = "vxworks", target_os = "wasi"))]
+impl OutputBuffer for OsString {
+    type Name = OsString;
+    type Bytes = OsString;
+
+    #[inline]
+    unsafe fn fill_name(self, fill: &mut Fill<'_>) -> io::Result<Self::Name> {
+        // `OsString`s aren't NUL-terminated, so strip the NUL.
+        let name = self.into_vec().fill_name(fill)?;
+        Ok(OsString::from_vec(name.into_bytes()))
+    }
+
+    #[inline]
+    unsafe fn fill_bytes(self, fill: &mut Fill<'_>) -> io::Result<Self::Bytes> {
+        Ok(OsString::from_vec(self.into_vec().fill_bytes(fill)?))
+    }
+}
+
+/// Run `fill` on the spare capacity of `buffer`, growing it until the output
+/// fits.
+///
+/// # Safety
+///
+/// `fill` must initialize at least as many bytes as the length it returns.
+#[cfg(feature = "alloc")]
+unsafe fn fill_vec(buffer: &mut Vec<u8>, fill: &mut Fill<'_>) -> io::Result<()> {
+    buffer.clear();
+    buffer.reserve(SMALL_PATH_BUFFER_SIZE);
+
+    loop {
+        match fill(buffer.spare_capacity_mut()) {
+            Err(io::Errno::RANGE) => {
+                // Use `Vec` reallocation strategy to grow capacity
+                // exponentially.
+                buffer.reserve(buffer.capacity() + 1);
+            }
+            Ok(len) => {
+                assert!(len <= buffer.capacity());
+
+                // SAFETY: The caller promises that `fill` initialized `len`
+                // bytes.
+                buffer.set_len(len);
+                return Ok(());
+            }
+            Err(errno) => return Err(errno),
+        }
+    }
+}
+
+mod private {
+    use core::mem::MaybeUninit;
+
+    pub trait Sealed {}
+
+    impl Sealed for &mut [MaybeUninit<u8>] {}
+    impl Sealed for &mut [u8] {}
+    impl<const N: usize> Sealed for &mut [u8; N] {}
+    #[cfg(feature = "alloc")]
+    impl Sealed for &mut alloc::vec::Vec<u8> {}
+    #[cfg(feature = "alloc")]
+    impl Sealed for alloc::vec::Vec<u8> {}
+    #[cfg(feature = "alloc")]
+    impl Sealed for crate::ffi::CString {}
+    #[cfg(feature = "std")]
+    #[cfg(any(unix, target_os = "hermit", target_os = "vxworks", target_os = "wasi"))]
+    impl Sealed for std::ffi::OsString {}
+}
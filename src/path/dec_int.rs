@@ -17,6 +17,10 @@ use std::os::wasi::ffi::OsStrExt;
 #[cfg(feature = "std")]
 use {core::fmt, std::ffi::OsStr, std::path::Path};
 
+/// The length of `i128::MIN` formatted in decimal, which is the longest value
+/// `DecInt` can hold.
+const BUF_LEN: usize = 40;
+
 /// Format an integer into a decimal `Path` component, without constructing a
 /// temporary `PathBuf` or `String`.
 ///
@@ -36,9 +40,10 @@ use {core::fmt, std::ffi::OsStr, std::path::Path};
 /// ```
 #[derive(Clone)]
 pub struct DecInt {
-    // 20 `u8`s is enough to hold the decimal ASCII representation of any
-    // `u64`, and we add one for a NUL terminator for `as_c_str`.
-    buf: [u8; 20 + 1],
+    // 40 `u8`s is enough to hold the decimal ASCII representation of any
+    // integer `itoa` supports, up to `i128::MIN`, and we add one for a NUL
+    // terminator for `as_c_str`.
+    buf: [u8; BUF_LEN + 1],
     len: usize,
 }
 
@@ -47,7 +52,7 @@ impl DecInt {
     #[inline]
     pub fn new<Int: Integer>(i: Int) -> Self {
         let mut me = DecIntWriter(Self {
-            buf: [0; 20 + 1],
+            buf: [0; BUF_LEN + 1],
             len: 0,
         });
         let mut buf = Buffer::new();
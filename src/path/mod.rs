@@ -1,10 +1,12 @@
 //! Filesystem path operations.
 
 mod arg;
+mod buffer;
 #[cfg(feature = "itoa")]
 mod dec_int;
 
 pub use arg::{option_into_with_c_str, Arg};
+pub use buffer::OutputBuffer;
 #[cfg(feature = "itoa")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "itoa")))]
 pub use dec_int::DecInt;
@@ -124,6 +124,11 @@ pub fn pipe_with(flags: PipeFlags) -> io::Result<(OwnedFd, OwnedFd)> {
 /// Passing `None` causes the read/write to start from the file offset, and the
 /// file offset is adjusted appropriately.
 ///
+/// This returns `Ok(0)` when `fd_in` is at end of file, or is a pipe or
+/// socket whose write end has been closed. To forward data between two
+/// sockets without copying it into userspace, splice from one socket into a
+/// pipe, and from the pipe into the other socket.
+///
 /// # References
 ///  - [Linux]
 ///
@@ -0,0 +1,377 @@
+//! Bindings for FreeBSD's Capsicum capability mode and capability rights.
+//!
+//! A process enters capability mode with [`cap_enter`], after which it can no
+//! longer access global namespaces such as the filesystem or the process id
+//! space, and can only operate on file descriptors it already holds. The
+//! operations permitted on each of those file descriptors can be restricted
+//! ahead of time with [`cap_rights_limit`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn main() -> rustix::io::Result<()> {
+//! use rustix::process::{cap_enter, cap_rights_limit, CapRight, CapRights};
+//!
+//! let stdin = rustix::stdio::stdin();
+//! cap_rights_limit(stdin, &CapRights::new(&[CapRight::READ, CapRight::EVENT]))?;
+//! cap_enter()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # References
+//!  - [FreeBSD]
+//!
+//! [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=capsicum&sektion=4
+
+use crate::backend::{c, process::syscalls};
+use crate::fd::AsFd;
+use crate::io;
+use core::fmt;
+
+/// `cap_enter()`—Enter capability mode.
+///
+/// Once in capability mode, a process can't leave it, and its children
+/// inherit it. Operations which access global namespaces fail with
+/// [`io::Errno::CAPMODE`], and operations on file descriptors which lack the
+/// needed rights fail with [`io::Errno::NOTCAPABLE`].
+///
+/// # References
+///  - [FreeBSD]
+///
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=cap_enter&sektion=2
+#[inline]
+pub fn cap_enter() -> io::Result<()> {
+    syscalls::cap_enter()
+}
+
+/// `cap_getmode()`—Test whether the process is in capability mode.
+///
+/// # References
+///  - [FreeBSD]
+///
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=cap_getmode&sektion=2
+#[inline]
+pub fn cap_getmode() -> io::Result<bool> {
+    syscalls::cap_getmode()
+}
+
+/// `cap_rights_limit(fd, rights)`—Limit the operations permitted on a file
+/// descriptor.
+///
+/// Rights can only be removed; attempting to add a right which `fd` doesn't
+/// already have fails with [`io::Errno::NOTCAPABLE`]. The limits apply
+/// whether or not the process is in capability mode, and are shared by all
+/// duplicates of `fd`.
+///
+/// # References
+///  - [FreeBSD]
+///
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=cap_rights_limit&sektion=2
+#[inline]
+pub fn cap_rights_limit<Fd: AsFd>(fd: Fd, rights: &CapRights) -> io::Result<()> {
+    syscalls::cap_rights_limit(fd.as_fd(), &rights.0)
+}
+
+/// `cap_rights_get(fd)`—Return the rights permitted on a file descriptor.
+///
+/// # References
+///  - [FreeBSD]
+///
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=cap_rights_get&sektion=3
+#[inline]
+#[doc(alias = "__cap_rights_get")]
+pub fn cap_rights_get<Fd: AsFd>(fd: Fd) -> io::Result<CapRights> {
+    syscalls::cap_rights_get(fd.as_fd()).map(CapRights)
+}
+
+/// A single Capsicum right, for use with [`CapRights`].
+///
+/// Some rights, such as [`CapRight::PREAD`], imply others, and are
+/// represented as the union of the rights they imply.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct CapRight(u64);
+
+/// Mask of the bits of a right which select the word of `cap_rights_t` it
+/// belongs in.
+const INDEX_MASK: u64 = 0x1f << 57;
+
+/// `CAP_RIGHTS_VERSION` occupies the two high bits of the first word.
+const VERSION_SHIFT: u32 = 62;
+
+#[rustfmt::skip]
+impl CapRight {
+    /// `CAP_READ`
+    pub const READ: Self = Self(c::CAP_READ);
+    /// `CAP_WRITE`
+    pub const WRITE: Self = Self(c::CAP_WRITE);
+    /// `CAP_SEEK`
+    pub const SEEK: Self = Self(c::CAP_SEEK);
+    /// `CAP_PREAD`
+    pub const PREAD: Self = Self(c::CAP_PREAD);
+    /// `CAP_PWRITE`
+    pub const PWRITE: Self = Self(c::CAP_PWRITE);
+    /// `CAP_MMAP`
+    pub const MMAP: Self = Self(c::CAP_MMAP);
+    /// `CAP_MMAP_R`
+    pub const MMAP_R: Self = Self(c::CAP_MMAP_R);
+    /// `CAP_MMAP_W`
+    pub const MMAP_W: Self = Self(c::CAP_MMAP_W);
+    /// `CAP_MMAP_X`
+    pub const MMAP_X: Self = Self(c::CAP_MMAP_X);
+    /// `CAP_MMAP_RW`
+    pub const MMAP_RW: Self = Self(c::CAP_MMAP_RW);
+    /// `CAP_MMAP_RX`
+    pub const MMAP_RX: Self = Self(c::CAP_MMAP_RX);
+    /// `CAP_MMAP_WX`
+    pub const MMAP_WX: Self = Self(c::CAP_MMAP_WX);
+    /// `CAP_MMAP_RWX`
+    pub const MMAP_RWX: Self = Self(c::CAP_MMAP_RWX);
+    /// `CAP_CREATE`
+    pub const CREATE: Self = Self(c::CAP_CREATE);
+    /// `CAP_FEXECVE`
+    pub const FEXECVE: Self = Self(c::CAP_FEXECVE);
+    /// `CAP_FSYNC`
+    pub const FSYNC: Self = Self(c::CAP_FSYNC);
+    /// `CAP_FTRUNCATE`
+    pub const FTRUNCATE: Self = Self(c::CAP_FTRUNCATE);
+    /// `CAP_LOOKUP`
+    pub const LOOKUP: Self = Self(c::CAP_LOOKUP);
+    /// `CAP_FCHDIR`
+    pub const FCHDIR: Self = Self(c::CAP_FCHDIR);
+    /// `CAP_FCHFLAGS`
+    pub const FCHFLAGS: Self = Self(c::CAP_FCHFLAGS);
+    /// `CAP_CHFLAGSAT`
+    pub const CHFLAGSAT: Self = Self(c::CAP_CHFLAGSAT);
+    /// `CAP_FCHMOD`
+    pub const FCHMOD: Self = Self(c::CAP_FCHMOD);
+    /// `CAP_FCHMODAT`
+    pub const FCHMODAT: Self = Self(c::CAP_FCHMODAT);
+    /// `CAP_FCHOWN`
+    pub const FCHOWN: Self = Self(c::CAP_FCHOWN);
+    /// `CAP_FCHOWNAT`
+    pub const FCHOWNAT: Self = Self(c::CAP_FCHOWNAT);
+    /// `CAP_FCNTL`
+    pub const FCNTL: Self = Self(c::CAP_FCNTL);
+    /// `CAP_FLOCK`
+    pub const FLOCK: Self = Self(c::CAP_FLOCK);
+    /// `CAP_FPATHCONF`
+    pub const FPATHCONF: Self = Self(c::CAP_FPATHCONF);
+    /// `CAP_FSCK`
+    pub const FSCK: Self = Self(c::CAP_FSCK);
+    /// `CAP_FSTAT`
+    pub const FSTAT: Self = Self(c::CAP_FSTAT);
+    /// `CAP_FSTATAT`
+    pub const FSTATAT: Self = Self(c::CAP_FSTATAT);
+    /// `CAP_FSTATFS`
+    pub const FSTATFS: Self = Self(c::CAP_FSTATFS);
+    /// `CAP_FUTIMES`
+    pub const FUTIMES: Self = Self(c::CAP_FUTIMES);
+    /// `CAP_FUTIMESAT`
+    pub const FUTIMESAT: Self = Self(c::CAP_FUTIMESAT);
+    /// `CAP_LINKAT_TARGET`
+    pub const LINKAT_TARGET: Self = Self(c::CAP_LINKAT_TARGET);
+    /// `CAP_LINKAT_SOURCE`
+    pub const LINKAT_SOURCE: Self = Self(c::CAP_LINKAT_SOURCE);
+    /// `CAP_MKDIRAT`
+    pub const MKDIRAT: Self = Self(c::CAP_MKDIRAT);
+    /// `CAP_MKFIFOAT`
+    pub const MKFIFOAT: Self = Self(c::CAP_MKFIFOAT);
+    /// `CAP_MKNODAT`
+    pub const MKNODAT: Self = Self(c::CAP_MKNODAT);
+    /// `CAP_RENAMEAT_SOURCE`
+    pub const RENAMEAT_SOURCE: Self = Self(c::CAP_RENAMEAT_SOURCE);
+    /// `CAP_RENAMEAT_TARGET`
+    pub const RENAMEAT_TARGET: Self = Self(c::CAP_RENAMEAT_TARGET);
+    /// `CAP_SYMLINKAT`
+    pub const SYMLINKAT: Self = Self(c::CAP_SYMLINKAT);
+    /// `CAP_UNLINKAT`
+    pub const UNLINKAT: Self = Self(c::CAP_UNLINKAT);
+    /// `CAP_ACCEPT`
+    pub const ACCEPT: Self = Self(c::CAP_ACCEPT);
+    /// `CAP_BIND`
+    pub const BIND: Self = Self(c::CAP_BIND);
+    /// `CAP_BINDAT`
+    pub const BINDAT: Self = Self(c::CAP_BINDAT);
+    /// `CAP_CONNECT`
+    pub const CONNECT: Self = Self(c::CAP_CONNECT);
+    /// `CAP_CONNECTAT`
+    pub const CONNECTAT: Self = Self(c::CAP_CONNECTAT);
+    /// `CAP_GETPEERNAME`
+    pub const GETPEERNAME: Self = Self(c::CAP_GETPEERNAME);
+    /// `CAP_GETSOCKNAME`
+    pub const GETSOCKNAME: Self = Self(c::CAP_GETSOCKNAME);
+    /// `CAP_GETSOCKOPT`
+    pub const GETSOCKOPT: Self = Self(c::CAP_GETSOCKOPT);
+    /// `CAP_LISTEN`
+    pub const LISTEN: Self = Self(c::CAP_LISTEN);
+    /// `CAP_PEELOFF`
+    pub const PEELOFF: Self = Self(c::CAP_PEELOFF);
+    /// `CAP_RECV`
+    pub const RECV: Self = Self(c::CAP_RECV);
+    /// `CAP_SEND`
+    pub const SEND: Self = Self(c::CAP_SEND);
+    /// `CAP_SETSOCKOPT`
+    pub const SETSOCKOPT: Self = Self(c::CAP_SETSOCKOPT);
+    /// `CAP_SHUTDOWN`
+    pub const SHUTDOWN: Self = Self(c::CAP_SHUTDOWN);
+    /// `CAP_SOCK_CLIENT`
+    pub const SOCK_CLIENT: Self = Self(c::CAP_SOCK_CLIENT);
+    /// `CAP_SOCK_SERVER`
+    pub const SOCK_SERVER: Self = Self(c::CAP_SOCK_SERVER);
+    /// `CAP_MAC_GET`
+    pub const MAC_GET: Self = Self(c::CAP_MAC_GET);
+    /// `CAP_MAC_SET`
+    pub const MAC_SET: Self = Self(c::CAP_MAC_SET);
+    /// `CAP_SEM_GETVALUE`
+    pub const SEM_GETVALUE: Self = Self(c::CAP_SEM_GETVALUE);
+    /// `CAP_SEM_POST`
+    pub const SEM_POST: Self = Self(c::CAP_SEM_POST);
+    /// `CAP_SEM_WAIT`
+    pub const SEM_WAIT: Self = Self(c::CAP_SEM_WAIT);
+    /// `CAP_EVENT`
+    pub const EVENT: Self = Self(c::CAP_EVENT);
+    /// `CAP_KQUEUE_EVENT`
+    pub const KQUEUE_EVENT: Self = Self(c::CAP_KQUEUE_EVENT);
+    /// `CAP_KQUEUE_CHANGE`
+    pub const KQUEUE_CHANGE: Self = Self(c::CAP_KQUEUE_CHANGE);
+    /// `CAP_KQUEUE`
+    pub const KQUEUE: Self = Self(c::CAP_KQUEUE);
+    /// `CAP_IOCTL`
+    pub const IOCTL: Self = Self(c::CAP_IOCTL);
+    /// `CAP_TTYHOOK`
+    pub const TTYHOOK: Self = Self(c::CAP_TTYHOOK);
+    /// `CAP_PDGETPID`
+    pub const PDGETPID: Self = Self(c::CAP_PDGETPID);
+    /// `CAP_PDWAIT`
+    pub const PDWAIT: Self = Self(c::CAP_PDWAIT);
+    /// `CAP_PDKILL`
+    pub const PDKILL: Self = Self(c::CAP_PDKILL);
+    /// `CAP_EXTATTR_DELETE`
+    pub const EXTATTR_DELETE: Self = Self(c::CAP_EXTATTR_DELETE);
+    /// `CAP_EXTATTR_GET`
+    pub const EXTATTR_GET: Self = Self(c::CAP_EXTATTR_GET);
+    /// `CAP_EXTATTR_LIST`
+    pub const EXTATTR_LIST: Self = Self(c::CAP_EXTATTR_LIST);
+    /// `CAP_EXTATTR_SET`
+    pub const EXTATTR_SET: Self = Self(c::CAP_EXTATTR_SET);
+    /// `CAP_ACL_CHECK`
+    pub const ACL_CHECK: Self = Self(c::CAP_ACL_CHECK);
+    /// `CAP_ACL_DELETE`
+    pub const ACL_DELETE: Self = Self(c::CAP_ACL_DELETE);
+    /// `CAP_ACL_GET`
+    pub const ACL_GET: Self = Self(c::CAP_ACL_GET);
+    /// `CAP_ACL_SET`
+    pub const ACL_SET: Self = Self(c::CAP_ACL_SET);
+}
+
+impl CapRight {
+    /// Return the index of the `cap_rights_t` word this right is stored in.
+    #[inline]
+    const fn index(self) -> usize {
+        // Rights in word `i` have bit `57 + i` set.
+        ((self.0 & INDEX_MASK) >> 57).trailing_zeros() as usize
+    }
+}
+
+/// `cap_rights_t`—A set of Capsicum rights.
+///
+/// # References
+///  - [FreeBSD]
+///
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=cap_rights_init&sektion=3
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[doc(alias = "cap_rights_t")]
+pub struct CapRights([u64; 2]);
+
+impl CapRights {
+    /// `cap_rights_init(rights, ...)`—Construct a set containing `rights`.
+    #[inline]
+    #[doc(alias = "cap_rights_init")]
+    pub fn new(rights: &[CapRight]) -> Self {
+        let mut set = Self::empty();
+        for right in rights {
+            set.set(*right);
+        }
+        set
+    }
+
+    /// Construct an empty set.
+    #[inline]
+    pub const fn empty() -> Self {
+        Self([
+            ((c::CAP_RIGHTS_VERSION as u64) << VERSION_SHIFT) | (1 << 57),
+            1 << 58,
+        ])
+    }
+
+    /// `cap_rights_set(self, right)`—Add `right` to this set.
+    #[inline]
+    #[doc(alias = "cap_rights_set")]
+    pub fn set(&mut self, right: CapRight) -> &mut Self {
+        self.0[right.index()] |= right.0;
+        self
+    }
+
+    /// `cap_rights_clear(self, right)`—Remove `right` from this set.
+    #[inline]
+    #[doc(alias = "cap_rights_clear")]
+    pub fn clear(&mut self, right: CapRight) -> &mut Self {
+        self.0[right.index()] &= !(right.0 & !INDEX_MASK);
+        self
+    }
+
+    /// `cap_rights_is_set(self, right)`—Test whether this set contains
+    /// `right`, including all the rights it implies.
+    #[inline]
+    #[doc(alias = "cap_rights_is_set")]
+    pub fn is_set(&self, right: CapRight) -> bool {
+        self.0[right.index()] & right.0 == right.0
+    }
+
+    /// `cap_rights_merge(self, other)`—Add all the rights in `other` to this
+    /// set.
+    #[inline]
+    #[doc(alias = "cap_rights_merge")]
+    pub fn merge(&mut self, other: &Self) -> &mut Self {
+        self.0[0] |= other.0[0];
+        self.0[1] |= other.0[1];
+        self
+    }
+
+    /// `cap_rights_remove(self, other)`—Remove all the rights in `other` from
+    /// this set.
+    #[inline]
+    #[doc(alias = "cap_rights_remove")]
+    pub fn remove(&mut self, other: &Self) -> &mut Self {
+        self.0[0] &= !(other.0[0] & !INDEX_MASK);
+        self.0[1] &= !(other.0[1] & !INDEX_MASK);
+        self
+    }
+
+    /// `cap_rights_contains(self, other)`—Test whether this set contains all
+    /// the rights in `other`.
+    #[inline]
+    #[doc(alias = "cap_rights_contains")]
+    pub fn contains(&self, other: &Self) -> bool {
+        self.0[0] & other.0[0] == other.0[0] && self.0[1] & other.0[1] == other.0[1]
+    }
+}
+
+impl Default for CapRights {
+    #[inline]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl fmt::Debug for CapRights {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CapRights")
+            .field(&format_args!("{:#018x}", self.0[0]))
+            .field(&format_args!("{:#018x}", self.0[1]))
+            .finish()
+    }
+}
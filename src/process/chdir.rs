@@ -4,12 +4,12 @@ use crate::backend::fd::AsFd;
 use crate::path;
 #[cfg(any(feature = "fs", not(target_os = "fuchsia")))]
 use crate::{backend, io};
+#[cfg(feature = "fs")]
+#[cfg(not(target_os = "wasi"))]
+use {crate::ffi::CStr, crate::path::OutputBuffer, core::mem::MaybeUninit};
 #[cfg(all(feature = "alloc", feature = "fs"))]
-use {
-    crate::ffi::{CStr, CString},
-    crate::path::SMALL_PATH_BUFFER_SIZE,
-    alloc::vec::Vec,
-};
+#[cfg(not(target_os = "wasi"))]
+use {crate::ffi::CString, alloc::vec::Vec};
 
 /// `chdir(path)`—Change the current working directory.
 ///
@@ -44,6 +44,9 @@ pub fn fchdir<Fd: AsFd>(fd: Fd) -> io::Result<()> {
 ///
 /// If `reuse` already has available capacity, reuse it if possible.
 ///
+/// To write into a fixed-size buffer without allocating, use
+/// [`getcwd_into`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -55,43 +58,49 @@ pub fn fchdir<Fd: AsFd>(fd: Fd) -> io::Result<()> {
 #[cfg_attr(doc_cfg, doc(cfg(feature = "fs")))]
 #[inline]
 pub fn getcwd<B: Into<Vec<u8>>>(reuse: B) -> io::Result<CString> {
-    _getcwd(reuse.into())
+    getcwd_into(reuse.into())
 }
 
-#[cfg(all(feature = "alloc", feature = "fs"))]
+/// `getCWD`—Return the current working directory, in a caller-provided
+/// buffer.
+///
+/// `buf` may be any [`OutputBuffer`], such as a `Vec<u8>` to allocate the
+/// result, or a fixed-size array to avoid allocating.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getcwd.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/getcwd.3.html
+#[cfg(feature = "fs")]
+#[cfg(not(target_os = "wasi"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "fs")))]
+#[inline]
 #[allow(unsafe_code)]
-fn _getcwd(mut buffer: Vec<u8>) -> io::Result<CString> {
-    buffer.clear();
-    buffer.reserve(SMALL_PATH_BUFFER_SIZE);
+pub fn getcwd_into<B: OutputBuffer>(buf: B) -> io::Result<B::Name> {
+    // SAFETY: `_getcwd` returns the length of the NUL-terminated string
+    // `getcwd` initialized.
+    unsafe { buf.fill_name(&mut _getcwd) }
+}
 
-    loop {
-        match backend::process::syscalls::getcwd(buffer.spare_capacity_mut()) {
-            Err(io::Errno::RANGE) => {
-                // Use `Vec` reallocation strategy to grow capacity
-                // exponentially.
-                buffer.reserve(buffer.capacity() + 1);
-            }
-            Ok(_) => {
-                // SAFETY:
-                // - "These functions return a null-terminated string"
-                // - [POSIX definition 3.375: String]: "A contiguous sequence
-                //   of bytes terminated by and including the first null byte."
-                //
-                // Thus, there will be a single NUL byte at the end of the
-                // string.
-                //
-                // [POSIX definition 3.375: String]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap03.html#tag_03_375
-                unsafe {
-                    buffer.set_len(
-                        CStr::from_ptr(buffer.as_ptr().cast())
-                            .to_bytes_with_nul()
-                            .len(),
-                    );
+#[cfg(feature = "fs")]
+#[cfg(not(target_os = "wasi"))]
+#[allow(unsafe_code)]
+fn _getcwd(buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
+    backend::process::syscalls::getcwd(buf)?;
 
-                    return Ok(CString::from_vec_with_nul_unchecked(buffer));
-                }
-            }
-            Err(errno) => return Err(errno),
-        }
+    // SAFETY:
+    // - "These functions return a null-terminated string"
+    // - [POSIX definition 3.375: String]: "A contiguous sequence of bytes
+    //   terminated by and including the first null byte."
+    //
+    // Thus, there will be a single NUL byte at the end of the string.
+    //
+    // [POSIX definition 3.375: String]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap03.html#tag_03_375
+    unsafe {
+        Ok(CStr::from_ptr(buf.as_ptr().cast())
+            .to_bytes_with_nul()
+            .len())
     }
 }
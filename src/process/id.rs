@@ -106,6 +106,79 @@ pub fn getegid() -> Gid {
     backend::ugid::syscalls::getegid()
 }
 
+/// The real, effective, and saved user IDs of a process, as returned by
+/// [`getresuid`].
+#[cfg(any(linux_kernel, freebsdlike, target_os = "openbsd"))]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct ResUid {
+    /// The real user ID.
+    pub real: Uid,
+    /// The effective user ID.
+    pub effective: Uid,
+    /// The saved set-user-ID.
+    pub saved: Uid,
+}
+
+/// The real, effective, and saved group IDs of a process, as returned by
+/// [`getresgid`].
+#[cfg(any(linux_kernel, freebsdlike, target_os = "openbsd"))]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct ResGid {
+    /// The real group ID.
+    pub real: Gid,
+    /// The effective group ID.
+    pub effective: Gid,
+    /// The saved set-group-ID.
+    pub saved: Gid,
+}
+
+/// `getresuid()`—Returns the process' real, effective, and saved user IDs.
+///
+/// The saved set-user-ID is what a process which has temporarily dropped
+/// privileges can switch its effective user ID back to.
+///
+/// # References
+///  - [Linux]
+///  - [FreeBSD]
+///  - [OpenBSD]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getresuid.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=getresuid&sektion=2
+/// [OpenBSD]: https://man.openbsd.org/getresuid.2
+#[cfg(any(linux_kernel, freebsdlike, target_os = "openbsd"))]
+#[inline]
+#[must_use]
+pub fn getresuid() -> ResUid {
+    let (real, effective, saved) = backend::process::syscalls::getresuid();
+    ResUid {
+        real,
+        effective,
+        saved,
+    }
+}
+
+/// `getresgid()`—Returns the process' real, effective, and saved group IDs.
+///
+/// # References
+///  - [Linux]
+///  - [FreeBSD]
+///  - [OpenBSD]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getresgid.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=getresgid&sektion=2
+/// [OpenBSD]: https://man.openbsd.org/getresgid.2
+#[cfg(any(linux_kernel, freebsdlike, target_os = "openbsd"))]
+#[inline]
+#[must_use]
+pub fn getresgid() -> ResGid {
+    let (real, effective, saved) = backend::process::syscalls::getresgid();
+    ResGid {
+        real,
+        effective,
+        saved,
+    }
+}
+
 /// `getpid()`—Returns the process' ID.
 ///
 /// # References
@@ -1,5 +1,7 @@
 //! Process-associated operations.
 
+#[cfg(target_os = "freebsd")]
+mod capsicum;
 #[cfg(not(target_os = "wasi"))]
 mod chdir;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
@@ -17,6 +19,8 @@ mod membarrier;
 mod pidfd;
 #[cfg(target_os = "linux")]
 mod pidfd_getfd;
+#[cfg(target_os = "openbsd")]
+mod pledge;
 #[cfg(linux_kernel)]
 mod prctl;
 #[cfg(not(any(target_os = "fuchsia", target_os = "vita", target_os = "wasi")))]
@@ -35,11 +39,19 @@ mod rlimit;
 #[cfg(any(freebsdlike, linux_kernel, target_os = "fuchsia"))]
 mod sched;
 mod sched_yield;
+#[cfg(linux_kernel)]
+mod sigmask;
+#[cfg(linux_kernel)]
+mod signalfd;
+#[cfg(all(linux_raw, feature = "alloc", feature = "fs", feature = "pipe"))]
+mod spawn;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have umask.
 mod umask;
 #[cfg(not(any(target_os = "espidf", target_os = "vita", target_os = "wasi")))]
 mod wait;
 
+#[cfg(target_os = "freebsd")]
+pub use capsicum::*;
 #[cfg(not(target_os = "wasi"))]
 pub use chdir::*;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
@@ -57,6 +69,8 @@ pub use membarrier::*;
 pub use pidfd::*;
 #[cfg(target_os = "linux")]
 pub use pidfd_getfd::*;
+#[cfg(target_os = "openbsd")]
+pub use pledge::*;
 #[cfg(linux_kernel)]
 pub use prctl::*;
 #[cfg(not(any(target_os = "fuchsia", target_os = "vita", target_os = "wasi")))]
@@ -74,6 +88,12 @@ pub use rlimit::*;
 #[cfg(any(freebsdlike, linux_kernel, target_os = "fuchsia"))]
 pub use sched::*;
 pub use sched_yield::sched_yield;
+#[cfg(linux_kernel)]
+pub use sigmask::*;
+#[cfg(linux_kernel)]
+pub use signalfd::*;
+#[cfg(all(linux_raw, feature = "alloc", feature = "fs", feature = "pipe"))]
+pub use spawn::Command;
 #[cfg(not(target_os = "wasi"))]
 pub use umask::*;
 #[cfg(not(any(target_os = "espidf", target_os = "vita", target_os = "wasi")))]
@@ -21,6 +21,13 @@ bitflags::bitflags! {
 /// `syscall(SYS_pidfd_open, pid, flags)`—Creates a file descriptor for a
 /// process.
 ///
+/// The file descriptor becomes readable when the process exits, so it can be
+/// waited on with `poll` or `epoll`, and the process can then be reaped with
+/// [`waitid`] with [`WaitId::PidFd`].
+///
+/// [`waitid`]: crate::process::waitid
+/// [`WaitId::PidFd`]: crate::process::WaitId::PidFd
+///
 /// # References
 ///  - [Linux]
 ///
@@ -33,6 +40,12 @@ pub fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
 /// `syscall(SYS_pidfd_send_signal, pidfd, sig, NULL, 0)`—Send a signal to a
 /// process specified by a file descriptor.
 ///
+/// Unlike [`kill_process`], this can't signal an unrelated process which
+/// reused the pid after the original process exited and was reaped; it fails
+/// with [`io::Errno::SRCH`] instead.
+///
+/// [`kill_process`]: crate::process::kill_process
+///
 /// # References
 ///  - [Linux]
 ///
@@ -0,0 +1,244 @@
+//! Bindings for OpenBSD's `pledge` and `unveil`.
+//!
+//! [`pledge`] restricts the system calls a process may make to those needed
+//! by a set of [`Promises`], and [`unveil`] restricts the parts of the
+//! filesystem it may see.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn main() -> rustix::io::Result<()> {
+//! use rustix::process::{pledge, unveil, unveil_lock, Promises, UnveilPermissions};
+//!
+//! unveil("/etc/ssl", UnveilPermissions::READ)?;
+//! unveil_lock()?;
+//! pledge(Some(Promises::STDIO | Promises::RPATH | Promises::INET), None)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::backend::process::syscalls;
+use crate::ffi::CStr;
+use crate::io;
+#[cfg(feature = "fs")]
+use crate::path;
+use bitflags::bitflags;
+
+bitflags! {
+    /// A set of `pledge` promises, for use with [`pledge`].
+    ///
+    /// Each promise permits a group of related system calls; see the
+    /// OpenBSD documentation for the details of each.
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct Promises: u64 {
+        /// `stdio`
+        const STDIO = 1 << 0;
+        /// `rpath`
+        const RPATH = 1 << 1;
+        /// `wpath`
+        const WPATH = 1 << 2;
+        /// `cpath`
+        const CPATH = 1 << 3;
+        /// `dpath`
+        const DPATH = 1 << 4;
+        /// `tmppath`
+        const TMPPATH = 1 << 5;
+        /// `inet`
+        const INET = 1 << 6;
+        /// `mcast`
+        const MCAST = 1 << 7;
+        /// `fattr`
+        const FATTR = 1 << 8;
+        /// `chown`
+        const CHOWN = 1 << 9;
+        /// `flock`
+        const FLOCK = 1 << 10;
+        /// `unix`
+        const UNIX = 1 << 11;
+        /// `dns`
+        const DNS = 1 << 12;
+        /// `getpw`
+        const GETPW = 1 << 13;
+        /// `sendfd`
+        const SENDFD = 1 << 14;
+        /// `recvfd`
+        const RECVFD = 1 << 15;
+        /// `tape`
+        const TAPE = 1 << 16;
+        /// `tty`
+        const TTY = 1 << 17;
+        /// `proc`
+        const PROC = 1 << 18;
+        /// `exec`
+        const EXEC = 1 << 19;
+        /// `prot_exec`
+        const PROT_EXEC = 1 << 20;
+        /// `settime`
+        const SETTIME = 1 << 21;
+        /// `ps`
+        const PS = 1 << 22;
+        /// `vminfo`
+        const VMINFO = 1 << 23;
+        /// `id`
+        const ID = 1 << 24;
+        /// `pf`
+        const PF = 1 << 25;
+        /// `route`
+        const ROUTE = 1 << 26;
+        /// `wroute`
+        const WROUTE = 1 << 27;
+        /// `audio`
+        const AUDIO = 1 << 28;
+        /// `video`
+        const VIDEO = 1 << 29;
+        /// `bpf`
+        const BPF = 1 << 30;
+        /// `unveil`
+        const UNVEIL = 1 << 31;
+        /// `error`
+        const ERROR = 1 << 32;
+    }
+}
+
+/// The names of each promise, as passed to `pledge`.
+const PROMISE_NAMES: [(Promises, &[u8]); 33] = [
+    (Promises::STDIO, b"stdio"),
+    (Promises::RPATH, b"rpath"),
+    (Promises::WPATH, b"wpath"),
+    (Promises::CPATH, b"cpath"),
+    (Promises::DPATH, b"dpath"),
+    (Promises::TMPPATH, b"tmppath"),
+    (Promises::INET, b"inet"),
+    (Promises::MCAST, b"mcast"),
+    (Promises::FATTR, b"fattr"),
+    (Promises::CHOWN, b"chown"),
+    (Promises::FLOCK, b"flock"),
+    (Promises::UNIX, b"unix"),
+    (Promises::DNS, b"dns"),
+    (Promises::GETPW, b"getpw"),
+    (Promises::SENDFD, b"sendfd"),
+    (Promises::RECVFD, b"recvfd"),
+    (Promises::TAPE, b"tape"),
+    (Promises::TTY, b"tty"),
+    (Promises::PROC, b"proc"),
+    (Promises::EXEC, b"exec"),
+    (Promises::PROT_EXEC, b"prot_exec"),
+    (Promises::SETTIME, b"settime"),
+    (Promises::PS, b"ps"),
+    (Promises::VMINFO, b"vminfo"),
+    (Promises::ID, b"id"),
+    (Promises::PF, b"pf"),
+    (Promises::ROUTE, b"route"),
+    (Promises::WROUTE, b"wroute"),
+    (Promises::AUDIO, b"audio"),
+    (Promises::VIDEO, b"video"),
+    (Promises::BPF, b"bpf"),
+    (Promises::UNVEIL, b"unveil"),
+    (Promises::ERROR, b"error"),
+];
+
+/// Large enough for every promise name, each followed by a space or NUL.
+const PROMISE_BUF_LEN: usize = 256;
+
+/// Format `promises` as the space-separated, NUL-terminated string `pledge`
+/// expects.
+fn promise_string(promises: Promises, buf: &mut [u8; PROMISE_BUF_LEN]) -> &CStr {
+    let mut len = 0;
+    for (promise, name) in PROMISE_NAMES {
+        if promises.contains(promise) {
+            if len != 0 {
+                buf[len] = b' ';
+                len += 1;
+            }
+            buf[len..len + name.len()].copy_from_slice(name);
+            len += name.len();
+        }
+    }
+    buf[len] = b'\0';
+    CStr::from_bytes_with_nul(&buf[..=len]).unwrap()
+}
+
+/// `pledge(promises, execpromises)`—Restrict the system calls the process
+/// may make.
+///
+/// `promises` restricts the current process, and `execpromises` restricts
+/// the programs it executes. `None` leaves the corresponding restriction
+/// unchanged. Promises can only be removed by later calls; attempting to add
+/// one fails with [`io::Errno::PERM`]. A process which then makes a system
+/// call outside its promises is killed with `SIGABRT`, or, if
+/// [`Promises::ERROR`] is given, the call fails with [`io::Errno::NOSYS`].
+///
+/// # References
+///  - [OpenBSD]
+///
+/// [OpenBSD]: https://man.openbsd.org/pledge.2
+#[inline]
+pub fn pledge(promises: Option<Promises>, execpromises: Option<Promises>) -> io::Result<()> {
+    let mut promises_buf = [0_u8; PROMISE_BUF_LEN];
+    let mut execpromises_buf = [0_u8; PROMISE_BUF_LEN];
+    syscalls::pledge(
+        promises.map(|promises| promise_string(promises, &mut promises_buf)),
+        execpromises.map(|execpromises| promise_string(execpromises, &mut execpromises_buf)),
+    )
+}
+
+bitflags! {
+    /// Permissions for a path, for use with [`unveil`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct UnveilPermissions: u8 {
+        /// `r`
+        const READ = 1 << 0;
+        /// `w`
+        const WRITE = 1 << 1;
+        /// `x`
+        const EXECUTE = 1 << 2;
+        /// `c`
+        const CREATE = 1 << 3;
+    }
+}
+
+/// `unveil(path, permissions)`—Make `path` visible with `permissions`.
+///
+/// The first call to `unveil` hides the rest of the filesystem. Later calls
+/// may make more paths visible, or change the permissions of paths already
+/// made visible, until [`unveil_lock`] is called. Empty `permissions` hide
+/// `path` and everything beneath it.
+///
+/// # References
+///  - [OpenBSD]
+///
+/// [OpenBSD]: https://man.openbsd.org/unveil.2
+#[cfg(feature = "fs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "fs")))]
+#[inline]
+pub fn unveil<P: path::Arg>(path: P, permissions: UnveilPermissions) -> io::Result<()> {
+    let mut buf = [0_u8; 5];
+    let mut len = 0;
+    for (permission, c) in [
+        (UnveilPermissions::READ, b'r'),
+        (UnveilPermissions::WRITE, b'w'),
+        (UnveilPermissions::EXECUTE, b'x'),
+        (UnveilPermissions::CREATE, b'c'),
+    ] {
+        if permissions.contains(permission) {
+            buf[len] = c;
+            len += 1;
+        }
+    }
+    let permissions = CStr::from_bytes_with_nul(&buf[..=len]).unwrap();
+
+    path.into_with_c_str(|path| syscalls::unveil(Some(path), Some(permissions)))
+}
+
+/// `unveil(NULL, NULL)`—Prevent further calls to [`unveil`].
+///
+/// # References
+///  - [OpenBSD]
+///
+/// [OpenBSD]: https://man.openbsd.org/unveil.2
+#[inline]
+pub fn unveil_lock() -> io::Result<()> {
+    syscalls::unveil(None, None)
+}
@@ -164,7 +164,7 @@ bitflags! {
 #[doc(alias = "PR_GET_UNALIGN")]
 pub fn unaligned_access_control() -> io::Result<UnalignedAccessControl> {
     let r = unsafe { prctl_get_at_arg2_optional::<c_uint>(PR_GET_UNALIGN)? };
-    UnalignedAccessControl::from_bits(r).ok_or(io::Errno::RANGE)
+    Ok(UnalignedAccessControl::from_bits_retain(r))
 }
 
 const PR_SET_UNALIGN: c_int = 6;
@@ -200,6 +200,9 @@ bitflags! {
         /// signal instead.
         #[doc(alias = "PR_UNALIGN_SIGFPE")]
         const SIGFPE = 2;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
     }
 }
 
@@ -213,7 +216,7 @@ bitflags! {
 #[doc(alias = "PR_GET_FPEMU")]
 pub fn floating_point_emulation_control() -> io::Result<FloatingPointEmulationControl> {
     let r = unsafe { prctl_get_at_arg2_optional::<c_uint>(PR_GET_FPEMU)? };
-    FloatingPointEmulationControl::from_bits(r).ok_or(io::Errno::RANGE)
+    Ok(FloatingPointEmulationControl::from_bits_retain(r))
 }
 
 const PR_SET_FPEMU: c_int = 10;
@@ -262,6 +265,9 @@ bitflags! {
         const RES = 0x08_0000;
         /// Floating point invalid operation.
         const INV = 0x10_0000;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
     }
 }
 
@@ -273,9 +279,9 @@ bitflags! {
 /// [`prctl(PR_GET_FPEXC,...)`]: https://man7.org/linux/man-pages/man2/prctl.2.html
 #[inline]
 #[doc(alias = "PR_GET_FPEXEC")]
-pub fn floating_point_exception_mode() -> io::Result<Option<FloatingPointExceptionMode>> {
-    unsafe { prctl_get_at_arg2_optional::<c_uint>(PR_GET_FPEXC) }
-        .map(FloatingPointExceptionMode::from_bits)
+pub fn floating_point_exception_mode() -> io::Result<FloatingPointExceptionMode> {
+    let r = unsafe { prctl_get_at_arg2_optional::<c_uint>(PR_GET_FPEXC)? };
+    Ok(FloatingPointExceptionMode::from_bits_retain(r))
 }
 
 const PR_SET_FPEXC: c_int = 12;
@@ -966,6 +972,9 @@ bitflags! {
         /// The speculation feature is disabled, mitigation is enabled, and the
         /// state will be cleared on `execve`.
         const DISABLE_NOEXEC = 1_u32 << 4;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
     }
 }
 
@@ -987,6 +996,9 @@ bitflags! {
         /// The speculation feature is disabled, mitigation is enabled, and the
         /// state will be cleared on `execve`.
         const DISABLE_NOEXEC = 1_u32 << 4;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
     }
 }
 
@@ -1000,9 +1012,9 @@ bitflags! {
 #[doc(alias = "PR_GET_SPECULATION_CTRL")]
 pub fn speculative_feature_state(
     feature: SpeculationFeature,
-) -> io::Result<Option<SpeculationFeatureState>> {
+) -> io::Result<SpeculationFeatureState> {
     let r = unsafe { prctl_2args(PR_GET_SPECULATION_CTRL, feature as usize as *mut _)? } as c_uint;
-    Ok(SpeculationFeatureState::from_bits(r))
+    Ok(SpeculationFeatureState::from_bits_retain(r))
 }
 
 const PR_SET_SPECULATION_CTRL: c_int = 53;
@@ -1073,7 +1085,7 @@ const PR_PAC_GET_ENABLED_KEYS: c_int = 61;
 #[doc(alias = "PR_PAC_GET_ENABLED_KEYS")]
 pub fn enabled_pointer_authentication_keys() -> io::Result<PointerAuthenticationKeys> {
     let r = unsafe { prctl_1arg(PR_PAC_GET_ENABLED_KEYS)? } as c_uint;
-    PointerAuthenticationKeys::from_bits(r).ok_or(io::Errno::RANGE)
+    Ok(PointerAuthenticationKeys::from_bits_retain(r))
 }
 
 const PR_PAC_SET_ENABLED_KEYS: c_int = 60;
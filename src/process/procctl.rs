@@ -305,6 +305,9 @@ bitflags! {
         const STOPPED = 16;
         /// The reported process is in the process of exiting.
         const EXITING = 32;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
     }
 }
 
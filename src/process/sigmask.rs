@@ -0,0 +1,112 @@
+use crate::backend::{self, c};
+use crate::io;
+use crate::process::Signal;
+use core::fmt;
+
+pub use backend::process::types::SigmaskHow;
+
+/// `sigset_t`—A set of signals.
+///
+/// `SigSet`s are used by [`sigprocmask`] and [`signalfd`], for example.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sigemptyset.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/sigsetops.3.html
+/// [`signalfd`]: crate::process::signalfd
+#[doc(alias = "sigset_t")]
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct SigSet(backend::process::types::RawSigSet);
+
+impl SigSet {
+    /// Create a new and empty `SigSet`.
+    #[doc(alias = "sigemptyset")]
+    #[inline]
+    pub fn new() -> Self {
+        Self(backend::process::sig_set::sigemptyset())
+    }
+
+    /// Add a signal to this `SigSet`.
+    #[doc(alias = "sigaddset")]
+    #[inline]
+    pub fn insert(&mut self, sig: Signal) {
+        backend::process::sig_set::sigaddset(&mut self.0, sig as _)
+    }
+
+    /// Remove a signal from this `SigSet`.
+    #[doc(alias = "sigdelset")]
+    #[inline]
+    pub fn remove(&mut self, sig: Signal) {
+        backend::process::sig_set::sigdelset(&mut self.0, sig as _)
+    }
+
+    /// Test whether a signal is in this `SigSet`.
+    #[doc(alias = "sigismember")]
+    #[inline]
+    pub fn contains(&self, sig: Signal) -> bool {
+        backend::process::sig_set::sigismember(&self.0, sig as _)
+    }
+
+    #[inline]
+    pub(crate) fn as_raw(&self) -> &backend::process::types::RawSigSet {
+        &self.0
+    }
+}
+
+impl Default for SigSet {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromIterator<Signal> for SigSet {
+    fn from_iter<I: IntoIterator<Item = Signal>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for sig in iter {
+            set.insert(sig);
+        }
+        set
+    }
+}
+
+impl fmt::Debug for SigSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut set = f.debug_set();
+        for raw in 1..=linux_raw_sys::general::_NSIG as c::c_int {
+            if backend::process::sig_set::sigismember(&self.0, raw) {
+                match Signal::from_raw(raw) {
+                    Some(sig) => set.entry(&sig),
+                    // Real-time signals don't have `Signal` values.
+                    None => set.entry(&raw),
+                };
+            }
+        }
+        set.finish()
+    }
+}
+
+/// `pthread_sigmask(how, set, &mut old)`—Adjust the calling thread's signal
+/// mask.
+///
+/// Returns the previous signal mask. If `set` is `None`, the mask is
+/// unchanged, and this just returns the current mask.
+///
+/// Signals which are blocked remain pending until they're unblocked, or
+/// consumed with [`signalfd`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/pthread_sigmask.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/pthread_sigmask.3.html
+/// [`signalfd`]: crate::process::signalfd
+#[doc(alias = "pthread_sigmask")]
+#[inline]
+pub fn sigprocmask(how: SigmaskHow, set: Option<&SigSet>) -> io::Result<SigSet> {
+    backend::process::syscalls::sigprocmask(how, set.map(SigSet::as_raw)).map(SigSet)
+}
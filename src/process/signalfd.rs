@@ -0,0 +1,156 @@
+//! The `signalfd` API.
+//!
+//! # Safety
+//!
+//! `signalfd_read` reinterprets the bytes read from the file descriptor as a
+//! `struct signalfd_siginfo`.
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, OwnedFd};
+use crate::process::{Pid, SigSet, Signal, Uid};
+use crate::{backend, io};
+use core::mem::size_of;
+
+pub use backend::process::types::SignalfdFlags;
+
+/// `signalfd(-1, mask, flags)`—Create a file descriptor for accepting
+/// signals.
+///
+/// Signals in `mask` become readable from the returned file descriptor, with
+/// [`signalfd_read`]. The signals should also be blocked with
+/// [`sigprocmask`], so that they aren't handled in the default way before
+/// they can be read.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+/// [`sigprocmask`]: crate::process::sigprocmask
+#[inline]
+pub fn signalfd(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    backend::process::syscalls::signalfd(mask.as_raw(), flags)
+}
+
+/// `signalfd(fd, mask, 0)`—Replace the set of signals accepted by a signalfd.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[inline]
+#[doc(alias = "signalfd")]
+pub fn signalfd_set_mask<Fd: AsFd>(fd: Fd, mask: &SigSet) -> io::Result<()> {
+    backend::process::syscalls::signalfd_set_mask(fd.as_fd(), mask.as_raw())
+}
+
+/// `read(fd, &mut signalfd_siginfo)`—Read a signal from a signalfd.
+///
+/// If no signals are pending and the file descriptor is non-blocking, this
+/// fails with [`io::Errno::AGAIN`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[inline]
+pub fn signalfd_read<Fd: AsFd>(fd: Fd) -> io::Result<SignalfdSiginfo> {
+    let mut buf = [0_u8; size_of::<SignalfdSiginfo>()];
+    if io::read(fd, &mut buf)? != buf.len() {
+        return Err(io::Errno::IO);
+    }
+
+    // SAFETY: `SignalfdSiginfo` is plain data, so any bytes are a valid
+    // value.
+    Ok(unsafe { buf.as_ptr().cast::<SignalfdSiginfo>().read_unaligned() })
+}
+
+/// `struct signalfd_siginfo`—A signal read from a signalfd.
+#[doc(alias = "signalfd_siginfo")]
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct SignalfdSiginfo {
+    ssi_signo: u32,
+    ssi_errno: i32,
+    ssi_code: i32,
+    ssi_pid: u32,
+    ssi_uid: u32,
+    ssi_fd: i32,
+    ssi_tid: u32,
+    ssi_band: u32,
+    ssi_overrun: u32,
+    ssi_trapno: u32,
+    ssi_status: i32,
+    ssi_int: i32,
+    ssi_ptr: u64,
+    ssi_utime: u64,
+    ssi_stime: u64,
+    ssi_addr: u64,
+    ssi_addr_lsb: u16,
+    __pad2: u16,
+    ssi_syscall: i32,
+    ssi_call_addr: u64,
+    ssi_arch: u32,
+    __pad: [u8; 28],
+}
+
+impl SignalfdSiginfo {
+    /// `ssi_signo`—Return the raw signal number.
+    #[inline]
+    pub const fn raw_signo(&self) -> i32 {
+        self.ssi_signo as i32
+    }
+
+    /// Return the signal, if it's one of the signals known to [`Signal`].
+    #[inline]
+    pub fn signal(&self) -> Option<Signal> {
+        Signal::from_raw(self.raw_signo())
+    }
+
+    /// `ssi_code`—Return the signal code, such as `SI_USER` or `SI_QUEUE`.
+    #[inline]
+    pub const fn code(&self) -> i32 {
+        self.ssi_code
+    }
+
+    /// `ssi_errno`—Return the error number, which is usually unused.
+    #[inline]
+    pub const fn errno(&self) -> i32 {
+        self.ssi_errno
+    }
+
+    /// `ssi_pid`—Return the ID of the sending process, if there is one.
+    #[inline]
+    pub fn pid(&self) -> Option<Pid> {
+        Pid::from_raw(self.ssi_pid as _)
+    }
+
+    /// `ssi_uid`—Return the real user ID of the sending process.
+    #[inline]
+    pub fn uid(&self) -> Uid {
+        // SAFETY: The kernel reports valid user IDs.
+        unsafe { Uid::from_raw(self.ssi_uid) }
+    }
+
+    /// `ssi_status`—Return the exit status or signal, for `SIGCHLD`.
+    #[inline]
+    pub const fn status(&self) -> i32 {
+        self.ssi_status
+    }
+
+    /// `ssi_int`—Return the integer sent with `sigqueue`.
+    #[inline]
+    pub const fn int(&self) -> i32 {
+        self.ssi_int
+    }
+
+    /// `ssi_ptr`—Return the pointer value sent with `sigqueue`.
+    #[inline]
+    pub const fn ptr(&self) -> u64 {
+        self.ssi_ptr
+    }
+}
+
+#[test]
+fn signalfd_siginfo_layout() {
+    assert_eq!(size_of::<SignalfdSiginfo>(), 128);
+}
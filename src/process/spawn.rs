@@ -0,0 +1,594 @@
+//! A minimal process spawning builder, in the style of `posix_spawn`.
+//!
+//! [`Command`] forks the current process and then, in the child, performs
+//! its configured file actions, working directory change, process group
+//! change, and signal setup before calling `execve`. Everything the child
+//! needs is prepared in the parent beforehand, so the child only makes raw
+//! system calls; it doesn't allocate, take locks, or call into a libc. This
+//! makes it safe to use in multi-threaded programs.
+//!
+//! On x86-64, [`Command::vfork`] creates the child with
+//! `clone(CLONE_VM | CLONE_VFORK)` instead, running it on a separate stack
+//! with a guard page until it calls `execve`. Other architectures always use
+//! `fork`.
+//!
+//! # References
+//!  - [POSIX `posix_spawn`]
+//!
+//! [POSIX `posix_spawn`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/posix_spawn.html
+#![allow(unsafe_code)]
+
+use crate::backend::process::types::RawSigSet;
+use crate::backend::{self, c};
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+use crate::ffi::{CStr, CString};
+use crate::fs::{Mode, OFlags};
+use crate::io::{self, FdFlags};
+use crate::path::Arg;
+use crate::pid::Pid;
+use crate::pipe::PipeFlags;
+use crate::process::{waitpid, SigmaskHow, WaitOptions};
+use crate::signal::Signal;
+use alloc::vec::Vec;
+#[cfg(target_arch = "x86_64")]
+use core::ffi::c_void;
+use core::mem::zeroed;
+use core::ptr::null;
+
+/// The exit status of a child which failed before it could call `execve`.
+const EXIT_SPAWN_FAILED: i32 = 127;
+
+/// The size of the stack a child created with `CLONE_VM` runs on until it
+/// calls `execve`.
+#[cfg(target_arch = "x86_64")]
+const VFORK_STACK_SIZE: usize = 64 * 1024;
+
+/// The size of the guard page below the stack of a child created with
+/// `CLONE_VM`. Pages are always 4 KiB on x86-64.
+#[cfg(target_arch = "x86_64")]
+const VFORK_GUARD_SIZE: usize = 4096;
+
+/// A file descriptor operation performed in the child before `execve`.
+enum FileAction<'a> {
+    Dup2(BorrowedFd<'a>, RawFd),
+    Open(CString, OFlags, Mode, RawFd),
+    Close(RawFd),
+}
+
+impl FileAction<'_> {
+    /// The file descriptor number this action replaces or closes.
+    fn target(&self) -> RawFd {
+        match *self {
+            Self::Dup2(_, target) => target,
+            Self::Open(_, _, _, target) => target,
+            Self::Close(target) => target,
+        }
+    }
+}
+
+/// A builder for spawning a new process with `fork` and `execve`.
+///
+/// Unlike `std::process::Command`, this doesn't search `PATH`, and the child
+/// doesn't inherit the parent's environment variables; it receives only the
+/// variables added with [`Command::env`]. Standard input, output, and error,
+/// and any other file descriptors without the `O_CLOEXEC` flag, are inherited
+/// unless changed with the file actions [`Command::dup2`],
+/// [`Command::open`], and [`Command::close`], which are performed in the
+/// order they're added.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> rustix::io::Result<()> {
+/// use rustix::fd::AsFd;
+/// use rustix::pipe::{pipe_with, PipeFlags};
+/// use rustix::process::{waitpid, Command, WaitOptions};
+///
+/// // Use `O_CLOEXEC` so that only the `dup2`'d copy of `writer` is inherited.
+/// let (reader, writer) = pipe_with(PipeFlags::CLOEXEC)?;
+/// let pid = Command::new("/bin/echo")
+///     .arg("hello")
+///     .env("LANG", "C")
+///     .dup2(writer.as_fd(), 1)
+///     .spawn()?;
+/// waitpid(Some(pid), WaitOptions::empty())?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///  - [POSIX `posix_spawn`]
+///  - [Linux `posix_spawn`]
+///
+/// [POSIX `posix_spawn`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/posix_spawn.html
+/// [Linux `posix_spawn`]: https://man7.org/linux/man-pages/man3/posix_spawn.3.html
+#[doc(alias = "posix_spawn")]
+pub struct Command<'a> {
+    path: CString,
+    args: Vec<CString>,
+    env: Vec<CString>,
+    actions: Vec<FileAction<'a>>,
+    current_dir: Option<CString>,
+    process_group: Option<Option<Pid>>,
+    reset_signal_mask: bool,
+    vfork: bool,
+    default_signals: Vec<Signal>,
+    error: Option<io::Errno>,
+}
+
+impl<'a> Command<'a> {
+    /// Construct a new `Command` for running the program at `path`, with
+    /// `path` as its `argv[0]`.
+    pub fn new<P: Arg>(path: P) -> Self {
+        let mut error = None;
+        let path = to_c_string(path, &mut error);
+        Self {
+            args: Vec::from([path.clone()]),
+            path,
+            env: Vec::new(),
+            actions: Vec::new(),
+            current_dir: None,
+            process_group: None,
+            reset_signal_mask: false,
+            vfork: false,
+            default_signals: Vec::new(),
+            error,
+        }
+    }
+
+    /// Append an argument.
+    pub fn arg<A: Arg>(&mut self, arg: A) -> &mut Self {
+        let arg = to_c_string(arg, &mut self.error);
+        self.args.push(arg);
+        self
+    }
+
+    /// Append several arguments.
+    pub fn args<I, A>(&mut self, args: I) -> &mut Self
+    where
+        I: IntoIterator<Item = A>,
+        A: Arg,
+    {
+        for arg in args {
+            self.arg(arg);
+        }
+        self
+    }
+
+    /// Add an environment variable.
+    ///
+    /// Fails at [`Command::spawn`] with [`io::Errno::INVAL`] if `key` is empty
+    /// or contains a `=`.
+    pub fn env<K: Arg, V: Arg>(&mut self, key: K, value: V) -> &mut Self {
+        let key = to_c_string(key, &mut self.error);
+        let value = to_c_string(value, &mut self.error);
+        let key = key.as_bytes();
+        if key.is_empty() || key.contains(&b'=') {
+            self.error.get_or_insert(io::Errno::INVAL);
+        }
+        let mut var = Vec::with_capacity(key.len() + 1 + value.as_bytes().len());
+        var.extend_from_slice(key);
+        var.push(b'=');
+        var.extend_from_slice(value.as_bytes());
+        // Neither part contains a NUL byte, so this can't fail.
+        self.env.push(CString::new(var).unwrap_or_default());
+        self
+    }
+
+    /// Duplicate `fd` onto the file descriptor number `target` in the child,
+    /// as with [`dup2`].
+    ///
+    /// The new `target` doesn't have the `O_CLOEXEC` flag, so it's inherited
+    /// by the new program, even if `fd` already is `target`.
+    ///
+    /// [`dup2`]: crate::io::dup2
+    #[doc(alias = "posix_spawn_file_actions_adddup2")]
+    pub fn dup2(&mut self, fd: BorrowedFd<'a>, target: RawFd) -> &mut Self {
+        self.actions.push(FileAction::Dup2(fd, target));
+        self
+    }
+
+    /// Open the file at `path` onto the file descriptor number `target` in
+    /// the child, as with [`open`].
+    ///
+    /// [`open`]: crate::fs::open
+    #[doc(alias = "posix_spawn_file_actions_addopen")]
+    pub fn open<P: Arg>(&mut self, target: RawFd, path: P, flags: OFlags, mode: Mode) -> &mut Self {
+        let path = to_c_string(path, &mut self.error);
+        self.actions
+            .push(FileAction::Open(path, flags, mode, target));
+        self
+    }
+
+    /// Close the file descriptor number `target` in the child.
+    #[doc(alias = "posix_spawn_file_actions_addclose")]
+    pub fn close(&mut self, target: RawFd) -> &mut Self {
+        self.actions.push(FileAction::Close(target));
+        self
+    }
+
+    /// Change the working directory of the child to `path`.
+    ///
+    /// This happens after the file actions, so relative paths passed to
+    /// [`Command::open`] are resolved in the parent's working directory.
+    #[doc(alias = "posix_spawn_file_actions_addchdir_np")]
+    pub fn current_dir<P: Arg>(&mut self, path: P) -> &mut Self {
+        self.current_dir = Some(to_c_string(path, &mut self.error));
+        self
+    }
+
+    /// Move the child into the process group `pgid`, or into a new process
+    /// group with the child's own id if `pgid` is `None`, as with
+    /// [`setpgid`].
+    ///
+    /// [`setpgid`]: crate::process::setpgid
+    #[doc(alias = "POSIX_SPAWN_SETPGROUP")]
+    pub fn process_group(&mut self, pgid: Option<Pid>) -> &mut Self {
+        self.process_group = Some(pgid);
+        self
+    }
+
+    /// Start the child with no signals blocked, instead of with the calling
+    /// thread's signal mask.
+    #[doc(alias = "POSIX_SPAWN_SETSIGMASK")]
+    pub fn reset_signal_mask(&mut self) -> &mut Self {
+        self.reset_signal_mask = true;
+        self
+    }
+
+    /// Reset `signal` to its default disposition in the child, even if it is
+    /// ignored in the parent.
+    ///
+    /// Signals with handlers are always reset to their default dispositions,
+    /// while ignored signals stay ignored across `execve` unless reset with
+    /// this.
+    #[doc(alias = "POSIX_SPAWN_SETSIGDEF")]
+    pub fn default_signal(&mut self, signal: Signal) -> &mut Self {
+        self.default_signals.push(signal);
+        self
+    }
+
+    /// Create the child with `clone(CLONE_VM | CLONE_VFORK)`, like `vfork`,
+    /// instead of with `fork`.
+    ///
+    /// The child then shares the parent's memory until it calls `execve`,
+    /// which avoids copying the parent's page tables. This makes spawning
+    /// much faster from processes with large address spaces. The calling
+    /// thread is suspended until the child calls `execve` or exits, while
+    /// other threads keep running.
+    ///
+    /// The usual hazards of `vfork` don't apply, because the child only runs
+    /// code in this module, on its own stack, and it only writes to memory
+    /// on that stack. The stack has a guard page, so an overflow faults
+    /// instead of corrupting the parent's memory. The child doesn't run any
+    /// user code, allocate, or take locks, and signals are blocked until it
+    /// has reset the parent's signal handlers.
+    ///
+    /// # Platform support
+    ///
+    /// This is currently only implemented on x86-64. On other architectures,
+    /// this setting is accepted but has no effect, and the child is created
+    /// with `fork`. The child behaves the same either way; only the cost of
+    /// copying the parent's page tables differs.
+    #[doc(alias = "CLONE_VFORK")]
+    #[doc(alias = "POSIX_SPAWN_USEVFORK")]
+    pub fn vfork(&mut self) -> &mut Self {
+        self.vfork = true;
+        self
+    }
+
+    /// Spawn the child process, and return its process id.
+    ///
+    /// This waits until the child has called `execve`. If anything fails in
+    /// the child before that point, the child is reaped and the error is
+    /// returned. Otherwise, the caller is responsible for waiting for the
+    /// child, for example with [`waitpid`].
+    ///
+    /// [`waitpid`]: crate::process::waitpid
+    pub fn spawn(&self) -> io::Result<Pid> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        // Prepare the `argv` and `envp` arrays now, as the child can't
+        // allocate.
+        let argv = pointers(&self.args);
+        let envp = pointers(&self.env);
+
+        // The child reports errors through this pipe. If it calls `execve`
+        // successfully, the `O_CLOEXEC` flag closes the pipe instead.
+        let (reader, writer) = backend::pipe::syscalls::pipe_with(PipeFlags::CLOEXEC)?;
+
+        // Block all signals until the child has reset its signal handlers, so
+        // that none of the parent's handlers run in the child.
+        let mut all: RawSigSet = unsafe { zeroed() };
+        for word in all.sig.iter_mut() {
+            *word = !0;
+        }
+        let old_mask = backend::process::syscalls::sigprocmask(SigmaskHow::SetMask, Some(&all))?;
+
+        let result = unsafe { self.start_child(&argv, &envp, &old_mask, writer.as_raw_fd()) };
+        let restored =
+            backend::process::syscalls::sigprocmask(SigmaskHow::SetMask, Some(&old_mask));
+        let pid = result?;
+
+        drop(writer);
+        if let Err(err) = restored {
+            // Don't leave a child behind that the caller doesn't know about.
+            let _ = backend::process::syscalls::kill_process(pid, Signal::Kill);
+            while let Err(io::Errno::INTR) = waitpid(Some(pid), WaitOptions::empty()) {}
+            return Err(err);
+        }
+        wait_for_exec(reader.as_fd(), pid)
+    }
+
+    /// Create the child process, and return its process id.
+    ///
+    /// # Safety
+    ///
+    /// All signals must be blocked.
+    unsafe fn start_child(
+        &self,
+        argv: &[*const u8],
+        envp: &[*const u8],
+        old_mask: &RawSigSet,
+        err_fd: RawFd,
+    ) -> io::Result<Pid> {
+        #[cfg(target_arch = "x86_64")]
+        if self.vfork {
+            let mut child = ChildArgs {
+                command: self,
+                argv,
+                envp,
+                old_mask,
+                err_fd,
+            };
+
+            // The stack grows down, from the page-aligned end of the mapping.
+            // It isn't freed until the child is done with it.
+            let stack = VforkStack::new()?;
+
+            return backend::process::syscalls::vfork_with_stack(
+                stack.top(),
+                vfork_child_main,
+                (&mut child as *mut ChildArgs<'_>).cast(),
+            );
+        }
+
+        match backend::process::syscalls::fork()? {
+            None => child_main(self, argv, envp, old_mask, err_fd),
+            Some(pid) => Ok(pid),
+        }
+    }
+
+    /// Set up the child and call `execve`, returning the error on failure.
+    ///
+    /// `err_fd` holds the write end of the error pipe, which is moved if a
+    /// file action targets it.
+    ///
+    /// # Safety
+    ///
+    /// This must only be called in a newly created child process, with all
+    /// signals blocked. It must not allocate or panic.
+    unsafe fn exec_child(
+        &self,
+        argv: &[*const u8],
+        envp: &[*const u8],
+        old_mask: &RawSigSet,
+        err_fd: &mut RawFd,
+    ) -> io::Errno {
+        // Reset signals with handlers, which belong to the parent, and any
+        // signals requested by the user.
+        for signal in 1..=linux_raw_sys::general::_NSIG as c::c_int {
+            if let Ok(action) = backend::process::syscalls::sigaction_raw(signal, None) {
+                let handler = action.sa_handler_kernel.map_or(0, |h| h as usize);
+                let requested = self
+                    .default_signals
+                    .iter()
+                    .any(|s| *s as c::c_int == signal);
+                if handler > 1 || (handler == 1 && requested) {
+                    // A zeroed `sigaction` has a handler of `SIG_DFL`.
+                    let default: linux_raw_sys::general::kernel_sigaction = zeroed();
+                    let _ = backend::process::syscalls::sigaction_raw(signal, Some(default));
+                }
+            }
+        }
+
+        if let Some(pgid) = self.process_group {
+            if let Err(err) = backend::process::syscalls::setpgid(None, pgid) {
+                return err;
+            }
+        }
+
+        for action in &self.actions {
+            if let Err(err) = perform(action, err_fd) {
+                return err;
+            }
+        }
+
+        if let Some(dir) = &self.current_dir {
+            if let Err(err) = backend::process::syscalls::chdir(dir) {
+                return err;
+            }
+        }
+
+        let mask = if self.reset_signal_mask {
+            zeroed()
+        } else {
+            *old_mask
+        };
+        if let Err(err) = backend::process::syscalls::sigprocmask(SigmaskHow::SetMask, Some(&mask))
+        {
+            return err;
+        }
+
+        backend::process::syscalls::execve(&self.path, argv.as_ptr(), envp.as_ptr())
+    }
+}
+
+/// The stack of a child created with `CLONE_VM`, with a guard page below it,
+/// so that overflowing it faults instead of corrupting the parent's memory.
+#[cfg(target_arch = "x86_64")]
+struct VforkStack(*mut c_void);
+
+#[cfg(target_arch = "x86_64")]
+impl VforkStack {
+    fn new() -> io::Result<Self> {
+        unsafe {
+            backend::process::syscalls::mmap_stack(
+                VFORK_GUARD_SIZE + VFORK_STACK_SIZE,
+                VFORK_GUARD_SIZE,
+            )
+            .map(Self)
+        }
+    }
+
+    /// The initial stack pointer, which is page-aligned, and so also 16-byte
+    /// aligned.
+    fn top(&self) -> *mut c_void {
+        unsafe { self.0.cast::<u8>().add(VFORK_GUARD_SIZE + VFORK_STACK_SIZE) }.cast()
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+impl Drop for VforkStack {
+    fn drop(&mut self) {
+        unsafe {
+            backend::process::syscalls::munmap_stack(self.0, VFORK_GUARD_SIZE + VFORK_STACK_SIZE)
+        }
+    }
+}
+
+/// The arguments to [`child_main`], for passing through `clone`.
+#[cfg(target_arch = "x86_64")]
+struct ChildArgs<'a> {
+    command: &'a Command<'a>,
+    argv: &'a [*const u8],
+    envp: &'a [*const u8],
+    old_mask: &'a RawSigSet,
+    err_fd: RawFd,
+}
+
+/// The entry point of a child created with `CLONE_VM`, on its own stack.
+#[cfg(target_arch = "x86_64")]
+unsafe extern "C" fn vfork_child_main(arg: *mut c_void) -> ! {
+    let args = &*arg.cast::<ChildArgs<'_>>();
+    child_main(
+        args.command,
+        args.argv,
+        args.envp,
+        args.old_mask,
+        args.err_fd,
+    )
+}
+
+/// Set up the child and call `execve`, or report the error to the parent
+/// through `err_fd` and exit.
+///
+/// # Safety
+///
+/// This must only be called in a newly created child process, with all
+/// signals blocked.
+unsafe fn child_main(
+    command: &Command<'_>,
+    argv: &[*const u8],
+    envp: &[*const u8],
+    old_mask: &RawSigSet,
+    mut err_fd: RawFd,
+) -> ! {
+    let error = command.exec_child(argv, envp, old_mask, &mut err_fd);
+    let bytes = error.raw_os_error().to_ne_bytes();
+    let _ = backend::io::syscalls::write(BorrowedFd::borrow_raw(err_fd), &bytes);
+    backend::process::syscalls::exit_group(EXIT_SPAWN_FAILED)
+}
+
+/// Perform a file action in the child.
+///
+/// # Safety
+///
+/// This must only be called in a newly created child process. It must not
+/// allocate or panic.
+unsafe fn perform(action: &FileAction<'_>, err_fd: &mut RawFd) -> io::Result<()> {
+    let target = action.target();
+
+    // Don't let the action clobber the error pipe.
+    if target == *err_fd {
+        let moved = backend::io::syscalls::fcntl_dupfd_cloexec(BorrowedFd::borrow_raw(*err_fd), 0)?;
+        *err_fd = moved.as_raw_fd();
+        core::mem::forget(moved);
+    }
+
+    match action {
+        FileAction::Dup2(fd, target) => dup2_inheritable(*fd, *target),
+        FileAction::Open(path, flags, mode, target) => {
+            let fd = backend::fs::syscalls::open(path, *flags, *mode)?;
+            if fd.as_raw_fd() == *target {
+                core::mem::forget(fd);
+                Ok(())
+            } else {
+                dup2_inheritable(fd.as_fd(), *target)
+            }
+        }
+        FileAction::Close(target) => {
+            backend::io::syscalls::close(*target);
+            Ok(())
+        }
+    }
+}
+
+/// `dup2(fd, target)`, also clearing `FD_CLOEXEC` if `fd` is `target`.
+unsafe fn dup2_inheritable(fd: BorrowedFd<'_>, target: RawFd) -> io::Result<()> {
+    if fd.as_raw_fd() == target {
+        return backend::io::syscalls::fcntl_setfd(fd, FdFlags::empty());
+    }
+    let mut new = OwnedFd::from_raw_fd(target);
+    let result = backend::io::syscalls::dup2(fd, &mut new);
+    core::mem::forget(new);
+    result
+}
+
+/// Wait for the child to call `execve`, or to report an error through the
+/// pipe `reader`.
+fn wait_for_exec(reader: BorrowedFd<'_>, pid: Pid) -> io::Result<Pid> {
+    let mut buf = [0_u8; 4];
+    let mut len = 0;
+    while len < buf.len() {
+        match crate::io::read(reader, &mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(io::Errno::INTR) => continue,
+            Err(err) => return Err(err),
+        }
+    }
+
+    match len {
+        // The pipe was closed by a successful `execve`.
+        0 => Ok(pid),
+        4 => {
+            // Reap the child, which has exited.
+            while let Err(io::Errno::INTR) = waitpid(Some(pid), WaitOptions::empty()) {}
+            Err(io::Errno::from_raw_os_error(i32::from_ne_bytes(buf)))
+        }
+        _ => Err(io::Errno::IO),
+    }
+}
+
+/// Convert `arg` to a `CString`, recording the first error in `error`.
+fn to_c_string<A: Arg>(arg: A, error: &mut Option<io::Errno>) -> CString {
+    match arg.into_c_str() {
+        Ok(s) => s.into_owned(),
+        Err(err) => {
+            error.get_or_insert(err);
+            CString::default()
+        }
+    }
+}
+
+/// Return a NULL-terminated array of pointers to the strings in `strings`.
+fn pointers(strings: &[CString]) -> Vec<*const u8> {
+    strings
+        .iter()
+        .map(|s| CStr::as_ptr(s).cast())
+        .chain(core::iter::once(null()))
+        .collect()
+}
@@ -1,4 +1,13 @@
 use crate::process::Pid;
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "fuchsia",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
+use crate::ugid::Uid;
 use crate::{backend, io};
 use bitflags::bitflags;
 
@@ -221,6 +230,34 @@ impl WaitidStatus {
         }
     }
 
+    /// Returns the process ID of the child whose state changed.
+    ///
+    /// This is useful with [`WaitId::All`] and [`WaitId::Pgid`], to learn
+    /// which child the status is for.
+    #[inline]
+    #[cfg(not(any(target_os = "emscripten", target_os = "fuchsia", target_os = "netbsd")))]
+    #[allow(unsafe_code)]
+    pub fn pid(&self) -> Option<Pid> {
+        // SAFETY: POSIX [specifies] that the `siginfo_t` returned by a
+        // `waitid` call always has a valid `si_pid` value.
+        //
+        // [specifies]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/waitid.html
+        Pid::from_raw(unsafe { self.0.si_pid() } as _)
+    }
+
+    /// Returns the real user ID of the child whose state changed.
+    #[inline]
+    #[cfg(not(any(target_os = "emscripten", target_os = "fuchsia", target_os = "netbsd")))]
+    #[allow(unsafe_code)]
+    pub fn uid(&self) -> Uid {
+        // SAFETY: POSIX [specifies] that the `siginfo_t` returned by a
+        // `waitid` call always has a valid `si_uid` value, and any `uid_t`
+        // value is a valid `Uid`.
+        //
+        // [specifies]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/waitid.html
+        unsafe { Uid::from_raw(self.0.si_uid() as _) }
+    }
+
     /// Returns a reference to the raw platform-specific `siginfo_t` struct.
     #[inline]
     pub const fn as_raw(&self) -> &backend::c::siginfo_t {
@@ -355,6 +392,22 @@ pub fn wait(waitopts: WaitOptions) -> io::Result<Option<(Pid, WaitStatus)>> {
 
 /// `waitid(_, _, _, opts)`—Wait for the specified child process to change
 /// state.
+///
+/// `options` must include at least one of [`WaitidOptions::EXITED`],
+/// [`WaitidOptions::STOPPED`], or [`WaitidOptions::CONTINUED`], to select
+/// which state changes to wait for. With [`WaitidOptions::NOWAIT`], the child
+/// is left in a waitable state, so a later call can reap it.
+///
+/// On success, returns the status of the selected process, including its
+/// pid. If `NOHANG` was specified in the options, and no selected child
+/// process changed state, returns `None`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/waitid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/waitid.2.html
 #[cfg(not(any(target_os = "openbsd", target_os = "redox", target_os = "wasi")))]
 #[inline]
 pub fn waitid<'a>(
@@ -41,6 +41,7 @@ enum Kind {
     Proc,
     Pid,
     Fd,
+    FdInfo,
     File,
     Symlink,
 }
@@ -67,14 +68,14 @@ fn check_proc_entry_with_stat(
 
     match kind {
         Kind::Proc => check_proc_root(entry, &entry_stat)?,
-        Kind::Pid | Kind::Fd => check_proc_subdir(entry, &entry_stat, proc_stat)?,
+        Kind::Pid | Kind::Fd | Kind::FdInfo => check_proc_subdir(entry, &entry_stat, proc_stat)?,
         Kind::File => check_proc_file(&entry_stat, proc_stat)?,
         Kind::Symlink => check_proc_symlink(&entry_stat, proc_stat)?,
     }
 
     // "/proc" directories are typically mounted r-xr-xr-x.
-    // "/proc/self/fd" is r-x------. Allow them to have fewer permissions, but
-    // not more.
+    // "/proc/self/fd" is r-x------, though "/proc/self/fdinfo" is r-xr-xr-x.
+    // Allow them to have fewer permissions, but not more.
     match kind {
         Kind::Symlink => {
             // On Linux, symlinks don't have their own permissions.
@@ -88,10 +89,10 @@ fn check_proc_entry_with_stat(
     }
 
     match kind {
-        Kind::Fd => {
-            // Check that the "/proc/self/fd" directory doesn't have any
-            // extraneous links into it (which might include unexpected
-            // subdirectories).
+        Kind::Fd | Kind::FdInfo => {
+            // Check that the "/proc/self/fd" and "/proc/self/fdinfo"
+            // directories don't have any extraneous links into them (which
+            // might include unexpected subdirectories).
             if entry_stat.st_nlink != 2 {
                 return Err(io::Errno::NOTSUP);
             }
@@ -340,14 +341,23 @@ fn new_static_fd(fd: OwnedFd, stat: Stat) -> (OwnedFd, Stat) {
 /// Returns a handle to Linux's `/proc/self/fdinfo` directory.
 ///
 /// This ensures that `/proc/self/fdinfo` is `procfs`, that nothing is mounted
-/// on top of it, and that it looks normal. It also returns the `Stat` of
-/// `/proc/self/fd`.
+/// on top of it, and that it looks normal.
+///
+/// To open the file for a specific file descriptor, use
+/// [`proc_self_fdinfo_fd`].
 ///
 /// # References
 ///  - [Linux]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
-fn proc_self_fdinfo() -> io::Result<(BorrowedFd<'static>, &'static Stat)> {
+#[inline]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "procfs")))]
+pub fn proc_self_fdinfo() -> io::Result<BorrowedFd<'static>> {
+    proc_self_fdinfo_with_stat().map(|(fd, _stat)| fd)
+}
+
+/// Returns a handle to Linux's `/proc/self/fdinfo` directory, and its `Stat`.
+fn proc_self_fdinfo_with_stat() -> io::Result<(BorrowedFd<'static>, &'static Stat)> {
     static PROC_SELF_FDINFO: StaticFd = StaticFd::new();
 
     PROC_SELF_FDINFO
@@ -359,7 +369,7 @@ fn proc_self_fdinfo() -> io::Result<(BorrowedFd<'static>, &'static Stat)> {
             // Open "/proc/self/fdinfo".
             let proc_self_fdinfo = proc_opendirat(proc_self, cstr!("fdinfo"))?;
             let proc_self_fdinfo_stat =
-                check_proc_entry(Kind::Fd, proc_self_fdinfo.as_fd(), Some(proc_stat))
+                check_proc_entry(Kind::FdInfo, proc_self_fdinfo.as_fd(), Some(proc_stat))
                     .map_err(|_err| io::Errno::NOTSUP)?;
 
             Ok((proc_self_fdinfo, proc_self_fdinfo_stat))
@@ -383,7 +393,7 @@ pub fn proc_self_fdinfo_fd<Fd: AsFd>(fd: Fd) -> io::Result<OwnedFd> {
 }
 
 fn _proc_self_fdinfo(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
-    let (proc_self_fdinfo, proc_self_fdinfo_stat) = proc_self_fdinfo()?;
+    let (proc_self_fdinfo, proc_self_fdinfo_stat) = proc_self_fdinfo_with_stat()?;
     let fd_str = DecInt::from_fd(fd);
     open_and_check_file(
         proc_self_fdinfo,
@@ -1,8 +1,30 @@
 //! Pseudoterminal operations.
 //!
+//! On Linux, these are implemented with `/dev/ptmx` and `ioctl`s, so they
+//! don't depend on libc.
+//!
 //! For the `openpty` and `login_tty` functions, see the
 //! [rustix-openpty crate].
 //!
+//! # Examples
+//!
+//! Allocate a pseudoterminal, and open both of its sides:
+//!
+//! ```
+//! # #[cfg(any(apple, linux_like, target_os = "freebsd", target_os = "fuchsia"))]
+//! # fn example() -> rustix::io::Result<()> {
+//! use rustix::pty::{grantpt, open_user, openpt, unlockpt, OpenptFlags};
+//!
+//! let flags = OpenptFlags::RDWR | OpenptFlags::NOCTTY;
+//! let controller = openpt(flags)?;
+//! grantpt(&controller)?;
+//! unlockpt(&controller)?;
+//! let user = open_user(&controller, flags)?;
+//! # let _ = user;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! [rustix-openpty crate]: https://crates.io/crates/rustix-openpty
 
 #![allow(unsafe_code)]
@@ -10,6 +32,8 @@
 use crate::backend::c;
 use crate::fd::{AsFd, OwnedFd};
 use crate::fs::OFlags;
+#[cfg(any(apple, linux_like, target_os = "freebsd", target_os = "fuchsia"))]
+use crate::path::OutputBuffer;
 use crate::{backend, io};
 #[cfg(all(
     feature = "alloc",
@@ -105,6 +129,9 @@ pub fn openpt(flags: OpenptFlags) -> io::Result<OwnedFd> {
 
 /// `ptsname(fd)`—Return the name of a pseudoterminal.
 ///
+/// To write into a fixed-size buffer without allocating, use
+/// [`ptsname_into`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -120,7 +147,31 @@ pub fn openpt(flags: OpenptFlags) -> io::Result<OwnedFd> {
 #[inline]
 #[doc(alias = "ptsname_r")]
 pub fn ptsname<Fd: AsFd, B: Into<Vec<u8>>>(fd: Fd, reuse: B) -> io::Result<CString> {
-    backend::pty::syscalls::ptsname(fd.as_fd(), reuse.into())
+    ptsname_into(fd, reuse.into())
+}
+
+/// `ptsname(fd)`—Return the name of a pseudoterminal, in a caller-provided
+/// buffer.
+///
+/// `buf` may be any [`OutputBuffer`], such as a `Vec<u8>` to allocate the
+/// result, or a fixed-size array to avoid allocating.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///  - [glibc]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/ptsname.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/ptsname.3.html
+/// [glibc]: https://www.gnu.org/software/libc/manual/html_node/Allocation.html#index-ptsname
+#[cfg(any(apple, linux_like, target_os = "freebsd", target_os = "fuchsia"))]
+#[inline]
+#[doc(alias = "ptsname_r")]
+pub fn ptsname_into<Fd: AsFd, B: OutputBuffer>(fd: Fd, buf: B) -> io::Result<B::Name> {
+    let fd = fd.as_fd();
+
+    // SAFETY: The backend returns the number of bytes it initialized.
+    unsafe { buf.fill_name(&mut |buf| backend::pty::syscalls::ptsname(fd, buf)) }
 }
 
 /// `unlockpt(fd)`—Unlock a pseudoterminal.
@@ -141,7 +192,7 @@ pub fn unlockpt<Fd: AsFd>(fd: Fd) -> io::Result<()> {
 /// `grantpt(fd)`—Grant access to the user side of a pseudoterminal.
 ///
 /// On Linux, calling this function has no effect, as the kernel is expected to
-/// grant the appropriate access. On all other platorms, this function has
+/// grant the appropriate access. On all other platforms, this function has
 /// unspecified behavior if the calling process has a [`Signal::Child`] signal
 /// handler installed.
 ///
@@ -170,9 +221,41 @@ pub fn grantpt<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     }
 }
 
-/// `ioctl(fd, TIOCGPTPEER)`—Open the user side of a pseduoterminal.
+/// Open the user side of a pseudoterminal.
+///
+/// On Linux, this uses [`ioctl_tiocgptpeer`], which opens the user side
+/// directly from the controller file descriptor. This avoids races and
+/// symlink attacks on the path of the user side, such as when `/dev/pts` is
+/// replaced in a container. On kernels older than Linux 4.13, which don't
+/// support it, and on other platforms, this falls back to opening the path
+/// returned by [`ptsname`].
+///
+/// `controller` must have been unlocked with [`unlockpt`], and on platforms
+/// other than Linux, granted with [`grantpt`].
+///
+/// [`ioctl_tiocgptpeer`]: https://docs.rs/rustix/*/x86_64-unknown-linux-gnu/rustix/pty/fn.ioctl_tiocgptpeer.html
+#[cfg(any(apple, linux_like, target_os = "freebsd", target_os = "fuchsia"))]
+pub fn open_user<Fd: AsFd>(controller: Fd, flags: OpenptFlags) -> io::Result<OwnedFd> {
+    use crate::fs::{open, Mode};
+
+    let controller = controller.as_fd();
+
+    #[cfg(target_os = "linux")]
+    match ioctl_tiocgptpeer(controller, flags) {
+        // Older kernels don't recognize `TIOCGPTPEER`.
+        Err(io::Errno::NOTTY) | Err(io::Errno::INVAL) => {}
+        otherwise => return otherwise,
+    }
+
+    let mut buf = [0_u8; 128];
+    let name = ptsname_into(controller, &mut buf)?;
+    open(name, flags.into(), Mode::empty())
+}
+
+/// `ioctl(fd, TIOCGPTPEER)`—Open the user side of a pseudoterminal.
 ///
-/// This function is currently only implemented on Linux.
+/// This function is currently only implemented on Linux. See [`open_user`]
+/// for a function which falls back to [`ptsname`] when this isn't supported.
 ///
 /// # References
 ///  - [Linux]
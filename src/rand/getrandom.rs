@@ -14,11 +14,24 @@ pub use backend::rand::types::GetRandomFlags;
 /// [`getrandom`]: https://crates.io/crates/getrandom
 /// [`rand`]: https://crates.io/crates/rand
 ///
+/// This may read fewer bytes than requested, and returns the number of bytes
+/// read. On Apple platforms and OpenBSD, this uses `getentropy`, which reads
+/// at most 256 bytes at a time.
+///
 /// # References
 ///  - [Linux]
+///  - [FreeBSD]
+///  - [NetBSD]
+///  - [OpenBSD]
+///  - [DragonFly BSD]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/getrandom.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=getrandom&sektion=2
+/// [NetBSD]: https://man.netbsd.org/getrandom.2
+/// [OpenBSD]: https://man.openbsd.org/getentropy.2
+/// [DragonFly BSD]: https://man.dragonflybsd.org/?command=getrandom&section=2
 #[inline]
+#[doc(alias = "getentropy")]
 pub fn getrandom(buf: &mut [u8], flags: GetRandomFlags) -> io::Result<usize> {
     unsafe { backend::rand::syscalls::getrandom(buf.as_mut_ptr(), buf.len(), flags) }
 }
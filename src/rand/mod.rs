@@ -1,7 +1,7 @@
 //! Random-related operations.
 
-#[cfg(linux_kernel)]
+#[cfg(any(linux_kernel, apple, freebsdlike, netbsdlike))]
 mod getrandom;
 
-#[cfg(linux_kernel)]
+#[cfg(any(linux_kernel, apple, freebsdlike, netbsdlike))]
 pub use getrandom::{getrandom, getrandom_uninit, GetRandomFlags};
@@ -471,7 +471,17 @@ pub const fn raw_stderr() -> RawFd {
     c::STDERR_FILENO as RawFd
 }
 
-/// Utility function to safely `dup2` over stdin (fd 0).
+/// `dup2(fd, STDIN_FILENO)`—Utility function to safely `dup2` over stdin (fd
+/// 0).
+///
+/// This replaces the process' stdin with a duplicate of `fd`, without the
+/// `O_CLOEXEC` flag, so that it is inherited across `exec`. `fd` itself is not
+/// closed. This is async-signal-safe, so it may be used in a child process
+/// between `fork` and `exec`.
+///
+/// To place a file descriptor at other numbers, see [`io::dup2_raw`].
+///
+/// [`io::dup2_raw`]: crate::io::dup2_raw
 #[cfg(not(any(windows, target_os = "wasi")))]
 #[allow(clippy::mem_forget)]
 #[inline]
@@ -484,7 +494,17 @@ pub fn dup2_stdin<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     Ok(())
 }
 
-/// Utility function to safely `dup2` over stdout (fd 1).
+/// `dup2(fd, STDOUT_FILENO)`—Utility function to safely `dup2` over stdout (fd
+/// 1).
+///
+/// This replaces the process' stdout with a duplicate of `fd`, without the
+/// `O_CLOEXEC` flag, so that it is inherited across `exec`. `fd` itself is not
+/// closed. This is async-signal-safe, so it may be used in a child process
+/// between `fork` and `exec`.
+///
+/// To place a file descriptor at other numbers, see [`io::dup2_raw`].
+///
+/// [`io::dup2_raw`]: crate::io::dup2_raw
 #[cfg(not(any(windows, target_os = "wasi")))]
 #[allow(clippy::mem_forget)]
 #[inline]
@@ -497,7 +517,17 @@ pub fn dup2_stdout<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     Ok(())
 }
 
-/// Utility function to safely `dup2` over stderr (fd 2).
+/// `dup2(fd, STDERR_FILENO)`—Utility function to safely `dup2` over stderr (fd
+/// 2).
+///
+/// This replaces the process' stderr with a duplicate of `fd`, without the
+/// `O_CLOEXEC` flag, so that it is inherited across `exec`. `fd` itself is not
+/// closed. This is async-signal-safe, so it may be used in a child process
+/// between `fork` and `exec`.
+///
+/// To place a file descriptor at other numbers, see [`io::dup2_raw`].
+///
+/// [`io::dup2_raw`]: crate::io::dup2_raw
 #[cfg(not(any(windows, target_os = "wasi")))]
 #[allow(clippy::mem_forget)]
 #[inline]
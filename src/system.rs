@@ -152,6 +152,8 @@ pub fn sysinfo() -> Sysinfo {
 
 /// `sethostname(name)`—Sets the system host name.
 ///
+/// To get the host name, use [`Uname::nodename`] on the result of [`uname`].
+///
 /// # References
 ///  - [Linux]
 ///
@@ -168,6 +170,20 @@ pub fn sethostname(name: &[u8]) -> io::Result<()> {
     backend::system::syscalls::sethostname(name)
 }
 
+/// `setdomainname(name)`—Sets the system NIS domain name.
+///
+/// # References
+///  - [Linux]
+///  - [FreeBSD]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setdomainname.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=setdomainname&sektion=3
+#[cfg(any(linux_kernel, bsd))]
+#[inline]
+pub fn setdomainname(name: &[u8]) -> io::Result<()> {
+    backend::system::syscalls::setdomainname(name)
+}
+
 /// Reboot command for use with [`reboot`].
 #[cfg(target_os = "linux")]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -51,3 +51,34 @@ pub fn ioctl_tiocnxcl<Fd: AsFd>(fd: Fd) -> io::Result<()> {
         ioctl::ioctl(fd, ctl)
     }
 }
+
+/// `ioctl(fd, TIOCSTI, &byte)`—Inserts a byte into a terminal's input queue.
+///
+/// The byte is delivered to readers of the terminal as if it had been typed.
+///
+/// # Security
+///
+/// This can be used to inject commands into a shell reading from the
+/// terminal, so it's restricted: unprivileged callers may only use it on
+/// their controlling terminal. Since Linux 6.2 it may be disabled entirely
+/// for unprivileged callers with the `dev.tty.legacy_tiocsti` sysctl, in
+/// which case this fails with [`io::Errno::IO`], and OpenBSD has removed it.
+/// Terminal emulators and expect-like tools should prefer writing to the
+/// controlling side of a pseudoterminal instead.
+///
+/// # References
+///  - [Linux]
+///  - [FreeBSD]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/TIOCSTI.2const.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=tty&sektion=4
+#[cfg(any(linux_kernel, apple, freebsdlike))]
+#[inline]
+#[doc(alias = "TIOCSTI")]
+pub fn ioctl_tiocsti<Fd: AsFd>(fd: Fd, byte: u8) -> io::Result<()> {
+    // SAFETY: TIOCSTI is a setter opcode which reads a single `char`.
+    unsafe {
+        let ctl = ioctl::Setter::<ioctl::BadOpcode<{ c::TIOCSTI as _ }>, u8>::new(byte);
+        ioctl::ioctl(fd, ctl)
+    }
+}
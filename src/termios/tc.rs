@@ -30,13 +30,20 @@ pub fn tcgetattr<Fd: AsFd>(fd: Fd) -> io::Result<Termios> {
 ///
 /// Also known as the `TIOCGWINSZ` operation with `ioctl`.
 ///
+/// Terminal emulators set the window size on the controlling side of a
+/// pseudoterminal, and programs running in it get it from the user side.
+/// When the size changes, the foreground process group receives `SIGWINCH`.
+///
 /// # References
+///  - [POSIX]
 ///  - [Linux]
 ///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/tcgetwinsize.html
 /// [Linux]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
 #[cfg(not(any(windows, target_os = "espidf", target_os = "wasi")))]
 #[inline]
 #[doc(alias = "TIOCGWINSZ")]
+#[doc(alias = "ioctl_tiocgwinsz")]
 pub fn tcgetwinsize<Fd: AsFd>(fd: Fd) -> io::Result<Winsize> {
     backend::termios::syscalls::tcgetwinsize(fd.as_fd())
 }
@@ -50,6 +57,8 @@ pub fn tcgetwinsize<Fd: AsFd>(fd: Fd) -> io::Result<Winsize> {
 /// instead handles this case by failing with [`io::Errno::OPNOTSUPP`] if the
 /// pid is 0.
 ///
+/// To set the foreground process group, see [`tcsetpgrp`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -67,6 +76,8 @@ pub fn tcgetpgrp<Fd: AsFd>(fd: Fd) -> io::Result<Pid> {
 ///
 /// Also known as the `TIOCSPGRP` operation with `ioctl`.
 ///
+/// To get the foreground process group, see [`tcgetpgrp`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -80,9 +91,13 @@ pub fn tcsetpgrp<Fd: AsFd>(fd: Fd, pid: Pid) -> io::Result<()> {
     backend::termios::syscalls::tcsetpgrp(fd.as_fd(), pid)
 }
 
-/// `tcsetattr(fd)`—Set terminal attributes.
+/// `tcsetattr(fd, optional_actions, termios)`—Set terminal attributes.
+///
+/// Also known as the `TCSETS` (or `TCSETS2` on Linux) operation with `ioctl`.
 ///
-/// Also known as the `TCSETS` (or `TCSETS2 on Linux) operation with `ioctl`.
+/// To put a terminal into raw mode, get its attributes with [`tcgetattr`],
+/// call [`Termios::make_raw`] on them, and set them with this function. Keep
+/// the original attributes to restore them later.
 ///
 /// # References
 ///  - [POSIX `tcsetattr`]
@@ -129,24 +144,29 @@ pub fn tcsendbreak<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     backend::termios::syscalls::tcsendbreak(fd.as_fd())
 }
 
-/// `tcdrain(fd, duration)`—Wait until all pending output has been written.
+/// `tcdrain(fd)`—Wait until all pending output has been written.
+///
+/// Also known as the `TCSBRK` operation with `ioctl`, with a duration
+/// parameter of 1.
 ///
 /// # References
 ///  - [POSIX `tcdrain`]
 ///  - [Linux `ioctl_tty`]
 ///  - [Linux `termios`]
 ///
-/// [POSIX `tcsetattr`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcdrain.html
+/// [POSIX `tcdrain`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcdrain.html
 /// [Linux `ioctl_tty`]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
 /// [Linux `termios`]: https://man7.org/linux/man-pages/man3/termios.3.html
 #[cfg(not(target_os = "espidf"))]
 #[inline]
+#[doc(alias = "TCSBRK")]
 pub fn tcdrain<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     backend::termios::syscalls::tcdrain(fd.as_fd())
 }
 
-/// `tcflush(fd, queue_selector)`—Wait until all pending output has been
-/// written.
+/// `tcflush(fd, queue_selector)`—Discard pending input and/or output.
+///
+/// Also known as the `TCFLSH` operation with `ioctl`.
 ///
 /// # References
 ///  - [POSIX `tcflush`]
@@ -165,6 +185,8 @@ pub fn tcflush<Fd: AsFd>(fd: Fd, queue_selector: QueueSelector) -> io::Result<()
 
 /// `tcflow(fd, action)`—Suspend or resume transmission or reception.
 ///
+/// Also known as the `TCXONC` operation with `ioctl`.
+///
 /// # References
 ///  - [POSIX `tcflow`]
 ///  - [Linux `ioctl_tty`]
@@ -195,17 +217,23 @@ pub fn tcgetsid<Fd: AsFd>(fd: Fd) -> io::Result<Pid> {
     backend::termios::syscalls::tcgetsid(fd.as_fd())
 }
 
-/// `tcsetwinsize(fd)`—Set the current terminal window size.
+/// `tcsetwinsize(fd, winsize)`—Set the current terminal window size.
 ///
 /// Also known as the `TIOCSWINSZ` operation with `ioctl`.
 ///
+/// If the size changes, this sends `SIGWINCH` to the terminal's foreground
+/// process group.
+///
 /// # References
+///  - [POSIX]
 ///  - [Linux]
 ///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/tcsetwinsize.html
 /// [Linux]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
 #[cfg(not(target_os = "espidf"))]
 #[inline]
 #[doc(alias = "TIOCSWINSZ")]
+#[doc(alias = "ioctl_tiocswinsz")]
 pub fn tcsetwinsize<Fd: AsFd>(fd: Fd, winsize: Winsize) -> io::Result<()> {
     backend::termios::syscalls::tcsetwinsize(fd.as_fd(), winsize)
 }
@@ -1243,9 +1243,10 @@ pub enum Action {
     IOn = c::TCION as u32,
 }
 
-/// `struct winsize` for use with [`tcgetwinsize`].
+/// `struct winsize` for use with [`tcgetwinsize`] and [`tcsetwinsize`].
 ///
 /// [`tcgetwinsize`]: crate::termios::tcgetwinsize
+/// [`tcsetwinsize`]: crate::termios::tcsetwinsize
 #[doc(alias = "winsize")]
 pub type Winsize = c::winsize;
 
@@ -12,6 +12,8 @@ mod libcap;
 mod prctl;
 #[cfg(linux_kernel)]
 mod setns;
+#[cfg(linux_kernel)]
+mod stack;
 
 #[cfg(not(target_os = "redox"))]
 pub use clock::*;
@@ -28,3 +30,7 @@ pub use libcap::{capabilities, set_capabilities, CapabilityFlags, CapabilitySets
 pub use prctl::*;
 #[cfg(linux_kernel)]
 pub use setns::*;
+#[cfg(all(linux_kernel, feature = "procfs"))]
+pub use stack::stack_bounds;
+#[cfg(linux_kernel)]
+pub use stack::{sigaltstack_query, AltStack};
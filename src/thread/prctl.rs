@@ -461,7 +461,7 @@ bitflags! {
 #[inline]
 pub fn capabilities_secure_bits() -> io::Result<CapabilitiesSecureBits> {
     let r = unsafe { prctl_1arg(PR_GET_SECUREBITS)? } as c_uint;
-    CapabilitiesSecureBits::from_bits(r).ok_or(io::Errno::RANGE)
+    Ok(CapabilitiesSecureBits::from_bits_retain(r))
 }
 
 const PR_SET_SECUREBITS: c_int = 28;
@@ -771,11 +771,11 @@ bitflags! {
 ///
 /// [`prctl(PR_GET_TAGGED_ADDR_CTRL,...)`]: https://man7.org/linux/man-pages/man2/prctl.2.html
 #[inline]
-pub fn current_tagged_address_mode() -> io::Result<(Option<TaggedAddressMode>, u32)> {
+pub fn current_tagged_address_mode() -> io::Result<(TaggedAddressMode, u32)> {
     let r = unsafe { prctl_1arg(PR_GET_TAGGED_ADDR_CTRL)? } as c_uint;
     let mode = r & 0b111_u32;
     let mte_tag = (r & PR_MTE_TAG_MASK) >> PR_MTE_TAG_SHIFT;
-    Ok((TaggedAddressMode::from_bits(mode), mte_tag))
+    Ok((TaggedAddressMode::from_bits_retain(mode), mte_tag))
 }
 
 const PR_SET_TAGGED_ADDR_CTRL: c_int = 55;
@@ -0,0 +1,66 @@
+//! Stack introspection.
+
+use crate::{backend, io};
+use core::ops::Range;
+
+/// The alternate signal stack of a thread, as reported by
+/// [`sigaltstack_query`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AltStack {
+    /// The range of addresses of the alternate signal stack.
+    pub bounds: Range<usize>,
+
+    /// Whether the thread is currently executing on the alternate signal
+    /// stack.
+    pub on_stack: bool,
+}
+
+/// `sigaltstack(NULL, &mut old)`—Query the current thread's alternate signal
+/// stack.
+///
+/// Returns `None` if the thread has no alternate signal stack. To set an
+/// alternate signal stack, see [`rustix::runtime::sigaltstack`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sigaltstack.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigaltstack.2.html
+/// [`rustix::runtime::sigaltstack`]: https://docs.rs/rustix/*/rustix/runtime/fn.sigaltstack.html
+#[inline]
+#[doc(alias = "sigaltstack")]
+pub fn sigaltstack_query() -> io::Result<Option<AltStack>> {
+    Ok(
+        backend::thread::syscalls::sigaltstack_query()?.map(|(start, end, on_stack)| AltStack {
+            bounds: start..end,
+            on_stack,
+        }),
+    )
+}
+
+/// Return the range of addresses of the current thread's stack.
+///
+/// With the libc backend, this uses `pthread_getattr_np`. With the linux_raw
+/// backend, this finds the mapping in `/proc/self/maps` which contains the
+/// current stack pointer.
+///
+/// For the main thread, whose stack grows on demand, the bounds may differ
+/// between the backends: `pthread_getattr_np` reports the maximum size
+/// allowed by `RLIMIT_STACK`, while `/proc/self/maps` reports the currently
+/// mapped region.
+///
+/// # References
+///  - [Linux `pthread_getattr_np`]
+///  - [Linux `proc`]
+///
+/// [Linux `pthread_getattr_np`]: https://man7.org/linux/man-pages/man3/pthread_getattr_np.3.html
+/// [Linux `proc`]: https://man7.org/linux/man-pages/man5/proc.5.html
+#[cfg(feature = "procfs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "procfs")))]
+#[inline]
+#[doc(alias = "pthread_getattr_np")]
+pub fn stack_bounds() -> io::Result<Range<usize>> {
+    let (start, end) = backend::thread::syscalls::stack_bounds()?;
+    Ok(start..end)
+}
@@ -1,10 +1,31 @@
 use crate::fd::{AsFd, OwnedFd};
+use crate::timespec::Timespec;
 use crate::{backend, io};
 
-pub use backend::time::types::{Itimerspec, TimerfdClockId, TimerfdFlags, TimerfdTimerFlags};
+pub use backend::time::types::{TimerfdClockId, TimerfdFlags, TimerfdTimerFlags};
+
+/// `struct itimerspec` for use with [`timerfd_gettime`] and
+/// [`timerfd_settime`].
+///
+/// Like [`Timespec`], this uses 64-bit seconds on all platforms.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Itimerspec {
+    /// The interval of an interval timer.
+    pub it_interval: Timespec,
+    /// Time remaining in the current interval.
+    pub it_value: Timespec,
+}
 
 /// `timerfd_create(clockid, flags)`—Create a timer.
 ///
+/// The returned file descriptor becomes readable when the timer expires, so
+/// it can be waited on with [`poll`] or [`epoll`] alongside other file
+/// descriptors. Use [`timerfd_read`] to read the number of expirations.
+///
+/// [`poll`]: https://docs.rs/rustix/*/rustix/event/fn.poll.html
+/// [`epoll`]: https://docs.rs/rustix/*/rustix/event/epoll/index.html
+///
 /// # References
 ///  - [Linux]
 ///
@@ -14,7 +35,10 @@ pub fn timerfd_create(clockid: TimerfdClockId, flags: TimerfdFlags) -> io::Resul
     backend::time::syscalls::timerfd_create(clockid, flags)
 }
 
-/// `timerfd_settime(clockid, flags, new_value)`—Set the time on a timer.
+/// `timerfd_settime(fd, flags, new_value)`—Set the time on a timer.
+///
+/// Setting `it_value` to zero disarms the timer. This returns the previous
+/// setting of the timer.
 ///
 /// # References
 ///  - [Linux]
@@ -29,7 +53,7 @@ pub fn timerfd_settime<Fd: AsFd>(
     backend::time::syscalls::timerfd_settime(fd.as_fd(), flags, new_value)
 }
 
-/// `timerfd_gettime(clockid, flags)`—Query a timer.
+/// `timerfd_gettime(fd)`—Query a timer.
 ///
 /// # References
 ///  - [Linux]
@@ -39,3 +63,25 @@ pub fn timerfd_settime<Fd: AsFd>(
 pub fn timerfd_gettime<Fd: AsFd>(fd: Fd) -> io::Result<Itimerspec> {
     backend::time::syscalls::timerfd_gettime(fd.as_fd())
 }
+
+/// `read(fd, &mut u64)`—Reads the number of expirations of a timer.
+///
+/// This returns the number of times the timer has expired since the last
+/// read, or since it was last set. If the timer hasn't expired and the file
+/// descriptor is non-blocking, this fails with [`io::Errno::AGAIN`]. If the
+/// clock was changed and the timer was set with
+/// [`TimerfdTimerFlags::CANCEL_ON_SET`], this fails with
+/// [`io::Errno::CANCELED`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/timerfd_create.2.html
+#[inline]
+pub fn timerfd_read<Fd: AsFd>(fd: Fd) -> io::Result<u64> {
+    let mut bytes = [0_u8; 8];
+    match io::read(fd, &mut bytes)? {
+        8 => Ok(u64::from_ne_bytes(bytes)),
+        _ => Err(io::Errno::IO),
+    }
+}
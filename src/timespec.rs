@@ -1,16 +1,15 @@
 //! `Timespec` and related types, which are used by multiple public API
 //! modules.
 
-#[cfg(not(fix_y2038))]
+#[cfg(all(libc, not(fix_y2038)))]
 use crate::backend::c;
 
-/// `struct timespec`
-#[cfg(not(fix_y2038))]
-pub type Timespec = c::timespec;
-
-/// `struct timespec`
-#[cfg(fix_y2038)]
-#[derive(Debug, Clone, Copy)]
+/// `struct timespec`—A quantity of time in seconds plus nanoseconds.
+///
+/// `tv_sec` is 64-bit on all platforms, so this type is y2038-safe even on
+/// platforms where the C `timespec` type has a 32-bit `tv_sec`. rustix
+/// converts to and from the platform's own type as needed.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
 #[repr(C)]
 pub struct Timespec {
     /// Seconds.
@@ -21,15 +20,9 @@ pub struct Timespec {
 }
 
 /// A type for the `tv_sec` field of [`Timespec`].
-#[cfg(not(fix_y2038))]
-#[allow(deprecated)]
-pub type Secs = c::time_t;
-
-/// A type for the `tv_sec` field of [`Timespec`].
-#[cfg(fix_y2038)]
 pub type Secs = i64;
 
-/// A type for the `tv_sec` field of [`Timespec`].
+/// A type for the `tv_nsec` field of [`Timespec`].
 #[cfg(any(
     fix_y2038,
     linux_raw,
@@ -86,6 +79,124 @@ impl From<Timespec> for LibcTimespec {
     }
 }
 
+#[cfg(feature = "std")]
+impl TryFrom<Timespec> for std::time::SystemTime {
+    type Error = crate::io::Errno;
+
+    /// Convert a `Timespec`, measured from the Unix epoch, into a
+    /// `SystemTime`.
+    ///
+    /// Fails with [`io::Errno::INVAL`] if `tv_nsec` is out of range, and with
+    /// [`io::Errno::OVERFLOW`] if the time can't be represented as a
+    /// `SystemTime`.
+    ///
+    /// [`io::Errno::INVAL`]: crate::io::Errno::INVAL
+    /// [`io::Errno::OVERFLOW`]: crate::io::Errno::OVERFLOW
+    fn try_from(t: Timespec) -> Result<Self, Self::Error> {
+        use crate::io;
+        use core::time::Duration;
+        use std::time::UNIX_EPOCH;
+
+        let nsecs = u32::try_from(t.tv_nsec).map_err(|_| io::Errno::INVAL)?;
+        if nsecs >= 1_000_000_000 {
+            return Err(io::Errno::INVAL);
+        }
+
+        let time = if t.tv_sec >= 0 {
+            UNIX_EPOCH.checked_add(Duration::new(t.tv_sec as u64, nsecs))
+        } else {
+            // Negative times are `tv_sec` seconds before the epoch, plus
+            // `tv_nsec` nanoseconds after that.
+            UNIX_EPOCH
+                .checked_sub(Duration::from_secs(t.tv_sec.unsigned_abs()))
+                .and_then(|time| time.checked_add(Duration::from_nanos(nsecs.into())))
+        };
+        time.ok_or(io::Errno::OVERFLOW)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<std::time::SystemTime> for Timespec {
+    type Error = crate::io::Errno;
+
+    /// Convert a `SystemTime` into a `Timespec` measured from the Unix
+    /// epoch.
+    ///
+    /// Fails with [`io::Errno::OVERFLOW`] if the time doesn't fit in a
+    /// `Timespec`.
+    ///
+    /// [`io::Errno::OVERFLOW`]: crate::io::Errno::OVERFLOW
+    fn try_from(time: std::time::SystemTime) -> Result<Self, Self::Error> {
+        use crate::io;
+        use std::time::UNIX_EPOCH;
+
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => Ok(Self {
+                tv_sec: after
+                    .as_secs()
+                    .try_into()
+                    .map_err(|_| io::Errno::OVERFLOW)?,
+                tv_nsec: after.subsec_nanos() as _,
+            }),
+            Err(before) => {
+                let before = before.duration();
+                let secs: Secs = before
+                    .as_secs()
+                    .try_into()
+                    .map_err(|_| io::Errno::OVERFLOW)?;
+                let nsecs = before.subsec_nanos();
+                if nsecs == 0 {
+                    Ok(Self {
+                        tv_sec: -secs,
+                        tv_nsec: 0,
+                    })
+                } else {
+                    Ok(Self {
+                        tv_sec: -secs - 1,
+                        tv_nsec: (1_000_000_000 - nsecs) as _,
+                    })
+                }
+            }
+        }
+    }
+}
+
+/// Convert a `Timespec` into the platform `timespec` type.
+///
+/// Without `fix_y2038`, the platform `timespec` may still have a 32-bit
+/// `tv_sec`, or padding fields, so this converts field by field. Fails with
+/// [`io::Errno::OVERFLOW`] if `tv_sec` doesn't fit.
+///
+/// [`io::Errno::OVERFLOW`]: crate::io::Errno::OVERFLOW
+#[cfg(all(libc, not(fix_y2038)))]
+#[cfg(any(
+    all(linux_kernel, feature = "event"),
+    feature = "fs",
+    feature = "thread",
+    feature = "time"
+))]
+pub(crate) fn to_libc_timespec(timespec: &Timespec) -> crate::io::Result<c::timespec> {
+    // SAFETY: `c::timespec` is plain data, which is valid when zeroed.
+    #[allow(unsafe_code)]
+    let mut result: c::timespec = unsafe { core::mem::zeroed() };
+    result.tv_sec = timespec
+        .tv_sec
+        .try_into()
+        .map_err(|_| crate::io::Errno::OVERFLOW)?;
+    result.tv_nsec = timespec.tv_nsec;
+    Ok(result)
+}
+
+/// Convert the platform `timespec` type into a `Timespec`.
+#[cfg(all(libc, not(fix_y2038)))]
+#[cfg(any(feature = "thread", feature = "time"))]
+pub(crate) fn from_libc_timespec(timespec: &c::timespec) -> Timespec {
+    Timespec {
+        tv_sec: timespec.tv_sec.into(),
+        tv_nsec: timespec.tv_nsec,
+    }
+}
+
 #[test]
 fn test_sizes() {
     assert_eq_size!(Secs, u64);
@@ -106,6 +217,22 @@ fn test_sizes() {
     assert_eq!(t.tv_sec as u64, 0x1_0000_0000_u64);
 }
 
+// Test that the platform `timespec` fits in a `Timespec`, and that on 64-bit
+// platforms, where `time_t` is 64-bit, they have the same layout.
+#[cfg(all(libc, not(fix_y2038)))]
+#[test]
+fn test_libc_timespec_layout() {
+    assert!(core::mem::size_of::<c::time_t>() <= core::mem::size_of::<Secs>());
+    assert_eq_size!(c::c_long, Nsecs);
+
+    #[cfg(target_pointer_width = "64")]
+    {
+        check_renamed_type!(Timespec, timespec);
+        check_renamed_struct_renamed_field!(Timespec, timespec, tv_sec, tv_sec);
+        check_renamed_struct_renamed_field!(Timespec, timespec, tv_nsec, tv_nsec);
+    }
+}
+
 // Test that our workarounds are needed.
 #[cfg(fix_y2038)]
 #[test]
@@ -0,0 +1,135 @@
+//! `RawFd`-based twins of functions in [`crate::fs`].
+
+use crate::fd::OwnedFd;
+use crate::{fs, io, path};
+use core::mem::MaybeUninit;
+
+raw_fd_twins! {
+    fn seek(fd, pos: fs::SeekFrom) -> io::Result<u64> = fs::seek;
+    fn tell(fd) -> io::Result<u64> = fs::tell;
+    #[cfg(not(target_os = "wasi"))]
+    fn fchmod(fd, mode: fs::Mode) -> io::Result<()> = fs::fchmod;
+    #[cfg(not(target_os = "wasi"))]
+    fn fchown(
+        fd,
+        owner: Option<fs::Uid>,
+        group: Option<fs::Gid>,
+    ) -> io::Result<()> = fs::fchown;
+    fn fstat(fd) -> io::Result<fs::Stat> = fs::fstat;
+    #[cfg(not(any(
+        solarish,
+        target_os = "espidf",
+        target_os = "haiku",
+        target_os = "netbsd",
+        target_os = "nto",
+        target_os = "redox",
+        target_os = "vita",
+        target_os = "wasi",
+    )))]
+    fn fstatfs(fd) -> io::Result<fs::StatFs> = fs::fstatfs;
+    #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
+    fn fstatvfs(fd) -> io::Result<fs::StatVfs> = fs::fstatvfs;
+    #[cfg(not(any(target_os = "espidf", target_os = "vita")))]
+    fn futimens(fd, times: &fs::Timestamps) -> io::Result<()> = fs::futimens;
+    #[cfg(not(any(
+        netbsdlike,
+        solarish,
+        target_os = "dragonfly",
+        target_os = "espidf",
+        target_os = "nto",
+        target_os = "redox",
+        target_os = "vita",
+    )))]
+    fn fallocate(
+        fd,
+        mode: fs::FallocateFlags,
+        offset: u64,
+        len: u64,
+    ) -> io::Result<()> = fs::fallocate;
+    fn is_file_read_write(fd) -> io::Result<(bool, bool)> = fs::is_file_read_write;
+    fn fsync(fd) -> io::Result<()> = fs::fsync;
+    #[cfg(not(any(
+        apple,
+        target_os = "dragonfly",
+        target_os = "espidf",
+        target_os = "haiku",
+        target_os = "redox",
+        target_os = "vita",
+    )))]
+    fn fdatasync(fd) -> io::Result<()> = fs::fdatasync;
+    fn ftruncate(fd, length: u64) -> io::Result<()> = fs::ftruncate;
+    #[cfg(not(any(
+        target_os = "espidf",
+        target_os = "solaris",
+        target_os = "vita",
+        target_os = "wasi"
+    )))]
+    fn flock(fd, operation: fs::FlockOperation) -> io::Result<()> = fs::flock;
+    #[cfg(linux_kernel)]
+    fn syncfs(fd) -> io::Result<()> = fs::syncfs;
+
+    fn fcntl_getfl(fd) -> io::Result<fs::OFlags> = fs::fcntl_getfl;
+    fn fcntl_setfl(fd, flags: fs::OFlags) -> io::Result<()> = fs::fcntl_setfl;
+    #[cfg(any(linux_kernel, target_os = "freebsd", target_os = "fuchsia"))]
+    fn fcntl_get_seals(fd) -> io::Result<fs::SealFlags> = fs::fcntl_get_seals;
+    #[cfg(any(linux_kernel, target_os = "freebsd", target_os = "fuchsia"))]
+    fn fcntl_add_seals(fd, seals: fs::SealFlags) -> io::Result<()> = fs::fcntl_add_seals;
+    #[cfg(not(any(
+        target_os = "emscripten",
+        target_os = "espidf",
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "vita",
+        target_os = "wasi"
+    )))]
+    fn fcntl_lock(fd, operation: fs::FlockOperation) -> io::Result<()> = fs::fcntl_lock;
+
+    #[cfg(not(target_os = "redox"))]
+    fn openat[P: path::Arg](
+        dirfd,
+        path: P,
+        oflags: fs::OFlags,
+        create_mode: fs::Mode,
+    ) -> io::Result<OwnedFd> = fs::openat;
+    #[cfg(not(target_os = "redox"))]
+    fn readlinkat_raw[P: path::Arg](
+        dirfd,
+        path: P,
+        buf: &mut [MaybeUninit<u8>],
+    ) -> io::Result<(&mut [u8], &mut [MaybeUninit<u8>])> = fs::readlinkat_raw;
+    #[cfg(not(target_os = "redox"))]
+    fn mkdirat[P: path::Arg](dirfd, path: P, mode: fs::Mode) -> io::Result<()> = fs::mkdirat;
+    #[cfg(not(any(target_os = "espidf", target_os = "redox")))]
+    fn unlinkat[P: path::Arg](dirfd, path: P, flags: fs::AtFlags) -> io::Result<()> = fs::unlinkat;
+    #[cfg(not(any(target_os = "espidf", target_os = "redox")))]
+    fn statat[P: path::Arg](dirfd, path: P, flags: fs::AtFlags) -> io::Result<fs::Stat> = fs::statat;
+    #[cfg(not(any(target_os = "espidf", target_os = "redox", target_os = "vita")))]
+    fn accessat[P: path::Arg](
+        dirfd,
+        path: P,
+        access: fs::Access,
+        flags: fs::AtFlags,
+    ) -> io::Result<()> = fs::accessat;
+    #[cfg(not(any(target_os = "espidf", target_os = "redox", target_os = "vita")))]
+    fn utimensat[P: path::Arg](
+        dirfd,
+        path: P,
+        times: &fs::Timestamps,
+        flags: fs::AtFlags,
+    ) -> io::Result<()> = fs::utimensat;
+    #[cfg(not(any(target_os = "espidf", target_os = "redox", target_os = "wasi")))]
+    fn chmodat[P: path::Arg](
+        dirfd,
+        path: P,
+        mode: fs::Mode,
+        flags: fs::AtFlags,
+    ) -> io::Result<()> = fs::chmodat;
+    #[cfg(not(any(target_os = "espidf", target_os = "redox", target_os = "wasi")))]
+    fn chownat[P: path::Arg](
+        dirfd,
+        path: P,
+        owner: Option<fs::Uid>,
+        group: Option<fs::Gid>,
+        flags: fs::AtFlags,
+    ) -> io::Result<()> = fs::chownat;
+}
@@ -0,0 +1,91 @@
+//! `RawFd`-based twins of functions in [`crate::io`].
+
+use crate::fd::{OwnedFd, RawFd};
+use crate::io;
+use core::mem::MaybeUninit;
+
+raw_fd_twins! {
+    fn read(fd, buf: &mut [u8]) -> io::Result<usize> = io::read;
+    fn read_uninit(
+        fd,
+        buf: &mut [MaybeUninit<u8>],
+    ) -> io::Result<(&mut [u8], &mut [MaybeUninit<u8>])> = io::read_uninit;
+    fn write(fd, buf: &[u8]) -> io::Result<usize> = io::write;
+    fn pread(fd, buf: &mut [u8], offset: u64) -> io::Result<usize> = io::pread;
+    fn pread_uninit(
+        fd,
+        buf: &mut [MaybeUninit<u8>],
+        offset: u64,
+    ) -> io::Result<(&mut [u8], &mut [MaybeUninit<u8>])> = io::pread_uninit;
+    fn pwrite(fd, buf: &[u8], offset: u64) -> io::Result<usize> = io::pwrite;
+
+    #[cfg(not(any(target_os = "espidf", target_os = "horizon")))]
+    fn readv(fd, bufs: &mut [io::IoSliceMut<'_>]) -> io::Result<usize> = io::readv;
+    #[cfg(not(any(target_os = "espidf", target_os = "horizon")))]
+    fn writev(fd, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> = io::writev;
+    #[cfg(not(any(
+        target_os = "espidf",
+        target_os = "haiku",
+        target_os = "horizon",
+        target_os = "nto",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "vita"
+    )))]
+    fn preadv(fd, bufs: &mut [io::IoSliceMut<'_>], offset: u64) -> io::Result<usize> = io::preadv;
+    #[cfg(not(any(
+        target_os = "espidf",
+        target_os = "haiku",
+        target_os = "horizon",
+        target_os = "nto",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "vita"
+    )))]
+    fn pwritev(fd, bufs: &[io::IoSlice<'_>], offset: u64) -> io::Result<usize> = io::pwritev;
+    #[cfg(linux_kernel)]
+    fn preadv2(
+        fd,
+        bufs: &mut [io::IoSliceMut<'_>],
+        offset: u64,
+        flags: io::ReadWriteFlags,
+    ) -> io::Result<usize> = io::preadv2;
+    #[cfg(linux_kernel)]
+    fn pwritev2(
+        fd,
+        bufs: &[io::IoSlice<'_>],
+        offset: u64,
+        flags: io::ReadWriteFlags,
+    ) -> io::Result<usize> = io::pwritev2;
+
+    #[cfg(not(target_os = "wasi"))]
+    fn dup(fd) -> io::Result<OwnedFd> = io::dup;
+    #[cfg(not(target_os = "wasi"))]
+    fn dup2(fd, new: &mut OwnedFd) -> io::Result<()> = io::dup2;
+    #[cfg(not(any(
+        target_os = "aix",
+        target_os = "espidf",
+        target_os = "horizon",
+        target_os = "nto",
+        target_os = "vita",
+        target_os = "wasi"
+    )))]
+    fn dup3(fd, new: &mut OwnedFd, flags: io::DupFlags) -> io::Result<()> = io::dup3;
+
+    fn fcntl_getfd(fd) -> io::Result<io::FdFlags> = io::fcntl_getfd;
+    fn fcntl_setfd(fd, flags: io::FdFlags) -> io::Result<()> = io::fcntl_setfd;
+    #[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
+    fn fcntl_dupfd_cloexec(fd, min: RawFd) -> io::Result<OwnedFd> = io::fcntl_dupfd_cloexec;
+    #[cfg(target_os = "espidf")]
+    fn fcntl_dupfd(fd, min: RawFd) -> io::Result<OwnedFd> = io::fcntl_dupfd;
+
+    #[cfg(apple)]
+    fn ioctl_fioclex(fd) -> io::Result<()> = io::ioctl_fioclex;
+    fn ioctl_fionbio(fd, value: bool) -> io::Result<()> = io::ioctl_fionbio;
+    #[cfg(not(any(target_os = "espidf", target_os = "horizon", target_os = "vita")))]
+    fn ioctl_fionread(fd) -> io::Result<u64> = io::ioctl_fionread;
+
+    #[cfg(not(target_os = "redox"))]
+    #[cfg(all(feature = "fs", feature = "net"))]
+    fn is_read_write(fd) -> io::Result<(bool, bool)> = io::is_read_write;
+}
@@ -0,0 +1,57 @@
+//! Unsafe `RawFd`-based twins of rustix's I/O-safe APIs.
+//!
+//! rustix's APIs take file descriptors as [`AsFd`] arguments, which statically
+//! ensure [I/O safety]. Codebases which still pass bare integers around can
+//! use the functions in this module to adopt rustix incrementally, before
+//! converting to [`OwnedFd`] and [`BorrowedFd`] wholesale.
+//!
+//! Each function here is a thin wrapper around the corresponding I/O-safe
+//! function, with the same name and behavior, except that its file descriptor
+//! argument is a [`RawFd`].
+//!
+//! # Safety
+//!
+//! Each function here requires that its `RawFd` argument be an open file
+//! descriptor which remains open for the duration of the call.
+//!
+//! [I/O safety]: https://github.com/rust-lang/rfcs/blob/master/text/3128-io-safety.md
+//! [`AsFd`]: crate::fd::AsFd
+//! [`OwnedFd`]: crate::fd::OwnedFd
+//! [`BorrowedFd`]: crate::fd::BorrowedFd
+//! [`RawFd`]: crate::fd::RawFd
+#![allow(unsafe_code)]
+
+/// Declare `unsafe` twins of I/O-safe functions which take a `RawFd` in place
+/// of their first `AsFd` argument.
+macro_rules! raw_fd_twins {
+    ($(
+        $(#[$attr:meta])*
+        fn $name:ident $([$($gen:tt)*])? ($fd:ident $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty = $path:path;
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[doc = concat!("Like [`", stringify!($path), "`], but takes a `RawFd`.")]
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!("`", stringify!($fd), "` must be an open file descriptor which remains open for the")]
+            /// duration of the call.
+            #[inline]
+            pub unsafe fn $name $(<$($gen)*>)? (
+                $fd: crate::fd::RawFd
+                $(, $arg: $ty)*
+            ) -> $ret {
+                $path(crate::fd::BorrowedFd::borrow_raw($fd) $(, $arg)*)
+            }
+        )*
+    };
+}
+
+#[cfg(not(windows))]
+#[cfg(feature = "fs")]
+pub mod fs;
+#[cfg(not(windows))]
+pub mod io;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[cfg(feature = "net")]
+pub mod net;
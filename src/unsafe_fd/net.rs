@@ -0,0 +1,78 @@
+//! `RawFd`-based twins of functions in [`crate::net`].
+
+use crate::fd::OwnedFd;
+use crate::{io, net};
+use core::mem::MaybeUninit;
+
+raw_fd_twins! {
+    fn bind(sockfd, addr: &net::SocketAddr) -> io::Result<()> = net::bind;
+    fn bind_any(sockfd, addr: &net::SocketAddrAny) -> io::Result<()> = net::bind_any;
+    fn bind_v4(sockfd, addr: &net::SocketAddrV4) -> io::Result<()> = net::bind_v4;
+    fn bind_v6(sockfd, addr: &net::SocketAddrV6) -> io::Result<()> = net::bind_v6;
+    #[cfg(unix)]
+    fn bind_unix(sockfd, addr: &net::SocketAddrUnix) -> io::Result<()> = net::bind_unix;
+    fn connect(sockfd, addr: &net::SocketAddr) -> io::Result<()> = net::connect;
+    fn connect_any(sockfd, addr: &net::SocketAddrAny) -> io::Result<()> = net::connect_any;
+    fn connect_v4(sockfd, addr: &net::SocketAddrV4) -> io::Result<()> = net::connect_v4;
+    fn connect_v6(sockfd, addr: &net::SocketAddrV6) -> io::Result<()> = net::connect_v6;
+    #[cfg(unix)]
+    fn connect_unix(sockfd, addr: &net::SocketAddrUnix) -> io::Result<()> = net::connect_unix;
+    fn connect_unspec(sockfd) -> io::Result<()> = net::connect_unspec;
+    fn listen(sockfd, backlog: i32) -> io::Result<()> = net::listen;
+    fn accept(sockfd) -> io::Result<OwnedFd> = net::accept;
+    fn accept_with(sockfd, flags: net::SocketFlags) -> io::Result<OwnedFd> = net::accept_with;
+    fn acceptfrom(sockfd) -> io::Result<(OwnedFd, Option<net::SocketAddrAny>)> = net::acceptfrom;
+    fn acceptfrom_with(
+        sockfd,
+        flags: net::SocketFlags,
+    ) -> io::Result<(OwnedFd, Option<net::SocketAddrAny>)> = net::acceptfrom_with;
+    fn shutdown(sockfd, how: net::Shutdown) -> io::Result<()> = net::shutdown;
+    fn getsockname(sockfd) -> io::Result<net::SocketAddrAny> = net::getsockname;
+    fn getpeername(sockfd) -> io::Result<Option<net::SocketAddrAny>> = net::getpeername;
+
+    fn recv(fd, buf: &mut [u8], flags: net::RecvFlags) -> io::Result<usize> = net::recv;
+    fn recv_uninit(
+        fd,
+        buf: &mut [MaybeUninit<u8>],
+        flags: net::RecvFlags,
+    ) -> io::Result<(&mut [u8], &mut [MaybeUninit<u8>])> = net::recv_uninit;
+    #[cfg(linux_kernel)]
+    fn recv_datagram_len(fd, flags: net::RecvFlags) -> io::Result<usize> = net::recv_datagram_len;
+    fn send(fd, buf: &[u8], flags: net::SendFlags) -> io::Result<usize> = net::send;
+    fn recvfrom(
+        fd,
+        buf: &mut [u8],
+        flags: net::RecvFlags,
+    ) -> io::Result<(usize, Option<net::SocketAddrAny>)> = net::recvfrom;
+    fn sendto(
+        fd,
+        buf: &[u8],
+        flags: net::SendFlags,
+        addr: &net::SocketAddr,
+    ) -> io::Result<usize> = net::sendto;
+    fn sendto_any(
+        fd,
+        buf: &[u8],
+        flags: net::SendFlags,
+        addr: &net::SocketAddrAny,
+    ) -> io::Result<usize> = net::sendto_any;
+    fn sendto_v4(
+        fd,
+        buf: &[u8],
+        flags: net::SendFlags,
+        addr: &net::SocketAddrV4,
+    ) -> io::Result<usize> = net::sendto_v4;
+    fn sendto_v6(
+        fd,
+        buf: &[u8],
+        flags: net::SendFlags,
+        addr: &net::SocketAddrV6,
+    ) -> io::Result<usize> = net::sendto_v6;
+    #[cfg(unix)]
+    fn sendto_unix(
+        fd,
+        buf: &[u8],
+        flags: net::SendFlags,
+        addr: &net::SocketAddrUnix,
+    ) -> io::Result<usize> = net::sendto_unix;
+}
@@ -130,3 +130,55 @@ fn test_epoll_event_data() {
     assert_eq!(d.u64(), !3 as *mut c_void as u64);
     assert_eq!(d.ptr() as u64, !3 as *mut c_void as u64);
 }
+
+#[test]
+fn test_epoll_poller() {
+    use rustix::fd::AsFd;
+    use rustix::net::{socketpair, SocketFlags};
+
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        None,
+    )
+    .unwrap();
+    let (c, d) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        None,
+    )
+    .unwrap();
+
+    let mut poller = epoll::Poller::new().unwrap();
+    let token_a = poller.register(a.as_fd(), epoll::EventFlags::IN).unwrap();
+    let token_c = poller.register(c.as_fd(), epoll::EventFlags::IN).unwrap();
+    assert_ne!(token_a, token_c);
+
+    write(&d, b"hello").unwrap();
+
+    let mut events = epoll::EventVec::with_capacity(4);
+    let ready = poller.wait(&mut events, 0).unwrap().collect::<Vec<_>>();
+    assert_eq!(ready, [(token_c, epoll::EventFlags::IN)]);
+
+    // Tokens aren't reused after deregistering.
+    poller.deregister(c.as_fd()).unwrap();
+    let token_c2 = poller.register(c.as_fd(), epoll::EventFlags::IN).unwrap();
+    assert_ne!(token_c, token_c2);
+
+    poller
+        .modify(a.as_fd(), token_a, epoll::EventFlags::OUT)
+        .unwrap();
+    let mut ready = poller.wait(&mut events, 0).unwrap().collect::<Vec<_>>();
+    ready.sort_by_key(|(token, _flags)| *token);
+    assert_eq!(
+        ready,
+        [
+            (token_a, epoll::EventFlags::OUT),
+            (token_c2, epoll::EventFlags::IN)
+        ]
+    );
+
+    drop(b);
+}
@@ -28,3 +28,23 @@ fn test_eventfd() {
     let u = u64::from_ne_bytes(bytes);
     assert_eq!(u, 5021);
 }
+
+#[cfg(any(linux_kernel, target_os = "freebsd", target_os = "illumos"))]
+#[test]
+fn test_eventfd_read_write() {
+    use rustix::event::{eventfd, eventfd_read, eventfd_write, EventfdFlags};
+
+    let efd = match eventfd(0, EventfdFlags::CLOEXEC | EventfdFlags::NONBLOCK) {
+        Ok(efd) => efd,
+        #[cfg(target_os = "freebsd")]
+        Err(rustix::io::Errno::NOSYS) => return, // FreeBSD 12 lacks `eventfd`
+        Err(err) => panic!("{:?}", err),
+    };
+
+    assert_eq!(eventfd_read(&efd), Err(rustix::io::Errno::AGAIN));
+
+    eventfd_write(&efd, 3).unwrap();
+    eventfd_write(&efd, 4).unwrap();
+    assert_eq!(eventfd_read(&efd), Ok(7));
+    assert_eq!(eventfd_read(&efd), Err(rustix::io::Errno::AGAIN));
+}
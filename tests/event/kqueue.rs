@@ -0,0 +1,75 @@
+use rustix::event::kqueue::{kevent, kqueue, Event, EventFilter, EventFlags};
+use rustix::fd::AsRawFd;
+use rustix::io::{read, write};
+use rustix::pipe::pipe;
+use std::time::Duration;
+
+#[test]
+fn test_kqueue_read() {
+    let kq = kqueue().unwrap();
+    let (reader, writer) = pipe().unwrap();
+
+    let changes = [Event::new(
+        EventFilter::Read(reader.as_raw_fd()),
+        EventFlags::ADD,
+        7,
+    )];
+    let mut events = Vec::with_capacity(4);
+    let n = unsafe { kevent(&kq, &changes, &mut events, Some(Duration::ZERO)) }.unwrap();
+    assert_eq!(n, 0);
+    assert!(events.is_empty());
+
+    assert_eq!(write(&writer, b"hello"), Ok(5));
+
+    let n = unsafe { kevent(&kq, &[], &mut events, None) }.unwrap();
+    assert_eq!(n, 1);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0].filter(), EventFilter::Read(fd) if fd == reader.as_raw_fd()));
+    assert_eq!(events[0].udata(), 7);
+    assert_eq!(events[0].data(), 5);
+
+    let mut buf = [0_u8; 5];
+    assert_eq!(read(&reader, &mut buf), Ok(5));
+    assert_eq!(&buf, b"hello");
+
+    // Once the data has been read, there's nothing to report.
+    let n = unsafe { kevent(&kq, &[], &mut events, Some(Duration::ZERO)) }.unwrap();
+    assert_eq!(n, 0);
+}
+
+#[cfg(any(apple, freebsdlike))]
+#[test]
+fn test_kqueue_user() {
+    use rustix::event::kqueue::{UserDefinedFlags, UserFlags};
+
+    let kq = kqueue().unwrap();
+    let mut events = Vec::with_capacity(1);
+
+    let add = Event::new(
+        EventFilter::User {
+            ident: 1,
+            flags: UserFlags::empty(),
+            user_flags: UserDefinedFlags::new(0),
+        },
+        EventFlags::ADD | EventFlags::CLEAR,
+        0,
+    );
+    unsafe { kevent(&kq, &[add], &mut events, Some(Duration::ZERO)) }.unwrap();
+    assert!(events.is_empty());
+
+    let trigger = Event::new(
+        EventFilter::User {
+            ident: 1,
+            flags: UserFlags::TRIGGER,
+            user_flags: UserDefinedFlags::new(0),
+        },
+        EventFlags::empty(),
+        0,
+    );
+    let n = unsafe { kevent(&kq, &[trigger], &mut events, None) }.unwrap();
+    assert_eq!(n, 1);
+    assert!(matches!(
+        events[0].filter(),
+        EventFilter::User { ident: 1, .. }
+    ));
+}
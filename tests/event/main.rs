@@ -9,4 +9,6 @@ mod epoll;
 #[cfg(not(windows))]
 #[cfg(not(target_os = "wasi"))]
 mod eventfd;
+#[cfg(all(feature = "pipe", bsd))]
+mod kqueue;
 mod poll;
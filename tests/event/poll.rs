@@ -64,3 +64,62 @@ fn test_poll_fd_set_fd() {
     let _ = a.into_raw_fd();
     let _ = b.into_raw_fd();
 }
+
+#[cfg(feature = "pipe")]
+#[cfg(not(any(windows, target_os = "wasi")))]
+#[test]
+fn test_readiness() {
+    use core::time::Duration;
+    use rustix::event::{is_readable, is_writable, wait_readable, wait_writable};
+    use rustix::io::write;
+    use rustix::pipe::pipe;
+
+    let (reader, writer) = pipe().unwrap();
+
+    assert!(!retry_on_intr(|| is_readable(&reader)).unwrap());
+    assert!(retry_on_intr(|| is_writable(&writer)).unwrap());
+    assert!(!retry_on_intr(|| wait_readable(&reader, Some(Duration::from_micros(10)))).unwrap());
+    assert!(retry_on_intr(|| wait_writable(&writer, None)).unwrap());
+
+    assert_eq!(retry_on_intr(|| write(&writer, b"a")).unwrap(), 1);
+
+    assert!(retry_on_intr(|| is_readable(&reader)).unwrap());
+    assert!(retry_on_intr(|| wait_readable(&reader, None)).unwrap());
+}
+
+#[cfg(feature = "pipe")]
+#[cfg(linux_kernel)]
+#[test]
+fn test_ppoll() {
+    use rustix::event::{ppoll, Timespec};
+    use rustix::io::write;
+    use rustix::pipe::pipe;
+
+    let (reader, writer) = pipe().unwrap();
+    let mut poll_fds = [PollFd::new(&reader, PollFlags::IN)];
+
+    // A short timeout elapses with nothing to read.
+    let timeout = Timespec {
+        tv_sec: 0,
+        tv_nsec: 1_000_000,
+    };
+    let num = retry_on_intr(|| ppoll(&mut poll_fds, Some(&timeout))).unwrap();
+    assert_eq!(num, 0);
+    assert!(poll_fds[0].revents().is_empty());
+
+    assert_eq!(retry_on_intr(|| write(&writer, b"a")).unwrap(), 1);
+
+    let num = retry_on_intr(|| ppoll(&mut poll_fds, None)).unwrap();
+    assert_eq!(num, 1);
+    assert_eq!(poll_fds[0].revents(), PollFlags::IN);
+
+    // An out-of-range timeout is rejected.
+    let timeout = Timespec {
+        tv_sec: 0,
+        tv_nsec: 1_000_000_000,
+    };
+    assert_eq!(
+        ppoll(&mut poll_fds, Some(&timeout)),
+        Err(rustix::io::Errno::INVAL)
+    );
+}
@@ -57,3 +57,67 @@ fn test_chmodat() {
     let reverted = statat(&dir, "file", AtFlags::empty()).unwrap();
     assert_ne!(reverted.st_mode as u64 & libc::S_IRWXU as u64, 0);
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_chmodat_flags() {
+    use rustix::fs::{chmodat, fstat, openat, statat, AtFlags, Mode, OFlags, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::RWXU).unwrap();
+    let _ = openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RWXU).unwrap();
+
+    // `AT_SYMLINK_NOFOLLOW` on a regular file.
+    match chmodat(&dir, "file", Mode::RUSR, AtFlags::SYMLINK_NOFOLLOW) {
+        Ok(()) => {
+            let stat = statat(&dir, "file", AtFlags::empty()).unwrap();
+            assert_eq!(
+                stat.st_mode as u64 & libc::S_IRWXU as u64,
+                libc::S_IRUSR as u64
+            );
+        }
+        // This requires `fchmodat2`.
+        Err(rustix::io::Errno::OPNOTSUPP) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+
+    // `AT_EMPTY_PATH` on an `O_PATH` file descriptor.
+    let file = openat(&dir, "file", OFlags::PATH | OFlags::CLOEXEC, Mode::empty()).unwrap();
+    match chmodat(&file, "", Mode::RWXU, AtFlags::EMPTY_PATH) {
+        Ok(()) => {
+            let stat = fstat(&file).unwrap();
+            assert_eq!(
+                stat.st_mode as u64 & libc::S_IRWXU as u64,
+                libc::S_IRWXU as u64
+            );
+        }
+        // Without `fchmodat2`, this falls back to `fchmod`, which doesn't
+        // support `O_PATH` file descriptors.
+        Err(rustix::io::Errno::BADF) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+
+    // Unsupported flags are rejected.
+    assert_eq!(
+        chmodat(&dir, "file", Mode::RWXU, AtFlags::REMOVEDIR),
+        Err(rustix::io::Errno::INVAL)
+    );
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_accessat_empty_path() {
+    use rustix::fs::{accessat, openat, Access, AtFlags, Mode, OFlags, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::RWXU).unwrap();
+    let _ = openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RWXU).unwrap();
+
+    let file = openat(&dir, "file", OFlags::PATH | OFlags::CLOEXEC, Mode::empty()).unwrap();
+    match accessat(&file, "", Access::READ_OK, AtFlags::EMPTY_PATH) {
+        Ok(()) => {}
+        // This requires `faccessat2`.
+        Err(rustix::io::Errno::NOSYS) | Err(rustix::io::Errno::INVAL) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+}
@@ -0,0 +1,112 @@
+use rustix::fs::{copy_fd_contents, fstat, openat, seek, Mode, OFlags, SeekFrom, CWD};
+use rustix::io::{pread, pwrite, read, write};
+
+#[test]
+fn test_copy_fd_contents() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let open = |name| {
+        openat(
+            &dir,
+            name,
+            OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+            Mode::RUSR | Mode::WUSR,
+        )
+        .unwrap()
+    };
+
+    // A sparse file, with data at the start and after a hole in the middle,
+    // and a hole at the end.
+    let src = open("src");
+    pwrite(&src, b"start", 0).unwrap();
+    pwrite(&src, b"middle", 1 << 20).unwrap();
+    rustix::fs::ftruncate(&src, 3 << 20).unwrap();
+    let size = 3 << 20;
+
+    let dst = open("dst");
+    assert_eq!(copy_fd_contents(&src, &dst), Ok(size));
+    assert_eq!(fstat(&dst).unwrap().st_size as u64, size);
+    assert_eq!(seek(&dst, SeekFrom::Current(0)), Ok(size));
+    let mut buf = [0xff_u8; 6];
+    assert_eq!(pread(&dst, &mut buf, 0), Ok(6));
+    assert_eq!(&buf, b"start\0");
+    assert_eq!(pread(&dst, &mut buf, 1 << 20), Ok(6));
+    assert_eq!(&buf, b"middle");
+    assert_eq!(pread(&dst, &mut buf, (2 << 20) + 7), Ok(6));
+    assert_eq!(&buf, &[0; 6]);
+
+    // Copying into the middle of existing data overwrites holes with zeros.
+    let dst = open("dst2");
+    pwrite(&dst, &[0xaa; 4 << 20], 0).unwrap();
+    seek(&dst, SeekFrom::Start(16)).unwrap();
+    assert_eq!(copy_fd_contents(&src, &dst), Ok(size));
+    assert_eq!(fstat(&dst).unwrap().st_size, 4 << 20);
+    let mut buf = [0xff_u8; 8];
+    assert_eq!(pread(&dst, &mut buf, 14), Ok(8));
+    assert_eq!(&buf, b"\xaa\xaastart\0");
+    assert_eq!(pread(&dst, &mut buf, 16 + (2 << 20)), Ok(8));
+    assert_eq!(&buf, &[0; 8]);
+    assert_eq!(pread(&dst, &mut buf, 16 + size), Ok(8));
+    assert_eq!(&buf, &[0xaa; 8]);
+
+    // An empty file copies nothing.
+    let empty = open("empty");
+    assert_eq!(copy_fd_contents(&empty, &dst), Ok(0));
+}
+
+#[cfg(feature = "pipe")]
+#[test]
+fn test_copy_fd_contents_to_pipe() {
+    let src = tempfile::tempfile().unwrap();
+    write(&src, b"hello").unwrap();
+    rustix::fs::ftruncate(&src, 100_000).unwrap();
+
+    let (reader, writer) = rustix::pipe::pipe().unwrap();
+    let thread = std::thread::spawn(move || {
+        let mut total = Vec::new();
+        let mut buf = [0_u8; 4096];
+        loop {
+            match read(&reader, &mut buf).unwrap() {
+                0 => break total,
+                n => total.extend_from_slice(&buf[..n]),
+            }
+        }
+    });
+    assert_eq!(copy_fd_contents(&src, &writer), Ok(100_000));
+    drop(writer);
+
+    let total = thread.join().unwrap();
+    assert_eq!(total.len(), 100_000);
+    assert_eq!(&total[..5], b"hello");
+    assert!(total[5..].iter().all(|b| *b == 0));
+}
+
+#[test]
+fn test_copy_fd_contents_pseudo_files() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    // Files in procfs have a size of 0, and files in sysfs have a size of a
+    // page, but both have contents that `copy_file_range` doesn't see.
+    for (name, path) in [
+        ("proc", "/proc/version"),
+        ("sys", "/sys/devices/system/cpu/online"),
+    ] {
+        let src = match openat(CWD, path, OFlags::RDONLY, Mode::empty()) {
+            Ok(src) => src,
+            Err(rustix::io::Errno::NOENT) => continue,
+            Err(err) => panic!("{:?}", err),
+        };
+        let dst = openat(
+            &dir,
+            name,
+            OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+            Mode::RUSR | Mode::WUSR,
+        )
+        .unwrap();
+
+        let expected = std::fs::read(path).unwrap();
+        assert_eq!(copy_fd_contents(&src, &dst), Ok(expected.len() as u64));
+        assert_eq!(std::fs::read(tmp.path().join(name)).unwrap(), expected);
+    }
+}
@@ -0,0 +1,64 @@
+use rustix::fs::{copy_file_range, openat, seek, Mode, OFlags, SeekFrom, CWD};
+use rustix::io::{pread, write};
+
+#[test]
+fn test_copy_file_range() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let open = |name| {
+        openat(
+            &dir,
+            name,
+            OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+            Mode::RUSR | Mode::WUSR,
+        )
+        .unwrap()
+    };
+
+    let src = open("src");
+    write(&src, b"hello, world").unwrap();
+    let dst = open("dst");
+
+    // With explicit offsets, the offsets are advanced and the file offsets
+    // are left alone.
+    let mut off_in = 7;
+    let mut off_out = 2;
+    match copy_file_range(&src, Some(&mut off_in), &dst, Some(&mut off_out), 5) {
+        Ok(n) => assert_eq!(n, 5),
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!((off_in, off_out), (12, 7));
+    assert_eq!(seek(&src, SeekFrom::Current(0)), Ok(12));
+    assert_eq!(seek(&dst, SeekFrom::Current(0)), Ok(0));
+    let mut buf = [0xff_u8; 7];
+    assert_eq!(pread(&dst, &mut buf, 0), Ok(7));
+    assert_eq!(&buf, b"\0\0world");
+
+    // At the end of the input, nothing is copied.
+    assert_eq!(
+        copy_file_range(&src, Some(&mut off_in), &dst, Some(&mut off_out), 5),
+        Ok(0)
+    );
+    assert_eq!((off_in, off_out), (12, 7));
+
+    // Without offsets, the file offsets are used and advanced.
+    seek(&src, SeekFrom::Start(0)).unwrap();
+    seek(&dst, SeekFrom::Start(7)).unwrap();
+    assert_eq!(copy_file_range(&src, None, &dst, None, 5), Ok(5));
+    assert_eq!(seek(&src, SeekFrom::Current(0)), Ok(5));
+    assert_eq!(seek(&dst, SeekFrom::Current(0)), Ok(12));
+    let mut buf = [0xff_u8; 12];
+    assert_eq!(pread(&dst, &mut buf, 0), Ok(12));
+    assert_eq!(&buf, b"\0\0worldhello");
+
+    // One explicit offset and one file offset.
+    let mut off_in = 5;
+    assert_eq!(
+        copy_file_range(&src, Some(&mut off_in), &dst, None, 100),
+        Ok(7)
+    );
+    assert_eq!(off_in, 12);
+    assert_eq!(seek(&src, SeekFrom::Current(0)), Ok(5));
+    assert_eq!(seek(&dst, SeekFrom::Current(0)), Ok(19));
+}
@@ -171,3 +171,26 @@ fn dir_iterator_handles_dir_removal_after_open() {
 
     assert!(dir.next().is_none());
 }
+
+#[test]
+fn test_dir_new_not_dir() {
+    let file = rustix::fs::openat(
+        rustix::fs::CWD,
+        rustix::cstr!("Cargo.toml"),
+        rustix::fs::OFlags::RDONLY | rustix::fs::OFlags::CLOEXEC,
+        rustix::fs::Mode::empty(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        rustix::fs::Dir::read_from(&file).unwrap_err(),
+        rustix::io::Errno::NOTDIR
+    );
+
+    // The libc backend's `fdopendir` fails up front, while the linux_raw
+    // backend leaves it to `getdents64` on the first read.
+    match rustix::fs::Dir::new(file) {
+        Err(err) => assert_eq!(err, rustix::io::Errno::NOTDIR),
+        Ok(mut dir) => assert_eq!(dir.read().unwrap().unwrap_err(), rustix::io::Errno::NOTDIR),
+    }
+}
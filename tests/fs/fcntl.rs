@@ -16,6 +16,28 @@ fn test_fcntl_dupfd_cloexec() {
     assert_eq!(new.as_fd().as_raw_fd(), 700);
 }
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_fcntl_dupfd() {
+    use rustix::fd::AsFd;
+    use rustix::io::FdFlags;
+    use std::os::unix::io::AsRawFd;
+
+    let file = rustix::fs::openat(
+        rustix::fs::CWD,
+        "Cargo.toml",
+        rustix::fs::OFlags::RDONLY | rustix::fs::OFlags::CLOEXEC,
+        rustix::fs::Mode::empty(),
+    )
+    .unwrap();
+
+    let new = rustix::fs::fcntl_dupfd(&file, 701).unwrap();
+    assert_eq!(new.as_fd().as_raw_fd(), 701);
+    assert!(!rustix::fs::fcntl_getfd(&new)
+        .unwrap()
+        .contains(FdFlags::CLOEXEC));
+}
+
 #[cfg(apple)]
 #[test]
 fn test_fcntl_apple() {
@@ -44,3 +66,73 @@ fn test_fcntl_apple() {
     rustix::fs::fcntl_nocache(&file, true).unwrap();
     rustix::fs::fcntl_global_nocache(&file, true).unwrap();
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_fcntl_lease() {
+    use rustix::fs::{fcntl_getlease, fcntl_setlease, openat, LeaseType, Mode, OFlags, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    drop(
+        openat(
+            &dir,
+            "file",
+            OFlags::WRONLY | OFlags::CREATE | OFlags::TRUNC,
+            Mode::RUSR | Mode::WUSR,
+        )
+        .unwrap(),
+    );
+    let file = openat(&dir, "file", OFlags::RDONLY, Mode::empty()).unwrap();
+
+    assert_eq!(fcntl_getlease(&file).unwrap(), None);
+
+    match fcntl_setlease(&file, Some(LeaseType::Read)) {
+        Ok(()) => {}
+        // Leases may be disabled, or unsupported by the filesystem.
+        Err(rustix::io::Errno::INVAL) | Err(rustix::io::Errno::ACCESS) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(fcntl_getlease(&file).unwrap(), Some(LeaseType::Read));
+
+    fcntl_setlease(&file, None).unwrap();
+    assert_eq!(fcntl_getlease(&file).unwrap(), None);
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_fcntl_notify() {
+    use rustix::fs::{fcntl_notify, openat, DnotifyFlags, Mode, OFlags, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        CWD,
+        tmp.path(),
+        OFlags::RDONLY | OFlags::DIRECTORY,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    // Register and then remove the notification, so that we don't need a
+    // `SIGIO` handler.
+    match fcntl_notify(&dir, DnotifyFlags::CREATE | DnotifyFlags::MULTISHOT) {
+        Ok(()) => {}
+        // dnotify may be disabled in the kernel configuration.
+        Err(rustix::io::Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    fcntl_notify(&dir, DnotifyFlags::empty()).unwrap();
+
+    // `F_NOTIFY` only works on directories.
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDONLY | OFlags::CREATE,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    assert_eq!(
+        fcntl_notify(&file, DnotifyFlags::CREATE),
+        Err(rustix::io::Errno::NOTDIR)
+    );
+}
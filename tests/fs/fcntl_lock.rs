@@ -31,3 +31,91 @@ fn test_fcntl_lock() {
     drop(f);
     drop(g);
 }
+
+#[test]
+fn test_fcntl_record_lock() {
+    use rustix::fs::{fcntl_getlk, fcntl_setlk, fcntl_setlkw, Flock, FlockType};
+
+    let f = tempfile::tempfile().unwrap();
+
+    // Record locks never conflict with other locks in the same process.
+    fcntl_setlk(&f, &Flock::new(FlockType::WriteLock, 0, 10)).unwrap();
+    fcntl_setlkw(&f, &Flock::new(FlockType::ReadLock, 5, 10)).unwrap();
+    assert_eq!(
+        fcntl_getlk(&f, &Flock::new(FlockType::WriteLock, 0, 0)).unwrap(),
+        None
+    );
+    fcntl_setlk(&f, &Flock::new(FlockType::Unlocked, 0, 0)).unwrap();
+
+    // Ranges past `i64::MAX` can't be represented.
+    assert_eq!(
+        fcntl_setlk(&f, &Flock::new(FlockType::WriteLock, u64::MAX, 1)),
+        Err(rustix::io::Errno::INVAL)
+    );
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_fcntl_ofd_lock() {
+    use rustix::fs::{
+        fcntl_getlk, fcntl_ofd_getlk, fcntl_ofd_setlk, fcntl_ofd_setlkw, fcntl_setlk, openat,
+        Flock, FlockType, Mode, OFlags, CWD,
+    };
+    use rustix::io::Errno;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("file");
+    let open = || {
+        openat(
+            CWD,
+            &path,
+            OFlags::RDWR | OFlags::CREATE | OFlags::CLOEXEC,
+            Mode::RUSR | Mode::WUSR,
+        )
+        .unwrap()
+    };
+    let a = open();
+    let b = open();
+
+    // Open file description locks conflict between open file descriptions,
+    // even in the same process.
+    fcntl_ofd_setlk(&a, &Flock::new(FlockType::WriteLock, 0, 10)).unwrap();
+    assert_eq!(
+        fcntl_ofd_setlk(&b, &Flock::new(FlockType::WriteLock, 5, 10)),
+        Err(Errno::AGAIN)
+    );
+    fcntl_ofd_setlkw(&b, &Flock::new(FlockType::ReadLock, 10, 10)).unwrap();
+    assert_eq!(
+        fcntl_ofd_getlk(&b, &Flock::new(FlockType::ReadLock, 0, 5)).unwrap(),
+        Some(Flock::new(FlockType::WriteLock, 0, 10))
+    );
+    assert_eq!(
+        fcntl_ofd_getlk(&a, &Flock::new(FlockType::ReadLock, 0, 0)).unwrap(),
+        None
+    );
+
+    // They also conflict with record locks.
+    assert!(matches!(
+        fcntl_setlk(&b, &Flock::new(FlockType::ReadLock, 0, 1)),
+        Err(Errno::AGAIN | Errno::ACCESS)
+    ));
+    assert_eq!(
+        fcntl_getlk(&b, &Flock::new(FlockType::WriteLock, 0, 5)).unwrap(),
+        Some(Flock::new(FlockType::WriteLock, 0, 10))
+    );
+
+    // Record locks report the process holding them.
+    fcntl_setlk(&b, &Flock::new(FlockType::WriteLock, 100, 0)).unwrap();
+    let lock = fcntl_ofd_getlk(&a, &Flock::new(FlockType::ReadLock, 200, 1))
+        .unwrap()
+        .unwrap();
+    assert_eq!(lock.typ, FlockType::WriteLock);
+    assert_eq!((lock.start, lock.len), (100, 0));
+    assert_eq!(
+        lock.pid.map(|pid| pid.as_raw_nonzero().get() as u32),
+        Some(std::process::id())
+    );
+
+    fcntl_ofd_setlk(&a, &Flock::new(FlockType::Unlocked, 0, 0)).unwrap();
+    fcntl_ofd_setlk(&b, &Flock::new(FlockType::WriteLock, 0, 10)).unwrap();
+}
@@ -185,3 +185,227 @@ fn test_setfl_append() {
     assert_eq!(rustix::io::read(&file, &mut buf), Ok(19));
     assert_eq!(&buf, b"uvwdefghijklmnopxyz\0\0\0\0\0\0\0\0\0\0\0\0\0");
 }
+
+#[cfg(not(any(
+    target_os = "aix",
+    target_os = "nto",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
+#[test]
+fn test_stat_accessors() {
+    use rustix::fs::{
+        fstat, futimens, FileType, Inode, Mode, OFlags, StatExt, Timespec, Timestamps,
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    let file = rustix::fs::open(
+        tmp.path().join("test.file"),
+        OFlags::RDWR | OFlags::CREATE,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    assert_eq!(rustix::io::write(&file, b"abcdefghijklmnop"), Ok(16));
+
+    let times = Timestamps {
+        last_access: Timespec {
+            tv_sec: 44000,
+            tv_nsec: 45000,
+        },
+        last_modification: Timespec {
+            tv_sec: 46000,
+            tv_nsec: 47000,
+        },
+    };
+    futimens(&file, &times).unwrap();
+
+    let stat = fstat(&file).unwrap();
+    assert_eq!(stat.file_type(), FileType::RegularFile);
+    assert_eq!(stat.mode(), Mode::RUSR | Mode::WUSR);
+    assert_eq!(stat.mode().symbolic().as_str(), "rw-------");
+    assert_eq!(stat.file_type().as_symbol(), '-');
+    assert!(!stat.mode().is_setuid());
+    assert_eq!(stat.size(), 16);
+    assert_eq!(stat.nlink(), 1);
+    assert_ne!(stat.ino(), Inode::from_raw(0));
+    #[cfg(feature = "process")]
+    assert_eq!(stat.uid(), rustix::process::geteuid());
+    assert_eq!(stat.accessed(), times.last_access);
+    assert_eq!(stat.modified(), times.last_modification);
+    assert!(stat.status_changed().tv_sec > 46000);
+
+    #[cfg(feature = "std")]
+    {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        let modified = SystemTime::try_from(stat.modified()).unwrap();
+        assert_eq!(modified, UNIX_EPOCH + Duration::new(46000, 47000));
+        assert_eq!(Timespec::try_from(modified), Ok(stat.modified()));
+    }
+}
+
+#[cfg(not(any(
+    target_os = "aix",
+    target_os = "nto",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
+#[test]
+fn test_stat_dev_ino() {
+    use rustix::fs::{fstat, stat, DeviceId, Inode, Mode, OFlags, StatExt};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("test.file");
+    let file = rustix::fs::open(&path, OFlags::RDWR | OFlags::CREATE, Mode::RUSR).unwrap();
+    let other = rustix::fs::open(
+        tmp.path().join("other.file"),
+        OFlags::RDWR | OFlags::CREATE,
+        Mode::RUSR,
+    )
+    .unwrap();
+
+    // A file is identified by its device and inode number.
+    let by_fd = fstat(&file).unwrap();
+    let by_path = stat(&path).unwrap();
+    assert_eq!((by_fd.dev(), by_fd.ino()), (by_path.dev(), by_path.ino()));
+
+    // Files in the same directory share a device, but not an inode.
+    let other = fstat(&other).unwrap();
+    assert_eq!(other.dev(), by_fd.dev());
+    assert_ne!(other.ino(), by_fd.ino());
+
+    assert_eq!(DeviceId::from_raw(by_fd.dev().as_raw()), by_fd.dev());
+    assert_eq!(Inode::from_raw(by_fd.ino().as_raw()), by_fd.ino());
+
+    #[cfg(feature = "std")]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        let metadata = std::fs::metadata(&path).unwrap();
+        assert_eq!(by_fd.dev().as_raw() as u64, metadata.dev());
+        assert_eq!(by_fd.ino().as_raw(), metadata.ino());
+    }
+}
+
+#[cfg(not(any(
+    netbsdlike,
+    solarish,
+    target_os = "dragonfly",
+    target_os = "espidf",
+    target_os = "nto",
+    target_os = "redox",
+    target_os = "vita",
+)))]
+#[test]
+fn test_fallocate() {
+    use rustix::fs::{fallocate, fstat, FallocateFlags};
+
+    let file = tempfile::tempfile().unwrap();
+    rustix::io::write(&file, &[0xa5; 100]).unwrap();
+
+    // Preallocating extends the file.
+    fallocate(&file, FallocateFlags::empty(), 0, 65536).unwrap();
+    assert_eq!(fstat(&file).unwrap().st_size, 65536);
+    fallocate(&file, FallocateFlags::empty(), 65536, 4096).unwrap();
+    assert_eq!(fstat(&file).unwrap().st_size, 65536 + 4096);
+
+    // It never shrinks the file, or changes its contents.
+    fallocate(&file, FallocateFlags::empty(), 0, 10).unwrap();
+    assert_eq!(fstat(&file).unwrap().st_size, 65536 + 4096);
+    let mut buf = [0_u8; 101];
+    assert_eq!(rustix::io::pread(&file, &mut buf, 0), Ok(101));
+    assert_eq!(&buf[..100], &[0xa5; 100]);
+    assert_eq!(buf[100], 0);
+}
+
+#[cfg(not(any(
+    apple,
+    netbsdlike,
+    solarish,
+    target_os = "dragonfly",
+    target_os = "espidf",
+    target_os = "haiku",
+    target_os = "redox",
+)))]
+#[test]
+fn test_fadvise() {
+    use rustix::fs::{fadvise, Advice};
+
+    let file = tempfile::tempfile().unwrap();
+    rustix::io::write(&file, &[0xa5; 8192]).unwrap();
+
+    for advice in [
+        Advice::Normal,
+        Advice::Sequential,
+        Advice::Random,
+        Advice::NoReuse,
+        Advice::WillNeed,
+        Advice::DontNeed,
+    ] {
+        fadvise(&file, 0, 0, advice).unwrap();
+        fadvise(&file, 4096, 4096, advice).unwrap();
+    }
+
+    // Advice doesn't change the contents.
+    let mut buf = [0_u8; 8192];
+    assert_eq!(rustix::io::pread(&file, &mut buf, 0), Ok(8192));
+    assert_eq!(buf, [0xa5; 8192]);
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_fallocate_ranges() {
+    use rustix::fs::{fallocate, fstat, FallocateFlags};
+    use rustix::io::{pread, pwrite, Errno};
+
+    let file = tempfile::tempfile().unwrap();
+    let block = fstat(&file).unwrap().st_blksize as usize;
+    for i in 0..4 {
+        pwrite(&file, &vec![i as u8 + 1; block], (i * block) as u64).unwrap();
+    }
+    let read_block = |i: usize| {
+        let mut buf = vec![0_u8; block];
+        assert_eq!(pread(&file, &mut buf, (i * block) as u64), Ok(block));
+        buf
+    };
+
+    // Punching a hole zeros the second block without changing the size.
+    match fallocate(
+        &file,
+        FallocateFlags::PUNCH_HOLE | FallocateFlags::KEEP_SIZE,
+        block as u64,
+        block as u64,
+    ) {
+        Ok(()) => {}
+        // Not all filesystems support these operations.
+        Err(Errno::OPNOTSUPP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(fstat(&file).unwrap().st_size as usize, 4 * block);
+    assert_eq!(read_block(1), vec![0; block]);
+
+    // Zeroing a range zeros the third block.
+    match fallocate(
+        &file,
+        FallocateFlags::ZERO_RANGE,
+        2 * block as u64,
+        block as u64,
+    ) {
+        Ok(()) => {}
+        Err(Errno::OPNOTSUPP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(fstat(&file).unwrap().st_size as usize, 4 * block);
+    assert_eq!(read_block(2), vec![0; block]);
+    assert_eq!(read_block(3), vec![4; block]);
+
+    // Collapsing the first block shifts the rest of the file down.
+    match fallocate(&file, FallocateFlags::COLLAPSE_RANGE, 0, block as u64) {
+        Ok(()) => {}
+        Err(Errno::OPNOTSUPP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(fstat(&file).unwrap().st_size as usize, 3 * block);
+    assert_eq!(read_block(0), vec![0; block]);
+    assert_eq!(read_block(2), vec![4; block]);
+}
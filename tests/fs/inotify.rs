@@ -0,0 +1,103 @@
+use rustix::fs::inotify::{
+    inotify_add_watch, inotify_init, inotify_remove_watch, CreateFlags, InotifyEvents, ReadFlags,
+    WatchFlags,
+};
+use rustix::fs::{openat, renameat, Mode, OFlags, CWD};
+use rustix::io::{read, Errno};
+use std::ffi::CStr;
+
+#[test]
+fn test_inotify_events() {
+    use rustix::fd::AsFd;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let inot = inotify_init(CreateFlags::CLOEXEC | CreateFlags::NONBLOCK).unwrap();
+    let wd = inotify_add_watch(
+        inot.as_fd(),
+        tmp.path(),
+        WatchFlags::CREATE | WatchFlags::MOVE,
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 4096];
+    assert_eq!(read(&inot, &mut buf), Err(Errno::AGAIN));
+
+    let _ = openat(&dir, "foo", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    renameat(&dir, "foo", &dir, "bar").unwrap();
+
+    let len = read(&inot, &mut buf).unwrap();
+    let events: Vec<_> = InotifyEvents::new(&buf[..len]).collect();
+    assert_eq!(events.len(), 3, "{:?}", events);
+    assert!(events.iter().all(|event| event.wd() == wd));
+
+    assert_eq!(events[0].events(), ReadFlags::CREATE);
+    assert_eq!(
+        events[0].file_name(),
+        Some(CStr::from_bytes_with_nul(b"foo\0").unwrap())
+    );
+
+    // The two halves of the rename share a cookie.
+    assert_eq!(events[1].events(), ReadFlags::MOVED_FROM);
+    assert_eq!(
+        events[1].file_name(),
+        Some(CStr::from_bytes_with_nul(b"foo\0").unwrap())
+    );
+    assert_eq!(events[2].events(), ReadFlags::MOVED_TO);
+    assert_eq!(
+        events[2].file_name(),
+        Some(CStr::from_bytes_with_nul(b"bar\0").unwrap())
+    );
+    assert_ne!(events[1].cookie(), 0);
+    assert_eq!(events[1].cookie(), events[2].cookie());
+
+    // Removing the watch generates an `IN_IGNORED` event.
+    inotify_remove_watch(inot.as_fd(), wd).unwrap();
+    let len = read(&inot, &mut buf).unwrap();
+    let mut events = InotifyEvents::new(&buf[..len]);
+    let event = events.next().unwrap();
+    assert_eq!(event.wd(), wd);
+    assert_eq!(event.events(), ReadFlags::IGNORED);
+    assert_eq!(event.file_name(), None);
+    assert!(events.next().is_none());
+}
+
+#[test]
+fn test_inotify_events_parse() {
+    fn record(buf: &mut Vec<u8>, wd: i32, mask: u32, cookie: u32, name: &[u8]) {
+        buf.extend_from_slice(&wd.to_ne_bytes());
+        buf.extend_from_slice(&mask.to_ne_bytes());
+        buf.extend_from_slice(&cookie.to_ne_bytes());
+        buf.extend_from_slice(&(name.len() as u32).to_ne_bytes());
+        buf.extend_from_slice(name);
+    }
+
+    // Start at an odd offset to test unaligned buffers.
+    let mut buf = vec![0_u8];
+    record(&mut buf, 1, ReadFlags::ISDIR.bits(), 0, b"");
+    record(&mut buf, 2, ReadFlags::DELETE.bits(), 7, b"x\0\0\0\0\0\0\0");
+    let complete = buf.len();
+    record(&mut buf, 3, ReadFlags::CREATE.bits(), 0, b"truncated\0\0\0");
+
+    let mut events = InotifyEvents::new(&buf[1..buf.len() - 1]);
+    let event = events.next().unwrap();
+    assert_eq!(event.wd(), 1);
+    assert_eq!(event.events(), ReadFlags::ISDIR);
+    assert_eq!(event.file_name(), None);
+    let event = events.next().unwrap();
+    assert_eq!(event.wd(), 2);
+    assert_eq!(event.events(), ReadFlags::DELETE);
+    assert_eq!(event.cookie(), 7);
+    assert_eq!(
+        event.file_name(),
+        Some(CStr::from_bytes_with_nul(b"x\0").unwrap())
+    );
+
+    // The last record is truncated, so it's not produced.
+    assert!(events.next().is_none());
+    assert!(events.next().is_none());
+
+    assert_eq!(InotifyEvents::new(&buf[1..complete]).count(), 2);
+    assert_eq!(InotifyEvents::new(&[]).count(), 0);
+}
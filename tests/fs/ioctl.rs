@@ -23,3 +23,51 @@ fn test_ioctl_ficlone() {
         Err(err) => panic!("{:?}", err),
     }
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_ioctl_fiemap() {
+    use rustix::fs::{ioctl_fiemap, FiemapExtentFlags, FiemapFlags};
+    use rustix::io;
+
+    let file = tempfile::tempfile().unwrap();
+    let mut extents = vec![Default::default(); 4];
+
+    // An empty file has no extents.
+    match ioctl_fiemap(&file, 0, u64::MAX, FiemapFlags::SYNC, &mut extents) {
+        Ok(()) => assert!(extents.is_empty()),
+        // Some filesystems, such as tmpfs, don't support `FIEMAP`.
+        Err(io::Errno::OPNOTSUPP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    // Write some data at the start, and some more after a large hole.
+    let data = vec![0xa5_u8; 64 * 1024];
+    rustix::io::pwrite(&file, &data, 0).unwrap();
+    rustix::io::pwrite(&file, &data, 16 * 1024 * 1024).unwrap();
+
+    ioctl_fiemap(&file, 0, u64::MAX, FiemapFlags::SYNC, &mut extents).unwrap();
+    assert!(!extents.is_empty());
+    assert_eq!(extents[0].logical(), 0);
+    let last = extents.last().unwrap();
+    assert!(last.flags().contains(FiemapExtentFlags::LAST));
+    assert!(last.logical() + last.length() >= 16 * 1024 * 1024 + data.len() as u64);
+
+    // The extents are in order, and none of them cover the hole.
+    let total: u64 = extents.iter().map(|extent| extent.length()).sum();
+    assert!(total < 16 * 1024 * 1024);
+    for pair in extents.windows(2) {
+        assert!(pair[0].logical() + pair[0].length() <= pair[1].logical());
+    }
+
+    // Mapping just the hole finds nothing.
+    ioctl_fiemap(
+        &file,
+        1024 * 1024,
+        1024 * 1024,
+        FiemapFlags::empty(),
+        &mut extents,
+    )
+    .unwrap();
+    assert!(extents.is_empty());
+}
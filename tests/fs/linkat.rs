@@ -56,3 +56,104 @@ fn test_linkat() {
         statat(&dir, "another", AtFlags::empty()).unwrap().st_ino
     );
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_linkat_tmpfile() {
+    use rustix::fs::{linkat, openat, statat, AtFlags, Mode, OFlags, CWD};
+    use rustix::io::{write, Errno};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let file = match openat(
+        &dir,
+        ".",
+        OFlags::WRONLY | OFlags::TMPFILE | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    ) {
+        Ok(file) => file,
+        // Not all filesystems support `O_TMPFILE`.
+        Err(Errno::OPNOTSUPP) | Err(Errno::ISDIR) | Err(Errno::NOENT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(write(&file, b"hello"), Ok(5));
+
+    // Linking with `AT_EMPTY_PATH` requires `CAP_DAC_READ_SEARCH`, so fall
+    // back to linking through `/proc/self/fd`.
+    match linkat(&file, "", &dir, "named", AtFlags::EMPTY_PATH) {
+        Ok(()) => {}
+        Err(Errno::NOENT) | Err(Errno::PERM) => {
+            let proc_path = format!("/proc/self/fd/{}", rustix::fd::AsRawFd::as_raw_fd(&file));
+            match linkat(CWD, proc_path, &dir, "named", AtFlags::SYMLINK_FOLLOW) {
+                Ok(()) => {}
+                // `/proc` may not be mounted.
+                Err(Errno::NOENT) => return,
+                Err(err) => panic!("{:?}", err),
+            }
+        }
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let stat = statat(&dir, "named", AtFlags::empty()).unwrap();
+    assert_eq!(stat.st_size, 5);
+    assert_eq!(stat.st_nlink, 1);
+    assert_eq!(std::fs::read(tmp.path().join("named")).unwrap(), b"hello");
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_linkat_symlink_follow() {
+    use rustix::fs::{linkat, openat, statat, symlinkat, AtFlags, FileType, Mode, OFlags, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let _ = openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    symlinkat("file", &dir, "symlink").unwrap();
+
+    // Without `AT_SYMLINK_FOLLOW`, the symlink itself is linked.
+    linkat(&dir, "symlink", &dir, "link_to_symlink", AtFlags::empty()).unwrap();
+    let stat = statat(&dir, "link_to_symlink", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Symlink);
+
+    // With it, the symlink's target is linked.
+    linkat(
+        &dir,
+        "symlink",
+        &dir,
+        "link_to_file",
+        AtFlags::SYMLINK_FOLLOW,
+    )
+    .unwrap();
+    let stat = statat(&dir, "link_to_file", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::RegularFile);
+    assert_eq!(
+        stat.st_ino,
+        statat(&dir, "file", AtFlags::empty()).unwrap().st_ino
+    );
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_linkat_o_path() {
+    use rustix::fs::{linkat, openat, statat, AtFlags, Mode, OFlags, CWD};
+    use rustix::io::Errno;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let _ = openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    let path_fd = openat(&dir, "file", OFlags::PATH | OFlags::CLOEXEC, Mode::empty()).unwrap();
+
+    match linkat(&path_fd, "", &dir, "link", AtFlags::EMPTY_PATH) {
+        Ok(()) => {}
+        // This requires `CAP_DAC_READ_SEARCH`.
+        Err(Errno::NOENT) | Err(Errno::PERM) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(
+        statat(&dir, "file", AtFlags::empty()).unwrap().st_ino,
+        statat(&dir, "link", AtFlags::empty()).unwrap().st_ino
+    );
+}
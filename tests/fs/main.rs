@@ -5,6 +5,10 @@
 #![cfg_attr(core_c_str, feature(core_c_str))]
 
 mod chmodat;
+#[cfg(linux_kernel)]
+mod copy;
+#[cfg(any(linux_kernel, target_os = "freebsd"))]
+mod copy_file_range;
 mod cwd;
 #[cfg(not(target_os = "redox"))]
 mod dir;
@@ -20,6 +24,8 @@ mod file;
 #[cfg(not(target_os = "wasi"))]
 mod flock;
 mod futimens;
+#[cfg(linux_kernel)]
+mod inotify;
 mod invalid_offset;
 #[cfg(not(target_os = "redox"))]
 mod ioctl;
@@ -29,6 +35,8 @@ mod long_paths;
 mod makedev;
 mod mkdirat;
 mod mknodat;
+#[cfg(not(target_os = "redox"))]
+mod open_options;
 #[cfg(linux_kernel)]
 mod openat;
 #[cfg(linux_kernel)]
@@ -36,10 +44,21 @@ mod openat2;
 #[cfg(not(target_os = "redox"))]
 mod readdir;
 mod readlinkat;
+#[cfg(any(
+    apple,
+    all(linux_kernel, not(any(target_arch = "sparc", target_arch = "sparc64")))
+))]
+mod reflink;
+#[cfg(any(linux_kernel, bsd))]
+mod remove;
 mod renameat;
+#[cfg(all(linux_kernel, feature = "procfs"))]
+mod reopen;
 #[cfg(any(linux_kernel, target_os = "freebsd"))]
 mod seals;
 mod seek;
+#[cfg(any(apple, freebsdlike, target_os = "linux"))]
+mod sendfile;
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 mod statfs;
 #[cfg(linux_kernel)]
@@ -47,7 +66,11 @@ mod statx;
 mod symlinkat;
 #[cfg(not(any(solarish, target_os = "redox", target_os = "wasi")))]
 mod sync;
+#[cfg(all(linux_kernel, feature = "rand"))]
+mod temp;
 mod utimensat;
+#[cfg(any(linux_kernel, bsd))]
+mod walk;
 #[cfg(any(apple, linux_kernel))]
 mod xattr;
 mod y2038;
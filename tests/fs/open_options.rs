@@ -0,0 +1,90 @@
+use rustix::fs::{fstat, Mode, OFlags, OpenOptionsAt, StatExt, CWD};
+use rustix::io::{read, write, Errno};
+
+#[test]
+fn test_open_options_at() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = rustix::fs::openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    // Nothing enabled is an error.
+    assert_eq!(
+        OpenOptionsAt::new().open(&dir, "file").unwrap_err(),
+        Errno::INVAL
+    );
+
+    // Creating requires writing.
+    assert_eq!(
+        OpenOptionsAt::new()
+            .read(true)
+            .create(true)
+            .open(&dir, "file")
+            .unwrap_err(),
+        Errno::INVAL
+    );
+
+    let file = OpenOptionsAt::new()
+        .write(true)
+        .create_new(true)
+        .mode(Mode::RUSR | Mode::WUSR)
+        .open(&dir, "file")
+        .unwrap();
+    assert_eq!(write(&file, b"hello").unwrap(), 5);
+    assert_eq!(fstat(&file).unwrap().mode(), Mode::RUSR | Mode::WUSR);
+
+    // `create_new` fails if the file exists.
+    assert_eq!(
+        OpenOptionsAt::new()
+            .write(true)
+            .create_new(true)
+            .open(&dir, "file")
+            .unwrap_err(),
+        Errno::EXIST
+    );
+
+    // Appending implies writing.
+    let file = OpenOptionsAt::new()
+        .append(true)
+        .open(&dir, "file")
+        .unwrap();
+    assert_eq!(write(&file, b" world").unwrap(), 6);
+
+    let file = OpenOptionsAt::new().read(true).open(&dir, "file").unwrap();
+    assert!(rustix::io::fcntl_getfd(&file)
+        .unwrap()
+        .contains(rustix::io::FdFlags::CLOEXEC));
+    let mut buf = [0_u8; 16];
+    assert_eq!(read(&file, &mut buf).unwrap(), 11);
+    assert_eq!(&buf[..11], b"hello world");
+
+    // Truncate.
+    let file = OpenOptionsAt::new()
+        .write(true)
+        .truncate(true)
+        .open(&dir, "file")
+        .unwrap();
+    assert_eq!(fstat(&file).unwrap().size(), 0);
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_open_options_at_resolve() {
+    use rustix::fs::{symlinkat, ResolveFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = rustix::fs::openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    symlinkat("/etc/passwd", &dir, "link").unwrap();
+
+    let mut options = OpenOptionsAt::new();
+    options
+        .read(true)
+        .resolve(ResolveFlags::BENEATH | ResolveFlags::NO_SYMLINKS);
+
+    match options.open(&dir, "link") {
+        // `openat2` isn't available.
+        Err(Errno::NOSYS) => return,
+        Err(err) => assert!(err == Errno::LOOP || err == Errno::XDEV, "{:?}", err),
+        Ok(_) => panic!("opened a symlink with `NO_SYMLINKS`"),
+    }
+
+    assert_eq!(options.open(&dir, "../escape").unwrap_err(), Errno::XDEV);
+}
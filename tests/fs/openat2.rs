@@ -190,3 +190,88 @@ fn test_openat2() {
     )
     .unwrap();
 }
+
+#[test]
+fn test_openat2_errors() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    // Errors from `openat2` itself aren't mistaken for it being unavailable.
+    match openat2(
+        &dir,
+        "missing",
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+        ResolveFlags::empty(),
+    ) {
+        Err(io::Errno::NOENT) => (),
+        Err(io::Errno::NOSYS) => return,
+        otherwise => panic!("unexpected result: {:?}", otherwise),
+    }
+
+    mkdirat(&dir, "sub", Mode::RWXU).unwrap();
+    symlinkat("..", &dir, "sub/up").unwrap();
+
+    // `..` which stays beneath `dirfd` is fine.
+    let _ = openat2_more(
+        &dir,
+        "sub/..",
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+        ResolveFlags::BENEATH,
+    )
+    .unwrap();
+
+    // Escaping fails with `EXDEV`, whether with `..` or a symlink.
+    let sub = openat(&dir, "sub", OFlags::RDONLY | OFlags::CLOEXEC, Mode::empty()).unwrap();
+    for path in ["..", "up", "/"] {
+        assert_eq!(
+            openat2_more(
+                &sub,
+                path,
+                OFlags::RDONLY | OFlags::CLOEXEC,
+                Mode::empty(),
+                ResolveFlags::BENEATH,
+            )
+            .unwrap_err(),
+            io::Errno::XDEV
+        );
+    }
+
+    // Following a symlink with `NO_SYMLINKS` fails with `ELOOP`.
+    assert_eq!(
+        openat2_more(
+            &dir,
+            "sub/up",
+            OFlags::RDONLY | OFlags::CLOEXEC,
+            Mode::empty(),
+            ResolveFlags::NO_SYMLINKS,
+        )
+        .unwrap_err(),
+        io::Errno::LOOP
+    );
+
+    // Unknown resolve flags and a mode without `O_CREAT` are rejected.
+    assert_eq!(
+        openat2(
+            &dir,
+            "sub",
+            OFlags::RDONLY | OFlags::CLOEXEC,
+            Mode::empty(),
+            ResolveFlags::from_bits_retain(1 << 63),
+        )
+        .unwrap_err(),
+        io::Errno::INVAL
+    );
+    assert_eq!(
+        openat2(
+            &dir,
+            "sub",
+            OFlags::RDONLY | OFlags::CLOEXEC,
+            Mode::RWXU,
+            ResolveFlags::empty(),
+        )
+        .unwrap_err(),
+        io::Errno::INVAL
+    );
+}
@@ -51,6 +51,44 @@ fn test_readlinkat() {
     assert_eq!(target.to_string_lossy(), "link");
 }
 
+#[cfg(not(target_os = "redox"))]
+#[test]
+fn test_readlinkat_buffers() {
+    use core::mem::MaybeUninit;
+    use rustix::fs::{openat, readlinkat_into, symlinkat, Mode, OFlags, CWD};
+    use std::ffi::OsString;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    symlinkat("file", &dir, "link").unwrap();
+
+    // Fixed-size buffers.
+    let mut buf = [0_u8; 5];
+    let target = readlinkat_into(&dir, "link", &mut buf).unwrap();
+    assert_eq!(target.to_bytes(), b"file");
+    let mut buf = [0_u8; 4];
+    assert_eq!(
+        readlinkat_into(&dir, "link", &mut buf),
+        Err(rustix::io::Errno::RANGE)
+    );
+    assert_eq!(
+        readlinkat_into(&dir, "link", &mut [0_u8; 0]),
+        Err(rustix::io::Errno::RANGE)
+    );
+    let mut buf = [MaybeUninit::<u8>::uninit(); 16];
+    let target = readlinkat_into(&dir, "link", &mut buf[..]).unwrap();
+    assert_eq!(target.to_bytes(), b"file");
+
+    // Growable buffers.
+    let mut vec = Vec::new();
+    let target = readlinkat_into(&dir, "link", &mut vec).unwrap();
+    assert_eq!(target.to_bytes(), b"file");
+    assert_eq!(vec, b"file\0");
+    let target = readlinkat_into(&dir, "link", OsString::new()).unwrap();
+    assert_eq!(target, "file");
+}
+
 #[cfg(not(target_os = "redox"))]
 #[test]
 fn test_readlinkat_raw() {
@@ -0,0 +1,46 @@
+use rustix::fs::{openat, reflinkat, statat, AtFlags, Mode, OFlags, CWD};
+use rustix::io::{self, pwrite};
+
+#[test]
+fn test_reflinkat() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let src = openat(
+        &dir,
+        "src",
+        OFlags::RDWR | OFlags::CREATE,
+        Mode::from_bits_truncate(0o640),
+    )
+    .unwrap();
+    pwrite(&src, b"reflinked", 0).unwrap();
+
+    match reflinkat(&src, &dir, "dst") {
+        Ok(()) => {
+            let dst = openat(&dir, "dst", OFlags::RDONLY, Mode::empty()).unwrap();
+            let mut buf = [0_u8; 16];
+            assert_eq!(rustix::io::read(&dst, &mut buf), Ok(9));
+            assert_eq!(&buf[..9], b"reflinked");
+        }
+        // Many filesystems can't share data between files. Make sure we
+        // didn't leave an empty file behind.
+        Err(io::Errno::OPNOTSUPP) | Err(io::Errno::XDEV) => {
+            assert_eq!(
+                statat(&dir, "dst", AtFlags::empty()).unwrap_err(),
+                io::Errno::NOENT
+            );
+        }
+        #[cfg(apple)]
+        Err(io::Errno::NOTSUP) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+
+    // The destination must not already exist.
+    openat(
+        &dir,
+        "existing",
+        OFlags::WRONLY | OFlags::CREATE,
+        Mode::RUSR,
+    )
+    .unwrap();
+    assert_eq!(reflinkat(&src, &dir, "existing"), Err(io::Errno::EXIST));
+}
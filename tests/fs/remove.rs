@@ -0,0 +1,79 @@
+use super::walk::create_tree;
+use rustix::fs::{remove_dir_all_at, CWD};
+use std::os::unix::fs::symlink;
+
+#[test]
+fn test_remove_dir_all_at() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path().join("dir");
+    std::fs::create_dir(&dir).unwrap();
+    create_tree(&dir);
+
+    // Symlinks are removed, not followed.
+    let outside = tmp.path().join("outside");
+    std::fs::create_dir(&outside).unwrap();
+    std::fs::write(outside.join("keep"), b"").unwrap();
+    symlink(&outside, dir.join("a/link")).unwrap();
+    symlink("/nonexistent", dir.join("a/b/dangling")).unwrap();
+
+    remove_dir_all_at(CWD, &dir).unwrap();
+    assert!(!dir.exists());
+    assert!(outside.join("keep").exists());
+}
+
+#[test]
+fn test_remove_dir_all_at_dirfd() {
+    let tmp = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(tmp.path().join("dir/sub")).unwrap();
+    std::fs::write(tmp.path().join("dir/sub/file"), b"").unwrap();
+
+    let dirfd = rustix::fs::openat(
+        CWD,
+        tmp.path(),
+        rustix::fs::OFlags::RDONLY | rustix::fs::OFlags::DIRECTORY,
+        rustix::fs::Mode::empty(),
+    )
+    .unwrap();
+    remove_dir_all_at(&dirfd, "dir").unwrap();
+    assert!(!tmp.path().join("dir").exists());
+    assert!(tmp.path().exists());
+}
+
+#[test]
+fn test_remove_dir_all_at_not_dir() {
+    let tmp = tempfile::tempdir().unwrap();
+    let target = tmp.path().join("target");
+    std::fs::create_dir(&target).unwrap();
+    std::fs::write(target.join("keep"), b"").unwrap();
+    symlink(&target, tmp.path().join("link")).unwrap();
+    std::fs::write(tmp.path().join("file"), b"").unwrap();
+
+    // A symlink to a directory isn't followed, or removed.
+    assert!(matches!(
+        remove_dir_all_at(CWD, tmp.path().join("link")),
+        Err(rustix::io::Errno::LOOP) | Err(rustix::io::Errno::NOTDIR)
+    ));
+    assert!(target.join("keep").exists());
+
+    assert_eq!(
+        remove_dir_all_at(CWD, tmp.path().join("file")),
+        Err(rustix::io::Errno::NOTDIR)
+    );
+    assert_eq!(
+        remove_dir_all_at(CWD, tmp.path().join("missing")),
+        Err(rustix::io::Errno::NOENT)
+    );
+}
+
+#[test]
+fn test_remove_dir_all_at_many_entries() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path().join("dir");
+    std::fs::create_dir(&dir).unwrap();
+    for i in 0..1000 {
+        std::fs::write(dir.join(format!("file{}", i)), b"").unwrap();
+    }
+
+    remove_dir_all_at(CWD, &dir).unwrap();
+    assert!(!dir.exists());
+}
@@ -0,0 +1,131 @@
+use rustix::fs::{fstat, openat, reopen, seek, statat, AtFlags, Mode, OFlags, SeekFrom, CWD};
+use rustix::io::{read, write, Errno};
+
+#[test]
+fn test_o_path() {
+    let tmp = tempfile::tempdir().unwrap();
+    std::fs::write(tmp.path().join("file"), b"hello").unwrap();
+    std::fs::create_dir(tmp.path().join("dir")).unwrap();
+    std::fs::write(tmp.path().join("dir/inner"), b"").unwrap();
+
+    let file = openat(
+        CWD,
+        tmp.path().join("file"),
+        OFlags::PATH | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    // An `O_PATH` file descriptor can't be read from.
+    let mut buf = [0_u8; 8];
+    assert_eq!(read(&file, &mut buf), Err(Errno::BADF));
+
+    // But it can be stat'd, including with `AT_EMPTY_PATH`.
+    let stat = fstat(&file).unwrap();
+    assert_eq!(stat.st_size, 5);
+    assert_eq!(
+        statat(&file, "", AtFlags::EMPTY_PATH).unwrap().st_ino,
+        stat.st_ino
+    );
+
+    // An `O_PATH` directory can be used as a `dirfd`.
+    let dir = openat(
+        CWD,
+        tmp.path().join("dir"),
+        OFlags::PATH | OFlags::DIRECTORY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    statat(&dir, "inner", AtFlags::empty()).unwrap();
+}
+
+#[test]
+fn test_reopen() {
+    let tmp = tempfile::tempdir().unwrap();
+    std::fs::write(tmp.path().join("file"), b"hello").unwrap();
+
+    let path_fd = openat(
+        CWD,
+        tmp.path().join("file"),
+        OFlags::PATH | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    // The file can be reopened after it's renamed.
+    std::fs::rename(tmp.path().join("file"), tmp.path().join("renamed")).unwrap();
+
+    let file = reopen(&path_fd, OFlags::RDONLY | OFlags::CLOEXEC).unwrap();
+    let mut buf = [0_u8; 8];
+    assert_eq!(read(&file, &mut buf), Ok(5));
+    assert_eq!(&buf[..5], b"hello");
+
+    let file = reopen(&path_fd, OFlags::WRONLY | OFlags::APPEND | OFlags::CLOEXEC).unwrap();
+    assert_eq!(write(&file, b" world"), Ok(6));
+    assert_eq!(
+        std::fs::read(tmp.path().join("renamed")).unwrap(),
+        b"hello world"
+    );
+}
+
+#[test]
+fn test_reopen_offset() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    write(&file, b"hello").unwrap();
+
+    // The new file description has its own offset.
+    let reader = reopen(&file, OFlags::RDONLY | OFlags::CLOEXEC).unwrap();
+    let mut buf = [0_u8; 5];
+    assert_eq!(read(&reader, &mut buf), Ok(5));
+    assert_eq!(&buf, b"hello");
+    assert_eq!(seek(&file, SeekFrom::Current(0)), Ok(5));
+
+    // `NOFOLLOW` is ignored.
+    let reader2 = reopen(&reader, OFlags::RDONLY | OFlags::NOFOLLOW).unwrap();
+    assert_eq!(read(&reader2, &mut buf), Ok(5));
+
+    // Sockets can't be reopened.
+    #[cfg(all(linux_kernel, feature = "net"))]
+    {
+        use rustix::net::{socket, AddressFamily, SocketType};
+        let sock = socket(AddressFamily::UNIX, SocketType::STREAM, None).unwrap();
+        assert_eq!(reopen(&sock, OFlags::RDWR).unwrap_err(), Errno::NXIO);
+    }
+}
+
+#[test]
+fn test_reopen_dir() {
+    let tmp = tempfile::tempdir().unwrap();
+    std::fs::write(tmp.path().join("file"), b"").unwrap();
+
+    let path_fd = openat(
+        CWD,
+        tmp.path(),
+        OFlags::PATH | OFlags::DIRECTORY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    let dir = reopen(
+        &path_fd,
+        OFlags::RDONLY | OFlags::DIRECTORY | OFlags::CLOEXEC,
+    )
+    .unwrap();
+    let mut dir = rustix::fs::Dir::read_from(&dir).unwrap();
+    assert!(dir.any(|entry| entry.unwrap().file_name().to_bytes() == b"file"));
+
+    // A directory can't be reopened for writing.
+    assert_eq!(
+        reopen(&path_fd, OFlags::WRONLY | OFlags::CLOEXEC).unwrap_err(),
+        Errno::ISDIR
+    );
+}
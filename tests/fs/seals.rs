@@ -37,3 +37,22 @@ fn test_seals() {
     // We sealed shrinking, so this should fail.
     ftruncate(&mut file, 0).unwrap_err();
 }
+
+#[test]
+fn test_seals_not_allowed() {
+    use rustix::fs::{fcntl_add_seals, fcntl_get_seals, memfd_create, MemfdFlags, SealFlags};
+
+    let fd = match memfd_create("test", MemfdFlags::CLOEXEC) {
+        Ok(fd) => fd,
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    // Without `ALLOW_SEALING`, the memfd starts out sealed against further
+    // seals.
+    assert_eq!(fcntl_get_seals(&fd).unwrap(), SealFlags::SEAL);
+    assert_eq!(
+        fcntl_add_seals(&fd, SealFlags::GROW),
+        Err(rustix::io::Errno::PERM)
+    );
+}
@@ -0,0 +1,79 @@
+use rustix::fs::{openat, seek, sendfile, Mode, OFlags, SeekFrom, CWD};
+use rustix::io::write;
+use std::io::Read;
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+
+#[test]
+fn test_sendfile_to_socket() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    write(&file, b"hello, world").unwrap();
+
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let (server, _) = listener.accept().unwrap();
+
+    // With an explicit offset, the offset is advanced and the file offset is
+    // left alone.
+    let mut offset = 7;
+    assert_eq!(sendfile(&server, &file, Some(&mut offset), 5), Ok(5));
+    assert_eq!(offset, 12);
+    assert_eq!(seek(&file, SeekFrom::Current(0)), Ok(12));
+
+    // At the end of the file, nothing is sent.
+    assert_eq!(sendfile(&server, &file, Some(&mut offset), 5), Ok(0));
+    assert_eq!(offset, 12);
+
+    // A count of zero sends nothing.
+    let mut offset = 0;
+    assert_eq!(sendfile(&server, &file, Some(&mut offset), 0), Ok(0));
+    assert_eq!(offset, 0);
+
+    // Without an offset, the file offset is used and advanced.
+    seek(&file, SeekFrom::Start(0)).unwrap();
+    assert_eq!(sendfile(&server, &file, None, 5), Ok(5));
+    assert_eq!(seek(&file, SeekFrom::Current(0)), Ok(5));
+
+    drop(server);
+    let mut buf = Vec::new();
+    client.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"worldhello");
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_sendfile_to_file() {
+    use rustix::io::pread;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let open = |name| {
+        openat(
+            &dir,
+            name,
+            OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+            Mode::RUSR | Mode::WUSR,
+        )
+        .unwrap()
+    };
+
+    let src = open("src");
+    write(&src, b"hello, world").unwrap();
+    let dst = open("dst");
+
+    let mut offset = 7;
+    assert_eq!(sendfile(&dst, &src, Some(&mut offset), 100), Ok(5));
+    assert_eq!(offset, 12);
+    assert_eq!(seek(&dst, SeekFrom::Current(0)), Ok(5));
+
+    let mut buf = [0_u8; 5];
+    assert_eq!(pread(&dst, &mut buf, 0), Ok(5));
+    assert_eq!(&buf, b"world");
+}
@@ -85,3 +85,59 @@ fn test_fstatvfs() {
     let f_frsize = statvfs.f_frsize;
     assert_ne!(f_frsize, 0);
 }
+
+#[test]
+fn test_statvfs_flags() {
+    use rustix::fs::{statvfs, StatVfsMountFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let flags = statvfs(tmp.path()).unwrap().f_flag;
+
+    // We just created a directory here, so it's not read-only.
+    assert!(!flags.contains(StatVfsMountFlags::RDONLY));
+
+    // Check that the flags match what libc reports.
+    #[cfg(all(linux_kernel, target_env = "gnu"))]
+    {
+        let path = std::ffi::CString::new(tmp.path().to_str().unwrap()).unwrap();
+        let mut buf = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+        assert_eq!(unsafe { libc::statvfs(path.as_ptr(), buf.as_mut_ptr()) }, 0);
+        let buf = unsafe { buf.assume_init() };
+        #[allow(clippy::useless_conversion)]
+        let f_flag = u64::from(buf.f_flag);
+        assert_eq!(flags.bits(), f_flag);
+    }
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_statvfs_flags_procfs() {
+    use rustix::fs::{statvfs, StatVfsMountFlags};
+
+    // Check our flags against the mount options in `/proc/self/mountinfo`.
+    let mountinfo = std::fs::read_to_string("/proc/self/mountinfo").unwrap();
+    let options = match mountinfo.lines().find_map(|line| {
+        let mut fields = line.split(' ');
+        let mount_point = fields.nth(4)?;
+        let options = fields.next()?;
+        (mount_point == "/proc").then(|| options.to_owned())
+    }) {
+        Some(options) => options,
+        None => return,
+    };
+    let options: Vec<&str> = options.split(',').collect();
+
+    let flags = statvfs("/proc").unwrap().f_flag;
+    assert_eq!(
+        flags.contains(StatVfsMountFlags::RDONLY),
+        options.contains(&"ro")
+    );
+    for (flag, name) in [
+        (StatVfsMountFlags::NOSUID, "nosuid"),
+        (StatVfsMountFlags::NODEV, "nodev"),
+        (StatVfsMountFlags::NOEXEC, "noexec"),
+        (StatVfsMountFlags::RELATIME, "relatime"),
+    ] {
+        assert_eq!(flags.contains(flag), options.contains(&name), "{}", name);
+    }
+}
@@ -37,3 +37,54 @@ fn test_statx_reserved() {
         Err(err) => assert_eq!(err, rustix::io::Errno::INVAL),
     }
 }
+
+#[test]
+fn test_direct_io_alignment() {
+    use rustix::fs::{direct_io_alignment, Mode, OFlags};
+
+    let f = std::fs::File::open("Cargo.toml").unwrap();
+    if let Some(align) = direct_io_alignment(&f).unwrap() {
+        assert!(align.mem_align.is_power_of_two());
+        assert!(align.offset_align.is_power_of_two());
+
+        // If the file supports direct I/O, we should be able to open it
+        // with `O_DIRECT`.
+        rustix::fs::open("Cargo.toml", OFlags::RDONLY | OFlags::DIRECT, Mode::empty()).unwrap();
+    }
+}
+
+#[test]
+fn test_statx_btime_mnt_id() {
+    use rustix::fs::{statx, AtFlags, StatxFlags, StatxTimestamp};
+
+    let tmp = tempfile::tempfile().unwrap();
+    let mask = StatxFlags::BASIC_STATS | StatxFlags::BTIME | StatxFlags::MNT_ID;
+    let stx = match statx(&tmp, "", AtFlags::EMPTY_PATH, mask) {
+        Err(rustix::io::Errno::NOSYS) => return,
+        otherwise => otherwise.unwrap(),
+    };
+    let stx_mask = StatxFlags::from_bits_retain(stx.stx_mask);
+    assert!(stx_mask.contains(StatxFlags::BASIC_STATS));
+
+    // Not all filesystems record the creation time, but if it's there, the
+    // file can't have been modified before it was created.
+    if stx_mask.contains(StatxFlags::BTIME) {
+        let btime: StatxTimestamp = stx.stx_btime;
+        assert!(btime.tv_nsec < 1_000_000_000);
+        assert!((btime.tv_sec, btime.tv_nsec) <= (stx.stx_mtime.tv_sec, stx.stx_mtime.tv_nsec));
+    }
+
+    // Check the mount ID against `/proc/self/fdinfo`.
+    if stx_mask.contains(StatxFlags::MNT_ID) {
+        use rustix::fd::AsRawFd;
+
+        let fdinfo = match std::fs::read_to_string(format!("/proc/self/fdinfo/{}", tmp.as_raw_fd()))
+        {
+            Ok(fdinfo) => fdinfo,
+            Err(_) => return,
+        };
+        if let Some(mnt_id) = fdinfo.lines().find_map(|line| line.strip_prefix("mnt_id:")) {
+            assert_eq!(mnt_id.trim().parse::<u64>().unwrap(), stx.stx_mnt_id);
+        }
+    }
+}
@@ -43,3 +43,25 @@ fn test_symlinkat() {
         libc::S_IFLNK as u64
     );
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_readlinkat_empty_path() {
+    use rustix::fs::{openat, readlinkat, symlinkat, Mode, OFlags, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    symlinkat("target", &dir, "link").unwrap();
+
+    // Open the symlink itself, and read it through the file descriptor.
+    let link = openat(
+        &dir,
+        "link",
+        OFlags::PATH | OFlags::NOFOLLOW | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    let target = readlinkat(&link, "", Vec::new()).unwrap();
+    assert_eq!(target.to_bytes(), b"target");
+}
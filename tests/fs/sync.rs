@@ -9,3 +9,21 @@ fn test_syncfs() {
     let f = std::fs::File::open("Cargo.toml").unwrap();
     rustix::fs::syncfs(&f).unwrap();
 }
+
+#[test]
+fn test_fsync_full() {
+    use rustix::io::pwrite;
+
+    let f = tempfile::tempfile().unwrap();
+    pwrite(&f, b"data", 0).unwrap();
+    rustix::fs::fsync_full(&f).unwrap();
+
+    #[cfg(apple)]
+    {
+        pwrite(&f, b"more", 4).unwrap();
+        match rustix::fs::fcntl_barrierfsync(&f) {
+            Ok(()) | Err(rustix::io::Errno::NOTSUP) | Err(rustix::io::Errno::INVAL) => {}
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use rustix::fs::{
+    create_temp_dir_in, create_temp_file_in, create_unnamed_temp_file_in, fstat, openat, statat,
+    AtFlags, FileType, Mode, OFlags, CWD,
+};
+use rustix::io::{pread, write};
+
+#[test]
+fn test_create_temp_file_in() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let (file, name) = create_temp_file_in(&dir, "prefix.").unwrap();
+    assert!(name.as_bytes().starts_with(b"prefix."));
+    assert_eq!(name.as_bytes().len(), "prefix.".len() + 12);
+    assert_eq!(name.as_c_str().to_bytes(), name.as_bytes());
+
+    let stat = statat(&dir, name.as_c_str(), AtFlags::empty()).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::RegularFile);
+    assert_eq!(stat.st_mode & 0o777, 0o600);
+    assert_eq!(stat.st_ino, fstat(&file).unwrap().st_ino);
+
+    let (_other, other_name) = create_temp_file_in(&dir, "prefix.").unwrap();
+    assert_ne!(name.as_bytes(), other_name.as_bytes());
+
+    assert_eq!(
+        create_temp_file_in(&dir, "a/b").unwrap_err(),
+        rustix::io::Errno::INVAL
+    );
+}
+
+#[test]
+fn test_create_temp_dir_in() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let name = create_temp_dir_in(&dir, "dir").unwrap();
+    assert!(name.as_bytes().starts_with(b"dir"));
+
+    let stat = statat(&dir, name.as_c_str(), AtFlags::empty()).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Directory);
+    assert_eq!(stat.st_mode & 0o777, 0o700);
+}
+
+#[test]
+fn test_create_unnamed_temp_file_in() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let file = create_unnamed_temp_file_in(&dir).unwrap();
+    assert_eq!(write(&file, b"hello"), Ok(5));
+    let mut buf = [0_u8; 5];
+    assert_eq!(pread(&file, &mut buf, 0), Ok(5));
+    assert_eq!(&buf, b"hello");
+
+    // The file doesn't appear in the directory.
+    assert_eq!(std::fs::read_dir(tmp.path()).unwrap().count(), 0);
+}
@@ -0,0 +1,137 @@
+use rustix::fs::{Walker, CWD};
+use std::collections::BTreeSet;
+use std::ffi::CString;
+use std::os::unix::fs::symlink;
+
+/// Walk `root`, returning the `(depth, name)` of each entry.
+fn walk(root: &std::path::Path) -> BTreeSet<(usize, CString)> {
+    let dir = rustix::fs::openat(
+        CWD,
+        root,
+        rustix::fs::OFlags::RDONLY,
+        rustix::fs::Mode::empty(),
+    )
+    .unwrap();
+    let mut walker = Walker::new(&dir).unwrap();
+    let mut entries = BTreeSet::new();
+    while let Some(entry) = walker.next() {
+        let entry = entry.unwrap();
+        assert!(entries.insert((entry.depth(), entry.file_name().to_owned())));
+    }
+    entries
+}
+
+/// Create the tree `a/b/c/`, `a/b/file`, `empty/`, and `top` under `root`.
+pub(crate) fn create_tree(root: &std::path::Path) {
+    std::fs::create_dir_all(root.join("a/b/c")).unwrap();
+    std::fs::create_dir(root.join("empty")).unwrap();
+    std::fs::write(root.join("a/b/file"), b"").unwrap();
+    std::fs::write(root.join("top"), b"").unwrap();
+}
+
+fn set(items: &[(usize, &str)]) -> BTreeSet<(usize, CString)> {
+    items
+        .iter()
+        .map(|(depth, name)| (*depth, CString::new(*name).unwrap()))
+        .collect()
+}
+
+#[test]
+fn test_walker() {
+    let tmp = tempfile::tempdir().unwrap();
+    create_tree(tmp.path());
+
+    // Symlinks, including ones which point out of the tree, aren't followed.
+    symlink("..", tmp.path().join("a/up")).unwrap();
+    symlink("/", tmp.path().join("a/b/root")).unwrap();
+
+    assert_eq!(
+        walk(tmp.path()),
+        set(&[
+            (0, "a"),
+            (0, "empty"),
+            (0, "top"),
+            (1, "b"),
+            (1, "up"),
+            (2, "c"),
+            (2, "file"),
+            (2, "root"),
+        ])
+    );
+}
+
+#[test]
+fn test_walker_skip_dir() {
+    let tmp = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(tmp.path().join("skip/inner")).unwrap();
+    std::fs::create_dir_all(tmp.path().join("keep/inner")).unwrap();
+
+    let mut walker = Walker::new(tempfile_dir(&tmp)).unwrap();
+    let mut entries = BTreeSet::new();
+    while let Some(entry) = walker.next() {
+        let entry = entry.unwrap();
+        let skip = entry.file_name().to_bytes() == b"skip";
+        entries.insert((entry.depth(), entry.file_name().to_owned()));
+        if skip {
+            walker.skip_dir();
+        }
+    }
+    assert_eq!(entries, set(&[(0, "keep"), (0, "skip"), (1, "inner")]));
+}
+
+fn tempfile_dir(tmp: &tempfile::TempDir) -> rustix::fd::OwnedFd {
+    rustix::fs::openat(
+        CWD,
+        tmp.path(),
+        rustix::fs::OFlags::RDONLY,
+        rustix::fs::Mode::empty(),
+    )
+    .unwrap()
+}
+
+/// Test walking a tree deeper than the file descriptor limit. This lowers
+/// `RLIMIT_NOFILE`, so it runs itself in a child process.
+#[cfg(feature = "process")]
+#[test]
+fn test_walker_deeper_than_fd_limit() {
+    use rustix::process::{getrlimit, setrlimit, Resource, Rlimit};
+
+    const DEPTH: usize = 64;
+    const VAR: &str = "RUSTIX_TEST_WALKER_CHILD";
+
+    if std::env::var_os(VAR).is_none() {
+        let status = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", "walk::test_walker_deeper_than_fd_limit"])
+            .args(["--test-threads", "1"])
+            .env(VAR, "1")
+            .status()
+            .unwrap();
+        assert!(status.success());
+        return;
+    }
+
+    let tmp = tempfile::tempdir().unwrap();
+    let mut path = tmp.path().to_owned();
+    let mut expected = Vec::new();
+    for depth in 0..DEPTH {
+        std::fs::write(path.join("file"), b"").unwrap();
+        path.push("dir");
+        std::fs::create_dir(&path).unwrap();
+        expected.push((depth, "dir"));
+        expected.push((depth, "file"));
+    }
+
+    let old = getrlimit(Resource::Nofile);
+    setrlimit(
+        Resource::Nofile,
+        Rlimit {
+            current: Some(DEPTH as u64 / 2),
+            maximum: old.maximum,
+        },
+    )
+    .unwrap();
+
+    assert_eq!(walk(tmp.path()), set(&expected));
+
+    setrlimit(Resource::Nofile, old).unwrap();
+}
@@ -119,3 +119,122 @@ fn xattr_basic() {
         enodata
     );
 }
+
+#[test]
+fn xattr_names() {
+    use rustix::fs::XattrNames;
+
+    let names: Vec<_> = XattrNames::new(b"user.a\0security.selinux\0user.b\0").collect();
+    assert_eq!(
+        names,
+        [
+            rustix::cstr!("user.a"),
+            rustix::cstr!("security.selinux"),
+            rustix::cstr!("user.b")
+        ]
+    );
+
+    assert_eq!(XattrNames::new(b"").next(), None);
+
+    // Trailing bytes without a NUL terminator are ignored.
+    let mut names = XattrNames::new(b"user.a\0user.");
+    assert_eq!(names.next(), Some(rustix::cstr!("user.a")));
+    assert_eq!(names.next(), None);
+    assert_eq!(names.next(), None);
+}
+
+#[test]
+fn xattr_list_names() {
+    use rustix::fs::{flistxattr_into, fsetxattr, XattrFlags, XattrNames};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let file = std::fs::File::create(tmp.path().join("file")).unwrap();
+
+    match fsetxattr(&file, "user.rustix.a", b"a", XattrFlags::CREATE) {
+        Ok(()) => {}
+        // The filesystem may not support user xattrs.
+        Err(rustix::io::Errno::NOTSUP) | Err(rustix::io::Errno::PERM) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    fsetxattr(&file, "user.rustix.b", b"b", XattrFlags::CREATE).unwrap();
+
+    let list = flistxattr_into(&file, Vec::new()).unwrap();
+    let mut names: Vec<_> = XattrNames::new(&list)
+        .filter(|name| name.to_bytes().starts_with(b"user.rustix."))
+        .collect();
+    names.sort();
+    assert_eq!(
+        names,
+        [
+            rustix::cstr!("user.rustix.a"),
+            rustix::cstr!("user.rustix.b")
+        ]
+    );
+}
+
+#[test]
+fn xattr_get_value() {
+    use rustix::fs::{fgetxattr, fgetxattr_into, fsetxattr, getxattr_into, XattrFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("file");
+    let file = std::fs::File::create(&path).unwrap();
+
+    // Larger than the initial allocation, so a `Vec` has to grow.
+    let big = vec![b'x'; 1000];
+    match fsetxattr(&file, "user.rustix.big", &big, XattrFlags::CREATE) {
+        Ok(()) => {}
+        // The filesystem may not support user xattrs.
+        Err(rustix::io::Errno::NOTSUP) | Err(rustix::io::Errno::PERM) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    fsetxattr(&file, "user.rustix.empty", b"", XattrFlags::CREATE).unwrap();
+
+    assert_eq!(
+        getxattr_into(&path, "user.rustix.big", Vec::new()).unwrap(),
+        big
+    );
+    assert_eq!(
+        fgetxattr_into(&file, "user.rustix.big", Vec::new()).unwrap(),
+        big
+    );
+
+    // Reuse an existing allocation.
+    let mut reuse = Vec::with_capacity(2048);
+    assert_eq!(
+        fgetxattr_into(&file, "user.rustix.big", &mut reuse).unwrap(),
+        &big[..]
+    );
+    assert_eq!(reuse, big);
+
+    // Fixed-size buffers.
+    let mut buf = [0_u8; 1024];
+    assert_eq!(
+        fgetxattr_into(&file, "user.rustix.big", &mut buf).unwrap(),
+        &big[..]
+    );
+    let mut small = [0_u8; 16];
+    assert_eq!(
+        fgetxattr_into(&file, "user.rustix.big", &mut small),
+        Err(rustix::io::Errno::RANGE)
+    );
+    assert_eq!(
+        fgetxattr_into(&file, "user.rustix.big", &mut []),
+        Err(rustix::io::Errno::RANGE)
+    );
+
+    // With an empty slice, the 0.38 API returns the size of the value.
+    assert_eq!(fgetxattr(&file, "user.rustix.big", &mut []), Ok(big.len()));
+    let mut buf = [0_u8; 1024];
+    assert_eq!(fgetxattr(&file, "user.rustix.big", &mut buf), Ok(big.len()));
+    assert_eq!(&buf[..big.len()], &big[..]);
+
+    // Empty values fit in any buffer.
+    assert_eq!(
+        fgetxattr_into(&file, "user.rustix.empty", &mut []).unwrap(),
+        b""
+    );
+    assert!(getxattr_into(&path, "user.rustix.empty", Vec::new())
+        .unwrap()
+        .is_empty());
+}
@@ -48,3 +48,36 @@ fn test_dup() {
         Ok(12)
     );
 }
+
+#[cfg(all(feature = "fs", not(target_os = "wasi")))]
+#[test]
+fn test_dup2_raw() {
+    use rustix::fd::AsRawFd;
+
+    // Pick a number well above anything the test harness has open.
+    const TARGET: rustix::fd::RawFd = 900;
+
+    let file = std::fs::File::open("Cargo.toml").unwrap();
+    let target = unsafe { rustix::io::dup2_raw(&file, TARGET).unwrap() };
+    assert_eq!(target.as_raw_fd(), TARGET);
+
+    // The new descriptor shares the file description.
+    let mut buf = [0_u8; 4];
+    assert_eq!(rustix::io::read(&file, &mut buf), Ok(4));
+    assert_eq!(
+        rustix::fs::seek(&target, rustix::fs::SeekFrom::Current(0)),
+        Ok(4)
+    );
+
+    // `dup2` doesn't set `FD_CLOEXEC`.
+    assert!(!rustix::io::fcntl_getfd(&target)
+        .unwrap()
+        .contains(rustix::io::FdFlags::CLOEXEC));
+
+    // Dropping the returned `OwnedFd` closes the descriptor.
+    drop(target);
+    assert_eq!(
+        rustix::io::fcntl_getfd(unsafe { rustix::fd::BorrowedFd::borrow_raw(TARGET) }),
+        Err(rustix::io::Errno::BADF)
+    );
+}
@@ -0,0 +1,46 @@
+use rustix::io::is_read_write;
+use rustix::net::{shutdown, Shutdown};
+use std::net::TcpListener;
+use std::os::unix::net::UnixStream;
+
+#[test]
+fn test_is_read_write_shutdown() {
+    let (a, b) = UnixStream::pair().unwrap();
+    assert_eq!(is_read_write(&a).unwrap(), (true, true));
+
+    shutdown(&a, Shutdown::Write).unwrap();
+    assert_eq!(is_read_write(&a).unwrap(), (true, false));
+
+    // The peer sees the end of the stream.
+    assert_eq!(is_read_write(&b).unwrap(), (false, true));
+
+    shutdown(&a, Shutdown::Read).unwrap();
+    assert_eq!(is_read_write(&a).unwrap(), (false, false));
+}
+
+#[test]
+fn test_is_read_write_pending_data() {
+    use std::io::Write;
+
+    let (mut a, b) = UnixStream::pair().unwrap();
+    a.write_all(b"x").unwrap();
+    shutdown(&a, Shutdown::Write).unwrap();
+
+    // Unread data is still readable, and isn't consumed.
+    assert_eq!(is_read_write(&b).unwrap(), (true, true));
+    assert_eq!(rustix::io::ioctl_fionread(&b).unwrap(), 1);
+}
+
+#[test]
+fn test_is_read_write_unconnected() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    assert_eq!(is_read_write(&listener).unwrap(), (false, false));
+
+    let socket = rustix::net::socket(
+        rustix::net::AddressFamily::INET,
+        rustix::net::SocketType::STREAM,
+        None,
+    )
+    .unwrap();
+    assert_eq!(is_read_write(&socket).unwrap(), (false, false));
+}
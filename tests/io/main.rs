@@ -8,6 +8,9 @@ mod error;
 mod from_into;
 #[cfg(not(target_os = "redox"))]
 mod ioctl;
+#[cfg(all(unix, feature = "fs", feature = "net"))]
+#[cfg(not(target_os = "redox"))]
+mod is_read_write;
 #[cfg(not(windows))]
 #[cfg(not(target_os = "redox"))] // redox doesn't have cwd/openat
 mod read_write;
@@ -1,4 +1,4 @@
-#[cfg(feature = "fs")]
+#[cfg(any(feature = "fs", feature = "pipe"))]
 use std::io::{IoSlice, IoSliceMut};
 
 #[cfg(feature = "fs")]
@@ -174,6 +174,53 @@ fn test_readwrite_uninit() {
     assert_eq!(&init, b"world");
 }
 
+#[cfg(feature = "fs")]
+#[test]
+fn test_read_to_vec() {
+    use rustix::fs::{openat, seek, Mode, OFlags, SeekFrom, CWD};
+    use rustix::io::{read_to_vec, read_to_vec_capped, write};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let data: Vec<u8> = (0..100_000_u32).map(|i| i as u8).collect();
+    write(&file, &data).unwrap();
+
+    // Data is appended to what's already in the buffer.
+    seek(&file, SeekFrom::Start(0)).unwrap();
+    let mut buf = b"prefix".to_vec();
+    assert_eq!(read_to_vec(&file, &mut buf), Ok(data.len()));
+    assert_eq!(&buf[..6], b"prefix");
+    assert_eq!(&buf[6..], &data[..]);
+
+    // At end-of-file, nothing more is read.
+    assert_eq!(read_to_vec(&file, &mut buf), Ok(0));
+    assert_eq!(buf.len(), 6 + data.len());
+
+    // A buffer with exactly enough capacity isn't reallocated.
+    seek(&file, SeekFrom::Start(0)).unwrap();
+    let mut buf = Vec::with_capacity(data.len());
+    assert_eq!(read_to_vec(&file, &mut buf), Ok(data.len()));
+    assert_eq!(buf, data);
+    assert_eq!(buf.capacity(), data.len());
+
+    // The capped variant stops at the limit, and can be resumed.
+    seek(&file, SeekFrom::Start(0)).unwrap();
+    let mut buf = Vec::new();
+    assert_eq!(read_to_vec_capped(&file, &mut buf, 1000), Ok(1000));
+    assert_eq!(&buf[..], &data[..1000]);
+    assert_eq!(read_to_vec_capped(&file, &mut buf, 1_000_000), Ok(99_000));
+    assert_eq!(buf, data);
+    assert_eq!(read_to_vec_capped(&file, &mut buf, 0), Ok(0));
+}
+
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 #[test]
 fn test_rwf_values() {
@@ -418,3 +465,82 @@ fn test_p_offsets() {
         }
     }
 }
+
+#[cfg(feature = "pipe")]
+#[test]
+fn test_write_all_read_exact() {
+    use rustix::io::{read_exact, write_all};
+    use rustix::pipe::pipe;
+
+    let (reader, writer) = pipe().unwrap();
+
+    // More than fits in a pipe buffer, so the writes are partial.
+    let data: Vec<u8> = (0..1_000_000_u32).map(|i| i as u8).collect();
+    let thread = {
+        let data = data.clone();
+        std::thread::spawn(move || write_all(&writer, &data))
+    };
+    let mut buf = vec![0_u8; data.len()];
+    read_exact(&reader, &mut buf).unwrap();
+    assert_eq!(buf, data);
+    thread.join().unwrap().unwrap();
+
+    // The writer has been closed, so this reaches end-of-file.
+    assert_eq!(
+        read_exact(&reader, &mut [0_u8; 1]),
+        Err(rustix::io::Errno::IO)
+    );
+    assert_eq!(read_exact(&reader, &mut []), Ok(()));
+}
+
+#[cfg(feature = "pipe")]
+#[cfg(not(target_os = "espidf"))] // no readv/writev
+#[test]
+fn test_write_all_read_exact_vectored() {
+    use rustix::io::{read_exact_vectored, write_all_vectored};
+    use rustix::pipe::pipe;
+
+    let (reader, writer) = pipe().unwrap();
+
+    let a: Vec<u8> = (0..300_000_u32).map(|i| i as u8).collect();
+    let b: Vec<u8> = (0..500_000_u32).map(|i| (i / 3) as u8).collect();
+    let thread = {
+        let (a, b) = (a.clone(), b.clone());
+        std::thread::spawn(move || {
+            write_all_vectored(
+                &writer,
+                &mut [
+                    IoSlice::new(&[]),
+                    IoSlice::new(&a),
+                    IoSlice::new(&[]),
+                    IoSlice::new(b"x"),
+                    IoSlice::new(&b),
+                ],
+            )
+        })
+    };
+
+    // Read into differently-sized buffers, so that partial reads end in the
+    // middle of them.
+    let mut c = vec![0_u8; 7];
+    let mut d = vec![0_u8; 654_321];
+    let mut e = vec![0_u8; a.len() + 1 + b.len() - c.len() - d.len()];
+    read_exact_vectored(
+        &reader,
+        &mut [
+            IoSliceMut::new(&mut c),
+            IoSliceMut::new(&mut []),
+            IoSliceMut::new(&mut d),
+            IoSliceMut::new(&mut e),
+        ],
+    )
+    .unwrap();
+    thread.join().unwrap().unwrap();
+
+    let expected: Vec<u8> = [&a[..], b"x", &b[..]].concat();
+    assert_eq!([c, d, e].concat(), expected);
+    assert_eq!(
+        read_exact_vectored(&reader, &mut [IoSliceMut::new(&mut [0_u8; 1])]),
+        Err(rustix::io::Errno::IO)
+    );
+}
@@ -147,6 +147,17 @@ fn test_madvise() {
         #[cfg(linux_kernel)]
         madvise(addr, 8192, Advice::LinuxDontNeed).unwrap();
 
+        // `MADV_COLLAPSE` may be unsupported, and may fail to collapse a
+        // region smaller than a huge page; just check that it's recognized.
+        #[cfg(linux_kernel)]
+        match madvise(addr, 8192, Advice::LinuxCollapse) {
+            Ok(())
+            | Err(rustix::io::Errno::INVAL)
+            | Err(rustix::io::Errno::AGAIN)
+            | Err(rustix::io::Errno::NOMEM) => {}
+            Err(err) => panic!("{:?}", err),
+        }
+
         munmap(addr, 8192).unwrap();
     }
 }
@@ -166,6 +177,51 @@ fn test_msync() {
     }
 }
 
+#[cfg(feature = "fs")]
+#[cfg(not(target_os = "redox"))]
+#[test]
+fn test_msync_shared() {
+    use rustix::fs::{openat, Mode, OFlags, CWD};
+    use rustix::io::{pread, write};
+    use rustix::mm::{mmap, msync, munmap, MapFlags, MsyncFlags, ProtFlags};
+    use std::ptr::null_mut;
+    use std::slice;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::RDWR | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    write(&file, &[b'a'; 8192]).unwrap();
+
+    unsafe {
+        let addr = mmap(
+            null_mut(),
+            8192,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::SHARED,
+            &file,
+            0,
+        )
+        .unwrap();
+        let slice = slice::from_raw_parts_mut(addr.cast::<u8>(), 8192);
+        slice[..4096].fill(b'b');
+
+        msync(addr, 8192, MsyncFlags::SYNC).unwrap();
+        munmap(addr, 8192).unwrap();
+    }
+
+    let mut buf = [0_u8; 8192];
+    assert_eq!(pread(&file, &mut buf, 0).unwrap(), 8192);
+    assert_eq!(&buf[..4096], &[b'b'; 4096]);
+    assert_eq!(&buf[4096..], &[b'a'; 4096]);
+}
+
 #[cfg(any(target_os = "emscripten", target_os = "linux"))]
 #[test]
 fn test_mremap() {
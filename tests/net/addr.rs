@@ -30,6 +30,48 @@ fn encode_decode() {
     }
 }
 
+#[cfg(linux_kernel)]
+#[test]
+fn encode_decode_raw() {
+    use rustix::net::{AddressFamily, SocketAddrAny, SocketAddrStorage};
+
+    // A `sockaddr_ll`, which rustix doesn't decode: `sll_family`,
+    // `sll_protocol`, `sll_ifindex`, `sll_hatype`, `sll_pkttype`,
+    // `sll_halen`, and `sll_addr`.
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&AddressFamily::PACKET.as_raw().to_ne_bytes());
+    bytes.extend_from_slice(&0x0800_u16.to_be_bytes());
+    bytes.extend_from_slice(&2_i32.to_ne_bytes());
+    bytes.extend_from_slice(&1_u16.to_ne_bytes());
+    bytes.extend_from_slice(&[0, 6]);
+    bytes.extend_from_slice(&[0x02, 0x42, 0xac, 0x11, 0x00, 0x02, 0, 0]);
+
+    unsafe {
+        let mut encoded = std::mem::MaybeUninit::<SocketAddrStorage>::zeroed();
+        std::ptr::copy_nonoverlapping(
+            bytes.as_ptr(),
+            encoded.as_mut_ptr().cast::<u8>(),
+            bytes.len(),
+        );
+        let decoded = SocketAddrAny::read(encoded.as_ptr(), bytes.len()).unwrap();
+        assert_eq!(decoded.address_family(), AddressFamily::PACKET);
+        let raw = match &decoded {
+            SocketAddrAny::Raw(raw) => raw,
+            other => panic!("unexpected address {:?}", other),
+        };
+        assert_eq!(raw.address_family(), AddressFamily::PACKET);
+        assert_eq!(raw.as_bytes(), &bytes[..]);
+
+        let mut reencoded = std::mem::MaybeUninit::<SocketAddrStorage>::uninit();
+        let len = decoded.write(reencoded.as_mut_ptr());
+        assert_eq!(len, bytes.len());
+        assert_eq!(
+            std::slice::from_raw_parts(reencoded.as_ptr().cast::<u8>(), len),
+            &bytes[..]
+        );
+    }
+}
+
 #[cfg(not(windows))]
 #[test]
 fn test_unix_addr() {
@@ -90,3 +132,103 @@ fn test_unix_addr() {
             .is_none());
     }
 }
+
+#[cfg(not(windows))]
+#[test]
+fn test_unix_addr_const() {
+    use rustix::cstr;
+    use rustix::io::Errno;
+    use rustix::net::SocketAddrUnix;
+
+    const fn unwrap(addr: rustix::io::Result<SocketAddrUnix>) -> SocketAddrUnix {
+        match addr {
+            Ok(addr) => addr,
+            Err(_) => panic!("invalid address"),
+        }
+    }
+
+    const PATH: SocketAddrUnix = unwrap(SocketAddrUnix::new_path_bytes(b"/foo/bar"));
+    assert_eq!(PATH.path().unwrap(), cstr!("/foo/bar"));
+    assert_eq!(PATH, SocketAddrUnix::new("/foo/bar").unwrap());
+
+    assert_eq!(
+        SocketAddrUnix::new_path_bytes(b"/foo\0/bar").unwrap_err(),
+        Errno::INVAL
+    );
+    assert_eq!(
+        SocketAddrUnix::new_path_bytes(&[b'a'; 2000]).unwrap_err(),
+        Errno::NAMETOOLONG
+    );
+
+    #[cfg(linux_kernel)]
+    {
+        const ABSTRACT: SocketAddrUnix = unwrap(SocketAddrUnix::new_abstract_name(b"test"));
+        assert_eq!(ABSTRACT.abstract_name().unwrap(), b"test");
+    }
+}
+
+#[cfg(not(windows))]
+#[test]
+fn test_unix_addr_unnamed() {
+    use rustix::net::SocketAddrUnix;
+
+    const UNNAMED: SocketAddrUnix = SocketAddrUnix::new_unnamed();
+    assert!(UNNAMED.is_unnamed());
+    assert!(UNNAMED.path().is_none());
+    assert_eq!(format!("{:?}", UNNAMED), "\"(unnamed)\"");
+    assert_eq!(UNNAMED, SocketAddrUnix::new_unnamed());
+
+    assert!(!SocketAddrUnix::new("foo").unwrap().is_unnamed());
+    assert_ne!(UNNAMED, SocketAddrUnix::new("foo").unwrap());
+
+    #[cfg(linux_kernel)]
+    {
+        assert!(UNNAMED.abstract_name().is_none());
+
+        // An empty abstract name is distinct from an unnamed address.
+        let empty = SocketAddrUnix::new_abstract_name(b"").unwrap();
+        assert!(!empty.is_unnamed());
+        assert_ne!(UNNAMED, empty);
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_unix_addr_std() {
+    use rustix::net::SocketAddrUnix;
+    use std::os::unix::net::SocketAddr;
+
+    let tmpdir = tempfile::tempdir().unwrap();
+    let path = tmpdir.path().join("sock");
+    let listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+    let std_addr = listener.local_addr().unwrap();
+
+    let addr = SocketAddrUnix::try_from(&std_addr).unwrap();
+    assert_eq!(addr, SocketAddrUnix::new(&path).unwrap());
+
+    let back = SocketAddr::try_from(addr).unwrap();
+    assert_eq!(back.as_pathname(), Some(path.as_path()));
+
+    let unnamed = std::os::unix::net::UnixDatagram::unbound()
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    assert!(unnamed.is_unnamed());
+    let addr = SocketAddrUnix::try_from(unnamed).unwrap();
+    assert!(addr.is_unnamed());
+    assert!(addr.path().is_none());
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_unix_addr_std_abstract() {
+    use rustix::net::SocketAddrUnix;
+    use std::os::unix::net::SocketAddr;
+
+    let addr = SocketAddrUnix::new_abstract_name(b"rustix-test-abstract").unwrap();
+    match SocketAddr::try_from(&addr) {
+        Ok(std_addr) => assert_eq!(SocketAddrUnix::try_from(std_addr).unwrap(), addr),
+        // Older Rust versions can't represent abstract addresses in std.
+        Err(err) => assert_eq!(err, rustix::io::Errno::INVAL),
+    }
+}
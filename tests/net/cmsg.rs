@@ -33,3 +33,360 @@ fn test_buffer_sizes() {
     assert!(cmsg_space!(ScmRights(1)) * 2 >= cmsg_space!(ScmRights(1), ScmRights(1)));
     assert!(cmsg_space!(ScmRights(1), ScmRights(0)) >= cmsg_space!(ScmRights(1)));
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_ip_tos_cmsg() {
+    use rustix::cmsg_space;
+    use rustix::io::{IoSlice, IoSliceMut};
+    use rustix::net::{
+        bind_v4, getsockname, recvmsg, sendmsg_v4, socket, sockopt, AddressFamily,
+        RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags, SendAncillaryBuffer,
+        SendAncillaryMessage, SendFlags, SocketAddrAny, SocketType,
+    };
+    use std::net::{Ipv4Addr, SocketAddrV4};
+
+    let receiver = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    bind_v4(&receiver, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = match getsockname(&receiver).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        _ => panic!("expected an IPv4 address"),
+    };
+    sockopt::set_ip_recvtos(&receiver, true).unwrap();
+
+    let sender = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+
+    // Send one packet with the socket's TOS, and one with a per-packet TOS.
+    sockopt::set_ip_tos(&sender, 0x28).unwrap();
+    sendmsg_v4(
+        &sender,
+        &addr,
+        &[IoSlice::new(b"a")],
+        &mut SendAncillaryBuffer::default(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let mut space = [0; cmsg_space!(IpTos(1))];
+    let mut cmsg_buffer = SendAncillaryBuffer::new(&mut space);
+    assert!(cmsg_buffer.push(SendAncillaryMessage::IpTos(0xb8)));
+    sendmsg_v4(
+        &sender,
+        &addr,
+        &[IoSlice::new(b"b")],
+        &mut cmsg_buffer,
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    for expected in [0x28, 0xb8] {
+        let mut data = [0_u8; 1];
+        let mut space = [0; cmsg_space!(IpTos(1))];
+        let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+        recvmsg(
+            &receiver,
+            &mut [IoSliceMut::new(&mut data)],
+            &mut cmsg_buffer,
+            RecvFlags::empty(),
+        )
+        .unwrap();
+
+        let tos = cmsg_buffer.drain().find_map(|msg| match msg {
+            RecvAncillaryMessage::IpTos(tos) => Some(tos),
+            _ => None,
+        });
+        assert_eq!(tos, Some(expected));
+    }
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_ip_pktinfo_cmsg() {
+    use rustix::cmsg_space;
+    use rustix::io::{IoSlice, IoSliceMut};
+    use rustix::net::{
+        bind_v4, getsockname, recvfrom, recvmsg, sendmsg_v4, sendto_v4, socket, sockopt,
+        AddressFamily, IpPktInfo, RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags,
+        SendAncillaryBuffer, SendAncillaryMessage, SendFlags, SocketAddrAny, SocketType,
+    };
+    use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+    // Bind to the wildcard address, so we don't know which address packets
+    // are sent to without `IP_PKTINFO`.
+    let server = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    bind_v4(&server, &SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0)).unwrap();
+    let port = match getsockname(&server).unwrap() {
+        SocketAddrAny::V4(addr) => addr.port(),
+        _ => panic!("expected an IPv4 address"),
+    };
+    assert!(!sockopt::get_ip_pktinfo(&server).unwrap());
+    sockopt::set_ip_pktinfo(&server, true).unwrap();
+    assert!(sockopt::get_ip_pktinfo(&server).unwrap());
+
+    let client = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    let dst = Ipv4Addr::new(127, 0, 0, 2);
+    sendto_v4(
+        &client,
+        b"hello",
+        SendFlags::empty(),
+        &SocketAddrV4::new(dst, port),
+    )
+    .unwrap();
+
+    let mut data = [0_u8; 16];
+    let mut space = [0; cmsg_space!(IpPktInfo(1))];
+    let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+    let result = recvmsg(
+        &server,
+        &mut [IoSliceMut::new(&mut data)],
+        &mut cmsg_buffer,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&data[..result.bytes], b"hello");
+    let from = match result.address {
+        Some(SocketAddrAny::V4(addr)) => addr,
+        other => panic!("unexpected address: {:?}", other),
+    };
+
+    let pktinfo = cmsg_buffer
+        .drain()
+        .find_map(|msg| match msg {
+            RecvAncillaryMessage::IpPktInfo(pktinfo) => Some(pktinfo),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(pktinfo.addr, dst);
+    assert_eq!(pktinfo.spec_dst, dst);
+    assert_ne!(pktinfo.ifindex, 0);
+
+    // Reply from the address the packet was sent to.
+    let mut space = [0; cmsg_space!(IpPktInfo(1))];
+    let mut cmsg_buffer = SendAncillaryBuffer::new(&mut space);
+    assert!(cmsg_buffer.push(SendAncillaryMessage::IpPktInfo(IpPktInfo {
+        ifindex: 0,
+        spec_dst: pktinfo.spec_dst,
+        addr: Ipv4Addr::UNSPECIFIED,
+    })));
+    sendmsg_v4(
+        &server,
+        &from,
+        &[IoSlice::new(b"world")],
+        &mut cmsg_buffer,
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let (n, addr) = recvfrom(&client, &mut data, RecvFlags::empty()).unwrap();
+    assert_eq!(&data[..n], b"world");
+    let addr: SocketAddr = match addr {
+        Some(SocketAddrAny::V4(addr)) => addr.into(),
+        other => panic!("unexpected address: {:?}", other),
+    };
+    assert_eq!(addr, SocketAddr::new(dst.into(), port));
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_ipv6_pktinfo_cmsg() {
+    use rustix::cmsg_space;
+    use rustix::io::{IoSlice, IoSliceMut};
+    use rustix::net::{
+        bind_v6, getsockname, recvfrom, recvmsg, sendmsg_v6, sendto_v6, socket, sockopt,
+        AddressFamily, Ipv6PktInfo, RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags,
+        SendAncillaryBuffer, SendAncillaryMessage, SendFlags, SocketAddrAny, SocketType,
+    };
+    use std::net::{Ipv6Addr, SocketAddrV6};
+
+    let server = socket(AddressFamily::INET6, SocketType::DGRAM, None).unwrap();
+    bind_v6(&server, &SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 0, 0)).unwrap();
+    let port = match getsockname(&server).unwrap() {
+        SocketAddrAny::V6(addr) => addr.port(),
+        _ => panic!("expected an IPv6 address"),
+    };
+    assert!(!sockopt::get_ipv6_recvpktinfo(&server).unwrap());
+    sockopt::set_ipv6_recvpktinfo(&server, true).unwrap();
+    assert!(sockopt::get_ipv6_recvpktinfo(&server).unwrap());
+
+    let client = socket(AddressFamily::INET6, SocketType::DGRAM, None).unwrap();
+    sendto_v6(
+        &client,
+        b"hello",
+        SendFlags::empty(),
+        &SocketAddrV6::new(Ipv6Addr::LOCALHOST, port, 0, 0),
+    )
+    .unwrap();
+
+    let mut data = [0_u8; 16];
+    let mut space = [0; cmsg_space!(Ipv6PktInfo(1))];
+    let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+    let result = recvmsg(
+        &server,
+        &mut [IoSliceMut::new(&mut data)],
+        &mut cmsg_buffer,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&data[..result.bytes], b"hello");
+    let from = match result.address {
+        Some(SocketAddrAny::V6(addr)) => addr,
+        other => panic!("unexpected address: {:?}", other),
+    };
+
+    let pktinfo = cmsg_buffer
+        .drain()
+        .find_map(|msg| match msg {
+            RecvAncillaryMessage::Ipv6PktInfo(pktinfo) => Some(pktinfo),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(pktinfo.addr, Ipv6Addr::LOCALHOST);
+    assert_ne!(pktinfo.ifindex, 0);
+
+    // Reply from the address and interface the packet was received on.
+    let mut space = [0; cmsg_space!(Ipv6PktInfo(1))];
+    let mut cmsg_buffer = SendAncillaryBuffer::new(&mut space);
+    assert!(
+        cmsg_buffer.push(SendAncillaryMessage::Ipv6PktInfo(Ipv6PktInfo {
+            addr: pktinfo.addr,
+            ifindex: pktinfo.ifindex,
+        }))
+    );
+    sendmsg_v6(
+        &server,
+        &from,
+        &[IoSlice::new(b"world")],
+        &mut cmsg_buffer,
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let (n, addr) = recvfrom(&client, &mut data, RecvFlags::empty()).unwrap();
+    assert_eq!(&data[..n], b"world");
+    match addr {
+        Some(SocketAddrAny::V6(addr)) => {
+            assert_eq!(*addr.ip(), Ipv6Addr::LOCALHOST);
+            assert_eq!(addr.port(), port);
+        }
+        other => panic!("unexpected address: {:?}", other),
+    }
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_ip_recverr_cmsg() {
+    use rustix::cmsg_space;
+    use rustix::io::{Errno, IoSliceMut};
+    use rustix::net::{
+        bind_v4, connect_v4, getsockname, recvmsg, send, socket, sockopt, AddressFamily,
+        RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags, SendFlags, SockExtendedErrOrigin,
+        SocketAddrAny, SocketType,
+    };
+    use std::net::{Ipv4Addr, SocketAddrV4};
+
+    // Find a port with nothing listening on it.
+    let closed = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    bind_v4(&closed, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = match getsockname(&closed).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        _ => panic!("expected an IPv4 address"),
+    };
+    drop(closed);
+
+    let sender = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    assert!(!sockopt::get_ip_recverr(&sender).unwrap());
+    sockopt::set_ip_recverr(&sender, true).unwrap();
+    assert!(sockopt::get_ip_recverr(&sender).unwrap());
+    connect_v4(&sender, &addr).unwrap();
+    send(&sender, b"hello", SendFlags::empty()).unwrap();
+
+    let mut data = [0_u8; 16];
+    let mut space = [0; cmsg_space!(IpRecvErr(1))];
+    let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+    let result = recvmsg(
+        &sender,
+        &mut [IoSliceMut::new(&mut data)],
+        &mut cmsg_buffer,
+        RecvFlags::ERRQUEUE,
+    )
+    .unwrap();
+
+    // The error queue returns the original datagram.
+    assert_eq!(&data[..result.bytes], b"hello");
+
+    let err = cmsg_buffer
+        .drain()
+        .find_map(|msg| match msg {
+            RecvAncillaryMessage::IpRecvErr(err) => Some(err),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(err.errno, Errno::CONNREFUSED);
+    assert_eq!(err.origin, SockExtendedErrOrigin::ICMP);
+    // ICMP "destination unreachable", "port unreachable".
+    assert_eq!(err.type_, 3);
+    assert_eq!(err.code, 3);
+    assert_eq!(
+        err.offender,
+        Some(SocketAddrAny::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)))
+    );
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_ipv6_recverr_local_cmsg() {
+    use rustix::cmsg_space;
+    use rustix::io::{Errno, IoSliceMut};
+    use rustix::net::sockopt::{self, PathMtuDiscovery};
+    use rustix::net::{
+        bind_v6, connect_v6, getsockname, recvmsg, send, socket, AddressFamily,
+        RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags, SendFlags, SockExtendedErrOrigin,
+        SocketAddrAny, SocketType,
+    };
+    use std::net::{Ipv6Addr, SocketAddrV6};
+
+    let receiver = socket(AddressFamily::INET6, SocketType::DGRAM, None).unwrap();
+    if bind_v6(&receiver, &SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0, 0, 0)).is_err() {
+        // IPv6 isn't available.
+        return;
+    }
+    let addr = match getsockname(&receiver).unwrap() {
+        SocketAddrAny::V6(addr) => addr,
+        _ => panic!("expected an IPv6 address"),
+    };
+
+    // Sending a datagram larger than the socket's MTU, with fragmentation
+    // disabled, fails locally, and queues an error with no offender.
+    let sender = socket(AddressFamily::INET6, SocketType::DGRAM, None).unwrap();
+    sockopt::set_ipv6_recverr(&sender, true).unwrap();
+    sockopt::set_ipv6_mtu_discover(&sender, PathMtuDiscovery::Do).unwrap();
+    connect_v6(&sender, &addr).unwrap();
+    sockopt::set_ipv6_mtu(&sender, 1280).unwrap();
+    assert_eq!(
+        send(&sender, &[0_u8; 2000], SendFlags::empty()),
+        Err(Errno::MSGSIZE)
+    );
+
+    let mut data = [0_u8; 16];
+    let mut space = [0; cmsg_space!(Ipv6RecvErr(1))];
+    let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+    recvmsg(
+        &sender,
+        &mut [IoSliceMut::new(&mut data)],
+        &mut cmsg_buffer,
+        RecvFlags::ERRQUEUE,
+    )
+    .unwrap();
+
+    let err = cmsg_buffer
+        .drain()
+        .find_map(|msg| match msg {
+            RecvAncillaryMessage::Ipv6RecvErr(err) => Some(err),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(err.errno, Errno::MSGSIZE);
+    assert_eq!(err.origin, SockExtendedErrOrigin::LOCAL);
+    assert_eq!(err.info, 1280);
+    assert_eq!(err.offender, None);
+}
@@ -582,6 +582,65 @@ fn net_v6_acceptfrom() {
     assert_eq!(request, &response[..n]);
 }
 
+/// Test `acceptfrom_into`, `getpeername_into`, and `recvfrom_into`.
+#[test]
+fn net_v4_acceptfrom_into() {
+    use rustix::net::{SocketAddrBuf, SocketFlags};
+
+    let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let addr = SocketAddr::new(localhost, 0);
+    let listener = rustix::net::socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    rustix::net::bind(&listener, &addr).expect("bind");
+    rustix::net::listen(&listener, 1).expect("listen");
+
+    let local_addr = rustix::net::getsockname(&listener).unwrap();
+    let sender = rustix::net::socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    rustix::net::connect_any(&sender, &local_addr).expect("connect");
+    let request = b"Hello, World!!!";
+    let n = rustix::net::send(&sender, request, SendFlags::empty()).expect("send");
+    assert_eq!(n, request.len());
+
+    let mut from = SocketAddrBuf::new();
+    assert_eq!(from.address_family(), None);
+    assert_eq!(from.decode(), None);
+
+    let accepted =
+        rustix::net::acceptfrom_into(&listener, SocketFlags::empty(), &mut from).expect("accept");
+    assert_eq!(from.address_family(), Some(AddressFamily::INET));
+    assert_eq!(
+        from.decode(),
+        Some(rustix::net::getsockname(&sender).unwrap())
+    );
+
+    let mut peer_addr = SocketAddrBuf::new();
+    rustix::net::getpeername_into(&accepted, &mut peer_addr).expect("getpeername");
+    assert_eq!(peer_addr.as_bytes(), from.as_bytes());
+    assert_eq!(peer_addr.decode(), from.decode());
+
+    // Stream sockets don't report a sender address.
+    let mut response = [0_u8; 128];
+    let n = rustix::net::recvfrom_into(&accepted, &mut response, RecvFlags::empty(), &mut from)
+        .expect("recvfrom");
+    assert_eq!(request, &response[..n]);
+    assert_eq!(from.address_family(), None);
+
+    // Datagram sockets do.
+    let receiver = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    rustix::net::bind(&receiver, &addr).expect("bind");
+    let receiver_addr = rustix::net::getsockname(&receiver).unwrap();
+    let sender = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    rustix::net::bind(&sender, &addr).expect("bind");
+    rustix::net::sendto_any(&sender, request, SendFlags::empty(), &receiver_addr).expect("send");
+
+    let n = rustix::net::recvfrom_into(&receiver, &mut response, RecvFlags::empty(), &mut from)
+        .expect("recvfrom");
+    assert_eq!(request, &response[..n]);
+    assert_eq!(
+        from.decode(),
+        Some(rustix::net::getsockname(&sender).unwrap())
+    );
+}
+
 /// Test `shutdown`.
 #[test]
 fn net_shutdown() {
@@ -604,3 +663,103 @@ fn net_shutdown() {
 
     drop(sender);
 }
+
+/// Test `Shutdown::from_raw` and `Shutdown::as_raw`.
+#[test]
+fn net_shutdown_raw() {
+    use rustix::net::Shutdown;
+
+    for how in [Shutdown::Read, Shutdown::Write, Shutdown::ReadWrite] {
+        assert_eq!(Shutdown::from_raw(how.as_raw()), Some(how));
+    }
+
+    #[cfg(unix)]
+    {
+        assert_eq!(Shutdown::Read.as_raw(), libc::SHUT_RD);
+        assert_eq!(Shutdown::Write.as_raw(), libc::SHUT_WR);
+        assert_eq!(Shutdown::ReadWrite.as_raw(), libc::SHUT_RDWR);
+    }
+
+    assert_eq!(Shutdown::from_raw(-1), None);
+    assert_eq!(Shutdown::from_raw(3), None);
+    assert_eq!(Shutdown::from_raw(i32::MAX), None);
+}
+
+/// Test `connect_with_timeout`.
+#[cfg(feature = "event")]
+#[test]
+fn net_v4_connect_with_timeout() {
+    use std::time::Duration;
+
+    let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let addr = SocketAddr::new(localhost, 0);
+    let listener = rustix::net::socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    rustix::net::bind(&listener, &addr).expect("bind");
+    rustix::net::listen(&listener, 1).expect("listen");
+    let local_addr = rustix::net::getsockname(&listener).unwrap();
+
+    let sender = rustix::net::socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    rustix::net::connect_with_timeout(&sender, &local_addr, Duration::from_secs(10))
+        .expect("connect");
+
+    // The socket is left in blocking mode.
+    #[cfg(all(not(windows), feature = "fs"))]
+    assert!(!rustix::fs::fcntl_getfl(&sender)
+        .unwrap()
+        .contains(rustix::fs::OFlags::NONBLOCK));
+
+    let request = b"Hello, World!!!";
+    let n = rustix::net::send(&sender, request, SendFlags::empty()).expect("send");
+    assert_eq!(n, request.len());
+    let accepted = rustix::net::accept(&listener).expect("accept");
+    let mut response = [0_u8; 128];
+    let n = rustix::net::recv(&accepted, &mut response, RecvFlags::empty()).expect("recv");
+    assert_eq!(request, &response[..n]);
+
+    // A socket which was already in non-blocking mode is left that way.
+    let nonblocking = rustix::net::socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    rustix::io::ioctl_fionbio(&nonblocking, true).unwrap();
+    rustix::net::connect_with_timeout(&nonblocking, &local_addr, Duration::from_secs(10))
+        .expect("connect");
+    #[cfg(all(not(windows), feature = "fs"))]
+    assert!(rustix::fs::fcntl_getfl(&nonblocking)
+        .unwrap()
+        .contains(rustix::fs::OFlags::NONBLOCK));
+    let _nonblocking_accepted = rustix::net::accept(&listener).expect("accept");
+
+    // Once the listener's accept queue is full, connections time out.
+    #[cfg(linux_kernel)]
+    {
+        let mut queued = Vec::new();
+        loop {
+            let sender =
+                rustix::net::socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+            match rustix::net::connect_with_timeout(
+                &sender,
+                &local_addr,
+                Duration::from_millis(100),
+            ) {
+                Ok(()) => queued.push(sender),
+                Err(rustix::io::Errno::TIMEDOUT) => break,
+                Err(err) => panic!("{:?}", err),
+            }
+            assert!(queued.len() < 8);
+        }
+    }
+
+    // Once nothing is listening, the connection is refused, and the error is
+    // reported from `SO_ERROR`.
+    drop(accepted);
+    drop(listener);
+    let sender = rustix::net::socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    assert_eq!(
+        rustix::net::connect_with_timeout(&sender, &local_addr, Duration::from_secs(10)),
+        Err(rustix::io::Errno::CONNREFUSED)
+    );
+
+    // Blocking mode is restored even when the connection fails.
+    #[cfg(all(not(windows), feature = "fs"))]
+    assert!(!rustix::fs::fcntl_getfl(&sender)
+        .unwrap()
+        .contains(rustix::fs::OFlags::NONBLOCK));
+}
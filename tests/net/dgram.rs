@@ -725,3 +725,43 @@ fn net_dgram_v6_acceptfrom() {
 
     assert_eq!(request, &response[..n]);
 }
+
+/// Test `recv_datagram_len` and `RecvFlags::TRUNC`.
+#[cfg(linux_kernel)]
+#[test]
+fn net_dgram_recv_trunc() {
+    use std::mem::MaybeUninit;
+
+    let (a, b) = rustix::net::socketpair(
+        AddressFamily::UNIX,
+        SocketType::DGRAM,
+        rustix::net::SocketFlags::CLOEXEC,
+        None,
+    )
+    .unwrap();
+
+    let request = b"Hello, World!!!";
+    rustix::net::send(&a, request, SendFlags::empty()).unwrap();
+    rustix::net::send(&a, request, SendFlags::empty()).unwrap();
+
+    // Peek at the length, without consuming the datagram.
+    assert_eq!(
+        rustix::net::recv_datagram_len(&b, RecvFlags::empty()).unwrap(),
+        request.len()
+    );
+    assert_eq!(
+        rustix::net::recv_datagram_len(&b, RecvFlags::DONTWAIT).unwrap(),
+        request.len()
+    );
+
+    // `TRUNC` reports the full length, even if the buffer is too small.
+    let mut response = [0_u8; 5];
+    let n = rustix::net::recv(&b, &mut response, RecvFlags::TRUNC).unwrap();
+    assert_eq!(n, request.len());
+    assert_eq!(&response, b"Hello");
+
+    let mut response = [MaybeUninit::<u8>::uninit(); 5];
+    let (init, uninit) = rustix::net::recv_uninit(&b, &mut response, RecvFlags::TRUNC).unwrap();
+    assert_eq!(init, b"Hello");
+    assert!(uninit.is_empty());
+}
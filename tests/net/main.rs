@@ -10,6 +10,8 @@ mod addr;
 mod cmsg;
 mod connect_bind_send;
 mod dgram;
+#[cfg(linux_kernel)]
+mod netlink;
 #[cfg(feature = "event")]
 mod poll;
 mod sockopt;
@@ -19,6 +21,8 @@ mod unix;
 mod unix_alloc;
 mod v4;
 mod v6;
+#[cfg(linux_kernel)]
+mod vsock;
 
 /// Windows requires us to call a setup function before using any of the
 /// socket APIs.
@@ -0,0 +1,285 @@
+use rustix::net::netlink::{self, SocketAddrNetlink, Uevent, UEVENT_KERNEL_GROUP};
+use rustix::net::{
+    bind_netlink, genetlink, socket, socket_with, AddressFamily, SocketFlags, SocketType,
+};
+
+#[test]
+fn test_uevent_parse() {
+    let msg = b"remove@/devices/virtual/net/tap0\0ACTION=remove\0DEVPATH=/devices/virtual/net/tap0\0SUBSYSTEM=net\0junk\0\0INTERFACE=tap0\0SEQNUM=1234\0";
+    let event = Uevent::parse(msg).unwrap();
+    assert_eq!(event.action(), b"remove");
+    assert_eq!(event.devpath(), b"/devices/virtual/net/tap0");
+
+    let vars = event.vars().collect::<Vec<_>>();
+    assert_eq!(
+        vars,
+        [
+            (&b"ACTION"[..], &b"remove"[..]),
+            (b"DEVPATH", b"/devices/virtual/net/tap0"),
+            (b"SUBSYSTEM", b"net"),
+            (b"INTERFACE", b"tap0"),
+            (b"SEQNUM", b"1234"),
+        ]
+    );
+    assert_eq!(event.get(b"INTERFACE"), Some(&b"tap0"[..]));
+    assert_eq!(event.get(b"MAJOR"), None);
+
+    // A header with no variables.
+    let event = Uevent::parse(b"change@/devices/foo").unwrap();
+    assert_eq!(event.action(), b"change");
+    assert_eq!(event.vars().next(), None);
+
+    // udev's binary format isn't a kernel uevent.
+    assert!(Uevent::parse(b"libudev\0\xfe\xed\xca\xfe").is_none());
+    assert!(Uevent::parse(b"").is_none());
+}
+
+#[test]
+fn test_uevent_bind() {
+    let fd = match socket_with(
+        AddressFamily::NETLINK,
+        SocketType::DGRAM,
+        SocketFlags::CLOEXEC,
+        Some(netlink::KOBJECT_UEVENT),
+    ) {
+        Ok(fd) => fd,
+        // Netlink may be unavailable in sandboxes.
+        Err(rustix::io::Errno::AFNOSUPPORT | rustix::io::Errno::PROTONOSUPPORT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let addr = SocketAddrNetlink::new(0, UEVENT_KERNEL_GROUP);
+    assert_eq!(addr.pid(), 0);
+    assert_eq!(addr.groups(), UEVENT_KERNEL_GROUP);
+
+    match bind_netlink(&fd, &addr) {
+        // Joining multicast groups may require privileges in some network
+        // namespaces.
+        Ok(()) | Err(rustix::io::Errno::PERM) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_genetlink_resolve_family() {
+    let fd = match socket(
+        AddressFamily::NETLINK,
+        SocketType::RAW,
+        Some(netlink::GENERIC),
+    ) {
+        Ok(fd) => fd,
+        // Netlink may be unavailable in sandboxes.
+        Err(rustix::io::Errno::AFNOSUPPORT | rustix::io::Errno::PROTONOSUPPORT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    assert_eq!(
+        genetlink::resolve_family(&fd, "nlctrl").unwrap(),
+        genetlink::ID_CTRL
+    );
+    assert_eq!(
+        genetlink::resolve_family(&fd, "no-such-family"),
+        Err(rustix::io::Errno::NOENT)
+    );
+    assert_eq!(
+        genetlink::resolve_family(&fd, "a-family-name-which-is-too-long"),
+        Err(rustix::io::Errno::INVAL)
+    );
+}
+
+#[test]
+fn test_sock_diag_inet() {
+    use rustix::net::sock_diag::{self, InetDiagReq, TCP_LISTEN};
+    use rustix::net::{bind_v4, getsockname, ipproto, listen, Ipv4Addr, SocketAddrV4};
+
+    let fd = match socket(
+        AddressFamily::NETLINK,
+        SocketType::RAW,
+        Some(netlink::SOCK_DIAG),
+    ) {
+        Ok(fd) => fd,
+        // Netlink may be unavailable in sandboxes.
+        Err(rustix::io::Errno::AFNOSUPPORT | rustix::io::Errno::PROTONOSUPPORT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let listener = socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    bind_v4(&listener, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    listen(&listener, 3).unwrap();
+    let local = getsockname(&listener).unwrap();
+
+    let mut req = InetDiagReq::new(AddressFamily::INET, ipproto::TCP);
+    req.states = 1 << TCP_LISTEN;
+    let mut found = false;
+    match sock_diag::inet_diag(&fd, &req, |msg| {
+        assert_eq!(msg.state, TCP_LISTEN);
+        // The listener's port is unique, so its address identifies it.
+        if rustix::net::SocketAddrAny::from(msg.source) == local {
+            assert_eq!(msg.family, AddressFamily::INET);
+            #[cfg(feature = "process")]
+            assert_eq!(msg.uid, rustix::process::geteuid().as_raw());
+            found = true;
+        }
+    }) {
+        Ok(()) => assert!(found),
+        // The inet_diag module may not be loaded.
+        Err(rustix::io::Errno::NOENT) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_sock_diag_unix() {
+    use rustix::net::sock_diag::{self, UnixDiagReq, UnixDiagShow, TCP_ESTABLISHED};
+    use rustix::net::socketpair;
+
+    let fd = match socket(
+        AddressFamily::NETLINK,
+        SocketType::RAW,
+        Some(netlink::SOCK_DIAG),
+    ) {
+        Ok(fd) => fd,
+        // Netlink may be unavailable in sandboxes.
+        Err(rustix::io::Errno::AFNOSUPPORT | rustix::io::Errno::PROTONOSUPPORT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        None,
+    )
+    .unwrap();
+    let a_inode = rustix::fs::fstat(&a).unwrap().st_ino as u32;
+    let b_inode = rustix::fs::fstat(&b).unwrap().st_ino as u32;
+    rustix::io::write(&a, b"hello").unwrap();
+
+    let mut req = UnixDiagReq::new(UnixDiagShow::PEER | UnixDiagShow::RQLEN);
+    req.states = 1 << TCP_ESTABLISHED;
+    let mut found = false;
+    match sock_diag::unix_diag(&fd, &req, |msg| {
+        if msg.inode == b_inode {
+            assert_eq!(msg.type_, SocketType::STREAM);
+            assert_eq!(msg.state, TCP_ESTABLISHED);
+            assert_eq!(msg.peer(), Some(a_inode));
+            assert_eq!(msg.queue_lengths().map(|(r, _w)| r), Some(5));
+            assert_eq!(msg.name(), None);
+            found = true;
+        }
+    }) {
+        Ok(()) => assert!(found),
+        // The unix_diag module may not be loaded.
+        Err(rustix::io::Errno::NOENT) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_rtnetlink_routes() {
+    use rustix::net::rtnetlink::{
+        self, Route, RTN_LOCAL, RT_SCOPE_LINK, RT_TABLE_LOCAL, RT_TABLE_MAIN,
+    };
+    use rustix::net::{IpAddr, Ipv4Addr};
+
+    let fd = match socket(AddressFamily::NETLINK, SocketType::RAW, None) {
+        Ok(fd) => fd,
+        // Netlink may be unavailable in sandboxes.
+        Err(rustix::io::Errno::AFNOSUPPORT | rustix::io::Errno::PROTONOSUPPORT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    let inet = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    let lo = rustix::net::netdevice::name_to_index(&inet, "lo").unwrap();
+
+    let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let route = rtnetlink::get_route(&fd, localhost).unwrap();
+    assert_eq!(route.family, AddressFamily::INET);
+    assert_eq!(route.destination, Some(localhost));
+    assert_eq!(route.destination_len, 32);
+    assert_eq!(route.type_, RTN_LOCAL);
+    assert_eq!(route.output_interface, Some(lo));
+
+    let mut found = false;
+    rtnetlink::list_routes(&fd, AddressFamily::INET, |route| {
+        assert_eq!(route.family, AddressFamily::INET);
+        if route.table == RT_TABLE_LOCAL && route.destination == Some(localhost) {
+            found = true;
+        }
+    })
+    .unwrap();
+    assert!(found);
+
+    // Add a route to a documentation prefix through the loopback interface,
+    // if we're privileged enough to.
+    let mut route = Route::new(Ipv4Addr::new(203, 0, 113, 0).into(), 24);
+    route.output_interface = Some(lo);
+    route.scope = RT_SCOPE_LINK;
+    match rtnetlink::add_route(&fd, &route) {
+        Ok(()) => {}
+        Err(rustix::io::Errno::PERM | rustix::io::Errno::ACCESS) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(
+        rtnetlink::add_route(&fd, &route),
+        Err(rustix::io::Errno::EXIST)
+    );
+
+    let chosen = rtnetlink::get_route(&fd, Ipv4Addr::new(203, 0, 113, 5).into()).unwrap();
+    assert_eq!(chosen.output_interface, Some(lo));
+    assert_eq!(chosen.table, RT_TABLE_MAIN);
+
+    rtnetlink::delete_route(&fd, &route).unwrap();
+    assert_eq!(
+        rtnetlink::delete_route(&fd, &route),
+        Err(rustix::io::Errno::SRCH)
+    );
+}
+
+#[test]
+fn test_netlink_usersock_sendto() {
+    use rustix::net::SocketAddrAny;
+    use rustix::net::{getsockname, recvfrom, sendto_any, sendto_netlink, RecvFlags, SendFlags};
+
+    let new_socket = || {
+        socket_with(
+            AddressFamily::NETLINK,
+            SocketType::RAW,
+            SocketFlags::CLOEXEC,
+            Some(netlink::USERSOCK),
+        )
+    };
+    let a = match new_socket() {
+        Ok(fd) => fd,
+        // Netlink may be unavailable in sandboxes.
+        Err(rustix::io::Errno::AFNOSUPPORT | rustix::io::Errno::PROTONOSUPPORT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    let b = new_socket().unwrap();
+
+    // Let the kernel assign port IDs.
+    bind_netlink(&a, &SocketAddrNetlink::new(0, 0)).unwrap();
+    bind_netlink(&b, &SocketAddrNetlink::new(0, 0)).unwrap();
+    let a_addr = match getsockname(&a).unwrap() {
+        SocketAddrAny::Netlink(addr) => addr,
+        other => panic!("unexpected address {:?}", other),
+    };
+    let b_addr = match getsockname(&b).unwrap() {
+        SocketAddrAny::Netlink(addr) => addr,
+        other => panic!("unexpected address {:?}", other),
+    };
+    assert_ne!(a_addr.pid(), 0);
+    assert_ne!(a_addr.pid(), b_addr.pid());
+
+    let mut buf = [0_u8; 16];
+
+    sendto_netlink(&a, b"hello", SendFlags::empty(), &b_addr).unwrap();
+    let (n, from) = recvfrom(&b, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..n], b"hello");
+    assert_eq!(from, Some(SocketAddrAny::Netlink(a_addr)));
+
+    sendto_any(&b, b"world", SendFlags::empty(), &a_addr.into()).unwrap();
+    let (n, from) = recvfrom(&a, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..n], b"world");
+    assert_eq!(from, Some(SocketAddrAny::Netlink(b_addr)));
+}
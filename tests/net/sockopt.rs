@@ -175,6 +175,42 @@ fn test_sockopts_socket(s: &OwnedFd) {
         assert_eq!(sockopt::get_socket_incoming_cpu(s).unwrap(), 3);
     }
 
+    // Check the initial values of the busy-polling options, set them, and
+    // check them. The kernel may not support busy-polling, and increasing
+    // the values requires `CAP_NET_ADMIN`.
+    #[cfg(target_os = "linux")]
+    {
+        match sockopt::get_socket_busy_poll(s) {
+            Ok(_) => match sockopt::set_socket_busy_poll(s, 50) {
+                Ok(()) => assert_eq!(sockopt::get_socket_busy_poll(s).unwrap(), 50),
+                Err(rustix::io::Errno::PERM) => {}
+                Err(err) => panic!("{:?}", err),
+            },
+            Err(rustix::io::Errno::NOPROTOOPT) => {}
+            Err(err) => panic!("{:?}", err),
+        }
+
+        match sockopt::get_socket_prefer_busy_poll(s) {
+            Ok(prefer) => {
+                assert!(!prefer);
+                match sockopt::set_socket_prefer_busy_poll(s, true) {
+                    Ok(()) => assert!(sockopt::get_socket_prefer_busy_poll(s).unwrap()),
+                    Err(rustix::io::Errno::PERM) => {}
+                    Err(err) => panic!("{:?}", err),
+                }
+            }
+            Err(rustix::io::Errno::NOPROTOOPT) => {}
+            Err(err) => panic!("{:?}", err),
+        }
+
+        // Nothing has been received yet.
+        match sockopt::get_socket_incoming_napi_id(s) {
+            Ok(id) => assert_eq!(id, 0),
+            Err(rustix::io::Errno::NOPROTOOPT) => {}
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+
     // Check the initial value of SO_NOSIGPIPE, set it, and check it.
     #[cfg(any(apple, freebsdlike, target_os = "netbsd"))]
     {
@@ -214,6 +250,19 @@ fn test_sockopts_tcp(s: &OwnedFd) {
     // Check that the nodelay flag is cleared.
     assert!(!sockopt::get_tcp_nodelay(s).unwrap());
 
+    #[cfg(not(windows))]
+    {
+        // Check the maximum segment size.
+        assert_ne!(sockopt::get_tcp_maxseg(s).unwrap(), 0);
+
+        // On Linux, an unconnected socket reports the user-requested value.
+        #[cfg(linux_kernel)]
+        {
+            sockopt::set_tcp_maxseg(s, 1200).unwrap();
+            assert_eq!(sockopt::get_tcp_maxseg(s).unwrap(), 1200);
+        }
+    }
+
     #[cfg(not(any(target_os = "openbsd", target_os = "haiku", target_os = "nto")))]
     {
         // Set keepalive values:
@@ -288,6 +337,14 @@ fn test_sockopts_tcp(s: &OwnedFd) {
         sockopt::set_tcp_cork(s, true).unwrap();
         assert!(sockopt::get_tcp_cork(s).unwrap());
     }
+
+    // Check the initial value of TCP_NOPUSH, set it, and check it.
+    #[cfg(any(apple, freebsdlike, target_os = "openbsd"))]
+    {
+        assert!(!sockopt::get_tcp_nopush(s).unwrap());
+        sockopt::set_tcp_nopush(s, true).unwrap();
+        assert!(sockopt::get_tcp_nopush(s).unwrap());
+    }
 }
 
 #[test]
@@ -493,3 +550,278 @@ fn test_sockopts_ipv6() {
 
     test_sockopts_tcp(&s);
 }
+
+/// Test that `get_socket_error` reports, and then clears, the outcome of a
+/// failed nonblocking `connect`.
+#[cfg(feature = "event")]
+#[cfg(not(windows))]
+#[test]
+fn test_sockopts_socket_error_connect() {
+    use rustix::net::{
+        bind_v4, connect_v4, getsockname, socket, Ipv4Addr, SocketAddrAny, SocketAddrV4,
+    };
+
+    // Find a port with nothing listening on it.
+    let addr = {
+        let s = socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+        bind_v4(&s, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+        match getsockname(&s).unwrap() {
+            SocketAddrAny::V4(addr) => addr,
+            _ => panic!(),
+        }
+    };
+
+    let s = socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    io::ioctl_fionbio(&s, true).unwrap();
+    match connect_v4(&s, &addr) {
+        Err(io::Errno::INPROGRESS) => {
+            assert!(rustix::event::wait_writable(&s, None).unwrap());
+            assert_eq!(
+                sockopt::get_socket_error(&s).unwrap(),
+                Err(io::Errno::CONNREFUSED)
+            );
+        }
+        Err(io::Errno::CONNREFUSED) => {}
+        otherwise => panic!("unexpected connect result: {:?}", otherwise),
+    }
+
+    // Reading the error cleared it.
+    assert_eq!(sockopt::get_socket_error(&s).unwrap(), Ok(()));
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_sockopts_original_dst() {
+    use rustix::net::{accept, bind, connect_any, getsockname, listen};
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+    // Connections that weren't redirected have no original destination.
+    for (family, ip) in [
+        (AddressFamily::INET, IpAddr::V4(Ipv4Addr::LOCALHOST)),
+        (AddressFamily::INET6, IpAddr::V6(Ipv6Addr::LOCALHOST)),
+    ] {
+        let listener = rustix::net::socket(family, SocketType::STREAM, None).unwrap();
+        bind(&listener, &SocketAddr::new(ip, 0)).unwrap();
+        listen(&listener, 1).unwrap();
+
+        let client = rustix::net::socket(family, SocketType::STREAM, None).unwrap();
+        connect_any(&client, &getsockname(&listener).unwrap()).unwrap();
+        let conn = accept(&listener).unwrap();
+
+        assert!(matches!(
+            sockopt::get_original_dst(&conn),
+            Err(io::Errno::NOENT | io::Errno::NOPROTOOPT)
+        ));
+    }
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_sockopts_transparent() {
+    let s = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    assert!(!sockopt::get_ip_transparent(&s).unwrap());
+    match sockopt::set_ip_transparent(&s, true) {
+        Ok(()) => assert!(sockopt::get_ip_transparent(&s).unwrap()),
+        // Setting `IP_TRANSPARENT` requires privileges.
+        Err(err) => assert_eq!(err, io::Errno::PERM),
+    }
+
+    let s = rustix::net::socket(AddressFamily::INET6, SocketType::DGRAM, None).unwrap();
+    assert!(!sockopt::get_ipv6_transparent(&s).unwrap());
+    match sockopt::set_ipv6_transparent(&s, true) {
+        Ok(()) => assert!(sockopt::get_ipv6_transparent(&s).unwrap()),
+        Err(err) => assert_eq!(err, io::Errno::PERM),
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_sockopts_reuseport_bpf() {
+    use rustix::net::{bind, getsockname, recv, sendto_any, RecvFlags, SendFlags, SockFilter};
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    let first = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    sockopt::set_socket_reuseport(&first, true).unwrap();
+    bind(&first, &SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0)).unwrap();
+    let addr = getsockname(&first).unwrap();
+
+    let second = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    sockopt::set_socket_reuseport(&second, true).unwrap();
+    rustix::net::bind_any(&second, &addr).unwrap();
+
+    // No program is attached yet.
+    match sockopt::set_socket_detach_reuseport_bpf(&first) {
+        Err(io::Errno::NOENT) => {}
+        // `SO_DETACH_REUSEPORT_BPF` is new in Linux 5.3.
+        Err(io::Errno::NOPROTOOPT) => return,
+        otherwise => panic!("{:?}", otherwise),
+    }
+
+    // `BPF_RET | BPF_K` with 1: select the second socket in the group.
+    let program = [SockFilter {
+        code: 0x06,
+        jt: 0,
+        jf: 0,
+        k: 1,
+    }];
+    sockopt::set_socket_attach_reuseport_cbpf(&first, &program).unwrap();
+
+    let sender = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    let mut buf = [0_u8; 8];
+    for _ in 0..8 {
+        sendto_any(&sender, b"hello", SendFlags::empty(), &addr).unwrap();
+        assert_eq!(recv(&second, &mut buf, RecvFlags::empty()).unwrap(), 5);
+    }
+    assert_eq!(
+        recv(&first, &mut buf, RecvFlags::DONTWAIT),
+        Err(io::Errno::AGAIN)
+    );
+
+    // Replace the program with one which selects the first socket.
+    let program = [SockFilter {
+        code: 0x06,
+        jt: 0,
+        jf: 0,
+        k: 0,
+    }];
+    sockopt::set_socket_attach_reuseport_cbpf(&second, &program).unwrap();
+    for _ in 0..8 {
+        sendto_any(&sender, b"hello", SendFlags::empty(), &addr).unwrap();
+        assert_eq!(recv(&first, &mut buf, RecvFlags::empty()).unwrap(), 5);
+    }
+    assert_eq!(
+        recv(&second, &mut buf, RecvFlags::DONTWAIT),
+        Err(io::Errno::AGAIN)
+    );
+
+    sockopt::set_socket_detach_reuseport_bpf(&second).unwrap();
+    assert_eq!(
+        sockopt::set_socket_detach_reuseport_bpf(&second),
+        Err(io::Errno::NOENT)
+    );
+
+    // A file descriptor which isn't an eBPF program is rejected.
+    assert_eq!(
+        sockopt::set_socket_attach_reuseport_ebpf(&first, &sender),
+        Err(io::Errno::INVAL)
+    );
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_sockopts_mtu_discover() {
+    use rustix::net::{bind, connect_any, getsockname, send, SendFlags};
+    use sockopt::PathMtuDiscovery;
+    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+
+    let modes = [
+        PathMtuDiscovery::Dont,
+        PathMtuDiscovery::Want,
+        PathMtuDiscovery::Do,
+        PathMtuDiscovery::Probe,
+        PathMtuDiscovery::Interface,
+        PathMtuDiscovery::Omit,
+    ];
+
+    let s = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    for mode in modes {
+        sockopt::set_ip_mtu_discover(&s, mode).unwrap();
+        assert_eq!(sockopt::get_ip_mtu_discover(&s).unwrap(), mode);
+    }
+
+    // The path MTU is only known once the socket is connected.
+    assert_eq!(sockopt::get_ip_mtu(&s), Err(io::Errno::NOTCONN));
+    bind(&s, &SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0)).unwrap();
+    connect_any(&s, &getsockname(&s).unwrap()).unwrap();
+    assert!(sockopt::get_ip_mtu(&s).unwrap() >= 68);
+
+    let s = rustix::net::socket(AddressFamily::INET6, SocketType::DGRAM, None).unwrap();
+    for mode in modes {
+        sockopt::set_ipv6_mtu_discover(&s, mode).unwrap();
+        assert_eq!(sockopt::get_ipv6_mtu_discover(&s).unwrap(), mode);
+    }
+
+    assert_eq!(sockopt::get_ipv6_mtu(&s), Err(io::Errno::NOTCONN));
+    assert_eq!(sockopt::set_ipv6_mtu(&s, 1000), Err(io::Errno::INVAL));
+    bind(&s, &SocketAddr::new(Ipv6Addr::LOCALHOST.into(), 0)).unwrap();
+    connect_any(&s, &getsockname(&s).unwrap()).unwrap();
+    assert!(sockopt::get_ipv6_mtu(&s).unwrap() >= 1280);
+
+    // With `Do`, datagrams larger than the MTU are rejected rather than
+    // fragmented.
+    sockopt::set_ipv6_mtu(&s, 1280).unwrap();
+    sockopt::set_ipv6_mtu_discover(&s, PathMtuDiscovery::Do).unwrap();
+    assert_eq!(
+        send(&s, &[0_u8; 2000], SendFlags::empty()),
+        Err(io::Errno::MSGSIZE)
+    );
+    sockopt::set_ipv6_mtu_discover(&s, PathMtuDiscovery::Want).unwrap();
+    assert_eq!(send(&s, &[0_u8; 2000], SendFlags::empty()), Ok(2000));
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_sockopts_buffer_size_force() {
+    let s = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+
+    // Linux doubles the requested size.
+    sockopt::set_socket_recv_buffer_size(&s, 8192).unwrap();
+    assert_eq!(sockopt::get_socket_recv_buffer_size(&s).unwrap(), 16384);
+    sockopt::set_socket_send_buffer_size(&s, 8192).unwrap();
+    assert_eq!(sockopt::get_socket_send_buffer_size(&s).unwrap(), 16384);
+
+    // The forcing variants can exceed the `rmem_max` and `wmem_max` limits.
+    let size_above = |limit: &str| -> Option<usize> {
+        let max: usize = std::fs::read_to_string(limit).ok()?.trim().parse().ok()?;
+        Some(max + 8192).filter(|size| *size <= i32::MAX as usize / 2)
+    };
+    if let Some(size) = size_above("/proc/sys/net/core/rmem_max") {
+        match sockopt::set_socket_recv_buffer_size_force(&s, size) {
+            Ok(()) => assert_eq!(sockopt::get_socket_recv_buffer_size(&s).unwrap(), size * 2),
+            // Forcing the size requires privileges.
+            Err(err) => assert_eq!(err, io::Errno::PERM),
+        }
+    }
+    if let Some(size) = size_above("/proc/sys/net/core/wmem_max") {
+        match sockopt::set_socket_send_buffer_size_force(&s, size) {
+            Ok(()) => assert_eq!(sockopt::get_socket_send_buffer_size(&s).unwrap(), size * 2),
+            Err(err) => assert_eq!(err, io::Errno::PERM),
+        }
+    }
+}
+
+#[cfg(not(windows))]
+#[test]
+fn test_sockopts_linger_reset() {
+    use rustix::net::{accept, bind, connect_any, getsockname, listen, recv, RecvFlags};
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    let listener = rustix::net::socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    bind(&listener, &SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0)).unwrap();
+    listen(&listener, 1).unwrap();
+
+    let client = rustix::net::socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    connect_any(&client, &getsockname(&listener).unwrap()).unwrap();
+    let conn = accept(&listener).unwrap();
+
+    // Timeouts are rounded up to whole seconds.
+    sockopt::set_socket_linger(&client, Some(Duration::from_millis(1500))).unwrap();
+    assert_eq!(
+        sockopt::get_socket_linger(&client).unwrap(),
+        Some(Duration::from_secs(2))
+    );
+
+    // A zero timeout makes closing the socket reset the connection.
+    sockopt::set_socket_linger(&client, Some(Duration::ZERO)).unwrap();
+    assert_eq!(
+        sockopt::get_socket_linger(&client).unwrap(),
+        Some(Duration::ZERO)
+    );
+    drop(client);
+
+    let mut buf = [0_u8; 8];
+    assert_eq!(
+        recv(&conn, &mut buf, RecvFlags::empty()),
+        Err(io::Errno::CONNRESET)
+    );
+}
@@ -580,6 +580,98 @@ fn test_unix_msg_with_scm_rights() {
     server.join().unwrap();
 }
 
+/// Send more file descriptors than the receiver has room for, and check that
+/// `RecvFlags::CTRUNC` reports the truncation.
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_unix_msg_with_scm_rights_truncated() {
+    use rustix::fd::AsFd;
+    use rustix::io::{IoSlice, IoSliceMut};
+    use rustix::net::{
+        recvmsg, sendmsg, RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags,
+        SendAncillaryBuffer, SendAncillaryMessage, SendFlags, SocketFlags,
+    };
+    use rustix::pipe::pipe;
+
+    let (send_sock, recv_sock) = rustix::net::socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        None,
+    )
+    .unwrap();
+
+    let (read_end, write_end) = pipe().unwrap();
+    // Send enough descriptors that they can't all fit in the alignment slack
+    // of a one-descriptor control buffer.
+    let fds = [read_end.as_fd(), write_end.as_fd()].repeat(4);
+    let mut space = [0; rustix::cmsg_space!(ScmRights(8))];
+    let mut cmsg_buffer = SendAncillaryBuffer::new(&mut space);
+    assert!(cmsg_buffer.push(SendAncillaryMessage::ScmRights(&fds)));
+    sendmsg(
+        &send_sock,
+        &[IoSlice::new(b"x")],
+        &mut cmsg_buffer,
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let mut buffer = [0; 1];
+    let mut space = [0; rustix::cmsg_space!(ScmRights(1))];
+    let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+    let result = recvmsg(
+        &recv_sock,
+        &mut [IoSliceMut::new(&mut buffer)],
+        &mut cmsg_buffer,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(result.bytes, 1);
+    assert!(result.flags.contains(RecvFlags::CTRUNC));
+
+    // Whatever descriptors did fit are still delivered.
+    let received = cmsg_buffer
+        .drain()
+        .filter_map(|msg| match msg {
+            RecvAncillaryMessage::ScmRights(rights) => Some(rights),
+            _ => None,
+        })
+        .flatten()
+        .count();
+    assert!(received < fds.len());
+}
+
+#[cfg(all(
+    feature = "process",
+    any(
+        linux_kernel,
+        apple,
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    )
+))]
+#[test]
+fn test_unix_peercred() {
+    use rustix::net::{sockopt, SocketFlags};
+    use rustix::process::{getegid, geteuid, getpid};
+
+    let (a, b) = rustix::net::socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        None,
+    )
+    .unwrap();
+
+    for sock in [&a, &b] {
+        let ucred = sockopt::get_socket_peercred(sock).unwrap();
+        assert_eq!(ucred.pid, getpid());
+        assert_eq!(ucred.uid, geteuid());
+        assert_eq!(ucred.gid, getegid());
+    }
+}
+
 #[cfg(all(feature = "process", linux_kernel))]
 #[test]
 fn test_unix_peercred_explicit() {
@@ -687,6 +779,116 @@ fn test_unix_peercred_implicit() {
     };
 }
 
+#[cfg(linux_kernel)]
+#[test]
+fn test_unix_peersec() {
+    use rustix::io::{IoSlice, IoSliceMut};
+    use rustix::net::{
+        recvmsg, sendmsg, sockopt, RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags,
+        SendAncillaryBuffer, SendFlags, SocketFlags,
+    };
+
+    let (send_sock, recv_sock) = rustix::net::socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        None,
+    )
+    .unwrap();
+
+    // Without an LSM which provides labels, there's no label to get.
+    let label = match sockopt::get_socket_peersec(&send_sock, Vec::new()) {
+        Ok(label) => Some(label),
+        Err(rustix::io::Errno::NOPROTOOPT) => None,
+        Err(err) => panic!("{:?}", err),
+    };
+    if let Some(label) = &label {
+        // A fixed-size buffer gets the same label, or fails if it's too small.
+        let mut buf = [0_u8; 256];
+        match sockopt::get_socket_peersec(&send_sock, &mut buf) {
+            Ok(fixed) => assert_eq!(fixed, label.as_c_str()),
+            Err(err) => assert_eq!(err, rustix::io::Errno::RANGE),
+        }
+    }
+
+    assert!(!sockopt::get_socket_passsec(&recv_sock).unwrap());
+    sockopt::set_socket_passsec(&recv_sock, true).unwrap();
+    assert!(sockopt::get_socket_passsec(&recv_sock).unwrap());
+
+    sendmsg(
+        &send_sock,
+        &[IoSlice::new(b"sec")],
+        &mut SendAncillaryBuffer::default(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let mut cmsg_space = [0; rustix::cmsg_space!(ScmSecurity(256))];
+    let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut cmsg_space);
+
+    let mut buffer = [0; BUFFER_SIZE];
+    recvmsg(
+        &recv_sock,
+        &mut [IoSliceMut::new(&mut buffer)],
+        &mut cmsg_buffer,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+
+    let received = cmsg_buffer.drain().find_map(|msg| match msg {
+        RecvAncillaryMessage::ScmSecurity(label) => Some(label.to_vec()),
+        _ => None,
+    });
+    if let (Some(label), Some(received)) = (&label, &received) {
+        assert_eq!(label.as_bytes(), &received[..]);
+    }
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_unix_autobind() {
+    use rustix::net::{getsockname, recvfrom, sendto_unix, RecvFlags, SendFlags, SocketAddrAny};
+
+    let recv_sock = socket(AddressFamily::UNIX, SocketType::DGRAM, None).unwrap();
+    let send_sock = socket(AddressFamily::UNIX, SocketType::DGRAM, None).unwrap();
+
+    // An unbound socket has an unnamed address.
+    match getsockname(&recv_sock).unwrap() {
+        SocketAddrAny::Unix(addr) => assert!(addr.is_unnamed()),
+        other => panic!("unexpected address {:?}", other),
+    }
+
+    // Binding to an unnamed address autobinds to a unique abstract name of
+    // five hex digits.
+    bind_unix(&recv_sock, &SocketAddrUnix::new_unnamed()).unwrap();
+    let recv_addr = match getsockname(&recv_sock).unwrap() {
+        SocketAddrAny::Unix(addr) => addr,
+        other => panic!("unexpected address {:?}", other),
+    };
+    let name = recv_addr.abstract_name().unwrap();
+    assert_eq!(name.len(), 5);
+    assert!(name.iter().all(u8::is_ascii_hexdigit));
+
+    // Messages from an unbound socket have no address.
+    sendto_unix(&send_sock, b"hello", SendFlags::empty(), &recv_addr).unwrap();
+    let mut buffer = [0; BUFFER_SIZE];
+    let (n, from) = recvfrom(&recv_sock, &mut buffer, RecvFlags::empty()).unwrap();
+    assert_eq!(&buffer[..n], b"hello");
+    match from {
+        None => {}
+        Some(SocketAddrAny::Unix(addr)) => assert!(addr.is_unnamed()),
+        other => panic!("unexpected address {:?}", other),
+    }
+
+    // Once the sender is autobound, its messages come from its name.
+    bind_unix(&send_sock, &SocketAddrUnix::new_unnamed()).unwrap();
+    let send_addr = getsockname(&send_sock).unwrap();
+    sendto_unix(&send_sock, b"world", SendFlags::empty(), &recv_addr).unwrap();
+    let (n, from) = recvfrom(&recv_sock, &mut buffer, RecvFlags::empty()).unwrap();
+    assert_eq!(&buffer[..n], b"world");
+    assert_eq!(from, Some(send_addr));
+}
+
 /// Like `test_unix_msg_with_scm_rights`, but with multiple file descriptors
 /// over multiple control messages.
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
@@ -0,0 +1,47 @@
+use rustix::net::vsock::{SocketAddrVsock, VMADDR_CID_ANY, VMADDR_PORT_ANY};
+use rustix::net::{
+    bind_vsock, getsockname, socket_with, AddressFamily, SocketAddrAny, SocketAddrStorage,
+    SocketFlags, SocketType,
+};
+
+#[test]
+fn test_vsock_encode_decode() {
+    let orig = SocketAddrVsock::new(3, 5000);
+    assert_eq!(orig.cid(), 3);
+    assert_eq!(orig.port(), 5000);
+
+    let any = SocketAddrAny::from(orig);
+    assert_eq!(any.address_family(), AddressFamily::VSOCK);
+
+    unsafe {
+        let mut encoded = std::mem::MaybeUninit::<SocketAddrStorage>::uninit();
+        let len = any.write(encoded.as_mut_ptr());
+        let decoded = SocketAddrAny::read(encoded.as_ptr(), len).unwrap();
+        assert_eq!(decoded, SocketAddrAny::Vsock(orig));
+    }
+}
+
+#[test]
+fn test_vsock_bind() {
+    let fd = match socket_with(
+        AddressFamily::VSOCK,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        None,
+    ) {
+        Ok(fd) => fd,
+        // vsock may be unavailable, such as when the module isn't loaded.
+        Err(rustix::io::Errno::AFNOSUPPORT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    bind_vsock(&fd, &SocketAddrVsock::new(VMADDR_CID_ANY, VMADDR_PORT_ANY)).unwrap();
+
+    match getsockname(&fd).unwrap() {
+        SocketAddrAny::Vsock(addr) => {
+            assert_eq!(addr.cid(), VMADDR_CID_ANY);
+            assert_ne!(addr.port(), VMADDR_PORT_ANY);
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+}
@@ -117,6 +117,30 @@ fn test_arg() {
     assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
     assert_eq!(cstr!("hello"), Borrow::borrow(&t.into_c_str().unwrap()));
 
+    let t: Cow<'_, Path> = Cow::Borrowed(Path::new("hello"));
+    assert_eq!("hello", t.as_str().unwrap());
+    assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
+    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(cstr!("hello"), Borrow::borrow(&t.into_c_str().unwrap()));
+
+    let t: Cow<'_, Path> = Cow::Owned(PathBuf::from("hello"));
+    assert_eq!("hello", t.as_str().unwrap());
+    assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
+    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(cstr!("hello"), Borrow::borrow(&t.into_c_str().unwrap()));
+
+    let t: Cow<'_, [u8]> = Cow::Borrowed(b"hello");
+    assert_eq!("hello", t.as_str().unwrap());
+    assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
+    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(cstr!("hello"), Borrow::borrow(&t.into_c_str().unwrap()));
+
+    let t: Cow<'_, [u8]> = Cow::Owned(b"hello".to_vec());
+    assert_eq!("hello", t.as_str().unwrap());
+    assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
+    assert_eq!(cstr!("hello"), Borrow::borrow(&t.as_cow_c_str().unwrap()));
+    assert_eq!(cstr!("hello"), Borrow::borrow(&t.into_c_str().unwrap()));
+
     let t: &[u8] = b"hello";
     assert_eq!("hello", t.as_str().unwrap());
     assert_eq!("hello".to_owned(), Arg::to_string_lossy(&t));
@@ -165,3 +189,43 @@ fn test_embedded_nul() {
     assert_eq!(t.as_cow_c_str().unwrap_err(), io::Errno::INVAL);
     assert_eq!(t.into_c_str().unwrap_err(), io::Errno::INVAL);
 }
+
+#[test]
+fn test_into_with_c_str_owned() {
+    // Owned strings are passed through a stack buffer when they're short, and
+    // a `CString` when they're long; both should behave the same.
+    for len in [5, 10_000] {
+        let s = "x".repeat(len);
+        let check = |c: &CStr| {
+            assert_eq!(c.to_bytes(), s.as_bytes());
+            Ok(())
+        };
+        s.clone().into_with_c_str(check).unwrap();
+        OsString::from(s.clone()).into_with_c_str(check).unwrap();
+        PathBuf::from(s.clone()).into_with_c_str(check).unwrap();
+        s.clone().into_bytes().into_with_c_str(check).unwrap();
+        Cow::<Path>::Owned(PathBuf::from(s.clone()))
+            .into_with_c_str(check)
+            .unwrap();
+        Cow::<[u8]>::Owned(s.clone().into_bytes())
+            .into_with_c_str(check)
+            .unwrap();
+
+        let nul = format!("{}\0", s);
+        let fail = |_: &CStr| -> io::Result<()> { panic!() };
+        assert_eq!(
+            nul.clone().into_with_c_str(fail).unwrap_err(),
+            io::Errno::INVAL
+        );
+        assert_eq!(
+            PathBuf::from(nul.clone())
+                .into_with_c_str(fail)
+                .unwrap_err(),
+            io::Errno::INVAL
+        );
+        assert_eq!(
+            nul.into_bytes().into_with_c_str(fail).unwrap_err(),
+            io::Errno::INVAL
+        );
+    }
+}
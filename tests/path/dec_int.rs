@@ -18,3 +18,13 @@ fn test_dec_int() {
         u64::MAX.to_string()
     );
 }
+
+#[test]
+fn test_dec_int_128() {
+    assert_eq!(DecInt::new(u128::MAX).as_str(), u128::MAX.to_string());
+    assert_eq!(DecInt::new(i128::MIN).as_str(), i128::MIN.to_string());
+    assert_eq!(
+        DecInt::new(i128::MIN).as_c_str().to_bytes(),
+        i128::MIN.to_string().as_bytes()
+    );
+}
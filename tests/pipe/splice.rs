@@ -125,3 +125,58 @@ fn test_vmsplice_read() {
     assert_eq!(&outputs.1, b" ");
     assert_eq!(&outputs.2, b"world");
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_splice_socket_proxy() {
+    use rustix::pipe::{pipe, splice, SpliceFlags};
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+
+    let (mut client, proxy_in) = UnixStream::pair().unwrap();
+    let (proxy_out, mut server) = UnixStream::pair().unwrap();
+    let (read_p, write_p) = pipe().unwrap();
+
+    client.write_all(b"hello world").unwrap();
+    drop(client);
+
+    // Forward everything from `proxy_in` to `proxy_out` through the pipe.
+    let mut total = 0;
+    loop {
+        let n = splice(&proxy_in, None, &write_p, None, 4, SpliceFlags::MORE).unwrap();
+        if n == 0 {
+            break;
+        }
+        let mut left = n;
+        while left > 0 {
+            left -= splice(&read_p, None, &proxy_out, None, left, SpliceFlags::MORE).unwrap();
+        }
+        total += n;
+    }
+    assert_eq!(total, 11);
+    drop(proxy_out);
+
+    let mut buf = Vec::new();
+    server.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"hello world");
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_splice_nonblock() {
+    use rustix::io;
+    use rustix::pipe::{pipe, splice, tee, SpliceFlags};
+
+    let (read_p1, _write_p1) = pipe().unwrap();
+    let (_read_p2, write_p2) = pipe().unwrap();
+
+    // Nothing has been written to the first pipe, so these would block.
+    assert_eq!(
+        splice(&read_p1, None, &write_p2, None, 5, SpliceFlags::NONBLOCK),
+        Err(io::Errno::AGAIN)
+    );
+    assert_eq!(
+        tee(&read_p1, &write_p2, 5, SpliceFlags::NONBLOCK),
+        Err(io::Errno::AGAIN)
+    );
+}
@@ -0,0 +1,38 @@
+use rustix::io;
+use rustix::process::*;
+
+#[test]
+fn test_cap_rights_set() {
+    let mut rights = CapRights::new(&[CapRight::READ, CapRight::FSTAT]);
+    assert!(rights.is_set(CapRight::READ));
+    assert!(rights.is_set(CapRight::FSTAT));
+    assert!(!rights.is_set(CapRight::WRITE));
+    assert!(!rights.is_set(CapRight::PREAD));
+
+    rights.set(CapRight::EVENT);
+    assert!(rights.is_set(CapRight::EVENT));
+    rights.clear(CapRight::READ);
+    assert!(!rights.is_set(CapRight::READ));
+    assert!(rights.is_set(CapRight::FSTAT));
+
+    let all = CapRights::new(&[CapRight::FSTAT, CapRight::EVENT, CapRight::WRITE]);
+    assert!(all.contains(&rights));
+    assert!(!rights.contains(&all));
+    assert_eq!(CapRights::default(), CapRights::empty());
+}
+
+#[test]
+fn test_cap_rights_limit() {
+    assert!(!cap_getmode().unwrap());
+
+    let file = tempfile::tempfile().unwrap();
+    let rights = CapRights::new(&[CapRight::READ, CapRight::FSTAT]);
+    cap_rights_limit(&file, &rights).unwrap();
+    assert_eq!(cap_rights_get(&file).unwrap(), rights);
+
+    // Rights can't be added back once removed.
+    assert_eq!(
+        cap_rights_limit(&file, &CapRights::new(&[CapRight::WRITE])),
+        Err(io::Errno::NOTCAPABLE)
+    );
+}
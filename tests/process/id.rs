@@ -36,6 +36,34 @@ fn test_getegid() {
     }
 }
 
+#[cfg(any(linux_kernel, freebsdlike, target_os = "openbsd"))]
+#[test]
+fn test_getresuid() {
+    let ids = process::getresuid();
+    assert_eq!(ids.real, process::getuid());
+    assert_eq!(ids.effective, process::geteuid());
+
+    let (mut ruid, mut euid, mut suid) = (0, 0, 0);
+    unsafe {
+        assert_eq!(libc::getresuid(&mut ruid, &mut euid, &mut suid), 0);
+    }
+    assert_eq!(ids.saved.as_raw(), suid);
+}
+
+#[cfg(any(linux_kernel, freebsdlike, target_os = "openbsd"))]
+#[test]
+fn test_getresgid() {
+    let ids = process::getresgid();
+    assert_eq!(ids.real, process::getgid());
+    assert_eq!(ids.effective, process::getegid());
+
+    let (mut rgid, mut egid, mut sgid) = (0, 0, 0);
+    unsafe {
+        assert_eq!(libc::getresgid(&mut rgid, &mut egid, &mut sgid), 0);
+    }
+    assert_eq!(ids.saved.as_raw(), sgid);
+}
+
 #[test]
 fn test_getpid() {
     assert_eq!(process::getpid(), process::getpid());
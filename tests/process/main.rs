@@ -4,6 +4,8 @@
 #![cfg(not(windows))]
 #![cfg_attr(core_c_str, feature(core_c_str))]
 
+#[cfg(target_os = "freebsd")]
+mod capsicum;
 mod cpu_set;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
@@ -11,6 +13,8 @@ mod id;
 mod membarrier;
 #[cfg(target_os = "linux")]
 mod pidfd;
+#[cfg(target_os = "openbsd")]
+mod pledge;
 #[cfg(linux_kernel)]
 mod prctl;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))] // WASI doesn't have [gs]etpriority.
@@ -20,6 +24,10 @@ mod procctl;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 mod rlimit;
 mod sched;
+#[cfg(linux_kernel)]
+mod signalfd;
+#[cfg(all(linux_raw, feature = "fs", feature = "pipe"))]
+mod spawn;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have umask.
 mod umask;
 #[cfg(not(any(target_os = "espidf", target_os = "wasi")))] // WASI doesn't have waitpid.
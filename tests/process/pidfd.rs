@@ -39,8 +39,15 @@ fn test_pidfd_waitid() {
     .expect("failed to wait")
     .unwrap();
 
-    // TODO
-    let _ = status;
+    assert!(status.stopped());
+    assert_eq!(status.stopping_signal(), Some(SIGSTOP as _));
+
+    unsafe { kill(child.id() as _, libc::SIGKILL) };
+    process::waitid(
+        process::WaitId::PidFd(pidfd.as_fd()),
+        process::WaitidOptions::EXITED,
+    )
+    .expect("failed to wait");
 }
 
 #[cfg(feature = "event")]
@@ -87,6 +94,87 @@ fn test_pidfd_poll() {
     .expect("failed to wait")
     .unwrap();
 
-    // TODO
-    let _ = status;
+    assert!(status.exited());
+    assert_eq!(status.exit_status(), Some(0));
+}
+
+#[test]
+#[serial]
+fn test_pidfd_send_signal() {
+    use std::os::unix::process::ExitStatusExt;
+
+    // Create a new process.
+    let mut child = Command::new("sleep")
+        .arg("100")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .expect("failed to execute child");
+
+    // Create a pidfd for the child process.
+    let pid = process::Pid::from_child(&child);
+    let pidfd = match process::pidfd_open(pid, process::PidfdFlags::empty()) {
+        Ok(pidfd) => pidfd,
+        Err(e) if e == io::Errno::NOSYS => {
+            // The kernel does not support pidfds.
+            child.kill().unwrap();
+            child.wait().unwrap();
+            return;
+        }
+        Err(e) => panic!("failed to open pidfd: {}", e),
+    };
+
+    process::pidfd_send_signal(&pidfd, process::Signal::Term).unwrap();
+
+    // Check the status without reaping the child.
+    let status = process::waitid(
+        process::WaitId::PidFd(pidfd.as_fd()),
+        process::WaitidOptions::EXITED | process::WaitidOptions::NOWAIT,
+    )
+    .expect("failed to wait")
+    .unwrap();
+
+    assert!(status.killed());
+    assert_eq!(status.terminating_signal(), Some(libc::SIGTERM as _));
+    assert_eq!(child.wait().unwrap().signal(), Some(libc::SIGTERM));
+
+    // The process has been reaped, so signaling it fails, rather than
+    // signaling some other process which reused its pid.
+    assert_eq!(
+        process::pidfd_send_signal(&pidfd, process::Signal::Term),
+        Err(io::Errno::SRCH)
+    );
+}
+
+#[cfg(feature = "pipe")]
+#[test]
+fn test_pidfd_getfd() {
+    use rustix::fd::AsRawFd;
+
+    let pidfd = match process::pidfd_open(process::getpid(), process::PidfdFlags::empty()) {
+        Ok(pidfd) => pidfd,
+        Err(e) if e == io::Errno::NOSYS => return,
+        Err(e) => panic!("failed to open pidfd: {}", e),
+    };
+
+    // Duplicate a file descriptor through our own pidfd.
+    let (reader, writer) = rustix::pipe::pipe().unwrap();
+    let dup = match process::pidfd_getfd(
+        &pidfd,
+        writer.as_raw_fd(),
+        process::PidfdGetfdFlags::empty(),
+    ) {
+        Ok(dup) => dup,
+        // `pidfd_getfd` is new in Linux 5.6, and may be denied by security
+        // policies.
+        Err(e) if e == io::Errno::NOSYS || e == io::Errno::PERM => return,
+        Err(e) => panic!("failed to get fd: {}", e),
+    };
+    assert_ne!(dup.as_raw_fd(), writer.as_raw_fd());
+    drop(writer);
+
+    assert_eq!(rustix::io::write(&dup, b"hello"), Ok(5));
+    let mut buf = [0_u8; 5];
+    assert_eq!(rustix::io::read(&reader, &mut buf), Ok(5));
+    assert_eq!(&buf, b"hello");
 }
@@ -0,0 +1,8 @@
+use rustix::process::*;
+
+#[test]
+fn test_pledge_unchanged() {
+    // Passing `None` for both leaves the process's promises unchanged, so
+    // this doesn't restrict the other tests.
+    pledge(None, None).unwrap();
+}
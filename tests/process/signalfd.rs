@@ -0,0 +1,67 @@
+use rustix::process::{
+    getpid, signalfd, signalfd_read, signalfd_set_mask, sigprocmask, SigSet, SigmaskHow, Signal,
+    SignalfdFlags,
+};
+
+#[test]
+fn test_sig_set() {
+    let mut set = SigSet::new();
+    assert!(!set.contains(Signal::Usr1));
+
+    set.insert(Signal::Usr1);
+    set.insert(Signal::Term);
+    assert!(set.contains(Signal::Usr1));
+    assert!(set.contains(Signal::Term));
+    assert!(!set.contains(Signal::Usr2));
+
+    set.remove(Signal::Usr1);
+    assert!(!set.contains(Signal::Usr1));
+    assert!(set.contains(Signal::Term));
+
+    let set: SigSet = [Signal::Hup, Signal::Sys].into_iter().collect();
+    assert!(set.contains(Signal::Hup));
+    assert!(set.contains(Signal::Sys));
+    assert_eq!(format!("{:?}", set), "{Hup, Sys}");
+}
+
+#[test]
+fn test_signalfd() {
+    // Signal masks are per-thread, so do this in a new thread, and use
+    // thread-directed signals, so that the signal isn't delivered to any
+    // other thread.
+    std::thread::spawn(|| {
+        let mask: SigSet = [Signal::Usr1].into_iter().collect();
+
+        let old = sigprocmask(SigmaskHow::Block, Some(&mask)).unwrap();
+        assert!(!old.contains(Signal::Usr1));
+        assert!(sigprocmask(SigmaskHow::Block, None)
+            .unwrap()
+            .contains(Signal::Usr1));
+
+        let fd = signalfd(&mask, SignalfdFlags::CLOEXEC | SignalfdFlags::NONBLOCK).unwrap();
+        assert_eq!(signalfd_read(&fd).unwrap_err(), rustix::io::Errno::AGAIN);
+
+        unsafe {
+            assert_eq!(libc::raise(libc::SIGUSR1), 0);
+        }
+
+        let info = signalfd_read(&fd).unwrap();
+        assert_eq!(info.signal(), Some(Signal::Usr1));
+        assert_eq!(info.raw_signo(), libc::SIGUSR1);
+        assert_eq!(info.pid(), Some(getpid()));
+        assert_eq!(signalfd_read(&fd).unwrap_err(), rustix::io::Errno::AGAIN);
+
+        // Switch the signalfd to a different signal.
+        let mask2: SigSet = [Signal::Usr2].into_iter().collect();
+        signalfd_set_mask(&fd, &mask2).unwrap();
+        sigprocmask(SigmaskHow::Block, Some(&mask2)).unwrap();
+        unsafe {
+            assert_eq!(libc::raise(libc::SIGUSR2), 0);
+        }
+        assert_eq!(signalfd_read(&fd).unwrap().signal(), Some(Signal::Usr2));
+
+        sigprocmask(SigmaskHow::SetMask, Some(&old)).unwrap();
+    })
+    .join()
+    .unwrap();
+}
@@ -0,0 +1,129 @@
+use rustix::fd::AsFd;
+use rustix::io::Errno;
+use rustix::pipe::PipeFlags;
+use rustix::process::{getpgid, waitpid, Command, WaitOptions};
+use serial_test::serial;
+
+// These tests must execute serially with the tests in `wait.rs`, which wait
+// for any child process, and could reap the children spawned here.
+
+fn read_all(fd: impl AsFd) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut buf = [0_u8; 256];
+    loop {
+        match rustix::io::read(&fd, &mut buf) {
+            Ok(0) => return out,
+            Ok(n) => out.extend_from_slice(&buf[..n]),
+            Err(Errno::INTR) => continue,
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+}
+
+#[test]
+#[serial]
+fn test_spawn() {
+    let (reader, writer) = rustix::pipe::pipe_with(PipeFlags::CLOEXEC).unwrap();
+    let pid = Command::new("/bin/sh")
+        .args(["-c", "echo \"$GREETING\"; pwd"])
+        .env("GREETING", "hello")
+        .current_dir("/")
+        .dup2(writer.as_fd(), 1)
+        .spawn()
+        .unwrap();
+    drop(writer);
+
+    assert_eq!(read_all(&reader), b"hello\n/\n");
+    let status = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+}
+
+#[test]
+#[serial]
+fn test_spawn_errors() {
+    // Failures in the child are reported by `spawn`.
+    assert_eq!(Command::new("/no/such/program").spawn(), Err(Errno::NOENT));
+    assert_eq!(
+        Command::new("/bin/sh").current_dir("/no/such/dir").spawn(),
+        Err(Errno::NOENT)
+    );
+
+    // Invalid arguments are reported without forking.
+    assert_eq!(
+        Command::new("/bin/sh").arg("a\0b").spawn(),
+        Err(Errno::INVAL)
+    );
+    assert_eq!(
+        Command::new("/bin/sh").env("A=B", "C").spawn(),
+        Err(Errno::INVAL)
+    );
+}
+
+#[test]
+#[serial]
+fn test_spawn_file_actions() {
+    use rustix::fs::{Mode, OFlags};
+
+    // Give the child a stdin which is `/dev/null`, and a stdout which is the
+    // pipe, at fd 7, and close the original stdout.
+    let (reader, writer) = rustix::pipe::pipe_with(PipeFlags::CLOEXEC).unwrap();
+    let pid = Command::new("/bin/sh")
+        .args([
+            "-c",
+            "read line || echo eof >&7; { echo lost; } 2>/dev/null || echo closed >&7",
+        ])
+        .open(0, "/dev/null", OFlags::RDONLY, Mode::empty())
+        .dup2(writer.as_fd(), 7)
+        .close(1)
+        .spawn()
+        .unwrap();
+    drop(writer);
+
+    assert_eq!(read_all(&reader), b"eof\nclosed\n");
+    let status = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+}
+
+#[test]
+#[serial]
+fn test_spawn_process_group() {
+    // The child blocks reading the pipe until we close the write end.
+    let (reader, writer) = rustix::pipe::pipe_with(PipeFlags::CLOEXEC).unwrap();
+    let pid = Command::new("/bin/sh")
+        .args(["-c", "read line"])
+        .dup2(reader.as_fd(), 0)
+        .process_group(None)
+        .reset_signal_mask()
+        .spawn()
+        .unwrap();
+
+    assert_eq!(getpgid(Some(pid)), Ok(pid));
+
+    drop(writer);
+    waitpid(Some(pid), WaitOptions::empty()).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_spawn_vfork() {
+    let (reader, writer) = rustix::pipe::pipe_with(PipeFlags::CLOEXEC).unwrap();
+    let pid = Command::new("/bin/sh")
+        .args(["-c", "echo \"$GREETING\"; pwd"])
+        .env("GREETING", "hello")
+        .current_dir("/")
+        .dup2(writer.as_fd(), 1)
+        .vfork()
+        .spawn()
+        .unwrap();
+    drop(writer);
+
+    assert_eq!(read_all(&reader), b"hello\n/\n");
+    let status = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+
+    // Failures in the child are reported the same way.
+    assert_eq!(
+        Command::new("/no/such/program").vfork().spawn(),
+        Err(Errno::NOENT)
+    );
+}
@@ -86,6 +86,11 @@ fn test_waitid() {
     assert!(status.stopped());
     #[cfg(not(any(target_os = "fuchsia", target_os = "netbsd")))]
     assert_eq!(status.stopping_signal(), Some(SIGSTOP as _));
+    #[cfg(not(any(target_os = "fuchsia", target_os = "netbsd")))]
+    {
+        assert_eq!(status.pid(), Some(pid));
+        assert_eq!(status.uid(), process::getuid());
+    }
 
     unsafe { kill(child.id() as _, SIGCONT) };
 
@@ -142,6 +147,19 @@ fn test_waitid() {
     #[cfg(not(any(target_os = "fuchsia", target_os = "netbsd")))]
     assert_eq!(status.terminating_signal(), Some(SIGKILL as _));
 
+    // `NOWAIT` leaves the child waitable, so it's found again. Wait for it
+    // specifically, so that this doesn't find children of other tests.
+    let status = process::waitid(
+        process::WaitId::Pid(pid),
+        process::WaitidOptions::EXITED | process::WaitidOptions::NOWAIT,
+    )
+    .expect("failed to wait")
+    .unwrap();
+
+    assert!(status.killed());
+    #[cfg(not(any(target_os = "fuchsia", target_os = "netbsd")))]
+    assert_eq!(status.pid(), Some(pid));
+
     let status = process::waitid(process::WaitId::Pid(pid), process::WaitidOptions::EXITED)
         .expect("failed to wait")
         .unwrap();
@@ -23,6 +23,20 @@ fn test_changing_working_directory() {
         "rustix's cwd doesn't match std's"
     );
 
+    let mut buf = [0_u8; 4096];
+    assert_eq!(
+        rustix::process::getcwd_into(&mut buf).unwrap(),
+        orig_cwd.as_c_str()
+    );
+    assert_eq!(
+        rustix::process::getcwd_into(&mut [0_u8; 1]),
+        Err(rustix::io::Errno::RANGE)
+    );
+    assert_eq!(
+        rustix::process::getcwd_into(std::ffi::OsString::new()).unwrap(),
+        std::env::current_dir().unwrap().as_os_str()
+    );
+
     #[cfg(not(target_os = "fuchsia"))]
     let orig_fd_cwd = rustix::fs::openat(rustix::fs::CWD, ".", OFlags::RDONLY, Mode::empty())
         .expect("get a fd for the current directory");
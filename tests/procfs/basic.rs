@@ -14,3 +14,46 @@ fn test_status_twice() {
     let fd = rustix::procfs::proc_self_status().unwrap();
     drop(fd);
 }
+
+#[test]
+fn test_proc_self_fdinfo() {
+    use rustix::fs::{fstat, statat, AtFlags};
+
+    let dir = rustix::procfs::proc_self_fdinfo().unwrap();
+    assert_ne!(dir.as_raw_fd(), 0);
+
+    // The directory handle is cached, and agrees with the per-fd helper.
+    let again = rustix::procfs::proc_self_fdinfo().unwrap();
+    assert_eq!(dir.as_raw_fd(), again.as_raw_fd());
+
+    let file = rustix::procfs::proc_self_fdinfo_fd(dir).unwrap();
+    let name = dir.as_raw_fd().to_string();
+    let stat = statat(dir, name, AtFlags::empty()).unwrap();
+    assert_eq!(fstat(&file).unwrap().st_ino, stat.st_ino);
+}
+
+#[test]
+fn test_proc_self_fdinfo_contents() {
+    let dir = rustix::procfs::proc_self_fdinfo().unwrap();
+    let file = rustix::procfs::proc_self_fdinfo_fd(dir).unwrap();
+    let mut buf = [0_u8; 4096];
+    let n = rustix::io::read(&file, &mut buf).unwrap();
+    let contents = core::str::from_utf8(&buf[..n]).unwrap();
+    assert!(contents.contains("pos:"));
+    assert!(contents.contains("flags:"));
+}
+
+#[test]
+fn test_proc_self_maps() {
+    let file = rustix::procfs::proc_self_maps().unwrap();
+    let mut contents = Vec::new();
+    let mut buf = [0_u8; 4096];
+    loop {
+        let n = rustix::io::read(&file, &mut buf).unwrap();
+        if n == 0 {
+            break;
+        }
+        contents.extend_from_slice(&buf[..n]);
+    }
+    assert!(contents.windows(7).any(|w| w == b"[stack]"));
+}
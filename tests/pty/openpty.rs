@@ -74,3 +74,65 @@ fn openpty_get_peer() {
 
     assert_eq!(s, "Hello, world!\n");
 }
+
+// Like `openpty_basic` but use `open_user` instead of `ptsname`.
+#[test]
+fn openpty_open_user() {
+    // Use `CLOEXEC` if we can.
+    #[cfg(any(linux_kernel, target_os = "freebsd", target_os = "netbsd"))]
+    let flags = OpenptFlags::RDWR | OpenptFlags::NOCTTY | OpenptFlags::CLOEXEC;
+    #[cfg(not(any(linux_kernel, target_os = "freebsd", target_os = "netbsd")))]
+    let flags = OpenptFlags::RDWR | OpenptFlags::NOCTTY;
+
+    let controller = openpt(flags).unwrap();
+
+    grantpt(&controller).unwrap();
+    unlockpt(&controller).unwrap();
+
+    let user = open_user(&controller, flags).unwrap();
+
+    // The user side is the terminal named by `ptsname`.
+    let name = ptsname(&controller, Vec::new()).unwrap();
+    let mut buf = [0_u8; 64];
+    assert_eq!(
+        ptsname_into(&controller, &mut buf).unwrap(),
+        name.as_c_str()
+    );
+    let stat = rustix::fs::fstat(&user).unwrap();
+    let expected = rustix::fs::stat(name).unwrap();
+    assert_eq!(stat.st_rdev, expected.st_rdev);
+
+    let mut controller = File::from(controller);
+    let mut user = File::from(user);
+
+    // The `'\x04'` is Ctrl-D, the default EOF control code.
+    controller.write_all(b"Hello, world!\n\x04").unwrap();
+
+    let mut s = String::new();
+    user.read_to_string(&mut s).unwrap();
+
+    assert_eq!(s, "Hello, world!\n");
+}
+
+// The user side can't be opened until the controller is unlocked.
+#[cfg(linux_kernel)]
+#[test]
+fn openpty_locked() {
+    let flags = OpenptFlags::RDWR | OpenptFlags::NOCTTY | OpenptFlags::CLOEXEC;
+    let controller = openpt(flags).unwrap();
+    grantpt(&controller).unwrap();
+
+    let name = ptsname(&controller, Vec::new()).unwrap();
+    assert_eq!(
+        openat(CWD, &name, OFlags::from(flags), Mode::empty()).unwrap_err(),
+        rustix::io::Errno::IO
+    );
+    #[cfg(target_os = "linux")]
+    assert_eq!(
+        ioctl_tiocgptpeer(&controller, flags).unwrap_err(),
+        rustix::io::Errno::IO
+    );
+
+    unlockpt(&controller).unwrap();
+    open_user(&controller, flags).unwrap();
+}
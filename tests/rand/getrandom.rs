@@ -14,3 +14,31 @@ fn test_getrandom_uninit() {
     let combined_len = init.len() + uninit.len();
     assert_eq!(buf.len(), combined_len);
 }
+
+#[test]
+fn test_getrandom_fill() {
+    // Fill a buffer larger than a single `getentropy` call can.
+    let mut buf = [0_u8; 1000];
+    let mut filled = 0;
+    while filled < buf.len() {
+        let n = getrandom(&mut buf[filled..], GetRandomFlags::empty()).unwrap();
+        assert!(n > 0);
+        assert!(n <= buf.len() - filled);
+        filled += n;
+    }
+
+    // The odds of this being all zeros are negligible.
+    assert!(buf.iter().any(|b| *b != 0));
+}
+
+#[cfg(any(linux_kernel, freebsdlike, target_os = "netbsd"))]
+#[test]
+fn test_getrandom_nonblock() {
+    let mut buf = [0_u8; 16];
+    match getrandom(&mut buf, GetRandomFlags::NONBLOCK) {
+        Ok(n) => assert!(n <= buf.len()),
+        // The random number generator may not be seeded yet.
+        Err(rustix::io::Errno::AGAIN) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+}
@@ -3,5 +3,5 @@
 #![cfg(feature = "rand")]
 #![cfg(not(windows))]
 
-#[cfg(linux_kernel)]
+#[cfg(any(linux_kernel, apple, freebsdlike, netbsdlike))]
 mod getrandom;
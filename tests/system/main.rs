@@ -10,3 +10,5 @@ mod reboot;
 #[cfg(linux_kernel)]
 mod sysinfo;
 mod uname;
+#[cfg(linux_kernel)]
+mod uts;
@@ -0,0 +1,26 @@
+#[cfg(feature = "thread")]
+#[test]
+fn test_sethostname_setdomainname() {
+    use rustix::system::{setdomainname, sethostname, uname};
+    use rustix::thread::{unshare, UnshareFlags};
+
+    // Do this in a separate thread, so that the new UTS namespace doesn't
+    // affect the rest of the tests.
+    std::thread::spawn(|| {
+        match unshare(UnshareFlags::NEWUTS) {
+            Ok(()) => {}
+            // We may not have `CAP_SYS_ADMIN`.
+            Err(rustix::io::Errno::PERM) => return,
+            Err(err) => panic!("{:?}", err),
+        }
+
+        sethostname(b"rustix-test-host").unwrap();
+        setdomainname(b"rustix-test-domain").unwrap();
+
+        let name = uname();
+        assert_eq!(name.nodename().to_bytes(), b"rustix-test-host");
+        assert_eq!(name.domainname().to_bytes(), b"rustix-test-domain");
+    })
+    .join()
+    .unwrap();
+}
@@ -10,6 +10,8 @@ mod pgrp;
 mod sid;
 #[cfg(all(not(windows), feature = "pty"))]
 mod termios;
+#[cfg(any(linux_kernel, apple, freebsdlike))]
+mod tiocsti;
 #[cfg(not(any(windows, target_os = "fuchsia")))]
 #[cfg(feature = "procfs")]
 mod ttyname;
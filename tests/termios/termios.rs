@@ -81,6 +81,48 @@ fn test_termios_winsize() {
     assert_eq!(check_sizes.ws_ypixel, sizes.ws_ypixel);
 }
 
+#[cfg(feature = "fs")]
+#[test]
+fn test_termios_winsize_user() {
+    use rustix::fs::{openat, Mode, OFlags, CWD};
+    use rustix::pty::*;
+    use rustix::termios::*;
+
+    let pty = match openpt(OpenptFlags::RDWR | OpenptFlags::NOCTTY) {
+        Ok(pty) => pty,
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    grantpt(&pty).unwrap();
+    unlockpt(&pty).unwrap();
+    let name = ptsname(&pty, Vec::new()).unwrap();
+    let user = openat(CWD, name, OFlags::RDWR | OFlags::NOCTTY, Mode::empty()).unwrap();
+
+    // A size set on the controller side is seen on the user side.
+    let mut sizes = match tcgetwinsize(&pty) {
+        Ok(sizes) => sizes,
+        #[cfg(apple)]
+        Err(rustix::io::Errno::NOTTY) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    sizes.ws_row = 24;
+    sizes.ws_col = 80;
+    tcsetwinsize(&pty, sizes).unwrap();
+
+    let check_sizes = tcgetwinsize(&user).unwrap();
+    assert_eq!(check_sizes.ws_row, 24);
+    assert_eq!(check_sizes.ws_col, 80);
+
+    // And vice versa.
+    sizes.ws_row = 50;
+    sizes.ws_col = 132;
+    tcsetwinsize(&user, sizes).unwrap();
+
+    let check_sizes = tcgetwinsize(&pty).unwrap();
+    assert_eq!(check_sizes.ws_row, 50);
+    assert_eq!(check_sizes.ws_col, 132);
+}
+
 // Disable on illumos where `tcgetattr` doesn't appear to support
 // pseudoterminals.
 #[cfg(not(target_os = "illumos"))]
@@ -151,6 +193,22 @@ fn test_termios_speeds() {
             assert_eq!(new_tio.input_speed(), 51);
             assert_eq!(new_tio.output_speed(), 51);
         }
+
+        // Speeds above the largest `B*` constant, such as those used by 3D
+        // printers, are encoded with `BOTHER` on Linux.
+        tio.set_speed(250_000).unwrap();
+        assert_eq!(tio.input_speed(), 250_000);
+        assert_eq!(tio.output_speed(), 250_000);
+        tcsetattr(&pty, OptionalActions::Now, &tio).unwrap();
+
+        #[allow(unused_variables)]
+        let new_tio = tcgetattr(&pty).unwrap();
+
+        #[cfg(not(all(linux_kernel, any(target_arch = "powerpc", target_arch = "powerpc64"))))]
+        {
+            assert_eq!(new_tio.input_speed(), 250_000);
+            assert_eq!(new_tio.output_speed(), 250_000);
+        }
     }
 
     // These platforms are known to support differing input and output speeds.
@@ -178,3 +236,66 @@ fn test_termios_tcgetattr_not_tty() {
         rustix::io::Errno::NOTTY
     );
 }
+
+#[cfg(not(target_os = "nto"))]
+#[test]
+fn test_termios_make_raw() {
+    use rustix::pty::*;
+    use rustix::termios::*;
+
+    let pty = match openpt(OpenptFlags::empty()) {
+        Ok(pty) => pty,
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    let orig = match tcgetattr(&pty) {
+        Ok(tio) => tio,
+        Err(rustix::io::Errno::NOSYS) => return,
+        #[cfg(apple)]
+        Err(rustix::io::Errno::NOTTY) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let mut raw = orig.clone();
+    raw.make_raw();
+    tcsetattr(&pty, OptionalActions::Now, &raw).unwrap();
+
+    let check = tcgetattr(&pty).unwrap();
+    assert!(!check
+        .local_modes
+        .intersects(LocalModes::ECHO | LocalModes::ICANON | LocalModes::ISIG));
+    assert!(!check
+        .input_modes
+        .intersects(InputModes::ICRNL | InputModes::IXON));
+    assert!(!check.output_modes.contains(OutputModes::OPOST));
+    assert_eq!(check.special_codes[SpecialCodeIndex::VMIN], 1);
+    assert_eq!(check.special_codes[SpecialCodeIndex::VTIME], 0);
+
+    // Restore the original attributes.
+    tcsetattr(&pty, OptionalActions::Drain, &orig).unwrap();
+    let check = tcgetattr(&pty).unwrap();
+    assert_eq!(check.local_modes, orig.local_modes);
+    assert_eq!(check.input_modes, orig.input_modes);
+}
+
+#[test]
+fn test_termios_flow() {
+    use rustix::pty::*;
+    use rustix::termios::*;
+
+    let pty = match openpt(OpenptFlags::empty()) {
+        Ok(pty) => pty,
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    match tcgetattr(&pty) {
+        Ok(_) => {}
+        Err(rustix::io::Errno::NOSYS) => return,
+        #[cfg(apple)]
+        Err(rustix::io::Errno::NOTTY) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    tcflow(&pty, Action::OOff).unwrap();
+    tcflow(&pty, Action::OOn).unwrap();
+}
@@ -0,0 +1,49 @@
+use rustix::io::Errno;
+use rustix::termios::ioctl_tiocsti;
+use tempfile::tempdir;
+
+#[cfg(feature = "fs")]
+#[test]
+fn tiocsti_notty() {
+    let tmpdir = tempdir().unwrap();
+    let fd = rustix::fs::open(
+        tmpdir.path(),
+        rustix::fs::OFlags::RDONLY,
+        rustix::fs::Mode::empty(),
+    )
+    .unwrap();
+
+    // A file is not a tty.
+    assert_eq!(ioctl_tiocsti(&fd, b'x'), Err(Errno::NOTTY));
+}
+
+#[cfg(all(feature = "fs", feature = "pty"))]
+#[test]
+fn tiocsti_pseudoterminal() {
+    use rustix::fs::{openat, Mode, OFlags, CWD};
+    use rustix::pty::*;
+
+    let pty = match openpt(OpenptFlags::RDWR | OpenptFlags::NOCTTY) {
+        Ok(pty) => pty,
+        Err(Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    grantpt(&pty).unwrap();
+    unlockpt(&pty).unwrap();
+    let name = ptsname(&pty, Vec::new()).unwrap();
+    let user = openat(CWD, name, OFlags::RDWR | OFlags::NOCTTY, Mode::empty()).unwrap();
+
+    // This isn't our controlling terminal, so this requires privileges, and
+    // newer Linux kernels may have disabled it entirely.
+    match ioctl_tiocsti(&user, b'x') {
+        Ok(()) => {}
+        Err(Errno::PERM) | Err(Errno::IO) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    ioctl_tiocsti(&user, b'\n').unwrap();
+
+    // The injected input is read from the user side, as if typed.
+    let mut buf = [0_u8; 2];
+    assert_eq!(rustix::io::read(&user, &mut buf), Ok(2));
+    assert_eq!(&buf, b"x\n");
+}
@@ -13,3 +13,5 @@ mod libcap;
 mod prctl;
 #[cfg(linux_kernel)]
 mod setns;
+#[cfg(linux_kernel)]
+mod stack;
@@ -0,0 +1,38 @@
+use rustix::thread::sigaltstack_query;
+#[cfg(feature = "procfs")]
+use rustix::thread::stack_bounds;
+
+#[cfg(feature = "procfs")]
+fn check_stack_bounds() {
+    let local = 0_u8;
+    let addr = core::ptr::addr_of!(local) as usize;
+    let bounds = stack_bounds().unwrap();
+    assert!(bounds.contains(&addr), "{:#x} not in {:#x?}", addr, bounds);
+}
+
+#[cfg(feature = "procfs")]
+#[test]
+fn test_stack_bounds() {
+    check_stack_bounds();
+}
+
+#[cfg(feature = "procfs")]
+#[test]
+fn test_stack_bounds_spawned_thread() {
+    std::thread::Builder::new()
+        .stack_size(1 << 20)
+        .spawn(check_stack_bounds)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn test_sigaltstack_query() {
+    // Rust's std installs an alternate signal stack on threads it spawns,
+    // for stack overflow detection, but we aren't running on it.
+    if let Some(alt) = sigaltstack_query().unwrap() {
+        assert!(!alt.on_stack);
+        assert!(alt.bounds.start < alt.bounds.end);
+    }
+}
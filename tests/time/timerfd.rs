@@ -73,3 +73,74 @@ fn test_timerfd_with_interval() {
     assert_eq!(set.it_interval.tv_sec, new.it_interval.tv_sec);
     assert_eq!(set.it_interval.tv_nsec, new.it_interval.tv_nsec);
 }
+
+#[test]
+fn test_timerfd_read() {
+    use rustix::time::timerfd_read;
+
+    let fd = timerfd_create(
+        TimerfdClockId::Monotonic,
+        TimerfdFlags::CLOEXEC | TimerfdFlags::NONBLOCK,
+    )
+    .unwrap();
+
+    // The timer isn't armed yet.
+    assert_eq!(timerfd_read(&fd), Err(rustix::io::Errno::AGAIN));
+
+    let set = Itimerspec {
+        it_interval: Timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        },
+        it_value: Timespec {
+            tv_sec: 0,
+            tv_nsec: 1,
+        },
+    };
+    timerfd_settime(&fd, TimerfdTimerFlags::empty(), &set).unwrap();
+
+    // Wait for the timer to expire.
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    assert_eq!(timerfd_read(&fd), Ok(1));
+    assert_eq!(timerfd_read(&fd), Err(rustix::io::Errno::AGAIN));
+}
+
+/// Test waiting for a non-blocking timer with `poll`, as an event loop would.
+#[cfg(feature = "event")]
+#[test]
+fn test_timerfd_poll() {
+    use rustix::event::{poll, PollFd, PollFlags};
+    use rustix::time::timerfd_read;
+
+    let fd = timerfd_create(
+        TimerfdClockId::Monotonic,
+        TimerfdFlags::CLOEXEC | TimerfdFlags::NONBLOCK,
+    )
+    .unwrap();
+
+    // The timer isn't armed, so it isn't readable.
+    let mut fds = [PollFd::new(&fd, PollFlags::IN)];
+    assert_eq!(poll(&mut fds, 0).unwrap(), 0);
+
+    let set = Itimerspec {
+        it_interval: Timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        },
+        it_value: Timespec {
+            tv_sec: 0,
+            tv_nsec: 1_000_000,
+        },
+    };
+    timerfd_settime(&fd, TimerfdTimerFlags::empty(), &set).unwrap();
+
+    let mut fds = [PollFd::new(&fd, PollFlags::IN)];
+    assert_eq!(poll(&mut fds, -1).unwrap(), 1);
+    assert!(fds[0].revents().contains(PollFlags::IN));
+    assert_eq!(timerfd_read(&fd), Ok(1));
+
+    // Disarming the timer returns the previous setting.
+    let old = timerfd_settime(&fd, TimerfdTimerFlags::empty(), &Itimerspec::default()).unwrap();
+    assert_eq!(old.it_interval, set.it_interval);
+    assert_eq!(timerfd_gettime(&fd).unwrap(), Itimerspec::default());
+}
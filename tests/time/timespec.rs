@@ -32,3 +32,23 @@ fn test_timespec_layout() {
         tv_nsec: 999_999_999,
     };
 }
+
+#[test]
+fn test_timespec_y2038_secs() {
+    use rustix::time::Timespec;
+
+    // `tv_sec` is 64-bit on all platforms.
+    let t = Timespec {
+        tv_sec: i64::from(i32::MAX) + 1,
+        tv_nsec: 0,
+    };
+    assert_eq!(t.tv_sec, 0x8000_0000);
+    assert_ne!(t, Timespec::default());
+    assert_eq!(
+        Timespec::default(),
+        Timespec {
+            tv_sec: 0,
+            tv_nsec: 0
+        }
+    );
+}
@@ -0,0 +1,8 @@
+#[test]
+fn test_raw_fstat() {
+    use rustix::fd::AsRawFd;
+
+    let file = std::fs::File::open("Cargo.toml").unwrap();
+    let stat = unsafe { rustix::unsafe_fd::fs::fstat(file.as_raw_fd()).unwrap() };
+    assert_eq!(stat.st_size as u64, file.metadata().unwrap().len());
+}
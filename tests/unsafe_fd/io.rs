@@ -0,0 +1,25 @@
+#[cfg(feature = "fs")]
+#[test]
+fn test_raw_read_write() {
+    use rustix::fd::AsRawFd;
+    use rustix::fs::{openat, Mode, OFlags, CWD};
+    use rustix::unsafe_fd::io::{pread, write};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    let raw = file.as_raw_fd();
+
+    let mut buf = [0_u8; 5];
+    unsafe {
+        assert_eq!(write(raw, b"hello").unwrap(), 5);
+        assert_eq!(pread(raw, &mut buf, 0).unwrap(), 5);
+    }
+    assert_eq!(&buf, b"hello");
+}
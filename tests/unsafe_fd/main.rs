@@ -0,0 +1,8 @@
+//! Tests for [`rustix::unsafe_fd`].
+
+#![cfg(feature = "unsafe_fd")]
+#![cfg(not(windows))]
+
+#[cfg(feature = "fs")]
+mod fs;
+mod io;